@@ -1,11 +1,12 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
 
 use codemap::{Span, Spanned};
+use indexmap::IndexMap;
 
 use crate::{
-    common::Identifier,
+    common::{Identifier, QuoteKind},
     error::SassResult,
-    value::Value,
+    value::{SassMap, Value},
     {Cow, Token},
 };
 
@@ -35,7 +36,29 @@ impl FuncArgs {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct CallArgs(pub HashMap<CallArg, SassResult<Spanned<Value>>>, pub Span);
+pub(crate) struct CallArgs(
+    pub IndexMap<CallArg, SassResult<Spanned<Value>>>,
+    pub Span,
+    pub RequestedNames,
+);
+
+/// The parameter names a builtin has looked up
+///
+/// Builtins take ownership of their arguments, so this is shared with the
+/// caller in order to report keyword arguments that the builtin never asked
+/// for once it returns
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestedNames(Rc<RefCell<HashSet<Identifier>>>);
+
+impl RequestedNames {
+    fn insert(&self, name: Identifier) {
+        self.0.borrow_mut().insert(name);
+    }
+
+    fn contains(&self, name: Identifier) -> bool {
+        self.0.borrow().contains(&name)
+    }
+}
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub(crate) enum CallArg {
@@ -61,7 +84,7 @@ impl CallArg {
 
 impl CallArgs {
     pub fn new(span: Span) -> Self {
-        CallArgs(HashMap::new(), span)
+        CallArgs(IndexMap::new(), span, RequestedNames::default())
     }
 
     pub fn to_css_string(self, is_compressed: bool) -> SassResult<Spanned<String>> {
@@ -91,7 +114,7 @@ impl CallArgs {
                     a.node.to_css_string(a.span, is_compressed)
                 })
                 .collect::<SassResult<Vec<Cow<'static, str>>>>()?
-                .join(", "),
+                .join(if is_compressed { "," } else { ", " }),
         );
         string.push(')');
         Ok(Spanned { node: string, span })
@@ -101,14 +124,16 @@ impl CallArgs {
     ///
     /// Removes the argument
     pub fn get_named<T: Into<Identifier>>(&mut self, val: T) -> Option<SassResult<Spanned<Value>>> {
-        self.0.remove(&CallArg::Named(val.into()))
+        let name = val.into();
+        self.2.insert(name);
+        self.0.shift_remove(&CallArg::Named(name))
     }
 
     /// Get a positional argument by 0-indexed position
     ///
     /// Removes the argument
     pub fn get_positional(&mut self, val: usize) -> Option<SassResult<Spanned<Value>>> {
-        self.0.remove(&CallArg::Positional(val))
+        self.0.shift_remove(&CallArg::Positional(val))
     }
 
     pub fn get<T: Into<Identifier>>(
@@ -116,19 +141,25 @@ impl CallArgs {
         position: usize,
         name: T,
     ) -> Option<SassResult<Spanned<Value>>> {
+        let name = name.into();
         match self.get_named(name) {
+            Some(..) if self.0.contains_key(&CallArg::Positional(position)) => Some(Err((
+                format!(
+                    "Argument ${} was passed both by position and by name.",
+                    name
+                ),
+                self.span(),
+            )
+                .into())),
             Some(v) => Some(v),
             None => self.get_positional(position),
         }
     }
 
     pub fn get_err(&mut self, position: usize, name: &'static str) -> SassResult<Value> {
-        match self.get_named(name) {
+        match self.get(position, name) {
             Some(v) => Ok(v?.node),
-            None => match self.get_positional(position) {
-                Some(v) => Ok(v?.node),
-                None => Err((format!("Missing argument ${}.", name), self.span()).into()),
-            },
+            None => Err((format!("Missing argument ${}.", name), self.span()).into()),
         }
     }
 
@@ -143,6 +174,7 @@ impl CallArgs {
                 .map(|(k, v)| (k.decrement(), v))
                 .collect(),
             self.1,
+            self.2,
         )
     }
 
@@ -169,11 +201,39 @@ impl CallArgs {
         Ok(())
     }
 
+    /// The number of arguments passed by position
+    fn num_positional(&self) -> usize {
+        self.0
+            .keys()
+            .filter(|arg| matches!(arg, CallArg::Positional(..)))
+            .count()
+    }
+
+    /// The names of all arguments passed by name, in sorted order
+    pub fn named(&self) -> Vec<Identifier> {
+        let mut names: Vec<Identifier> = self
+            .0
+            .keys()
+            .filter_map(|arg| match arg {
+                CallArg::Named(name) => Some(*name),
+                CallArg::Positional(..) => None,
+            })
+            .collect();
+        names.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        names
+    }
+
+    /// Keyword arguments are checked against the parameters that were actually
+    /// looked up, once the callable has returned (see [`CallArgs::verify_named`])
     pub fn max_args(&self, max: usize) -> SassResult<()> {
-        let len = self.len();
+        let len = self.num_positional();
         if len > max {
             let mut err = String::with_capacity(50);
-            err.push_str(&format!("Only {} argument", max));
+            err.push_str(&format!("Only {} ", max));
+            if len != self.len() {
+                err.push_str("positional ");
+            }
+            err.push_str("argument");
             if max != 1 {
                 err.push('s');
             }
@@ -190,6 +250,75 @@ impl CallArgs {
         Ok(())
     }
 
+    /// Returns a handle to the parameter names looked up by whoever these
+    /// arguments are passed to
+    pub fn requested_names(&self) -> RequestedNames {
+        self.2.clone()
+    }
+
+    /// Ensures that every keyword argument in `names` was looked up by the builtin
+    /// these arguments were passed to
+    pub fn verify_named(
+        names: &[Identifier],
+        requested: &RequestedNames,
+        span: Span,
+    ) -> SassResult<()> {
+        let unknown: Vec<Identifier> = names
+            .iter()
+            .copied()
+            .filter(|name| !requested.contains(*name))
+            .collect();
+
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        Err((no_argument_named(&unknown), span).into())
+    }
+
+    /// Checks these arguments against the parameters of a user-defined callable,
+    /// producing the same errors in the same order as `dart-sass`
+    pub fn verify(&self, params: &FuncArgs) -> SassResult<()> {
+        let positional = self.num_positional();
+        let named = self.named();
+        let mut named_used = 0;
+
+        for (idx, param) in params.0.iter().enumerate() {
+            if param.is_variadic {
+                return Ok(());
+            }
+
+            if idx < positional {
+                if named.contains(&param.name) {
+                    return Err((
+                        format!(
+                            "Argument ${} was passed both by position and by name.",
+                            param.name
+                        ),
+                        self.span(),
+                    )
+                        .into());
+                }
+            } else if named.contains(&param.name) {
+                named_used += 1;
+            } else if param.default.is_none() {
+                return Err((format!("Missing argument ${}.", param.name), self.span()).into());
+            }
+        }
+
+        self.max_args(params.len())?;
+
+        if named_used < named.len() {
+            let unknown: Vec<Identifier> = named
+                .into_iter()
+                .filter(|name| !params.0.iter().any(|param| param.name == *name))
+                .collect();
+            return Err((no_argument_named(&unknown), self.span()).into());
+        }
+
+        Ok(())
+    }
+
     pub fn default_arg(
         &mut self,
         position: usize,
@@ -218,7 +347,30 @@ impl CallArgs {
         })
     }
 
+    /// Removes every remaining keyword argument, collecting them into a map from
+    /// name (without the leading `$`) to value in the order they were passed
+    ///
+    /// This is used to populate the keywords of an argument list
+    pub fn get_keywords(&mut self) -> SassResult<SassMap> {
+        let mut keywords = SassMap::new();
+        let named: Vec<Identifier> = self
+            .0
+            .keys()
+            .filter_map(|arg| match arg {
+                CallArg::Named(name) => Some(*name),
+                CallArg::Positional(..) => None,
+            })
+            .collect();
+        for name in named {
+            if let Some(val) = self.0.shift_remove(&CallArg::Named(name)) {
+                keywords.insert(Value::String(name.to_string(), QuoteKind::None), val?.node);
+            }
+        }
+        Ok(keywords)
+    }
+
     pub fn get_variadic(self) -> SassResult<Vec<Spanned<Value>>> {
+        let named = self.named();
         let mut vals = Vec::new();
         let mut args = match self
             .0
@@ -227,7 +379,7 @@ impl CallArgs {
             .collect::<Result<Vec<(usize, SassResult<Spanned<Value>>)>, String>>()
         {
             Ok(v) => v,
-            Err(e) => return Err((format!("No argument named ${}.", e), self.1).into()),
+            Err(..) => return Err((no_argument_named(&named), self.1).into()),
         };
 
         args.sort_by(|(a1, _), (a2, _)| a1.cmp(a2));
@@ -239,3 +391,19 @@ impl CallArgs {
         Ok(vals)
     }
 }
+
+/// `No argument named $a.` or `No arguments named $a, $b, or $c.`
+fn no_argument_named(names: &[Identifier]) -> String {
+    let names: Vec<String> = names.iter().map(|name| format!("${}", name)).collect();
+    let list = match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, [first])) => format!("{} or {}", first, last),
+        Some((last, rest)) => format!("{}, or {}", rest.join(", "), last),
+        None => String::new(),
+    };
+    format!(
+        "No argument{} named {}.",
+        if names.len() == 1 { "" } else { "s" },
+        list
+    )
+}
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+/// The `(with: ...)` or `(without: ...)` query of an `@at-root` rule, which
+/// determines the rules that its contents are written outside of
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AtRootQuery {
+    /// Whether `names` are the rules to stay inside of, rather than the rules
+    /// to leave
+    pub include: bool,
+
+    /// The lowercase names of the rules, where `rule` stands for style rules
+    /// and `all` for every rule
+    pub names: HashSet<String>,
+}
+
+impl AtRootQuery {
+    fn is_all(&self) -> bool {
+        self.names.contains("all")
+    }
+
+    /// Whether the contents are written outside of the at-rule named `name`
+    pub fn excludes_name(&self, name: &str) -> bool {
+        (self.is_all() || self.names.contains(name)) != self.include
+    }
+
+    /// Whether the contents are written outside of style rules
+    pub fn excludes_style_rules(&self) -> bool {
+        (self.is_all() || self.names.contains("rule")) != self.include
+    }
+}
+
+impl Default for AtRootQuery {
+    /// `(without: rule)`, the query of an `@at-root` rule that doesn't have one
+    fn default() -> Self {
+        Self {
+            include: false,
+            names: vec!["rule".to_owned()].into_iter().collect(),
+        }
+    }
+}
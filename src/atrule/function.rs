@@ -8,8 +8,12 @@ use crate::{args::FuncArgs, Token};
 pub(crate) struct Function {
     pub args: FuncArgs,
     pub body: Vec<Token>,
-    pub declared_at_root: bool,
-    pos: Span,
+    /// The number of scopes where the function was declared, which are the
+    /// only local scopes visible in its body, or 0 if it was declared at the
+    /// root
+    pub scope_len: usize,
+    /// The span of the function's name where it is declared
+    pub pos: Span,
 }
 
 impl Hash for Function {
@@ -27,11 +31,11 @@ impl PartialEq for Function {
 impl Eq for Function {}
 
 impl Function {
-    pub fn new(args: FuncArgs, body: Vec<Token>, declared_at_root: bool, pos: Span) -> Self {
+    pub fn new(args: FuncArgs, body: Vec<Token>, scope_len: usize, pos: Span) -> Self {
         Function {
             args,
             body,
-            declared_at_root,
+            scope_len,
             pos,
         }
     }
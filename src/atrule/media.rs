@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-use std::fmt;
+use std::fmt::{self, Write};
 
 use crate::{parse::Stmt, selector::Selector};
 
@@ -47,6 +47,99 @@ impl MediaQuery {
         }
     }
 
+    /// Parses a serialized media query list, such as `screen and (color), print`
+    ///
+    /// Returns `None` if any query in the list can't be represented as a
+    /// `MediaQuery`, for example because its conditions are joined by `or`
+    pub fn parse_list(list: &str) -> Option<Vec<Self>> {
+        split_top_level(list, |c| c == ',')
+            .into_iter()
+            .map(Self::parse)
+            .collect()
+    }
+
+    fn parse(query: &str) -> Option<Self> {
+        let words = split_top_level(query, char::is_whitespace);
+
+        let (modifier, media_type, rest) = match words.as_slice() {
+            [first, ..] if first.starts_with('(') => (None, None, words.as_slice()),
+            [modifier, media_type, rest @ ..]
+                if (modifier.eq_ignore_ascii_case("not")
+                    || modifier.eq_ignore_ascii_case("only"))
+                    && !media_type.starts_with('(') =>
+            {
+                (Some(*modifier), Some(*media_type), rest)
+            }
+            [media_type, rest @ ..] => (None, Some(*media_type), rest),
+            [] => return None,
+        };
+
+        // conditions following a media type are introduced by `and`
+        let conditions = match (media_type, rest) {
+            (Some(..), [and, conditions @ ..])
+                if and.eq_ignore_ascii_case("and") && !conditions.is_empty() =>
+            {
+                conditions
+            }
+            (Some(..), []) | (None, ..) => rest,
+            (Some(..), ..) => return None,
+        };
+
+        // conditions alternate with the `and`s that join them
+        if !conditions.is_empty() && conditions.len() % 2 == 0 {
+            return None;
+        }
+
+        let mut features = Vec::new();
+
+        for (idx, word) in conditions.iter().enumerate() {
+            if idx % 2 == 1 {
+                if !word.eq_ignore_ascii_case("and") {
+                    return None;
+                }
+            } else if word.starts_with('(') && word.ends_with(')') {
+                features.push((*word).to_owned());
+            } else {
+                return None;
+            }
+        }
+
+        Some(Self {
+            modifier: modifier.map(ToOwned::to_owned),
+            media_type: media_type.map(ToOwned::to_owned),
+            features,
+        })
+    }
+
+    /// Merges two media query lists, as when one `@media` rule is nested inside
+    /// another
+    ///
+    /// Returns `None` if the intersection can't be represented in CSS, and an
+    /// empty list if no media can match both lists.
+    pub fn merge_lists(outer: &[Self], inner: &[Self]) -> Option<Vec<Self>> {
+        let mut merged = Vec::new();
+
+        for query1 in outer {
+            for query2 in inner {
+                match query1.merge(query2) {
+                    MediaQueryMergeResult::Empty => continue,
+                    MediaQueryMergeResult::Unrepresentable => return None,
+                    MediaQueryMergeResult::Success(query) => merged.push(query),
+                }
+            }
+        }
+
+        Some(merged)
+    }
+
+    pub fn serialize_list(queries: &[Self]) -> String {
+        queries
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
     #[allow(clippy::if_not_else)]
     fn merge(&self, other: &Self) -> MediaQueryMergeResult {
         let this_modifier = self.modifier.as_ref().map(|m| m.to_ascii_lowercase());
@@ -69,7 +162,7 @@ impl MediaQuery {
         let features;
 
         if (this_modifier.as_deref() == Some("not")) != (other_modifier.as_deref() == Some("not")) {
-            if this_modifier == other_modifier {
+            if this_type == other_type {
                 let negative_features = if this_modifier.as_deref() == Some("not") {
                     &self.features
                 } else {
@@ -208,6 +301,7 @@ impl fmt::Display for MediaQuery {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(modifier) = &self.modifier {
             f.write_str(modifier)?;
+            f.write_char(' ')?;
         }
         if let Some(media_type) = &self.media_type {
             f.write_str(media_type)?;
@@ -225,3 +319,31 @@ enum MediaQueryMergeResult {
     Unrepresentable,
     Success(MediaQuery),
 }
+
+/// Splits `s` at every character matching `is_separator` that isn't inside
+/// parentheses, brackets, or quotes, discarding empty pieces
+fn split_top_level(s: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut depth = 0_usize;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (idx, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), ..) if c == q => quote = None,
+            (Some(..), ..) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '(') | (None, '[') => depth += 1,
+            (None, ')') | (None, ']') => depth = depth.saturating_sub(1),
+            (None, ..) if depth == 0 && is_separator(c) => {
+                pieces.push(s[start..idx].trim());
+                start = idx + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    pieces.push(s[start..].trim());
+    pieces.retain(|piece| !piece.is_empty());
+    pieces
+}
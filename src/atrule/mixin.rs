@@ -4,6 +4,7 @@ use crate::{
     args::{CallArgs, FuncArgs},
     error::SassResult,
     parse::{Parser, Stmt},
+    scope::Scopes,
     Token,
 };
 
@@ -23,7 +24,7 @@ impl fmt::Debug for Mixin {
                 .field("args", &u.args)
                 .field("body", &u.body)
                 .field("accepts_content_block", &u.accepts_content_block)
-                .field("declared_at_root", &u.declared_at_root)
+                .field("scope_len", &u.scope_len)
                 .finish(),
             Self::Builtin(..) => f.debug_struct("BuiltinMixin").finish(),
         }
@@ -35,13 +36,13 @@ impl Mixin {
         args: FuncArgs,
         body: Vec<Token>,
         accepts_content_block: bool,
-        declared_at_root: bool,
+        scope_len: usize,
     ) -> Self {
         Mixin::UserDefined(UserDefinedMixin::new(
             args,
             body,
             accepts_content_block,
-            declared_at_root,
+            scope_len,
         ))
     }
 }
@@ -51,7 +52,10 @@ pub(crate) struct UserDefinedMixin {
     pub args: FuncArgs,
     pub body: Vec<Token>,
     pub accepts_content_block: bool,
-    pub declared_at_root: bool,
+
+    /// The number of scopes where the mixin was declared, which are the only
+    /// local scopes visible in its body, or 0 if it was declared at the root
+    pub scope_len: usize,
 }
 
 impl UserDefinedMixin {
@@ -59,18 +63,18 @@ impl UserDefinedMixin {
         args: FuncArgs,
         body: Vec<Token>,
         accepts_content_block: bool,
-        declared_at_root: bool,
+        scope_len: usize,
     ) -> Self {
         Self {
             args,
             body,
             accepts_content_block,
-            declared_at_root,
+            scope_len,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub(crate) struct Content {
     /// The literal block, serialized as a list of tokens
     pub content: Option<Vec<Token>>,
@@ -78,13 +82,18 @@ pub(crate) struct Content {
     /// Optional args, e.g. `@content(a, b, c);`
     pub content_args: Option<FuncArgs>,
 
-    /// The number of scopes at the use of `@include`
+    /// The number of scopes shared by the `@include` and the body of the
+    /// mixin, which are those visible where the mixin was declared
     ///
     /// This is used to "reset" back to the state of the `@include`
     /// without actually cloning the scope or putting it in an `Rc`
     pub scope_len: usize,
 
-    /// Whether or not the mixin this `@content` block is inside of was
-    /// declared in the global scope
-    pub declared_at_root: bool,
+    /// The rest of the scopes at the use of `@include`
+    ///
+    /// The mixin can't see these, so they are moved out of the way while it
+    /// runs and kept here until its `@content` is evaluated. Each block on the
+    /// stack holds its own, so that a block which itself contains `@content`
+    /// is evaluated in the scope of the right `@include`.
+    pub scopes: Scopes,
 }
@@ -1,8 +1,10 @@
+pub(crate) use at_root::AtRootQuery;
 pub(crate) use function::Function;
 pub(crate) use kind::AtRuleKind;
 pub(crate) use supports::SupportsRule;
 pub(crate) use unknown::UnknownAtRule;
 
+mod at_root;
 mod function;
 pub mod keyframes;
 mod kind;
@@ -0,0 +1,276 @@
+//! # Compiling stylesheets on request, for `axum` and other `tower` servers
+//!
+//! [`CompileService`] answers requests for `.css` files by compiling the
+//! `.scss` file of the same name, so that a web app can serve its styles
+//! during development without a separate build step. For example, with
+//! `axum`,
+//!
+//! ```ignore
+//! let app = axum::Router::new().nest_service("/css", grass::axum::CompileService::new("styles"));
+//! ```
+//!
+//! serves `styles/site/main.scss` at `/css/site/main.css`.
+//!
+//! Compiled stylesheets are cached, along with every file that was read, or
+//! looked for, while compiling them. A stylesheet is only compiled again once
+//! one of those files changes, is created, or is deleted. Responses carry an
+//! `ETag`, so browsers only download a stylesheet again once it has changed.
+//!
+//! Compilation happens on the thread that polls the service, so this is
+//! intended for development rather than production. The cache isn't locked
+//! while compiling, so requests for other stylesheets aren't held up.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    convert::Infallible,
+    future::{ready, Ready},
+    hash::{Hash, Hasher},
+    path::{Component, Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll},
+};
+
+use http::{
+    header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH},
+    HeaderValue, Method, Request, Response, StatusCode,
+};
+use tower_service::Service;
+
+use crate::{
+    fs::{Dependencies, RecordingFs},
+    Logger, Options, OutputStyle, StdFs, StdLogger,
+};
+
+/// A [`tower_service::Service`] that serves stylesheets compiled from the
+/// `.scss` files in a directory
+///
+/// A request for `/a/b.css` is answered by compiling `a/b.scss`, relative to
+/// the directory given to [`CompileService::new`]. Requests for other paths,
+/// for partials, and for paths that leave the directory get a 404 response.
+/// Compilation errors get a 500 response whose body is the error message.
+///
+/// Clones of the service share their cache.
+#[derive(Clone, Debug)]
+pub struct CompileService {
+    root: PathBuf,
+    load_paths: Vec<PathBuf>,
+    style: OutputStyle,
+    logger: Arc<dyn Logger + Send>,
+    cache: Arc<Mutex<HashMap<PathBuf, Arc<Compiled>>>>,
+}
+
+#[derive(Debug)]
+struct Compiled {
+    css: String,
+    etag: HeaderValue,
+    /// Every file that was read or looked for during compilation
+    dependencies: Dependencies,
+}
+
+impl Compiled {
+    fn is_fresh(&self) -> bool {
+        self.dependencies.are_unchanged()
+    }
+}
+
+impl CompileService {
+    /// Serve the stylesheets in `root`
+    #[inline]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            load_paths: Vec::new(),
+            style: OutputStyle::Expanded,
+            logger: Arc::new(StdLogger),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Append a path to the list of load paths
+    ///
+    /// See [`Options::load_path`](crate::Options::load_path) for more
+    /// information about load paths.
+    #[inline]
+    #[must_use]
+    pub fn load_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.load_paths.push(path.into());
+        self
+    }
+
+    /// The output style to compile stylesheets with
+    ///
+    /// By default, output is expanded.
+    #[inline]
+    #[must_use]
+    pub fn style(mut self, style: OutputStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The logger that warnings and the messages of `@debug` rules are sent
+    /// to while compiling
+    ///
+    /// By default, they are written to stderr by [`StdLogger`]. The logger is
+    /// shared, so the caller can keep a reference to it.
+    #[inline]
+    #[must_use]
+    pub fn logger(mut self, logger: Arc<dyn Logger + Send>) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Answer a request without going through [`tower_service::Service`],
+    /// e.g. from a handler in a web framework that isn't built on `tower`
+    #[inline]
+    pub fn respond<B>(&self, request: &Request<B>) -> Response<String> {
+        if request.method() != Method::GET && request.method() != Method::HEAD {
+            return plain_response(StatusCode::METHOD_NOT_ALLOWED, "Method not allowed");
+        }
+
+        let path = match self.source_path(request.uri().path()) {
+            Some(path) => path,
+            None => return plain_response(StatusCode::NOT_FOUND, "Not found"),
+        };
+
+        let cached = self.cache().get(&path).cloned();
+
+        let compiled = match cached {
+            Some(compiled) if compiled.is_fresh() => compiled,
+            _ => {
+                if !path.is_file() {
+                    self.cache().remove(&path);
+                    return plain_response(StatusCode::NOT_FOUND, "Not found");
+                }
+
+                match self.compile(&path) {
+                    Ok(compiled) => {
+                        let compiled = Arc::new(compiled);
+                        self.cache().insert(path, Arc::clone(&compiled));
+                        compiled
+                    }
+                    Err(message) => {
+                        self.cache().remove(&path);
+                        return plain_response(StatusCode::INTERNAL_SERVER_ERROR, message);
+                    }
+                }
+            }
+        };
+
+        let is_not_modified = request
+            .headers()
+            .get(IF_NONE_MATCH)
+            .and_then(|value| value.to_str().ok())
+            .map_or(false, |value| {
+                value
+                    .split(',')
+                    .any(|etag| compiled.etag == etag.trim() || etag.trim() == "*")
+            });
+
+        let mut response = if is_not_modified {
+            let mut response = Response::new(String::new());
+            *response.status_mut() = StatusCode::NOT_MODIFIED;
+            response
+        } else {
+            let mut response = Response::new(if request.method() == Method::HEAD {
+                String::new()
+            } else {
+                compiled.css.clone()
+            });
+            response.headers_mut().insert(
+                CONTENT_TYPE,
+                HeaderValue::from_static("text/css; charset=utf-8"),
+            );
+            response
+        };
+
+        let headers = response.headers_mut();
+        headers.insert(ETAG, compiled.etag.clone());
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        response
+    }
+}
+
+impl<B> Service<Request<B>> for CompileService {
+    type Response = Response<String>;
+    type Error = Infallible;
+    type Future = Ready<Result<Response<String>, Infallible>>;
+
+    #[inline]
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    #[inline]
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        ready(Ok(self.respond(&req)))
+    }
+}
+
+impl CompileService {
+    /// The stylesheet to compile for a request for `url`, if it names a
+    /// `.css` file inside the root directory that isn't a partial
+    fn source_path(&self, url: &str) -> Option<PathBuf> {
+        let name = url.strip_suffix(".css")?;
+
+        let mut path = self.root.clone();
+
+        for component in Path::new(name.trim_start_matches('/')).components() {
+            match component {
+                Component::Normal(component) => path.push(component),
+                Component::CurDir => {}
+                Component::ParentDir | Component::RootDir | Component::Prefix(..) => return None,
+            }
+        }
+
+        let file_name = path.file_name()?.to_str()?;
+
+        if file_name.starts_with('_') {
+            return None;
+        }
+
+        let file_name = format!("{}.scss", file_name);
+        path.set_file_name(file_name);
+
+        Some(path)
+    }
+
+    /// The cache, which is only locked to look up or store a stylesheet and
+    /// never while compiling one
+    fn cache(&self) -> MutexGuard<'_, HashMap<PathBuf, Arc<Compiled>>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn compile(&self, path: &Path) -> Result<Compiled, String> {
+        let fs = RecordingFs::new(&StdFs);
+        let load_paths: Vec<&Path> = self.load_paths.iter().map(PathBuf::as_path).collect();
+        let options = Options::default()
+            .fs(&fs)
+            .style(self.style)
+            .load_paths(&load_paths)
+            .logger(&*self.logger);
+
+        let css = crate::from_path(&path.to_string_lossy(), &options).map_err(|e| e.to_string())?;
+
+        let mut hasher = DefaultHasher::new();
+        css.hash(&mut hasher);
+        let etag = HeaderValue::from_str(&format!("\"{:016x}\"", hasher.finish()))
+            .map_err(|e| e.to_string())?;
+
+        Ok(Compiled {
+            css,
+            etag,
+            dependencies: fs.into_dependencies(),
+        })
+    }
+}
+
+fn plain_response(status: StatusCode, body: impl Into<String>) -> Response<String> {
+    let mut response = Response::new(body.into());
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    response
+}
@@ -1,4 +1,7 @@
-use super::{Builtin, GlobalFunctionMap};
+use super::{
+    alpha_channel, is_special_slash, is_var, preserving_notation, slash_alpha, Builtin,
+    GlobalFunctionMap,
+};
 
 use codemap::Spanned;
 use num_traits::One;
@@ -7,6 +10,7 @@ use crate::{
     args::CallArgs,
     color::Color,
     common::{Brackets, ListSeparator, QuoteKind},
+    compat::Deprecation,
     error::SassResult,
     parse::Parser,
     unit::Unit,
@@ -38,6 +42,20 @@ fn inner_hsl(name: &'static str, mut args: CallArgs, parser: &mut Parser) -> Sas
                 .into());
         }
 
+        let alpha = slash_alpha(&mut channels, parser);
+
+        if channels.last().map_or(false, is_special_slash) {
+            return Ok(Value::String(
+                format!(
+                    "{}({})",
+                    name,
+                    Value::List(channels, ListSeparator::Space, Brackets::None)
+                        .to_css_string(args.span(), false)?
+                ),
+                QuoteKind::None,
+            ));
+        }
+
         if channels.iter().any(Value::is_special_function) {
             let channel_sep = if channels.len() < 3 {
                 ListSeparator::Space
@@ -95,15 +113,31 @@ fn inner_hsl(name: &'static str, mut args: CallArgs, parser: &mut Parser) -> Sas
             None => return Err(("Missing element $hue.", args.span()).into()),
         };
 
+        let alpha = match alpha {
+            Some(alpha) => alpha_channel(alpha, &args, parser)?,
+            None => Number::one(),
+        };
+
         Ok(Value::Color(Box::new(Color::from_hsla(
-            hue,
-            saturation,
-            lightness,
-            Number::one(),
+            hue, saturation, lightness, alpha,
         ))))
     } else {
         let hue = args.get_err(0, "hue")?;
         let saturation = args.get_err(1, "saturation")?;
+
+        // either could provide more than one channel
+        if len == 2 && (is_var(&hue) || is_var(&saturation)) {
+            return Ok(Value::String(
+                format!(
+                    "{}({})",
+                    name,
+                    Value::List(vec![hue, saturation], ListSeparator::Comma, Brackets::None)
+                        .to_css_string(args.span(), false)?
+                ),
+                QuoteKind::None,
+            ));
+        }
+
         let lightness = args.get_err(2, "lightness")?;
         let alpha = args.default_arg(
             3,
@@ -203,11 +237,11 @@ fn inner_hsl(name: &'static str, mut args: CallArgs, parser: &mut Parser) -> Sas
 }
 
 pub(crate) fn hsl(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    inner_hsl("hsl", args, parser)
+    preserving_notation("hsl", args, parser, inner_hsl)
 }
 
 pub(crate) fn hsla(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    inner_hsl("hsla", args, parser)
+    preserving_notation("hsla", args, parser, inner_hsl)
 }
 
 pub(crate) fn hue(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
@@ -336,14 +370,22 @@ fn darken(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
 fn saturate(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     args.max_args(2)?;
     if args.len() == 1 {
-        return Ok(Value::String(
-            format!(
-                "saturate({})",
-                args.get_err(0, "amount")?
-                    .to_css_string(args.span(), false)?
-            ),
-            QuoteKind::None,
-        ));
+        // `saturate($amount)` is the plain CSS filter function
+        return match args.get_err(0, "amount")? {
+            v @ Value::Dimension(..) => Ok(Value::String(
+                format!("saturate({})", v.to_css_string(args.span(), false)?),
+                QuoteKind::None,
+            )),
+            v if v.is_special_function() => Ok(Value::String(
+                format!("saturate({})", v.to_css_string(args.span(), false)?),
+                QuoteKind::None,
+            )),
+            v => Err((
+                format!("$amount: {} is not a number.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into()),
+        };
     }
 
     let amount = match args.get_err(1, "amount")? {
@@ -362,12 +404,6 @@ fn saturate(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     };
     let color = match args.get_err(0, "color")? {
         Value::Color(c) => c,
-        Value::Dimension(Some(n), u, _) => {
-            return Ok(Value::String(
-                format!("saturate({}{})", n.inspect(), u),
-                QuoteKind::None,
-            ))
-        }
         v => {
             return Err((
                 format!("$color: {} is not a color.", v.inspect(args.span())?),
@@ -408,15 +444,29 @@ fn desaturate(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     Ok(Value::Color(Box::new(color.desaturate(amount))))
 }
 
-pub(crate) fn grayscale(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+/// `module`: whether this is `color.grayscale()` rather than the global
+/// `grayscale()`
+fn inner_grayscale(mut args: CallArgs, parser: &mut Parser, module: bool) -> SassResult<Value> {
     args.max_args(1)?;
     let color = match args.get_err(0, "color")? {
         Value::Color(c) => c,
-        Value::Dimension(Some(n), u, _) => {
-            return Ok(Value::String(
-                format!("grayscale({}{})", n.inspect(), u),
-                QuoteKind::None,
-            ))
+        v @ Value::Dimension(..) => {
+            let result = format!("grayscale({})", v.to_css_string(args.span(), false)?);
+
+            if module {
+                parser.deprecation(
+                    Deprecation::ColorModuleCompat,
+                    &format!(
+                        "Passing a number ({}) to color.grayscale() is deprecated.\n\nRecommendation: {}",
+                        v.inspect(args.span())?,
+                        result
+                    ),
+                    &format!("$color: {} is not a color.", v.inspect(args.span())?),
+                    args.span(),
+                )?;
+            }
+
+            return Ok(Value::String(result, QuoteKind::None));
         }
         v => {
             return Err((
@@ -429,6 +479,14 @@ pub(crate) fn grayscale(mut args: CallArgs, parser: &mut Parser) -> SassResult<V
     Ok(Value::Color(Box::new(color.desaturate(Number::one()))))
 }
 
+pub(crate) fn grayscale(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_grayscale(args, parser, false)
+}
+
+pub(crate) fn color_grayscale(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_grayscale(args, parser, true)
+}
+
 pub(crate) fn complement(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     args.max_args(1)?;
     let color = match args.get_err(0, "color")? {
@@ -444,14 +502,15 @@ pub(crate) fn complement(mut args: CallArgs, parser: &mut Parser) -> SassResult<
     Ok(Value::Color(Box::new(color.complement())))
 }
 
-pub(crate) fn invert(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+/// `module`: whether this is `color.invert()` rather than the global `invert()`
+fn inner_invert(mut args: CallArgs, parser: &mut Parser, module: bool) -> SassResult<Value> {
     args.max_args(2)?;
     let weight = match args.get(1, "weight") {
         Some(Err(e)) => return Err(e),
         Some(Ok(Spanned {
             node: Value::Dimension(Some(n), u, _),
             ..
-        })) => Some(bound!(args, "weight", n, u, 0, 100) / Number::from(100)),
+        })) => Some((bound!(args, "weight", n, u, 0, 100) / Number::from(100), u)),
         Some(Ok(Spanned {
             node: Value::Dimension(None, ..),
             ..
@@ -470,23 +529,35 @@ pub(crate) fn invert(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
     };
     match args.get_err(0, "color")? {
         Value::Color(c) => Ok(Value::Color(Box::new(
-            c.invert(weight.unwrap_or_else(Number::one)),
+            c.invert(weight.map_or_else(Number::one, |(weight, _)| weight)),
         ))),
-        Value::Dimension(Some(n), u, _) => {
-            if weight.is_some() {
+        v @ Value::Dimension(..) => {
+            // the plain CSS `invert()` filter takes no weight, so only the
+            // default one is allowed
+            if matches!(&weight, Some((w, u)) if !w.is_one() || *u != Unit::Percent) {
                 return Err((
                     "Only one argument may be passed to the plain-CSS invert() function.",
                     args.span(),
                 )
                     .into());
             }
-            Ok(Value::String(
-                format!("invert({}{})", n.inspect(), u),
-                QuoteKind::None,
-            ))
-        }
-        Value::Dimension(None, u, _) => {
-            Ok(Value::String(format!("invert(NaN{})", u), QuoteKind::None))
+
+            let result = format!("invert({})", v.to_css_string(args.span(), false)?);
+
+            if module {
+                parser.deprecation(
+                    Deprecation::ColorModuleCompat,
+                    &format!(
+                        "Passing a number ({}) to color.invert() is deprecated.\n\nRecommendation: {}",
+                        v.inspect(args.span())?,
+                        result
+                    ),
+                    &format!("$color: {} is not a color.", v.inspect(args.span())?),
+                    args.span(),
+                )?;
+            }
+
+            Ok(Value::String(result, QuoteKind::None))
         }
         v => Err((
             format!("$color: {} is not a color.", v.inspect(args.span())?),
@@ -496,6 +567,14 @@ pub(crate) fn invert(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
     }
 }
 
+pub(crate) fn invert(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_invert(args, parser, false)
+}
+
+pub(crate) fn color_invert(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_invert(args, parser, true)
+}
+
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("hsl", Builtin::new(hsl));
     f.insert("hsla", Builtin::new(hsla));
@@ -1,8 +1,11 @@
-use num_traits::One;
+use super::{is_special_slash, is_var, slash_alpha};
+
+use num_traits::{One, Zero};
 
 use crate::{
     args::CallArgs,
     color::Color,
+    common::{Brackets, ListSeparator},
     error::SassResult,
     parse::Parser,
     unit::Unit,
@@ -23,10 +26,11 @@ pub(crate) fn blackness(mut args: CallArgs, parser: &mut Parser) -> SassResult<V
         }
     };
 
-    let blackness =
-        Number::from(1) - (color.red().max(color.green()).max(color.blue()) / Number::from(255));
-
-    Ok(Value::Dimension(Some(blackness * 100), Unit::Percent, true))
+    Ok(Value::Dimension(
+        Some(color.blackness()),
+        Unit::Percent,
+        true,
+    ))
 }
 
 pub(crate) fn whiteness(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
@@ -43,9 +47,48 @@ pub(crate) fn whiteness(mut args: CallArgs, parser: &mut Parser) -> SassResult<V
         }
     };
 
-    let whiteness = color.red().min(color.green()).min(color.blue()) / Number::from(255);
+    Ok(Value::Dimension(
+        Some(color.whiteness()),
+        Unit::Percent,
+        true,
+    ))
+}
 
-    Ok(Value::Dimension(Some(whiteness * 100), Unit::Percent, true))
+/// Asserts that `value` is a percentage between `0%` and `100%`, as required
+/// of the whiteness and blackness channels
+fn percent_channel(value: Option<Value>, name: &str, args: &CallArgs) -> SassResult<Number> {
+    match value {
+        Some(Value::Dimension(Some(n), Unit::Percent, ..))
+            if n >= Number::zero() && n <= Number::from(100) =>
+        {
+            Ok(n)
+        }
+        Some(v @ Value::Dimension(Some(_), Unit::Percent, ..))
+        | Some(v @ Value::Dimension(None, Unit::Percent, ..)) => Err((
+            format!(
+                "${}: Expected {} to be within 0% and 100%.",
+                name,
+                v.inspect(args.span())?
+            ),
+            args.span(),
+        )
+            .into()),
+        Some(v @ Value::Dimension(..)) => Err((
+            format!(
+                "${}: Expected {} to have unit \"%\".",
+                name,
+                v.inspect(args.span())?
+            ),
+            args.span(),
+        )
+            .into()),
+        Some(v) => Err((
+            format!("${}: {} is not a number.", name, v.inspect(args.span())?),
+            args.span(),
+        )
+            .into()),
+        None => Err((format!("Missing element ${}.", name), args.span()).into()),
+    }
 }
 
 pub(crate) fn hwb(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
@@ -55,78 +98,134 @@ pub(crate) fn hwb(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value>
         return Err(("Missing argument $channels.", args.span()).into());
     }
 
-    let hue = match args.get(0, "hue") {
-        Some(Ok(v)) => match v.node {
-            Value::Dimension(Some(n), ..) => n,
-            Value::Dimension(None, ..) => todo!(),
-            v => {
-                return Err((
-                    format!("$hue: {} is not a number.", v.inspect(args.span())?),
-                    args.span(),
-                )
-                    .into())
+    let (hue, whiteness, blackness, alpha) = if args.len() == 1 {
+        let original = args.get_err(0, "channels")?;
+        let (channels, alpha) = match original.clone() {
+            Value::List(v, ListSeparator::Slash, Brackets::None) => {
+                if v.len() != 2 {
+                    return Err((
+                        format!(
+                            "Only 2 slash-separated elements allowed, but {} {} passed.",
+                            v.len(),
+                            if v.len() == 1 { "was" } else { "were" }
+                        ),
+                        args.span(),
+                    )
+                        .into());
+                }
+
+                let mut v = v.into_iter();
+                let channels = v.next().unwrap();
+                (channels, v.next())
             }
-        },
-        Some(Err(e)) => return Err(e),
-        None => return Err(("Missing element $hue.", args.span()).into()),
-    };
+            Value::List(mut v, ListSeparator::Space, Brackets::None) => {
+                let alpha = slash_alpha(&mut v, parser);
+                (Value::List(v, ListSeparator::Space, Brackets::None), alpha)
+            }
+            v => (v, None),
+        };
 
-    let whiteness = match args.get(1, "whiteness") {
-        Some(Ok(v)) => match v.node {
-            Value::Dimension(Some(n), Unit::Percent, ..) => n,
-            v @ Value::Dimension(Some(..), ..) => {
-                return Err((
-                    format!(
-                        "$whiteness: Expected {} to have unit \"%\".",
-                        v.inspect(args.span())?
-                    ),
-                    args.span(),
-                )
-                    .into())
+        // `hwb()` isn't a plain CSS function, so it can't be emitted as written
+        let has_var = match &channels {
+            Value::List(v, ListSeparator::Space, Brackets::None) => {
+                (v.len() < 3 && v.iter().any(is_var)) || v.last().map_or(false, is_special_slash)
             }
-            Value::Dimension(None, ..) => todo!(),
-            v => {
+            v => is_var(v),
+        };
+
+        if has_var {
+            return Err((
+                format!(
+                    "Expected numeric channels, got \"hwb({})\".",
+                    original.to_css_string(args.span(), false)?
+                ),
+                args.span(),
+            )
+                .into());
+        }
+
+        let channels = match channels {
+            Value::List(_, ListSeparator::Comma, Brackets::Bracketed) => {
                 return Err((
-                    format!("$whiteness: {} is not a number.", v.inspect(args.span())?),
+                    "$channels must be an unbracketed, space-separated list.",
                     args.span(),
                 )
                     .into())
             }
-        },
-        Some(Err(e)) => return Err(e),
-        None => return Err(("Missing element $whiteness.", args.span()).into()),
+            Value::List(_, _, Brackets::Bracketed) => {
+                return Err(("$channels must be an unbracketed list.", args.span()).into())
+            }
+            Value::List(_, ListSeparator::Comma, _) => {
+                return Err(("$channels must be a space-separated list.", args.span()).into())
+            }
+            Value::List(v, ..) => v,
+            v => vec![v],
+        };
+
+        if channels.len() > 3 {
+            return Err((
+                format!(
+                    "Only 3 elements allowed, but {} were passed.",
+                    channels.len()
+                ),
+                args.span(),
+            )
+                .into());
+        }
+
+        let mut channels = channels.into_iter();
+
+        (channels.next(), channels.next(), channels.next(), alpha)
+    } else {
+        (
+            args.get(0, "hue").transpose()?.map(|v| v.node),
+            args.get(1, "whiteness").transpose()?.map(|v| v.node),
+            args.get(2, "blackness").transpose()?.map(|v| v.node),
+            args.get(3, "alpha").transpose()?.map(|v| v.node),
+        )
     };
 
-    let blackness = match args.get(2, "blackness") {
-        Some(Ok(v)) => match v.node {
-            Value::Dimension(Some(n), ..) => n,
-            Value::Dimension(None, ..) => todo!(),
-            v => {
-                return Err((
-                    format!("$blackness: {} is not a number.", v.inspect(args.span())?),
-                    args.span(),
-                )
-                    .into())
-            }
-        },
-        Some(Err(e)) => return Err(e),
-        None => return Err(("Missing element $blackness.", args.span()).into()),
+    let hue = match hue {
+        Some(Value::Dimension(n, unit, ..)) => (n, unit),
+        Some(v) => {
+            return Err((
+                format!("$hue: {} is not a number.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+        None => return Err(("Missing element $hue.", args.span()).into()),
     };
 
-    let alpha = match args.get(3, "alpha") {
-        Some(Ok(v)) => match v.node {
-            Value::Dimension(Some(n), Unit::Percent, ..) => n / Number::from(100),
-            Value::Dimension(Some(n), ..) => n,
-            Value::Dimension(None, ..) => todo!(),
-            v => {
-                return Err((
-                    format!("$alpha: {} is not a number.", v.inspect(args.span())?),
-                    args.span(),
-                )
-                    .into())
-            }
-        },
-        Some(Err(e)) => return Err(e),
+    let whiteness = percent_channel(whiteness, "whiteness", &args)?;
+    let blackness = percent_channel(blackness, "blackness", &args)?;
+
+    // the hue of a shade of gray is never used, so it may be `NaN`
+    let hue = match hue {
+        (Some(n), _) => n,
+        (None, _) if whiteness.clone() + blackness.clone() >= Number::from(100) => Number::zero(),
+        (None, unit) => {
+            return Err((
+                format!("$hue: NaN{} is not a finite number.", unit),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let alpha = match alpha {
+        Some(Value::Dimension(Some(n), Unit::Percent, ..)) => n / Number::from(100),
+        Some(Value::Dimension(Some(n), ..)) => n,
+        Some(Value::Dimension(None, ..)) => {
+            return Err(("$alpha: Expected NaN to be within 0 and 1.", args.span()).into())
+        }
+        Some(v) => {
+            return Err((
+                format!("$alpha: {} is not a number.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
         None => Number::one(),
     };
 
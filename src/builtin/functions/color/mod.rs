@@ -1,5 +1,15 @@
 use super::{Builtin, GlobalFunctionMap};
 
+use crate::{
+    args::CallArgs,
+    common::QuoteKind,
+    error::SassResult,
+    parse::Parser,
+    unit::Unit,
+    value::{Number, Value},
+    Token,
+};
+
 pub mod hsl;
 pub mod hwb;
 pub mod opacity;
@@ -12,3 +22,113 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     other::declare(f);
     rgb::declare(f);
 }
+
+/// Calls `f`, which creates a color from its channels, and if
+/// `Options::preserve_color_format` is set, emits the color using the same
+/// function, e.g. `rgb(0, 0, 0)` rather than `black`
+fn preserving_notation(
+    name: &'static str,
+    args: CallArgs,
+    parser: &mut Parser,
+    f: fn(&'static str, CallArgs, &mut Parser) -> SassResult<Value>,
+) -> SassResult<Value> {
+    // `rgb($color, $alpha)` modifies an existing color
+    if !parser.options.preserve_color_format || args.len() == 2 {
+        return f(name, args, parser);
+    }
+
+    // keyword arguments can't be written in plain CSS
+    let written = args
+        .clone()
+        .to_css_string(parser.options.is_compressed())
+        .ok();
+
+    Ok(match (f(name, args, parser)?, written) {
+        (Value::Color(color), Some(written)) => Value::Color(Box::new(
+            color
+                .with_repr(format!("{}{}", name, written.node))
+                .as_written(),
+        )),
+        (value, ..) => value,
+    })
+}
+
+/// Whether `value` is a call to `var()`, whose value, and so the number of
+/// channels it provides, isn't known until the CSS is used
+fn is_var(value: &Value) -> bool {
+    matches!(value, Value::String(s, QuoteKind::None) if s.to_ascii_lowercase().starts_with("var("))
+}
+
+/// Whether `value` is a channel followed by an alpha that can't be evaluated,
+/// such as `0.5/var(--alpha)`, which is what dividing a number by a special
+/// function produces
+fn is_special_slash(value: &Value) -> bool {
+    matches!(value, Value::String(s, QuoteKind::None) if s.contains('/'))
+}
+
+/// Splits the alpha off of the last of `channels` if it's written after a
+/// slash, as in `rgb(1 2 3 / 0.5)`
+///
+/// A slash between two numbers that aren't divided is kept as an unquoted
+/// string, so the channel and alpha are parsed back out of it.
+fn slash_alpha(channels: &mut Vec<Value>, parser: &mut Parser) -> Option<Value> {
+    let (channel, alpha) = match channels.last() {
+        Some(Value::String(s, QuoteKind::None)) => {
+            let idx = s.find('/')?;
+            (
+                parse_number(&s[..idx], parser)?,
+                parse_number(&s[idx + 1..], parser)?,
+            )
+        }
+        _ => return None,
+    };
+
+    channels.pop();
+    channels.push(channel);
+
+    Some(alpha)
+}
+
+fn parse_number(text: &str, parser: &mut Parser) -> Option<Value> {
+    // only numbers and units are parsed, so nothing can be evaluated
+    if !text
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '%'))
+    {
+        return None;
+    }
+
+    let toks = text
+        .chars()
+        .map(|c| Token::new(parser.span_before, c))
+        .collect::<Vec<Token>>();
+
+    match parser.parse_value_from_vec(&toks, false).ok()?.node {
+        v @ Value::Dimension(..) => Some(v),
+        _ => None,
+    }
+}
+
+/// The `$alpha` of a color, which may be a percentage
+fn alpha_channel(alpha: Value, args: &CallArgs, parser: &Parser) -> SassResult<Number> {
+    match alpha {
+        Value::Dimension(Some(n), Unit::None, _) => Ok(n),
+        Value::Dimension(Some(n), Unit::Percent, _) => Ok(n / Number::from(100)),
+        Value::Dimension(None, ..) => {
+            Err(("$alpha: Expected NaN to be within 0 and 1.", args.span()).into())
+        }
+        v @ Value::Dimension(..) => Err((
+            format!(
+                "$alpha: Expected {} to have no units or \"%\".",
+                v.to_css_string(args.span(), parser.options.is_compressed())?
+            ),
+            args.span(),
+        )
+            .into()),
+        v => Err((
+            format!("$alpha: {} is not a number.", v.inspect(args.span())?),
+            args.span(),
+        )
+            .into()),
+    }
+}
@@ -1,8 +1,8 @@
 use super::{Builtin, GlobalFunctionMap};
 
 use crate::{
-    args::CallArgs, common::QuoteKind, error::SassResult, parse::Parser, unit::Unit, value::Number,
-    value::Value,
+    args::CallArgs, color::Color, common::QuoteKind, compat::Deprecation, error::SassResult,
+    parse::Parser, unit::Unit, value::Number, value::Value,
 };
 
 /// Check if `s` matches the regex `^[a-zA-Z]+\s*=`
@@ -35,49 +35,88 @@ mod test {
     }
 }
 
-pub(crate) fn alpha(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    if args.len() <= 1 {
+/// `module`: whether this is `color.alpha()` rather than the global `alpha()`
+fn inner_alpha(mut args: CallArgs, parser: &mut Parser, module: bool) -> SassResult<Value> {
+    let span = args.span();
+    let (result, error) = if args.len() <= 1 {
         match args.get_err(0, "color")? {
-            Value::Color(c) => Ok(Value::Dimension(Some(c.alpha()), Unit::None, true)),
-            Value::String(s, QuoteKind::None) if is_ms_filter(&s) => {
-                Ok(Value::String(format!("alpha({})", s), QuoteKind::None))
+            Value::Color(c) => return Ok(Value::Dimension(Some(c.alpha()), Unit::None, true)),
+            Value::String(s, QuoteKind::None) if is_ms_filter(&s) => (
+                format!("alpha({})", s),
+                format!("$color: {} is not a color.", s),
+            ),
+            v => {
+                return Err((
+                    format!("$color: {} is not a color.", v.inspect(args.span())?),
+                    args.span(),
+                )
+                    .into())
             }
-            v => Err((
-                format!("$color: {} is not a color.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into()),
         }
     } else {
-        let err = args.max_args(1);
-        let args = args
+        let error = format!("Only 1 argument allowed, but {} were passed.", args.len());
+        let filters = args
             .get_variadic()?
             .into_iter()
             .map(|arg| match arg.node {
-                Value::String(s, QuoteKind::None) if is_ms_filter(&s) => Ok(s),
-                _ => {
-                    err.clone()?;
-                    unreachable!()
-                }
+                Value::String(s, QuoteKind::None) if is_ms_filter(&s) => Some(s),
+                _ => None,
             })
-            .collect::<SassResult<Vec<String>>>()?;
+            .collect::<Option<Vec<String>>>();
+
+        match filters {
+            Some(filters) => (format!("alpha({})", filters.join(", ")), error),
+            None => return Err((error, span).into()),
+        }
+    };
 
-        Ok(Value::String(
-            format!("alpha({})", args.join(", "),),
-            QuoteKind::None,
-        ))
+    if module {
+        parser.deprecation(
+            Deprecation::ColorModuleCompat,
+            &format!(
+                "Using color.alpha() for a Microsoft filter is deprecated.\n\nRecommendation: {}",
+                result
+            ),
+            &error,
+            span,
+        )?;
     }
+
+    Ok(Value::String(result, QuoteKind::None))
+}
+
+pub(crate) fn alpha(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_alpha(args, parser, false)
+}
+
+pub(crate) fn color_alpha(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_alpha(args, parser, true)
 }
 
-pub(crate) fn opacity(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+/// `module`: whether this is `color.opacity()` rather than the global
+/// `opacity()`
+fn inner_opacity(mut args: CallArgs, parser: &mut Parser, module: bool) -> SassResult<Value> {
     args.max_args(1)?;
     match args.get_err(0, "color")? {
         Value::Color(c) => Ok(Value::Dimension(Some(c.alpha()), Unit::None, true)),
-        Value::Dimension(Some(num), unit, _) => Ok(Value::String(
-            format!("opacity({}{})", num.inspect(), unit),
-            QuoteKind::None,
-        )),
-        Value::Dimension(None, ..) => todo!(),
+        v @ Value::Dimension(..) => {
+            let result = format!("opacity({})", v.to_css_string(args.span(), false)?);
+
+            if module {
+                parser.deprecation(
+                    Deprecation::ColorModuleCompat,
+                    &format!(
+                        "Passing a number ({}) to color.opacity() is deprecated.\n\nRecommendation: {}",
+                        v.inspect(args.span())?,
+                        result
+                    ),
+                    &format!("$color: {} is not a color.", v.inspect(args.span())?),
+                    args.span(),
+                )?;
+            }
+
+            Ok(Value::String(result, QuoteKind::None))
+        }
         v => Err((
             format!("$color: {} is not a color.", v.inspect(args.span())?),
             args.span(),
@@ -86,61 +125,17 @@ pub(crate) fn opacity(mut args: CallArgs, parser: &mut Parser) -> SassResult<Val
     }
 }
 
-// todo: unify `opacify` and `fade_in`
-fn opacify(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    args.max_args(2)?;
-    let color = match args.get_err(0, "color")? {
-        Value::Color(c) => c,
-        v => {
-            return Err((
-                format!("$color: {} is not a color.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into())
-        }
-    };
-    let amount = match args.get_err(1, "amount")? {
-        Value::Dimension(Some(n), u, _) => bound!(args, "amount", n, u, 0, 1),
-        Value::Dimension(None, ..) => todo!(),
-        v => {
-            return Err((
-                format!("$amount: {} is not a number.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into())
-        }
-    };
-    Ok(Value::Color(Box::new(color.fade_in(amount))))
+pub(crate) fn opacity(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_opacity(args, parser, false)
 }
 
-fn fade_in(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    args.max_args(2)?;
-    let color = match args.get_err(0, "color")? {
-        Value::Color(c) => c,
-        v => {
-            return Err((
-                format!("$color: {} is not a color.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into())
-        }
-    };
-    let amount = match args.get_err(1, "amount")? {
-        Value::Dimension(Some(n), u, _) => bound!(args, "amount", n, u, 0, 1),
-        Value::Dimension(None, ..) => todo!(),
-        v => {
-            return Err((
-                format!("$amount: {} is not a number.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into())
-        }
-    };
-    Ok(Value::Color(Box::new(color.fade_in(amount))))
+pub(crate) fn color_opacity(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    inner_opacity(args, parser, true)
 }
 
-// todo: unify with `fade_out`
-fn transparentize(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+/// The `$color` and `$amount` arguments shared by `opacify()` and
+/// `transparentize()`, and their aliases
+fn color_and_amount(args: &mut CallArgs, parser: &mut Parser) -> SassResult<(Box<Color>, Number)> {
     args.max_args(2)?;
     let color = match args.get_err(0, "color")? {
         Value::Color(c) => c,
@@ -154,33 +149,13 @@ fn transparentize(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value>
     };
     let amount = match args.get_err(1, "amount")? {
         Value::Dimension(Some(n), u, _) => bound!(args, "amount", n, u, 0, 1),
-        Value::Dimension(None, ..) => todo!(),
-        v => {
+        Value::Dimension(None, u, _) => {
             return Err((
-                format!("$amount: {} is not a number.", v.inspect(args.span())?),
+                format!("$amount: Expected NaN{} to be within 0{} and 1{}.", u, u, u),
                 args.span(),
             )
                 .into())
         }
-    };
-    Ok(Value::Color(Box::new(color.fade_out(amount))))
-}
-
-fn fade_out(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    args.max_args(2)?;
-    let color = match args.get_err(0, "color")? {
-        Value::Color(c) => c,
-        v => {
-            return Err((
-                format!("$color: {} is not a color.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into())
-        }
-    };
-    let amount = match args.get_err(1, "amount")? {
-        Value::Dimension(Some(n), u, _) => bound!(args, "amount", n, u, 0, 1),
-        Value::Dimension(None, ..) => todo!(),
         v => {
             return Err((
                 format!("$amount: {} is not a number.", v.inspect(args.span())?),
@@ -189,6 +164,18 @@ fn fade_out(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
                 .into())
         }
     };
+    Ok((color, amount))
+}
+
+/// Also available as `fade-in()`
+fn opacify(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    let (color, amount) = color_and_amount(&mut args, parser)?;
+    Ok(Value::Color(Box::new(color.fade_in(amount))))
+}
+
+/// Also available as `fade-out()`
+fn transparentize(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    let (color, amount) = color_and_amount(&mut args, parser)?;
     Ok(Value::Color(Box::new(color.fade_out(amount))))
 }
 
@@ -196,7 +183,7 @@ pub(crate) fn declare(f: &mut GlobalFunctionMap) {
     f.insert("alpha", Builtin::new(alpha));
     f.insert("opacity", Builtin::new(opacity));
     f.insert("opacify", Builtin::new(opacify));
-    f.insert("fade-in", Builtin::new(fade_in));
+    f.insert("fade-in", Builtin::new(opacify));
     f.insert("transparentize", Builtin::new(transparentize));
-    f.insert("fade-out", Builtin::new(fade_out));
+    f.insert("fade-out", Builtin::new(transparentize));
 }
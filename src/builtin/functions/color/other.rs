@@ -1,6 +1,6 @@
 use super::{Builtin, GlobalFunctionMap};
 
-use num_traits::{One, Signed, Zero};
+use num_traits::Signed;
 
 use crate::{
     args::CallArgs,
@@ -12,285 +12,229 @@ use crate::{
     value::{Number, Value},
 };
 
-macro_rules! opt_rgba {
-    ($args:ident, $name:ident, $arg:literal, $low:literal, $high:literal) => {
-        let $name = match $args.default_named_arg($arg, Value::Null)? {
-            Value::Dimension(Some(n), u, _) => Some(bound!($args, $arg, n, u, $low, $high)),
-            Value::Dimension(None, ..) => todo!(),
-            Value::Null => None,
-            v => {
-                return Err((
-                    format!("${}: {} is not a number.", $arg, v.inspect($args.span())?),
-                    $args.span(),
-                )
-                    .into())
-            }
-        };
-    };
-}
-
-macro_rules! opt_hsl {
-    ($args:ident, $name:ident, $arg:literal, $low:literal, $high:literal) => {
-        let $name = match $args.default_named_arg($arg, Value::Null)? {
-            Value::Dimension(Some(n), u, _) => {
-                Some(bound!($args, $arg, n, u, $low, $high) / Number::from(100))
-            }
-            Value::Dimension(None, ..) => todo!(),
-            Value::Null => None,
-            v => {
-                return Err((
-                    format!("${}: {} is not a number.", $arg, v.inspect($args.span())?),
-                    $args.span(),
-                )
-                    .into())
-            }
-        };
-    };
+/// How `change-color()`, `adjust-color()`, and `scale-color()` update the
+/// channels they are passed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Update {
+    /// Replace the channel with the argument
+    Change,
+    /// Add the argument to the channel
+    Adjust,
+    /// Move the channel the argument's percentage of the way to its minimum
+    /// or maximum
+    Scale,
 }
 
-pub(crate) fn change_color(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    if args.positional_arg(1).is_some() {
-        return Err((
-            "Only one positional argument is allowed. All other arguments must be passed by name.",
-            args.span(),
-        )
-            .into());
-    }
+/// Removes the keyword argument for the channel `name`, whose values range
+/// from 0 to `max`, checking that it is a number within the range allowed
+/// for `update`
+///
+/// `unit` is the unit the range is written with in errors, and `assert_percent`
+/// requires the argument to have the unit `%`, as is always the case when
+/// scaling.
+fn channel_arg(
+    args: &mut CallArgs,
+    update: Update,
+    name: &'static str,
+    max: i32,
+    unit: &'static str,
+    assert_percent: bool,
+) -> SassResult<Option<Number>> {
+    let span = args.span();
 
-    let color = match args.get_err(0, "color")? {
-        Value::Color(c) => c,
+    let (num, num_unit) = match args.default_named_arg(name, Value::Null)? {
+        Value::Dimension(num, unit, _) => (num, unit),
+        Value::Null => return Ok(None),
         v => {
             return Err((
-                format!("$color: {} is not a color.", v.inspect(args.span())?),
-                args.span(),
+                format!("${}: {} is not a number.", name, v.inspect(span)?),
+                span,
             )
                 .into())
         }
     };
 
-    opt_rgba!(args, alpha, "alpha", 0, 1);
-    opt_rgba!(args, red, "red", 0, 255);
-    opt_rgba!(args, green, "green", 0, 255);
-    opt_rgba!(args, blue, "blue", 0, 255);
-
-    if red.is_some() || green.is_some() || blue.is_some() {
-        return Ok(Value::Color(Box::new(Color::from_rgba(
-            red.unwrap_or_else(|| color.red()),
-            green.unwrap_or_else(|| color.green()),
-            blue.unwrap_or_else(|| color.blue()),
-            alpha.unwrap_or_else(|| color.alpha()),
-        ))));
-    }
-
-    let hue = match args.default_named_arg("hue", Value::Null)? {
-        Value::Dimension(Some(n), ..) => Some(n),
-        Value::Dimension(None, ..) => todo!(),
-        Value::Null => None,
-        v => {
+    let (max, unit) = if update == Update::Scale || assert_percent {
+        if num_unit != Unit::Percent {
             return Err((
-                format!("$hue: {} is not a number.", v.inspect(args.span())?),
-                args.span(),
+                format!(
+                    "${}: Expected {} to have unit \"%\".",
+                    name,
+                    Value::Dimension(num, num_unit, true).inspect(span)?
+                ),
+                span,
             )
-                .into())
+                .into());
         }
+
+        (100, "%")
+    } else {
+        (max, unit)
     };
 
-    opt_hsl!(args, saturation, "saturation", 0, 100);
-    opt_hsl!(args, luminance, "lightness", 0, 100);
+    let min = if update == Update::Change { 0 } else { -max };
 
-    if hue.is_some() || saturation.is_some() || luminance.is_some() {
-        // Color::as_hsla() returns more exact values than Color::hue(), etc.
-        let (this_hue, this_saturation, this_luminance, this_alpha) = color.as_hsla();
-        return Ok(Value::Color(Box::new(Color::from_hsla(
-            hue.unwrap_or(this_hue),
-            saturation.unwrap_or(this_saturation),
-            luminance.unwrap_or(this_luminance),
-            alpha.unwrap_or(this_alpha),
-        ))));
+    match num {
+        Some(num) if num >= Number::from(min) && num <= Number::from(max) => Ok(Some(num)),
+        num => Err((
+            format!(
+                "${}: Expected {}{} to be within {}{} and {}{}.",
+                name,
+                num.map_or_else(|| "NaN".to_owned(), |num| num.inspect()),
+                num_unit,
+                min,
+                unit,
+                max,
+                unit,
+            ),
+            span,
+        )
+            .into()),
     }
-
-    Ok(Value::Color(if let Some(a) = alpha {
-        Box::new(color.with_alpha(a))
-    } else {
-        color
-    }))
 }
 
-pub(crate) fn adjust_color(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+/// The implementation shared by `change-color()`, `adjust-color()`, and
+/// `scale-color()`, which update the RGB, HSL, or HWB channels of a color
+/// along with its alpha channel
+fn update_components(mut args: CallArgs, update: Update) -> SassResult<Value> {
+    let span = args.span();
+
+    if args.positional_arg(1).is_some() {
+        return Err((
+            "Only one positional argument is allowed. All other arguments must be passed by name.",
+            span,
+        )
+            .into());
+    }
+
     let color = match args.get_err(0, "color")? {
         Value::Color(c) => c,
         v => {
             return Err((
-                format!("$color: {} is not a color.", v.inspect(args.span())?),
-                args.span(),
+                format!("$color: {} is not a color.", v.inspect(span)?),
+                span,
             )
                 .into())
         }
     };
 
-    opt_rgba!(args, alpha, "alpha", -1, 1);
-    opt_rgba!(args, red, "red", -255, 255);
-    opt_rgba!(args, green, "green", -255, 255);
-    opt_rgba!(args, blue, "blue", -255, 255);
+    let alpha = channel_arg(&mut args, update, "alpha", 1, "", false)?;
+    let red = channel_arg(&mut args, update, "red", 255, "", false)?;
+    let green = channel_arg(&mut args, update, "green", 255, "", false)?;
+    let blue = channel_arg(&mut args, update, "blue", 255, "", false)?;
 
-    if red.is_some() || green.is_some() || blue.is_some() {
-        return Ok(Value::Color(Box::new(Color::from_rgba(
-            color.red() + red.unwrap_or_else(Number::zero),
-            color.green() + green.unwrap_or_else(Number::zero),
-            color.blue() + blue.unwrap_or_else(Number::zero),
-            color.alpha() + alpha.unwrap_or_else(Number::zero),
-        ))));
-    }
-
-    let hue = match args.default_named_arg("hue", Value::Null)? {
-        Value::Dimension(Some(n), ..) => Some(n),
-        Value::Dimension(None, ..) => todo!(),
-        Value::Null => None,
-        v => {
-            return Err((
-                format!("$hue: {} is not a number.", v.inspect(args.span())?),
-                args.span(),
-            )
-                .into())
+    // the hue can't be scaled, as it has no minimum or maximum
+    let hue = if update == Update::Scale {
+        None
+    } else {
+        match args.default_named_arg("hue", Value::Null)? {
+            Value::Dimension(Some(n), ..) => Some(n),
+            Value::Null => None,
+            v => return Err((format!("$hue: {} is not a number.", v.inspect(span)?), span).into()),
         }
     };
 
-    opt_hsl!(args, saturation, "saturation", -100, 100);
-    opt_hsl!(args, luminance, "lightness", -100, 100);
+    let saturation = channel_arg(&mut args, update, "saturation", 100, "%", false)?;
+    let lightness = channel_arg(&mut args, update, "lightness", 100, "%", false)?;
+    let whiteness = channel_arg(&mut args, update, "whiteness", 100, "%", true)?;
+    let blackness = channel_arg(&mut args, update, "blackness", 100, "%", true)?;
 
-    if hue.is_some() || saturation.is_some() || luminance.is_some() {
-        // Color::as_hsla() returns more exact values than Color::hue(), etc.
-        let (this_hue, this_saturation, this_luminance, this_alpha) = color.as_hsla();
-        return Ok(Value::Color(Box::new(Color::from_hsla(
-            this_hue + hue.unwrap_or_else(Number::zero),
-            this_saturation + saturation.unwrap_or_else(Number::zero),
-            this_luminance + luminance.unwrap_or_else(Number::zero),
-            this_alpha + alpha.unwrap_or_else(Number::zero),
-        ))));
-    }
+    let has_rgb = red.is_some() || green.is_some() || blue.is_some();
+    let has_sl = saturation.is_some() || lightness.is_some();
+    let has_wb = whiteness.is_some() || blackness.is_some();
 
-    Ok(Value::Color(if let Some(a) = alpha {
-        let temp_alpha = color.alpha();
-        Box::new(color.with_alpha(temp_alpha + a))
-    } else {
-        color
-    }))
-}
+    if has_rgb && (has_sl || has_wb || hue.is_some()) {
+        return Err((
+            format!(
+                "RGB parameters may not be passed along with {} parameters.",
+                if has_wb { "HWB" } else { "HSL" }
+            ),
+            span,
+        )
+            .into());
+    }
 
-#[allow(clippy::cognitive_complexity)]
-// todo: refactor into rgb and hsl?
-pub(crate) fn scale_color(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    pub(crate) fn scale(val: Number, by: Number, max: Number) -> Number {
-        if by.is_zero() {
-            return val;
-        }
-        val.clone() + (if by.is_positive() { max - val } else { val }) * by
+    if has_sl && has_wb {
+        return Err((
+            "HSL parameters may not be passed along with HWB parameters.",
+            span,
+        )
+            .into());
     }
 
-    let span = args.span();
-    let color = match args.get_err(0, "color")? {
-        Value::Color(c) => c,
-        v => {
-            return Err((
-                format!("$color: {} is not a color.", v.inspect(span)?),
-                span,
-            )
-                .into())
-        }
-    };
+    let update_channel = |arg: Option<Number>, current: Number, max: i32| -> Number {
+        let arg = match arg {
+            Some(arg) => arg,
+            None => return current,
+        };
 
-    macro_rules! opt_scale_arg {
-        ($args:ident, $name:ident, $arg:literal, $low:literal, $high:literal) => {
-            let $name = match $args.default_named_arg($arg, Value::Null)? {
-                Value::Dimension(Some(n), Unit::Percent, _) => {
-                    Some(bound!($args, $arg, n, Unit::Percent, $low, $high) / Number::from(100))
-                }
-                Value::Dimension(None, ..) => todo!(),
-                v @ Value::Dimension(..) => {
-                    return Err((
-                        format!(
-                            "${}: Expected {} to have unit \"%\".",
-                            $arg,
-                            v.inspect($args.span())?
-                        ),
-                        $args.span(),
-                    )
-                        .into())
-                }
-                Value::Null => None,
-                v => {
-                    return Err((
-                        format!("${}: {} is not a number.", $arg, v.inspect($args.span())?),
-                        $args.span(),
-                    )
-                        .into())
+        match update {
+            Update::Change => arg,
+            Update::Adjust => (current + arg).clamp(0, max),
+            Update::Scale => {
+                let by = arg / Number::from(100);
+
+                if by.is_positive() {
+                    current.clone() + (Number::from(max) - current) * by
+                } else {
+                    current.clone() + current * by
                 }
-            };
-        };
-    }
+            }
+        }
+    };
 
-    opt_scale_arg!(args, alpha, "alpha", -100, 100);
-    opt_scale_arg!(args, red, "red", -100, 100);
-    opt_scale_arg!(args, green, "green", -100, 100);
-    opt_scale_arg!(args, blue, "blue", -100, 100);
+    let new_alpha = update_channel(alpha.clone(), color.alpha(), 1);
 
-    if red.is_some() || green.is_some() || blue.is_some() {
-        return Ok(Value::Color(Box::new(Color::from_rgba(
-            scale(
-                color.red(),
-                red.unwrap_or_else(Number::zero),
-                Number::from(255),
-            ),
-            scale(
-                color.green(),
-                green.unwrap_or_else(Number::zero),
-                Number::from(255),
-            ),
-            scale(
-                color.blue(),
-                blue.unwrap_or_else(Number::zero),
-                Number::from(255),
-            ),
-            scale(
-                color.alpha(),
-                alpha.unwrap_or_else(Number::zero),
-                Number::one(),
-            ),
-        ))));
-    }
+    // Color::as_hsla() returns more exact values than Color::hue(), etc.
+    let (this_hue, this_saturation, this_lightness, _) = color.as_hsla();
 
-    opt_scale_arg!(args, saturation, "saturation", -100, 100);
-    opt_scale_arg!(args, luminance, "lightness", -100, 100);
+    let has_hue = hue.is_some();
 
-    if saturation.is_some() || luminance.is_some() {
-        // Color::as_hsla() returns more exact values than Color::hue(), etc.
-        let (this_hue, this_saturation, this_luminance, this_alpha) = color.as_hsla();
-        return Ok(Value::Color(Box::new(Color::from_hsla(
-            scale(this_hue, Number::zero(), Number::from(360)),
-            scale(
-                this_saturation,
-                saturation.unwrap_or_else(Number::zero),
-                Number::one(),
-            ),
-            scale(
-                this_luminance,
-                luminance.unwrap_or_else(Number::zero),
-                Number::one(),
-            ),
-            scale(
-                this_alpha,
-                alpha.unwrap_or_else(Number::zero),
-                Number::one(),
-            ),
-        ))));
-    }
+    let new_hue = match hue {
+        Some(hue) if update == Update::Change => hue,
+        Some(hue) => this_hue + hue,
+        None => this_hue,
+    };
 
-    Ok(Value::Color(if let Some(a) = alpha {
-        let temp_alpha = color.alpha();
-        Box::new(color.with_alpha(scale(temp_alpha, a, Number::one())))
+    Ok(Value::Color(Box::new(if has_rgb {
+        Color::from_rgba(
+            update_channel(red, color.red(), 255),
+            update_channel(green, color.green(), 255),
+            update_channel(blue, color.blue(), 255),
+            new_alpha,
+        )
+    } else if has_wb {
+        Color::from_hwb(
+            new_hue,
+            update_channel(whiteness, color.whiteness(), 100),
+            update_channel(blackness, color.blackness(), 100),
+            new_alpha,
+        )
+    } else if has_hue || has_sl {
+        Color::from_hsla(
+            new_hue,
+            update_channel(saturation, this_saturation * Number::from(100), 100)
+                / Number::from(100),
+            update_channel(lightness, this_lightness * Number::from(100), 100) / Number::from(100),
+            new_alpha,
+        )
+    } else if alpha.is_some() {
+        color.with_alpha(new_alpha)
     } else {
-        color
-    }))
+        return Ok(Value::Color(color));
+    })))
+}
+
+pub(crate) fn change_color(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    update_components(args, Update::Change)
+}
+
+pub(crate) fn adjust_color(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    update_components(args, Update::Adjust)
+}
+
+pub(crate) fn scale_color(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    update_components(args, Update::Scale)
 }
 
 pub(crate) fn ie_hex_str(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
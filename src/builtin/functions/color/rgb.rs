@@ -1,4 +1,6 @@
-use super::{Builtin, GlobalFunctionMap};
+use super::{
+    alpha_channel, is_special_slash, preserving_notation, slash_alpha, Builtin, GlobalFunctionMap,
+};
 
 use num_traits::One;
 
@@ -40,6 +42,20 @@ fn inner_rgb(name: &'static str, mut args: CallArgs, parser: &mut Parser) -> Sas
                 .into());
         }
 
+        let alpha = slash_alpha(&mut channels, parser);
+
+        if channels.last().map_or(false, is_special_slash) {
+            return Ok(Value::String(
+                format!(
+                    "{}({})",
+                    name,
+                    Value::List(channels, ListSeparator::Space, Brackets::None)
+                        .to_css_string(args.span(), false)?
+                ),
+                QuoteKind::None,
+            ));
+        }
+
         if channels.iter().any(Value::is_special_function) {
             let channel_sep = if channels.len() < 3 {
                 ListSeparator::Space
@@ -150,7 +166,12 @@ fn inner_rgb(name: &'static str, mut args: CallArgs, parser: &mut Parser) -> Sas
             None => return Err(("Missing element $red.", args.span()).into()),
         };
 
-        let color = Color::from_rgba(red, green, blue, Number::one());
+        let alpha = match alpha {
+            Some(alpha) => alpha_channel(alpha, &args, parser)?,
+            None => Number::one(),
+        };
+
+        let color = Color::from_rgba(red, green, blue, alpha);
 
         Ok(Value::Color(Box::new(color)))
     } else if len == 2 {
@@ -352,11 +373,11 @@ fn inner_rgb(name: &'static str, mut args: CallArgs, parser: &mut Parser) -> Sas
 }
 
 pub(crate) fn rgb(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    inner_rgb("rgb", args, parser)
+    preserving_notation("rgb", args, parser, inner_rgb)
 }
 
 pub(crate) fn rgba(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    inner_rgb("rgba", args, parser)
+    preserving_notation("rgba", args, parser, inner_rgb)
 }
 
 pub(crate) fn red(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
@@ -82,7 +82,7 @@ pub(crate) fn set_nth(mut args: CallArgs, parser: &mut Parser) -> SassResult<Val
     args.max_args(3)?;
     let (mut list, sep, brackets) = match args.get_err(0, "list")? {
         Value::List(v, sep, b) => (v, sep, b),
-        Value::ArgList(v) => (
+        Value::ArgList(v, ..) => (
             v.into_iter().map(|val| val.node).collect(),
             ListSeparator::Comma,
             Brackets::None,
@@ -142,6 +142,7 @@ pub(crate) fn append(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
     args.max_args(3)?;
     let (mut list, sep, brackets) = match args.get_err(0, "list")? {
         Value::List(v, sep, b) => (v, sep, b),
+        Value::Map(m) => (m.as_list(), ListSeparator::Comma, Brackets::None),
         v => (vec![v], ListSeparator::Space, Brackets::None),
     };
     let val = args.get_err(1, "val")?;
@@ -154,9 +155,10 @@ pub(crate) fn append(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
             "auto" => sep,
             "comma" => ListSeparator::Comma,
             "space" => ListSeparator::Space,
+            "slash" => ListSeparator::Slash,
             _ => {
                 return Err((
-                    "$separator: Must be \"space\", \"comma\", or \"auto\".",
+                    "$separator: Must be \"space\", \"comma\", \"slash\", or \"auto\".",
                     args.span(),
                 )
                     .into())
@@ -203,9 +205,10 @@ pub(crate) fn join(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value>
             }
             "comma" => ListSeparator::Comma,
             "space" => ListSeparator::Space,
+            "slash" => ListSeparator::Slash,
             _ => {
                 return Err((
-                    "$separator: Must be \"space\", \"comma\", or \"auto\".",
+                    "$separator: Must be \"space\", \"comma\", \"slash\", or \"auto\".",
                     args.span(),
                 )
                     .into())
@@ -9,12 +9,11 @@ use crate::{
 };
 
 pub(crate) fn map_get(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    args.max_args(2)?;
     let key = args.get_err(1, "key")?;
     let map = match args.get_err(0, "map")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map: {} is not a map.", v.inspect(args.span())?),
@@ -23,16 +22,26 @@ pub(crate) fn map_get(mut args: CallArgs, parser: &mut Parser) -> SassResult<Val
                 .into())
         }
     };
-    Ok(map.get(&key).unwrap_or(Value::Null))
+
+    // any further keys are looked up in the nested maps
+    let mut value = map.get(&key);
+
+    for key in args.get_variadic()? {
+        value = match value {
+            Some(Value::Map(nested)) => nested.get(&key.node),
+            Some(..) | None => return Ok(Value::Null),
+        };
+    }
+
+    Ok(value.unwrap_or(Value::Null))
 }
 
 pub(crate) fn map_has_key(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
-    args.max_args(2)?;
     let key = args.get_err(1, "key")?;
-    let map = match args.get_err(0, "map")? {
+    let mut map = match args.get_err(0, "map")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map: {} is not a map.", v.inspect(args.span())?),
@@ -41,6 +50,19 @@ pub(crate) fn map_has_key(mut args: CallArgs, parser: &mut Parser) -> SassResult
                 .into())
         }
     };
+
+    // with further keys, the last one is looked for in the map found by
+    // following the others
+    let mut key = key;
+
+    for next in args.get_variadic()? {
+        map = match map.get(&key) {
+            Some(Value::Map(nested)) => nested,
+            Some(..) | None => return Ok(Value::False),
+        };
+        key = next.node;
+    }
+
     Ok(Value::bool(map.get(&key).is_some()))
 }
 
@@ -49,7 +71,7 @@ pub(crate) fn map_keys(mut args: CallArgs, parser: &mut Parser) -> SassResult<Va
     let map = match args.get_err(0, "map")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map: {} is not a map.", v.inspect(args.span())?),
@@ -70,7 +92,7 @@ pub(crate) fn map_values(mut args: CallArgs, parser: &mut Parser) -> SassResult<
     let map = match args.get_err(0, "map")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map: {} is not a map.", v.inspect(args.span())?),
@@ -96,7 +118,7 @@ pub(crate) fn map_merge(mut args: CallArgs, parser: &mut Parser) -> SassResult<V
     let mut map1 = match args.get_err(0, "map1")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map1: {} is not a map.", v.inspect(args.span())?),
@@ -109,7 +131,7 @@ pub(crate) fn map_merge(mut args: CallArgs, parser: &mut Parser) -> SassResult<V
     let map2 = match args.get_err(map2_position, "map2")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map2: {} is not a map.", v.inspect(args.span())?),
@@ -167,7 +189,7 @@ pub(crate) fn map_remove(mut args: CallArgs, parser: &mut Parser) -> SassResult<
     let mut map = match args.get_err(0, "map")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map: {} is not a map.", v.inspect(args.span())?),
@@ -190,7 +212,7 @@ pub(crate) fn map_set(mut args: CallArgs, parser: &mut Parser) -> SassResult<Val
     let mut map = match args.get_err(0, "map")? {
         Value::Map(m) => m,
         Value::List(v, ..) if v.is_empty() => SassMap::new(),
-        Value::ArgList(v) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
         v => {
             return Err((
                 format!("$map: {} is not a map.", v.inspect(args.span())?),
@@ -200,6 +222,12 @@ pub(crate) fn map_set(mut args: CallArgs, parser: &mut Parser) -> SassResult<Val
         }
     };
 
+    match value_position {
+        0 => return Err(("Expected $args to contain a key.", args.span()).into()),
+        1 => return Err(("Expected $args to contain a value.", args.span()).into()),
+        _ => {}
+    }
+
     let key = args.get_err(key_position, "key")?;
     let value = args.get_err(value_position, "value")?;
 
@@ -1,5 +1,6 @@
 use super::{Builtin, GlobalFunctionMap};
 
+use codemap::Spanned;
 #[cfg(feature = "random")]
 use num_traits::{One, Signed, ToPrimitive, Zero};
 #[cfg(feature = "random")]
@@ -7,11 +8,12 @@ use rand::Rng;
 
 use crate::{
     args::CallArgs,
-    common::Op,
+    compat::{Deprecation, DeprecationStatus},
     error::SassResult,
     parse::{HigherIntermediateValue, Parser, ValueVisitor},
     unit::Unit,
     value::{Number, Value},
+    Cow,
 };
 
 pub(crate) fn percentage(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
@@ -118,12 +120,11 @@ pub(crate) fn comparable(mut args: CallArgs, parser: &mut Parser) -> SassResult<
     Ok(Value::bool(unit1.comparable(&unit2)))
 }
 
-// TODO: write tests for this
 #[cfg(feature = "random")]
 pub(crate) fn random(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     args.max_args(1)?;
-    let limit = match args.default_arg(0, "limit", Value::Null)? {
-        Value::Dimension(Some(n), ..) => n,
+    let (limit, unit) = match args.default_arg(0, "limit", Value::Null)? {
+        Value::Dimension(Some(n), unit, ..) => (n, unit),
         Value::Dimension(None, u, ..) => {
             return Err((format!("$limit: NaN{} is not an int.", u), args.span()).into())
         }
@@ -144,8 +145,33 @@ pub(crate) fn random(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
         }
     };
 
+    let unit = if unit == Unit::None {
+        unit
+    } else {
+        match parser
+            .options
+            .deprecation_status(Deprecation::RandomWithUnits)
+        {
+            DeprecationStatus::Supported => Unit::None,
+            DeprecationStatus::Deprecated => {
+                parser.warn(&Spanned {
+                    node: Cow::owned(format!(
+                        "random() will no longer ignore $limit units ({}{}) in a future release.\n\nRecommendation: random(math.div($limit, 1{})) * 1{}",
+                        limit.inspect(),
+                        unit,
+                        unit,
+                        unit
+                    )),
+                    span: args.span(),
+                })?;
+                Unit::None
+            }
+            DeprecationStatus::Removed => unit,
+        }
+    };
+
     if limit.is_one() {
-        return Ok(Value::Dimension(Some(Number::one()), Unit::None, true));
+        return Ok(Value::Dimension(Some(Number::one()), unit, true));
     }
 
     if limit.is_decimal() {
@@ -181,7 +207,7 @@ pub(crate) fn random(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
     let mut rng = rand::thread_rng();
     Ok(Value::Dimension(
         Some(Number::from(rng.gen_range(0..limit) + 1)),
-        Unit::None,
+        unit,
         true,
     ))
 }
@@ -284,12 +310,20 @@ pub(crate) fn divide(mut args: CallArgs, parser: &mut Parser) -> SassResult<Valu
     let number1 = args.get_err(0, "number1")?;
     let number2 = args.get_err(1, "number2")?;
 
-    ValueVisitor::new(parser, args.span()).eval(
-        HigherIntermediateValue::BinaryOp(
-            Box::new(HigherIntermediateValue::Literal(number1)),
-            Op::Div,
-            Box::new(HigherIntermediateValue::Literal(number2)),
-        ),
+    if !matches!(number1, Value::Dimension(..)) || !matches!(number2, Value::Dimension(..)) {
+        // `math.div()` was added alongside the deprecation of `/` for division
+        if parser.options.deprecation_status(Deprecation::SlashDiv) != DeprecationStatus::Supported
+        {
+            parser.warn(&Spanned {
+                node: Cow::const_str("math.div() will only support number arguments in a future release.\n\nUse list.slash() instead for a slash separator."),
+                span: args.span(),
+            })?;
+        }
+    }
+
+    ValueVisitor::new(parser, args.span()).div(
+        HigherIntermediateValue::Literal(number1),
+        HigherIntermediateValue::Literal(number2),
         true,
     )
 }
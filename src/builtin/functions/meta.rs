@@ -5,6 +5,7 @@ use codemap::Spanned;
 use crate::{
     args::CallArgs,
     common::{Identifier, QuoteKind},
+    compat::Deprecation,
     error::SassResult,
     parse::Parser,
     unit::Unit,
@@ -13,10 +14,22 @@ use crate::{
 
 fn if_(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     args.max_args(3)?;
-    if args.get_err(0, "condition")?.is_true() {
-        Ok(args.get_err(1, "if-true")?)
+    let condition = args.get_err(0, "condition")?;
+
+    // both branches are looked up so that their names are known, but only the
+    // chosen branch is evaluated
+    let if_true = args.get(1, "if-true");
+    let if_false = args.get(2, "if-false");
+
+    let (value, name) = if condition.is_true() {
+        (if_true, "if-true")
     } else {
-        Ok(args.get_err(2, "if-false")?)
+        (if_false, "if-false")
+    };
+
+    match value {
+        Some(v) => Ok(v?.node),
+        None => Err((format!("Missing argument ${}.", name), args.span()).into()),
     }
 }
 
@@ -217,8 +230,8 @@ pub(crate) fn function_exists(mut args: CallArgs, parser: &mut Parser) -> SassRe
 
 pub(crate) fn get_function(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     args.max_args(3)?;
-    let name: Identifier = match args.get_err(0, "name")? {
-        Value::String(s, _) => s.into(),
+    let name = match args.get_err(0, "name")? {
+        Value::String(s, _) => s,
         v => {
             return Err((
                 format!("$name: {} is not a string.", v.inspect(args.span())?),
@@ -240,8 +253,8 @@ pub(crate) fn get_function(mut args: CallArgs, parser: &mut Parser) -> SassResul
         }
     };
 
-    let func = match if let Some(module_name) = module {
-        if css {
+    if css {
+        if module.is_some() {
             return Err((
                 "$css and $module may not both be passed at once.",
                 args.span(),
@@ -249,6 +262,12 @@ pub(crate) fn get_function(mut args: CallArgs, parser: &mut Parser) -> SassResul
                 .into());
         }
 
+        return Ok(Value::FunctionRef(SassFunction::Plain(name)));
+    }
+
+    let name = Identifier::from(name);
+
+    let func = match if let Some(module_name) = module {
         parser
             .modules
             .get(module_name.into(), args.span())?
@@ -257,21 +276,48 @@ pub(crate) fn get_function(mut args: CallArgs, parser: &mut Parser) -> SassResul
                 span: args.span(),
             })?
     } else {
-        parser.scopes.get_fn(name, parser.global_scope)
+        lookup_function(name, parser)
     } {
         Some(f) => f,
-        None => match GLOBAL_FUNCTIONS.get(name.as_str()) {
-            Some(f) => SassFunction::Builtin(f.clone(), name),
-            None => return Err((format!("Function not found: {}", name), args.span()).into()),
-        },
+        None => return Err((format!("Function not found: {}", name), args.span()).into()),
     };
 
     Ok(Value::FunctionRef(func))
 }
 
+/// The user-defined or global builtin function called `name`
+fn lookup_function(name: Identifier, parser: &Parser) -> Option<SassFunction> {
+    parser.scopes.get_fn(name, parser.global_scope).or_else(|| {
+        GLOBAL_FUNCTIONS
+            .get(name.as_str())
+            .map(|f| SassFunction::Builtin(f.clone(), name))
+    })
+}
+
 pub(crate) fn call(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    let span = args.span();
     let func = match args.get_err(0, "function")? {
         Value::FunctionRef(f) => f,
+        // functions could be called by name before `get-function()` existed,
+        // in which case an unknown name is a plain CSS function
+        Value::String(name, quotes) => {
+            let error = format!(
+                "$function: {} is not a function reference.",
+                Value::String(name.clone(), quotes).inspect(span)?
+            );
+
+            parser.deprecation(
+                Deprecation::CallString,
+                &format!(
+                    "Passing a string to call() is deprecated and will be illegal in Dart Sass 2.0.0.\n\nRecommendation: call(get-function({}))",
+                    Value::String(name.clone(), quotes).inspect(span)?
+                ),
+                &error,
+                span,
+            )?;
+
+            lookup_function(Identifier::from(&name), parser).unwrap_or(SassFunction::Plain(name))
+        }
         v => {
             return Err((
                 format!(
@@ -283,7 +329,7 @@ pub(crate) fn call(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value>
                 .into())
         }
     };
-    func.call(args.decrement(), None, parser)
+    func.call(args.decrement(), None, span, parser)
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -301,15 +347,20 @@ pub(crate) fn content_exists(args: CallArgs, parser: &mut Parser) -> SassResult<
     ))
 }
 
-#[allow(unused_variables, clippy::needless_pass_by_value)]
-pub(crate) fn keywords(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+pub(crate) fn keywords(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     args.max_args(1)?;
 
-    Err((
-        "Builtin function `keywords` is not yet implemented",
-        args.span(),
-    )
-        .into())
+    match args.get_err(0, "args")? {
+        Value::ArgList(_, keywords) => Ok(Value::Map(keywords)),
+        v => Err((
+            format!(
+                "$args: {} is not an argument list.",
+                v.inspect(args.span())?
+            ),
+            args.span(),
+        )
+            .into()),
+    }
 }
 
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
@@ -4,7 +4,9 @@ use num_bigint::BigInt;
 use num_traits::{Signed, ToPrimitive, Zero};
 
 #[cfg(feature = "random")]
-use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use rand::{distributions::Alphanumeric, thread_rng, Rng, SeedableRng};
+#[cfg(feature = "random")]
+use rand_chacha::ChaCha8Rng;
 
 use crate::{
     args::CallArgs,
@@ -208,8 +210,13 @@ pub(crate) fn str_index(mut args: CallArgs, parser: &mut Parser) -> SassResult<V
         }
     };
 
+    // Sass indices count code points, not bytes
     Ok(match s1.find(&substr) {
-        Some(v) => Value::Dimension(Some(Number::from(v + 1)), Unit::None, true),
+        Some(v) => Value::Dimension(
+            Some(Number::from(s1[..v].chars().count() + 1)),
+            Unit::None,
+            true,
+        ),
         None => Value::Null,
     })
 }
@@ -318,15 +325,100 @@ pub(crate) fn str_insert(mut args: CallArgs, parser: &mut Parser) -> SassResult<
 
 #[cfg(feature = "random")]
 #[allow(clippy::needless_pass_by_value)]
-pub(crate) fn unique_id(args: CallArgs, _: &mut Parser) -> SassResult<Value> {
+pub(crate) fn unique_id(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
     args.max_args(0)?;
-    let mut rng = thread_rng();
-    let string = std::iter::repeat(())
-        .map(|()| rng.sample(Alphanumeric))
-        .map(char::from)
-        .take(7)
-        .collect();
-    Ok(Value::String(string, QuoteKind::None))
+
+    let prefix = parser.options.unique_id_prefix;
+    let length = parser.options.unique_id_length;
+
+    if !is_identifier_prefix(prefix) {
+        return Err((
+            format!(
+                "unique-id() can't generate a valid identifier starting with \"{}\".",
+                prefix
+            ),
+            args.span(),
+        )
+            .into());
+    }
+
+    if length == 0 && needs_leading_letter(prefix) {
+        return Err((
+            format!(
+                "unique-id() can't generate a valid identifier from \"{}\" without any random characters.",
+                prefix
+            ),
+            args.span(),
+        )
+            .into());
+    }
+
+    let id = match parser.options.unique_id_seed {
+        // each identifier gets its own generator so that the sequence depends
+        // only on the seed and the number of identifiers generated before it.
+        // `StdRng` may change between versions of `rand`, so a named
+        // algorithm keeps the output the same across builds and platforms
+        Some(seed) => generate_id(
+            &mut ChaCha8Rng::seed_from_u64(seed.wrapping_add(*parser.unique_id_count)),
+            prefix,
+            length,
+        ),
+        None => generate_id(&mut thread_rng(), prefix, length),
+    };
+
+    *parser.unique_id_count += 1;
+
+    Ok(Value::String(id, QuoteKind::None))
+}
+
+/// Whether `prefix` followed by letters and digits is a valid CSS identifier,
+/// as long as the first character after it is a letter
+#[cfg(feature = "random")]
+fn is_identifier_prefix(prefix: &str) -> bool {
+    fn is_name_start(c: char) -> bool {
+        c.is_ascii_alphabetic() || c == '_' || !c.is_ascii()
+    }
+
+    let mut chars = prefix.chars();
+
+    let starts_identifier = match chars.next() {
+        None => true,
+        Some('-') => chars.next().map_or(true, |c| is_name_start(c) || c == '-'),
+        Some(c) => is_name_start(c),
+    };
+
+    starts_identifier
+        && prefix
+            .chars()
+            .all(|c| is_name_start(c) || c.is_ascii_digit() || c == '-')
+}
+
+/// Whether an identifier starting with `prefix` would be invalid if the next
+/// character were a digit
+#[cfg(feature = "random")]
+fn needs_leading_letter(prefix: &str) -> bool {
+    prefix.is_empty() || prefix == "-"
+}
+
+#[cfg(feature = "random")]
+fn generate_id<R: Rng>(rng: &mut R, prefix: &str, mut length: usize) -> String {
+    let mut string = prefix.to_owned();
+
+    // an identifier may not start with a digit, so if the prefix doesn't
+    // start one, the first random character is always a letter
+    if needs_leading_letter(prefix) && length > 0 {
+        string.push(rng.gen_range(b'a'..=b'z') as char);
+        length -= 1;
+    }
+
+    string.extend(
+        std::iter::repeat(())
+            .map(|()| rng.sample(Alphanumeric))
+            .map(char::from)
+            .take(length),
+    );
+
+    string
 }
 
 pub(crate) fn declare(f: &mut GlobalFunctionMap) {
@@ -1,25 +1,99 @@
-use crate::builtin::{
-    color::{
-        hsl::{complement, grayscale, hue, invert, lightness, saturation},
-        hwb::{blackness, hwb, whiteness},
-        opacity::alpha,
-        other::{adjust_color, change_color, ie_hex_str, scale_color},
-        rgb::{blue, green, mix, red},
+use crate::{
+    args::CallArgs,
+    builtin::{
+        color::{
+            hsl::{color_grayscale, color_invert, complement, hue, lightness, saturation},
+            hwb::{blackness, hwb, whiteness},
+            opacity::{color_alpha, color_opacity},
+            other::{adjust_color, change_color, ie_hex_str, scale_color},
+            rgb::{blue, green, mix, red},
+        },
+        modules::Module,
     },
-    modules::Module,
+    error::SassResult,
+    parse::Parser,
+    value::Value,
 };
 
+/// The global color functions that aren't part of `sass:color`, which error
+/// with a suggestion of the `color.adjust()` call to use instead
+///
+/// `channel` is the argument to `color.adjust()` that `$amount` is passed as,
+/// negated if `negative` is set.
+fn removed_color_function(
+    mut args: CallArgs,
+    parser: &mut Parser,
+    name: &'static str,
+    channel: &'static str,
+    negative: bool,
+) -> SassResult<Value> {
+    args.max_args(2)?;
+    let color = args.get_err(0, "color")?;
+    let amount = args.get_err(1, "amount")?;
+    let is_compressed = parser.options.is_compressed();
+
+    Err((
+        format!(
+            "The function {name}() isn't in the sass:color module.\n\nRecommendation: color.adjust({}, ${channel}: {}{})\n\nMore info: https://sass-lang.com/documentation/functions/color#{name}",
+            color.to_css_string(args.span(), is_compressed)?,
+            if negative { "-" } else { "" },
+            amount.to_css_string(args.span(), is_compressed)?,
+            name = name,
+            channel = channel,
+        ),
+        args.span(),
+    )
+        .into())
+}
+
+fn adjust_hue(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "adjust-hue", "hue", false)
+}
+
+fn lighten(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "lighten", "lightness", false)
+}
+
+fn darken(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "darken", "lightness", true)
+}
+
+fn saturate(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "saturate", "saturation", false)
+}
+
+fn desaturate(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "desaturate", "saturation", true)
+}
+
+fn opacify(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "opacify", "alpha", false)
+}
+
+fn fade_in(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "fade-in", "alpha", false)
+}
+
+fn transparentize(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "transparentize", "alpha", true)
+}
+
+fn fade_out(args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
+    removed_color_function(args, parser, "fade-out", "alpha", true)
+}
+
 pub(crate) fn declare(f: &mut Module) {
     f.insert_builtin("adjust", adjust_color);
-    f.insert_builtin("alpha", alpha);
+    f.insert_builtin("alpha", color_alpha);
+    f.insert_builtin("opacity", color_opacity);
     f.insert_builtin("blue", blue);
     f.insert_builtin("change", change_color);
     f.insert_builtin("complement", complement);
-    f.insert_builtin("grayscale", grayscale);
+    f.insert_builtin("grayscale", color_grayscale);
     f.insert_builtin("green", green);
     f.insert_builtin("hue", hue);
     f.insert_builtin("ie-hex-str", ie_hex_str);
-    f.insert_builtin("invert", invert);
+    f.insert_builtin("invert", color_invert);
     f.insert_builtin("lightness", lightness);
     f.insert_builtin("mix", mix);
     f.insert_builtin("red", red);
@@ -28,4 +102,13 @@ pub(crate) fn declare(f: &mut Module) {
     f.insert_builtin("blackness", blackness);
     f.insert_builtin("whiteness", whiteness);
     f.insert_builtin("hwb", hwb);
+    f.insert_builtin("adjust-hue", adjust_hue);
+    f.insert_builtin("lighten", lighten);
+    f.insert_builtin("darken", darken);
+    f.insert_builtin("saturate", saturate);
+    f.insert_builtin("desaturate", desaturate);
+    f.insert_builtin("opacify", opacify);
+    f.insert_builtin("fade-in", fade_in);
+    f.insert_builtin("transparentize", transparentize);
+    f.insert_builtin("fade-out", fade_out);
 }
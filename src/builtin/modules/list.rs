@@ -1,8 +1,31 @@
-use crate::builtin::{
-    list::{append, index, is_bracketed, join, length, list_separator, nth, set_nth, zip},
-    modules::Module,
+use crate::{
+    args::CallArgs,
+    builtin::{
+        list::{append, index, is_bracketed, join, length, list_separator, nth, set_nth, zip},
+        modules::Module,
+    },
+    common::{Brackets, ListSeparator},
+    error::SassResult,
+    parse::Parser,
+    value::Value,
 };
 
+fn slash(args: CallArgs, _: &mut Parser) -> SassResult<Value> {
+    let span = args.span();
+
+    let elements = args
+        .get_variadic()?
+        .into_iter()
+        .map(|element| element.node)
+        .collect::<Vec<Value>>();
+
+    if elements.len() < 2 {
+        return Err(("At least two elements are required.", span).into());
+    }
+
+    Ok(Value::List(elements, ListSeparator::Slash, Brackets::None))
+}
+
 pub(crate) fn declare(f: &mut Module) {
     f.insert_builtin("append", append);
     f.insert_builtin("index", index);
@@ -12,5 +35,6 @@ pub(crate) fn declare(f: &mut Module) {
     f.insert_builtin("separator", list_separator);
     f.insert_builtin("nth", nth);
     f.insert_builtin("set-nth", set_nth);
+    f.insert_builtin("slash", slash);
     f.insert_builtin("zip", zip);
 }
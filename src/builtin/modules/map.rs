@@ -1,8 +1,131 @@
-use crate::builtin::{
-    map::{map_get, map_has_key, map_keys, map_merge, map_remove, map_set, map_values},
-    modules::Module,
+use codemap::Spanned;
+
+use crate::{
+    args::CallArgs,
+    builtin::{
+        map::{map_get, map_has_key, map_keys, map_merge, map_remove, map_set, map_values},
+        modules::Module,
+    },
+    error::SassResult,
+    parse::Parser,
+    value::{SassMap, Value},
 };
 
+/// The map that `value` represents, if any, since an empty list is also an
+/// empty map
+fn try_map(value: &Value) -> Option<SassMap> {
+    match value {
+        Value::Map(m) => Some(m.clone()),
+        Value::List(v, ..) if v.is_empty() => Some(SassMap::new()),
+        Value::ArgList(v, ..) if v.is_empty() => Some(SassMap::new()),
+        _ => None,
+    }
+}
+
+/// The value of `key` in `map`, without consuming it
+fn get_cloned(map: &SassMap, key: &Value) -> Option<Value> {
+    map.iter()
+        .find(|(k, ..)| k == key)
+        .map(|(.., value)| value.clone())
+}
+
+/// Merges `map2` into `map1`, merging rather than replacing the values of keys
+/// that are maps in both
+fn merge_deeply(mut map1: SassMap, map2: SassMap) -> SassMap {
+    for (key, value) in map2 {
+        let merged = match (
+            get_cloned(&map1, &key).as_ref().and_then(try_map),
+            try_map(&value),
+        ) {
+            // merging an empty map leaves the original value as it is, even
+            // if it is an empty list
+            (Some(..), Some(value_map)) if value_map.iter().next().is_none() => continue,
+            (Some(existing), Some(value_map)) => Value::Map(merge_deeply(existing, value_map)),
+            _ => value,
+        };
+
+        map1.insert(key, merged);
+    }
+
+    map1
+}
+
+fn deep_merge(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
+    args.max_args(2)?;
+
+    let map1 = match args.get_err(0, "map1")? {
+        Value::Map(m) => m,
+        Value::List(v, ..) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
+        v => {
+            return Err((
+                format!("$map1: {} is not a map.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let map2 = match args.get_err(1, "map2")? {
+        Value::Map(m) => m,
+        Value::List(v, ..) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
+        v => {
+            return Err((
+                format!("$map2: {} is not a map.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    Ok(Value::Map(merge_deeply(map1, map2)))
+}
+
+/// Removes `key` from the map found by following `path` from `map`, if there
+/// is one
+fn remove_nested(mut map: SassMap, path: &[Spanned<Value>], key: &Value) -> SassMap {
+    match path.split_first() {
+        Some((first, rest)) => {
+            if let Some(Value::Map(nested)) = get_cloned(&map, &first.node) {
+                map.insert(
+                    first.node.clone(),
+                    Value::Map(remove_nested(nested, rest, key)),
+                );
+            }
+        }
+        None => map.remove(key),
+    }
+
+    map
+}
+
+fn deep_remove(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
+    let span = args.span();
+
+    let map = match args.get_err(0, "map")? {
+        Value::Map(m) => m,
+        Value::List(v, ..) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
+        v => {
+            return Err((
+                format!("$map: {} is not a map.", v.inspect(args.span())?),
+                args.span(),
+            )
+                .into())
+        }
+    };
+
+    let keys = args.get_variadic()?;
+
+    let (key, path) = match keys.split_last() {
+        Some(split) => split,
+        None => return Err(("Missing argument $key.", span).into()),
+    };
+
+    Ok(Value::Map(remove_nested(map, path, &key.node)))
+}
+
 pub(crate) fn declare(f: &mut Module) {
     f.insert_builtin("get", map_get);
     f.insert_builtin("has-key", map_has_key);
@@ -11,4 +134,6 @@ pub(crate) fn declare(f: &mut Module) {
     f.insert_builtin("remove", map_remove);
     f.insert_builtin("values", map_values);
     f.insert_builtin("set", map_set);
+    f.insert_builtin("deep-merge", deep_merge);
+    f.insert_builtin("deep-remove", deep_remove);
 }
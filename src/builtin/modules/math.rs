@@ -1,5 +1,4 @@
-use std::cmp::Ordering;
-
+use codemap::Span;
 use num_traits::{One, Signed, Zero};
 
 use crate::{
@@ -9,7 +8,6 @@ use crate::{
         meta::{unit, unitless},
         modules::Module,
     },
-    common::Op,
     error::SassResult,
     parse::Parser,
     unit::Unit,
@@ -19,12 +17,36 @@ use crate::{
 #[cfg(feature = "random")]
 use crate::builtin::math::random;
 
+/// Whether `a >= b`, converting `b` to the units of `a`
+///
+/// Comparisons involving `NaN` are always false.
+fn greater_than_or_equals(
+    a: &(Option<Number>, Unit),
+    b: &(Option<Number>, Unit),
+    span: Span,
+) -> SassResult<bool> {
+    if !a.1.comparable(&b.1) {
+        return Err((format!("Incompatible units {} and {}.", b.1, a.1), span).into());
+    }
+
+    Ok(match (&a.0, &b.0) {
+        (Some(n1), Some(n2)) => {
+            if a.1 == b.1 || a.1 == Unit::None || b.1 == Unit::None {
+                n1 >= n2
+            } else {
+                n1 >= &n2.clone().convert(&b.1, &a.1)
+            }
+        }
+        _ => false,
+    })
+}
+
 fn clamp(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     args.max_args(3)?;
     let span = args.span();
 
     let min = match args.get_err(0, "min")? {
-        v @ Value::Dimension(..) => v,
+        Value::Dimension(n, u, _) => (n, u),
         v => {
             return Err((
                 format!("$min: {} is not a number.", v.inspect(args.span())?),
@@ -35,7 +57,7 @@ fn clamp(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     };
 
     let number = match args.get_err(1, "number")? {
-        v @ Value::Dimension(..) => v,
+        Value::Dimension(n, u, _) => (n, u),
         v => {
             return Err((
                 format!("$number: {} is not a number.", v.inspect(span)?),
@@ -46,59 +68,52 @@ fn clamp(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     };
 
     let max = match args.get_err(2, "max")? {
-        v @ Value::Dimension(..) => v,
+        Value::Dimension(n, u, _) => (n, u),
         v => return Err((format!("$max: {} is not a number.", v.inspect(span)?), span).into()),
     };
 
-    // ensure that `min` and `max` are compatible
-    min.cmp(&max, span, Op::LessThan)?;
+    let min_has_units = min.1 != Unit::None;
 
-    let min_unit = match min {
-        Value::Dimension(_, ref u, _) => u,
-        _ => unreachable!(),
-    };
-    let number_unit = match number {
-        Value::Dimension(_, ref u, _) => u,
-        _ => unreachable!(),
-    };
-    let max_unit = match max {
-        Value::Dimension(_, ref u, _) => u,
-        _ => unreachable!(),
-    };
+    if min_has_units != (number.1 != Unit::None) || min_has_units != (max.1 != Unit::None) {
+        let (arg2, arg2_name) = if min_has_units == (number.1 != Unit::None) {
+            (&max.1, "$max")
+        } else {
+            (&number.1, "$number")
+        };
+
+        let unit1 = if min_has_units {
+            format!("has unit {}", min.1)
+        } else {
+            "is unitless".to_owned()
+        };
+
+        let unit2 = if arg2 == &Unit::None {
+            "is unitless".to_owned()
+        } else {
+            format!("has unit {}", arg2)
+        };
 
-    if min_unit == &Unit::None && number_unit != &Unit::None {
-        return Err((
-            format!(
-                "$min is unitless but $number has unit {}. Arguments must all have units or all be unitless.",
-                number_unit
-            ), span).into());
-    } else if min_unit != &Unit::None && number_unit == &Unit::None {
-        return Err((
-                format!(
-                    "$min has unit {} but $number is unitless. Arguments must all have units or all be unitless.",
-                    min_unit
-                ), span).into());
-    } else if min_unit != &Unit::None && max_unit == &Unit::None {
         return Err((
             format!(
-                "$min has unit {} but $max is unitless. Arguments must all have units or all be unitless.",
-                min_unit
-            ), span).into());
-    }
-
-    match min.cmp(&number, span, Op::LessThan)? {
-        Ordering::Greater => return Ok(min),
-        Ordering::Equal => return Ok(number),
-        Ordering::Less => {}
+                "$min {} but {} {}. Arguments must all have units or all be unitless.",
+                unit1, arg2_name, unit2
+            ),
+            span,
+        )
+            .into());
     }
 
-    match max.cmp(&number, span, Op::GreaterThan)? {
-        Ordering::Less => return Ok(max),
-        Ordering::Equal => return Ok(number),
-        Ordering::Greater => {}
-    }
+    let result = if greater_than_or_equals(&min, &max, span)?
+        || greater_than_or_equals(&min, &number, span)?
+    {
+        min
+    } else if greater_than_or_equals(&number, &max, span)? {
+        max
+    } else {
+        number
+    };
 
-    Ok(number)
+    Ok(Value::Dimension(result.0, result.1, true))
 }
 
 fn hypot(args: CallArgs, _: &mut Parser) -> SassResult<Value> {
@@ -113,16 +128,13 @@ fn hypot(args: CallArgs, _: &mut Parser) -> SassResult<Value> {
         }
     });
 
-    let first: (Number, Unit) = match numbers.next().unwrap()? {
-        (Some(n), u) => (n.clone() * n, u),
-        (None, u) => return Ok(Value::Dimension(None, u, true)),
-    };
+    let (first, first_unit) = numbers.next().unwrap()?;
 
     let rest = numbers
         .enumerate()
         .map(|(idx, val)| -> SassResult<Option<Number>> {
             let (number, unit) = val?;
-            if first.1 == Unit::None {
+            if first_unit == Unit::None {
                 if unit == Unit::None {
                     Ok(number.map(|n| n.clone() * n))
                 } else {
@@ -142,52 +154,65 @@ fn hypot(args: CallArgs, _: &mut Parser) -> SassResult<Value> {
                     format!(
                         "Argument 1 has unit {} but argument {} is unitless. \
                         Arguments must all have units or all be unitless.",
-                        first.1,
+                        first_unit,
                         idx + 2,
                     ),
                     span,
                 )
                     .into())
-            } else if first.1.comparable(&unit) {
+            } else if first_unit.comparable(&unit) {
                 Ok(number
-                    .map(|n| n.convert(&unit, &first.1))
+                    .map(|n| n.convert(&unit, &first_unit))
                     .map(|n| n.clone() * n))
             } else {
                 Err((
-                    format!("Incompatible units {} and {}.", first.1, unit),
+                    format!("Incompatible units {} and {}.", first_unit, unit),
                     span,
                 )
                     .into())
             }
         })
-        .collect::<SassResult<Option<Vec<Number>>>>()?;
+        .collect::<SassResult<Vec<Option<Number>>>>()?
+        .into_iter()
+        .collect::<Option<Vec<Number>>>();
 
-    let rest = match rest {
-        Some(v) => v,
-        None => return Ok(Value::Dimension(None, first.1, true)),
+    let (first_squared, rest) = match (first, rest) {
+        (Some(n), Some(v)) => (n.clone() * n, v),
+        _ => return Ok(Value::Dimension(None, first_unit, true)),
     };
 
-    let sum = first.0 + rest.into_iter().fold(Number::zero(), |a, b| a + b);
+    let sum = first_squared + rest.into_iter().fold(Number::zero(), |a, b| a + b);
+
+    Ok(Value::Dimension(sum.sqrt(), first_unit, true))
+}
 
-    Ok(Value::Dimension(sum.sqrt(), first.1, true))
+/// The result of a floating point operation, where `None` is `NaN`
+///
+/// Infinite results can't be represented yet, so they're an error.
+fn float_result(result: Option<f64>, span: Span) -> SassResult<Option<Number>> {
+    match result {
+        Some(n) if n.is_infinite() => Err(("Infinity not yet implemented.", span).into()),
+        Some(n) if n.is_nan() => Ok(None),
+        Some(n) => Ok(Some(Number::from(n))),
+        None => Ok(None),
+    }
 }
 
 fn log(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     args.max_args(2)?;
 
     let number = match args.get_err(0, "number")? {
-        Value::Dimension(Some(n), Unit::None, ..) => n,
-        v @ Value::Dimension(Some(..), ..) => {
+        Value::Dimension(n, Unit::None, ..) => n,
+        v @ Value::Dimension(..) => {
             return Err((
                 format!(
-                    "$number: Expected {} to be unitless.",
+                    "$number: Expected {} to have no units.",
                     v.inspect(args.span())?
                 ),
                 args.span(),
             )
                 .into())
         }
-        v @ Value::Dimension(None, ..) => return Ok(v),
         v => {
             return Err((
                 format!("$number: {} is not a number.", v.inspect(args.span())?),
@@ -199,18 +224,17 @@ fn log(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
 
     let base = match args.default_arg(1, "base", Value::Null)? {
         Value::Null => None,
-        Value::Dimension(Some(n), Unit::None, ..) => Some(n),
-        v @ Value::Dimension(Some(..), ..) => {
+        Value::Dimension(n, Unit::None, ..) => Some(n),
+        v @ Value::Dimension(..) => {
             return Err((
                 format!(
-                    "$number: Expected {} to be unitless.",
+                    "$base: Expected {} to have no units.",
                     v.inspect(args.span())?
                 ),
                 args.span(),
             )
                 .into())
         }
-        v @ Value::Dimension(None, ..) => return Ok(v),
         v => {
             return Err((
                 format!("$base: {} is not a number.", v.inspect(args.span())?),
@@ -220,20 +244,17 @@ fn log(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
         }
     };
 
+    let number = number.and_then(Number::as_float);
+
+    let result = match base {
+        None => number.map(f64::ln),
+        Some(base) => number
+            .zip(base.and_then(Number::as_float))
+            .map(|(number, base)| number.ln() / base.ln()),
+    };
+
     Ok(Value::Dimension(
-        if let Some(base) = base {
-            if base.is_zero() {
-                Some(Number::zero())
-            } else {
-                number.log(base)
-            }
-        } else if number.is_negative() {
-            None
-        } else if number.is_zero() {
-            todo!()
-        } else {
-            number.ln()
-        },
+        float_result(result, args.span())?,
         Unit::None,
         true,
     ))
@@ -243,8 +264,8 @@ fn pow(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     args.max_args(2)?;
 
     let base = match args.get_err(0, "base")? {
-        Value::Dimension(Some(n), Unit::None, ..) => n,
-        v @ Value::Dimension(Some(..), ..) => {
+        Value::Dimension(n, Unit::None, ..) => n,
+        v @ Value::Dimension(..) => {
             return Err((
                 format!(
                     "$base: Expected {} to have no units.",
@@ -254,7 +275,6 @@ fn pow(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
             )
                 .into())
         }
-        Value::Dimension(None, ..) => return Ok(Value::Dimension(None, Unit::None, true)),
         v => {
             return Err((
                 format!("$base: {} is not a number.", v.inspect(args.span())?),
@@ -265,8 +285,8 @@ fn pow(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
     };
 
     let exponent = match args.get_err(1, "exponent")? {
-        Value::Dimension(Some(n), Unit::None, ..) => n,
-        v @ Value::Dimension(Some(..), ..) => {
+        Value::Dimension(n, Unit::None, ..) => n,
+        v @ Value::Dimension(..) => {
             return Err((
                 format!(
                     "$exponent: Expected {} to have no units.",
@@ -276,7 +296,6 @@ fn pow(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
             )
                 .into())
         }
-        Value::Dimension(None, ..) => return Ok(Value::Dimension(None, Unit::None, true)),
         v => {
             return Err((
                 format!("$exponent: {} is not a number.", v.inspect(args.span())?),
@@ -286,7 +305,16 @@ fn pow(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
         }
     };
 
-    Ok(Value::Dimension(base.pow(exponent), Unit::None, true))
+    let result = base
+        .and_then(Number::as_float)
+        .zip(exponent.and_then(Number::as_float))
+        .map(|(base, exponent)| base.powf(exponent));
+
+    Ok(Value::Dimension(
+        float_result(result, args.span())?,
+        Unit::None,
+        true,
+    ))
 }
 
 fn sqrt(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
@@ -295,7 +323,8 @@ fn sqrt(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
 
     Ok(match number {
         Value::Dimension(Some(n), Unit::None, ..) => Value::Dimension(n.sqrt(), Unit::None, true),
-        v @ Value::Dimension(Some(..), ..) => {
+        Value::Dimension(None, Unit::None, ..) => Value::Dimension(None, Unit::None, true),
+        v @ Value::Dimension(..) => {
             return Err((
                 format!(
                     "$number: Expected {} to have no units.",
@@ -305,7 +334,6 @@ fn sqrt(mut args: CallArgs, _: &mut Parser) -> SassResult<Value> {
             )
                 .into())
         }
-        Value::Dimension(None, ..) => Value::Dimension(None, Unit::None, true),
         v => {
             return Err((
                 format!("$number: {} is not a number.", v.inspect(args.span())?),
@@ -620,4 +648,25 @@ pub(crate) fn declare(f: &mut Module) {
         "pi",
         Value::Dimension(Some(Number::from(std::f64::consts::PI)), Unit::None, true),
     );
+    f.insert_builtin_var(
+        "epsilon",
+        Value::Dimension(Some(Number::from(f64::EPSILON)), Unit::None, true),
+    );
+    // 2^53 - 1, the largest integer that a double represents exactly
+    f.insert_builtin_var(
+        "max-safe-integer",
+        Value::Dimension(
+            Some(Number::from(9_007_199_254_740_991_f64)),
+            Unit::None,
+            true,
+        ),
+    );
+    f.insert_builtin_var(
+        "min-safe-integer",
+        Value::Dimension(
+            Some(Number::from(-9_007_199_254_740_991_f64)),
+            Unit::None,
+            true,
+        ),
+    );
 }
@@ -11,7 +11,7 @@ use crate::{
     },
     error::SassResult,
     parse::{Parser, Stmt},
-    value::Value,
+    value::{SassMap, Value},
 };
 
 fn load_css(mut args: CallArgs, parser: &mut Parser) -> SassResult<Vec<Stmt>> {
@@ -19,7 +19,13 @@ fn load_css(mut args: CallArgs, parser: &mut Parser) -> SassResult<Vec<Stmt>> {
 
     let span = args.span();
 
-    let url = match args.get_err(0, "module")? {
+    // `$url` is the documented name; `$module` is accepted for backwards compatibility
+    let url = match args.get_named("url") {
+        Some(v) => v?.node,
+        None => args.get_err(0, "module")?,
+    };
+
+    let url = match url {
         Value::String(s, ..) => s,
         v => {
             return Err((
@@ -31,42 +37,41 @@ fn load_css(mut args: CallArgs, parser: &mut Parser) -> SassResult<Vec<Stmt>> {
     };
 
     let with = match args.default_arg(1, "with", Value::Null)? {
-        Value::Map(map) => Some(map),
-        Value::Null => None,
+        Value::Map(map) => map,
+        Value::List(v, ..) if v.is_empty() => SassMap::new(),
+        Value::ArgList(v, ..) if v.is_empty() => SassMap::new(),
+        Value::Null => SassMap::new(),
         v => return Err((format!("$with: {} is not a map.", v.inspect(span)?), span).into()),
     };
 
-    // todo: tests for `with`
-    if let Some(with) = with {
-        let mut config = ModuleConfig::default();
-
-        for (key, value) in with {
-            let key = match key {
-                Value::String(s, ..) => s,
-                v => {
-                    return Err((
-                        format!("$with key: {} is not a string.", v.inspect(span)?),
-                        span,
-                    )
-                        .into())
-                }
-            };
-
-            config.insert(
-                Spanned {
-                    node: key.into(),
+    let mut config = ModuleConfig::for_load_css();
+
+    for (key, value) in with {
+        let key = match key {
+            Value::String(s, ..) => s,
+            v => {
+                return Err((
+                    format!("$with key: {} is not a string.", v.inspect(span)?),
                     span,
-                },
-                value.span(span),
-            )?;
-        }
+                )
+                    .into())
+            }
+        };
+
+        config.insert(
+            Spanned {
+                node: key.into(),
+                span,
+            },
+            value.span(span),
+        )?;
+    }
 
-        let (_, stmts) = parser.load_module(&url, &mut config)?;
+    // the module is loaded with its own scope, rather than imported into the
+    // current one
+    let (_, stmts) = parser.load_module(&url, &mut config, false)?;
 
-        Ok(stmts)
-    } else {
-        parser.parse_single_import(&url, span)
-    }
+    Ok(stmts)
 }
 
 fn module_functions(mut args: CallArgs, parser: &mut Parser) -> SassResult<Value> {
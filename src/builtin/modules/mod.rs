@@ -1,15 +1,16 @@
-use std::collections::BTreeMap;
+use std::mem;
 
 use codemap::{Span, Spanned};
+use indexmap::IndexMap;
 
 use crate::{
     args::CallArgs,
     atrule::mixin::{BuiltinMixin, Mixin},
     builtin::Builtin,
     common::{Identifier, QuoteKind},
-    error::SassResult,
+    error::{SassError, SassResult},
     parse::Parser,
-    scope::Scope,
+    scope::{undefined_error, Scope},
     value::{SassFunction, SassMap, Value},
 };
 
@@ -21,7 +22,7 @@ mod meta;
 mod selector;
 mod string;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct Module {
     pub scope: Scope,
 
@@ -33,35 +34,149 @@ pub(crate) struct Module {
     is_builtin: bool,
 }
 
-#[derive(Debug, Default)]
-pub(crate) struct Modules(BTreeMap<Identifier, Module>);
+/// Members that can be suggested to users outside of their module
+fn public_names<'a>(
+    names: impl Iterator<Item = &'a Identifier> + 'a,
+) -> impl Iterator<Item = &'a Identifier> + 'a {
+    names.filter(|name| !name.as_str().starts_with('-'))
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Modules(IndexMap<Identifier, Module>);
 
 #[derive(Debug, Default)]
-pub(crate) struct ModuleConfig(BTreeMap<Identifier, Value>);
+pub(crate) struct ModuleConfig {
+    /// The configured values, spanning from the name of the variable to the
+    /// end of its value
+    values: IndexMap<Identifier, Spanned<Value>>,
+
+    /// Whether this configuration was passed on by `@forward` rather than
+    /// written with `with`, in which case the module doesn't have to declare
+    /// every variable in it
+    implicit: bool,
+
+    /// Whether errors about this configuration name the variable they are
+    /// about, as the variables passed to `meta.load-css()` all share the span
+    /// of the call
+    names_in_errors: bool,
+}
 
 impl ModuleConfig {
+    /// An empty configuration for `meta.load-css()`
+    pub fn for_load_css() -> Self {
+        ModuleConfig {
+            names_in_errors: true,
+            ..ModuleConfig::default()
+        }
+    }
+
     /// Removes and returns element with name
     pub fn get(&mut self, name: Identifier) -> Option<Value> {
-        self.0.remove(&name)
+        self.values.shift_remove(&name).map(|value| value.node)
+    }
+
+    /// The error for the module not declaring the first variable left in this
+    /// configuration with `!default`, or pointing to `span` if there is none
+    pub fn undeclared_error(&self, span: Span) -> Box<SassError> {
+        match self.values.iter().next() {
+            Some((name, value)) if self.names_in_errors => (
+                format!(
+                    "${} was not declared with !default in the @used module.",
+                    name
+                ),
+                value.span,
+            )
+                .into(),
+            Some((_, value)) => (
+                "This variable was not declared with !default in the @used module.",
+                value.span,
+            )
+                .into(),
+            None => (
+                "This variable was not declared with !default in the @used module.",
+                span,
+            )
+                .into(),
+        }
+    }
+
+    /// The span of the first variable left in this configuration, if any
+    pub fn first_span(&self) -> Option<Span> {
+        self.values.values().next().map(|value| value.span)
     }
 
     /// If this structure is not empty at the end of
     /// an `@use`, we must throw an error
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.values.is_empty()
+    }
+
+    pub const fn is_implicit(&self) -> bool {
+        self.implicit
     }
 
     pub fn insert(&mut self, name: Spanned<Identifier>, value: Spanned<Value>) -> SassResult<()> {
-        if self.0.insert(name.node, value.node).is_some() {
-            Err((
-                "The same variable may only be configured once.",
-                name.span.merge(value.span),
+        let span = name.span.merge(value.span);
+
+        if self
+            .values
+            .insert(
+                name.node,
+                Spanned {
+                    node: value.node,
+                    span,
+                },
             )
-                .into())
+            .is_some()
+        {
+            Err(if self.names_in_errors {
+                (
+                    format!("The variable ${} was configured twice.", name.node),
+                    span,
+                )
+                    .into()
+            } else {
+                ("The same variable may only be configured once.", span).into()
+            })
         } else {
             Ok(())
         }
     }
+
+    /// Moves the variables that an `@forward` rule with `prefix` passes on,
+    /// as decided by `is_forwarded`, into the configuration of the forwarded
+    /// module, without their prefix
+    pub fn split_forwarded(
+        &mut self,
+        prefix: &str,
+        is_forwarded: impl Fn(Identifier) -> bool,
+    ) -> Self {
+        let (forwarded, kept): (IndexMap<_, _>, IndexMap<_, _>) = mem::take(&mut self.values)
+            .into_iter()
+            .partition(|(name, _)| name.as_str().starts_with(prefix) && is_forwarded(*name));
+
+        self.values = kept;
+
+        ModuleConfig {
+            values: forwarded
+                .into_iter()
+                .map(|(name, value)| (Identifier::from(&name.as_str()[prefix.len()..]), value))
+                .collect(),
+            implicit: true,
+            names_in_errors: self.names_in_errors,
+        }
+    }
+
+    /// Takes back the variables that a module loaded with `@forward` didn't
+    /// declare, so that the forwarding module may declare them itself
+    pub fn restore_forwarded(&mut self, forwarded: Self, prefix: &str) {
+        self.values.extend(
+            forwarded
+                .values
+                .into_iter()
+                .map(|(name, value)| (Identifier::from(format!("{}{}", prefix, name)), value)),
+        );
+    }
 }
 
 impl Modules {
@@ -132,7 +247,12 @@ impl Module {
 
         match self.scope.vars.get(&name.node) {
             Some(v) => Ok(v),
-            None => Err(("Undefined variable.", name.span).into()),
+            None => Err(undefined_error(
+                "Undefined variable.",
+                "$",
+                name,
+                public_names(self.scope.vars.keys()),
+            )),
         }
     }
 
@@ -167,7 +287,12 @@ impl Module {
 
         match self.scope.mixins.get(&name.node) {
             Some(v) => Ok(v.clone()),
-            None => Err(("Undefined mixin.", name.span).into()),
+            None => Err(undefined_error(
+                "Undefined mixin.",
+                "",
+                name,
+                public_names(self.scope.mixins.keys()),
+            )),
         }
     }
 
@@ -191,6 +316,19 @@ impl Module {
         Ok(self.scope.functions.get(&name.node).cloned())
     }
 
+    /// Like `Module::get_fn`, but errors if the function does not exist
+    pub fn get_fn_or_err(&self, name: Spanned<Identifier>) -> SassResult<SassFunction> {
+        match self.get_fn(name)? {
+            Some(f) => Ok(f),
+            None => Err(undefined_error(
+                "Undefined function.",
+                "",
+                name,
+                public_names(self.scope.functions.keys()),
+            )),
+        }
+    }
+
     pub fn var_exists(&self, name: Identifier) -> bool {
         !name.as_str().starts_with('-') && self.scope.var_exists(name)
     }
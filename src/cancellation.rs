@@ -0,0 +1,52 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A handle for stopping a compilation from another thread, e.g. once the
+/// request that started it has gone away.
+///
+/// Clones of a token share their state, so that one clone can be given to
+/// [`Options::cancellation`](crate::Options::cancellation) while another is
+/// kept around to call [`CancellationToken::cancel`]. The compilation then
+/// stops before its next statement, failing with an error for which
+/// [`Error::is_cancelled`](crate::Error::is_cancelled) returns `true`.
+///
+/// ```
+/// use grass::{CancellationToken, Options};
+///
+/// let token = CancellationToken::new();
+/// token.cancel();
+///
+/// let err = grass::from_string(
+///     "a { color: red; }".to_owned(),
+///     &Options::default().cancellation(&token),
+/// )
+/// .unwrap_err();
+/// assert!(err.is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that has not been cancelled
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop any compilation using this token, or any clone of it
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`CancellationToken::cancel`] has been called on this token,
+    /// or any clone of it
+    #[must_use]
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
@@ -32,6 +32,9 @@ pub(crate) struct Color {
     rgba: Rgba,
     hsla: Option<Hsla>,
     repr: String,
+    /// Whether `repr` is how the color was written in the stylesheet, and
+    /// should be emitted as-is, even in compressed output
+    is_as_written: bool,
 }
 
 impl PartialEq for Color {
@@ -54,6 +57,7 @@ impl Color {
             rgba: Rgba::new(red, green, blue, alpha),
             hsla: None,
             repr,
+            is_as_written: false,
         }
     }
 
@@ -69,6 +73,7 @@ impl Color {
             rgba: Rgba::new(red, green, blue, alpha),
             hsla: Some(hsla),
             repr,
+            is_as_written: false,
         }
     }
 }
@@ -161,11 +166,21 @@ impl Hsla {
 
 // RGBA color functions
 impl Color {
+    /// Create a new `Color` from the byte values of its channels, as written
+    /// in hex notation
+    ///
+    /// `alpha` is scaled from `0..=255` to `0..=1`.
     pub fn new(red: u8, green: u8, blue: u8, alpha: u8, repr: String) -> Self {
         Color {
-            rgba: Rgba::new(red.into(), green.into(), blue.into(), alpha.into()),
+            rgba: Rgba::new(
+                red.into(),
+                green.into(),
+                blue.into(),
+                Number::small_ratio(alpha, 255),
+            ),
             hsla: None,
             repr,
+            is_as_written: false,
         }
     }
 
@@ -202,7 +217,7 @@ impl Color {
     /// Algorithm adapted from
     /// <https://github.com/sass/dart-sass/blob/0d0270cb12a9ac5cce73a4d0785fecb00735feee/lib/src/functions/color.dart#L718>
     pub fn mix(self, other: &Color, weight: Number) -> Self {
-        let weight = weight.clamp(0, 100);
+        let weight = weight.clamp(0, 1);
         let normalized_weight = weight.clone() * Number::from(2) - Number::one();
         let alpha_distance = self.alpha() - other.alpha();
 
@@ -473,12 +488,7 @@ impl Color {
 /// Opacity color functions
 impl Color {
     pub fn alpha(&self) -> Number {
-        let a = self.rgba.alpha();
-        if a > Number::one() {
-            a / Number::from(255)
-        } else {
-            a
-        }
+        self.rgba.alpha()
     }
 
     /// Change `alpha` to value given
@@ -516,6 +526,17 @@ impl Color {
 
 /// HWB color functions
 impl Color {
+    /// The whiteness of this color, as a percentage from 0 to 100
+    pub fn whiteness(&self) -> Number {
+        self.red().min(self.green()).min(self.blue()) / Number::from(255) * Number::from(100)
+    }
+
+    /// The blackness of this color, as a percentage from 0 to 100
+    pub fn blackness(&self) -> Number {
+        (Number::one() - self.red().max(self.green()).max(self.blue()) / Number::from(255))
+            * Number::from(100)
+    }
+
     pub fn from_hwb(
         mut hue: Number,
         mut white: Number,
@@ -573,18 +594,18 @@ impl Color {
     }
 }
 
-/// Get the proper representation from RGBA values
-fn repr(red: &Number, green: &Number, blue: &Number, alpha: &Number) -> String {
-    fn into_u8(channel: &Number) -> u8 {
-        if channel > &Number::from(255) {
-            255_u8
-        } else if channel.is_negative() {
-            0_u8
-        } else {
-            channel.round().to_integer().to_u8().unwrap_or(255)
-        }
+fn into_u8(channel: &Number) -> u8 {
+    if channel > &Number::from(255) {
+        255_u8
+    } else if channel.is_negative() {
+        0_u8
+    } else {
+        channel.round().to_integer().to_u8().unwrap_or(255)
     }
+}
 
+/// Get the proper representation from RGBA values
+fn repr(red: &Number, green: &Number, blue: &Number, alpha: &Number) -> String {
     let red_u8 = into_u8(red);
     let green_u8 = into_u8(green);
     let blue_u8 = into_u8(blue);
@@ -605,6 +626,76 @@ fn repr(red: &Number, green: &Number, blue: &Number, alpha: &Number) -> String {
     }
 }
 
+impl Color {
+    /// Replace the text used to emit this color
+    pub fn with_repr(mut self, repr: String) -> Self {
+        self.repr = repr;
+        self
+    }
+
+    /// Emit this color exactly as it is currently represented, even in
+    /// compressed output, for `Options::preserve_color_format`
+    ///
+    /// Any operation that produces a new color discards this.
+    pub fn as_written(mut self) -> Self {
+        self.is_as_written = true;
+        self
+    }
+
+    pub const fn is_as_written(&self) -> bool {
+        self.is_as_written
+    }
+
+    /// The shortest representation of this color, for compressed output
+    ///
+    /// Opaque colors are written as a color name if one is at least as short
+    /// as their hex representation, e.g. `red` rather than `#f00`, and
+    /// otherwise as a hex color, using the 3-digit form where possible.
+    /// Translucent colors written in `#rgba` or `#rrggbbaa` notation keep
+    /// that notation, using the 4-digit form where possible. Other fully
+    /// transparent black colors are written as `transparent`.
+    pub fn to_compressed_string(&self) -> String {
+        let red = into_u8(&self.rgba.red);
+        let green = into_u8(&self.rgba.green);
+        let blue = into_u8(&self.rgba.blue);
+        let alpha = self.alpha();
+
+        if alpha < Number::one() && self.repr.starts_with('#') {
+            let channels = [red, green, blue, into_u8(&(alpha * Number::from(255)))];
+            return if channels.iter().all(|c| c >> 4 == c & 0xF) {
+                channels.iter().fold("#".to_owned(), |mut hex, c| {
+                    hex.push_str(&format!("{:x}", c & 0xF));
+                    hex
+                })
+            } else {
+                channels.iter().fold("#".to_owned(), |mut hex, c| {
+                    hex.push_str(&format!("{:0>2x}", c));
+                    hex
+                })
+            };
+        }
+
+        if alpha.is_zero() && [red, green, blue] == [0, 0, 0] {
+            return "transparent".to_owned();
+        }
+
+        if alpha < Number::one() {
+            return format!("rgba({},{},{},{})", red, green, blue, alpha.to_string(true));
+        }
+
+        let hex = if [red, green, blue].iter().all(|c| c >> 4 == c & 0xF) {
+            format!("#{:x}{:x}{:x}", red & 0xF, green & 0xF, blue & 0xF)
+        } else {
+            format!("#{:0>2x}{:0>2x}{:0>2x}", red, green, blue)
+        };
+
+        match NAMED_COLORS.get_by_rgba([red, green, blue]) {
+            Some(name) if name.len() <= hex.len() => (*name).to_owned(),
+            _ => hex,
+        }
+    }
+}
+
 impl Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.repr)
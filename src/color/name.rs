@@ -18,6 +18,9 @@ impl NamedColorMap {
 }
 
 // Adding one color requires adding to both map due to limitation in phf crate.
+//
+// Colors with more than one name, such as `gray` and `grey`, are emitted using
+// the name that appears in `rgba_to_name`.
 pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
     name_to_rgba: phf::phf_map! {
         "aliceblue" => [0xF0, 0xF8, 0xFF, 0xFF],
@@ -40,11 +43,13 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "cornflowerblue" => [0x64, 0x95, 0xED, 0xFF],
         "cornsilk" => [0xFF, 0xF8, 0xDC, 0xFF],
         "crimson" => [0xDC, 0x14, 0x3C, 0xFF],
+        "cyan" => [0x00, 0xFF, 0xFF, 0xFF],
         "darkblue" => [0x00, 0x00, 0x8B, 0xFF],
         "darkcyan" => [0x00, 0x8B, 0x8B, 0xFF],
         "darkgoldenrod" => [0xB8, 0x86, 0x0B, 0xFF],
         "darkgray" => [0xA9, 0xA9, 0xA9, 0xFF],
         "darkgreen" => [0x00, 0x64, 0x00, 0xFF],
+        "darkgrey" => [0xA9, 0xA9, 0xA9, 0xFF],
         "darkkhaki" => [0xBD, 0xB7, 0x6B, 0xFF],
         "darkmagenta" => [0x8B, 0x00, 0x8B, 0xFF],
         "darkolivegreen" => [0x55, 0x6B, 0x2F, 0xFF],
@@ -55,11 +60,13 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "darkseagreen" => [0x8F, 0xBC, 0x8F, 0xFF],
         "darkslateblue" => [0x48, 0x3D, 0x8B, 0xFF],
         "darkslategray" => [0x2F, 0x4F, 0x4F, 0xFF],
+        "darkslategrey" => [0x2F, 0x4F, 0x4F, 0xFF],
         "darkturquoise" => [0x00, 0xCE, 0xD1, 0xFF],
         "darkviolet" => [0x94, 0x00, 0xD3, 0xFF],
         "deeppink" => [0xFF, 0x14, 0x93, 0xFF],
         "deepskyblue" => [0x00, 0xBF, 0xFF, 0xFF],
         "dimgray" => [0x69, 0x69, 0x69, 0xFF],
+        "dimgrey" => [0x69, 0x69, 0x69, 0xFF],
         "dodgerblue" => [0x1E, 0x90, 0xFF, 0xFF],
         "firebrick" => [0xB2, 0x22, 0x22, 0xFF],
         "floralwhite" => [0xFF, 0xFA, 0xF0, 0xFF],
@@ -72,6 +79,7 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "gray" => [0x80, 0x80, 0x80, 0xFF],
         "green" => [0x00, 0x80, 0x00, 0xFF],
         "greenyellow" => [0xAD, 0xFF, 0x2F, 0xFF],
+        "grey" => [0x80, 0x80, 0x80, 0xFF],
         "honeydew" => [0xF0, 0xFF, 0xF0, 0xFF],
         "hotpink" => [0xFF, 0x69, 0xB4, 0xFF],
         "indianred" => [0xCD, 0x5C, 0x5C, 0xFF],
@@ -88,16 +96,19 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "lightgoldenrodyellow" => [0xFA, 0xFA, 0xD2, 0xFF],
         "lightgray" => [0xD3, 0xD3, 0xD3, 0xFF],
         "lightgreen" => [0x90, 0xEE, 0x90, 0xFF],
+        "lightgrey" => [0xD3, 0xD3, 0xD3, 0xFF],
         "lightpink" => [0xFF, 0xB6, 0xC1, 0xFF],
         "lightsalmon" => [0xFF, 0xA0, 0x7A, 0xFF],
         "lightseagreen" => [0x20, 0xB2, 0xAA, 0xFF],
         "lightskyblue" => [0x87, 0xCE, 0xFA, 0xFF],
         "lightslategray" => [0x77, 0x88, 0x99, 0xFF],
+        "lightslategrey" => [0x77, 0x88, 0x99, 0xFF],
         "lightsteelblue" => [0xB0, 0xC4, 0xDE, 0xFF],
         "lightyellow" => [0xFF, 0xFF, 0xE0, 0xFF],
         "lime" => [0x00, 0xFF, 0x00, 0xFF],
         "limegreen" => [0x32, 0xCD, 0x32, 0xFF],
         "linen" => [0xFA, 0xF0, 0xE6, 0xFF],
+        "magenta" => [0xFF, 0x00, 0xFF, 0xFF],
         "maroon" => [0x80, 0x00, 0x00, 0xFF],
         "mediumaquamarine" => [0x66, 0xCD, 0xAA, 0xFF],
         "mediumblue" => [0x00, 0x00, 0xCD, 0xFF],
@@ -145,6 +156,7 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "skyblue" => [0x87, 0xCE, 0xEB, 0xFF],
         "slateblue" => [0x6A, 0x5A, 0xCD, 0xFF],
         "slategray" => [0x70, 0x80, 0x90, 0xFF],
+        "slategrey" => [0x70, 0x80, 0x90, 0xFF],
         "snow" => [0xFF, 0xFA, 0xFA, 0xFF],
         "springgreen" => [0x00, 0xFF, 0x7F, 0xFF],
         "steelblue" => [0x46, 0x82, 0xB4, 0xFF],
@@ -152,6 +164,7 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "teal" => [0x00, 0x80, 0x80, 0xFF],
         "thistle" => [0xD8, 0xBF, 0xD8, 0xFF],
         "tomato" => [0xFF, 0x63, 0x47, 0xFF],
+        "transparent" => [0x00, 0x00, 0x00, 0x00],
         "turquoise" => [0x40, 0xE0, 0xD0, 0xFF],
         "violet" => [0xEE, 0x82, 0xEE, 0xFF],
         "wheat" => [0xF5, 0xDE, 0xB3, 0xFF],
@@ -159,7 +172,6 @@ pub(crate) static NAMED_COLORS: NamedColorMap = NamedColorMap {
         "whitesmoke" => [0xF5, 0xF5, 0xF5, 0xFF],
         "yellow" => [0xFF, 0xFF, 0x00, 0xFF],
         "yellowgreen" => [0x9A, 0xCD, 0x32, 0xFF],
-        "transparent" => [0x00, 0x00, 0x00, 0x00],
     },
     rgba_to_name: phf::phf_map! {
         [0xF0, 0xF8, 0xFF] => "aliceblue",
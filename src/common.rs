@@ -89,6 +89,9 @@ pub(crate) enum Brackets {
 pub(crate) enum ListSeparator {
     Space,
     Comma,
+    /// Only produced by `list.slash()` and the `$separator` argument of
+    /// list functions
+    Slash,
 }
 
 impl ListSeparator {
@@ -96,6 +99,7 @@ impl ListSeparator {
         match self {
             Self::Space => " ",
             Self::Comma => ", ",
+            Self::Slash => " / ",
         }
     }
 
@@ -103,6 +107,7 @@ impl ListSeparator {
         match self {
             Self::Space => " ",
             Self::Comma => ",",
+            Self::Slash => "/",
         }
     }
 
@@ -110,6 +115,7 @@ impl ListSeparator {
         match self {
             Self::Space => "space",
             Self::Comma => "comma",
+            Self::Slash => "slash",
         }
     }
 }
@@ -118,7 +124,11 @@ impl ListSeparator {
 /// when inside identifiers.
 ///
 /// This struct protects that invariant by normalizing all
-/// underscores into hypens.
+/// underscores into hypens. Every name that Sass resolves -- variables,
+/// functions, mixins, keyword arguments, module members, and `@use ... with`
+/// configuration -- is looked up through an `Identifier`, while plain CSS
+/// identifiers (such as unknown function names) are kept as `String`s so
+/// they are emitted exactly as written.
 #[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Copy)]
 pub(crate) struct Identifier(InternedString);
 
@@ -0,0 +1,106 @@
+use std::fmt;
+
+/// A Sass implementation, at a specific release, whose behavior should be
+/// reproduced where it differs between releases.
+///
+/// ```
+/// use grass::{Compat, Options, Version};
+///
+/// let options = Options::default().compat(Compat::DartSass(Version::new(1, 33, 0)));
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compat {
+    /// Behave like the given release of `dart-sass`
+    DartSass(Version),
+}
+
+/// A version number in the form `major.minor.patch`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    #[must_use]
+    #[inline]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Behavior that `dart-sass` deprecated in a 1.x release and removes in 2.0.0
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Deprecation {
+    /// Using `/` for division outside of `calc()`
+    SlashDiv,
+    /// Passing a `$limit` with units to `random()`, which ignores them
+    RandomWithUnits,
+    /// Selectors with leading or trailing combinators, or with multiple
+    /// combinators in a row, once nesting has been resolved
+    BogusCombinators,
+    /// Passing the name of a function to `call()`, rather than the function
+    /// returned by `get-function()`
+    CallString,
+    /// Writing `@elseif` rather than `@else if`
+    Elseif,
+    /// Writing `!default` or `!global` more than once in a variable
+    /// declaration
+    DuplicateVarFlags,
+    /// Passing a number to a `sass:color` function, such as `color.invert()`,
+    /// to emit the plain CSS function of the same name
+    ColorModuleCompat,
+}
+
+impl Deprecation {
+    const REMOVED_IN: Version = Version::new(2, 0, 0);
+
+    const fn deprecated_in(self) -> Version {
+        match self {
+            Self::SlashDiv => Version::new(1, 33, 0),
+            Self::RandomWithUnits => Version::new(1, 54, 5),
+            Self::BogusCombinators => Version::new(1, 54, 0),
+            Self::CallString => Version::new(1, 0, 0),
+            Self::Elseif => Version::new(1, 3, 2),
+            Self::DuplicateVarFlags => Version::new(1, 62, 0),
+            Self::ColorModuleCompat => Version::new(1, 23, 0),
+        }
+    }
+
+    /// How the targeted implementation treats this behavior
+    ///
+    /// With no target, everything is supported as it was before being deprecated
+    pub fn status(self, compat: Option<Compat>) -> DeprecationStatus {
+        match compat {
+            Some(Compat::DartSass(version)) if version >= Self::REMOVED_IN => {
+                DeprecationStatus::Removed
+            }
+            Some(Compat::DartSass(version)) if version >= self.deprecated_in() => {
+                DeprecationStatus::Deprecated
+            }
+            Some(Compat::DartSass(..)) | None => DeprecationStatus::Supported,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DeprecationStatus {
+    /// The behavior is allowed silently
+    Supported,
+    /// The behavior is allowed, but a warning is emitted
+    Deprecated,
+    /// The behavior is an error or has been replaced
+    Removed,
+}
@@ -2,11 +2,12 @@ use std::{
     error::Error,
     fmt::{self, Display},
     io,
-    rc::Rc,
+    ops::Range,
     string::FromUtf8Error,
+    sync::Arc,
 };
 
-use codemap::{Span, SpanLoc};
+use codemap::{CodeMap, Span, SpanLoc};
 
 pub type SassResult<T> = Result<T, Box<SassError>>;
 
@@ -20,8 +21,10 @@ pub type SassResult<T> = Result<T, Box<SassError>>;
 ///```scss
 /// Error: $number: foo is not a number.
 ///     |
+/// 307 |   a {
 /// 308 |     color: unit(foo);
 ///     |                 ^^^
+/// 309 |   }
 ///     |
 /// ./input.scss:308:17
 ///```
@@ -31,95 +34,571 @@ pub type SassResult<T> = Result<T, Box<SassError>>;
 /// directly to the error by simply clicking the file name.
 ///
 /// Note that this is a deviation from the Sass specification.
+///
+/// Tools that embed `grass` can instead read the location of an error through
+/// [`file`](SassError::file), [`line`](SassError::line),
+/// [`column`](SassError::column), and [`span`](SassError::span):
+///
+/// ```
+/// let err = grass::from_string(
+///     "a {\n  color: $b;\n}\n".to_owned(),
+///     &grass::Options::default(),
+/// )
+/// .unwrap_err();
+///
+/// assert_eq!(err.kind(), grass::ErrorKind::Stylesheet);
+/// assert_eq!(err.message(), "Undefined variable.");
+/// assert_eq!(err.file(), Some("stdin"));
+/// assert_eq!(err.line(), Some(2));
+/// assert_eq!(err.column(), Some(10));
+/// assert_eq!(err.span(), Some(13..15));
+/// ```
 #[derive(Debug, Clone)]
 pub struct SassError {
     kind: SassErrorKind,
 }
 
 impl SassError {
-    pub(crate) fn raw(self) -> (String, Span) {
+    /// The message, span, and call stack of an error raised while parsing,
+    /// or the error itself if it has no location, e.g. an `io::Error`
+    pub(crate) fn raw(self) -> Result<(String, Span, Vec<StackFrame>), Self> {
         match self.kind {
-            SassErrorKind::Raw(string, span) => (string, span),
-            e => todo!("unable to get raw of {:?}", e),
+            SassErrorKind::Raw {
+                message,
+                span,
+                trace,
+            } => Ok((message, span, trace.unwrap_or_default())),
+            kind => Err(SassError { kind }),
         }
     }
 
-    pub(crate) const fn from_loc(message: String, loc: SpanLoc, unicode: bool) -> Self {
+    pub(crate) const fn from_loc(
+        message: String,
+        loc: SpanLoc,
+        offsets: Range<usize>,
+        trace: Vec<TraceLine>,
+        unicode: bool,
+        color: bool,
+    ) -> Self {
         SassError {
             kind: SassErrorKind::ParseError {
                 message,
                 loc,
+                offsets,
+                trace,
                 unicode,
+                color,
             },
         }
     }
+
+    /// An error for a compilation that was stopped before it finished
+    pub(crate) fn cancelled(message: &'static str) -> Box<Self> {
+        Box::new(SassError {
+            kind: SassErrorKind::Cancelled(message),
+        })
+    }
+
+    /// Whether compilation failed because it was cancelled through a
+    /// [`CancellationToken`](crate::CancellationToken) or ran past its
+    /// [timeout](crate::Options::timeout), rather than because of a problem
+    /// with the stylesheet
+    #[must_use]
+    #[inline]
+    pub const fn is_cancelled(&self) -> bool {
+        matches!(self.kind, SassErrorKind::Cancelled(..))
+    }
+
+    /// The kind of problem that stopped compilation
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ErrorKind {
+        match self.kind {
+            SassErrorKind::Raw { .. } | SassErrorKind::ParseError { .. } => ErrorKind::Stylesheet,
+            SassErrorKind::IoError(..) => ErrorKind::Io,
+            SassErrorKind::FromUtf8Error(..) => ErrorKind::Encoding,
+            SassErrorKind::Cancelled(..) => ErrorKind::Cancelled,
+        }
+    }
+
+    /// The message of this error, as written after `Error: ` in its
+    /// [`Display`] output
+    #[must_use]
+    #[inline]
+    pub fn message(&self) -> String {
+        match &self.kind {
+            SassErrorKind::Raw { message, .. } | SassErrorKind::ParseError { message, .. } => {
+                message.clone()
+            }
+            SassErrorKind::IoError(err) => err.to_string(),
+            SassErrorKind::FromUtf8Error(message) => message.clone(),
+            SassErrorKind::Cancelled(message) => (*message).to_owned(),
+        }
+    }
+
+    fn loc(&self) -> Option<&SpanLoc> {
+        match &self.kind {
+            SassErrorKind::ParseError { loc, .. } => Some(loc),
+            _ => None,
+        }
+    }
+
+    /// The name of the file the error was raised in, as it appears in the
+    /// [`Display`] output, e.g. `stdin` for [`from_string`](crate::from_string)
+    ///
+    /// This is `None` for errors that don't have a location in a stylesheet,
+    /// such as failures to read a file.
+    #[must_use]
+    #[inline]
+    pub fn file(&self) -> Option<&str> {
+        self.loc().map(|loc| loc.file.name())
+    }
+
+    /// The line the error was raised on, counting from 1
+    #[must_use]
+    #[inline]
+    pub fn line(&self) -> Option<usize> {
+        self.loc().map(|loc| loc.begin.line + 1)
+    }
+
+    /// The column, in characters, that the error was raised at, counting
+    /// from 1
+    #[must_use]
+    #[inline]
+    pub fn column(&self) -> Option<usize> {
+        self.loc().map(|loc| loc.begin.column + 1)
+    }
+
+    /// The byte offsets in the source of [`file`](SassError::file) of the
+    /// code the error was raised for, which is underlined in the
+    /// [`Display`] output
+    #[must_use]
+    #[inline]
+    pub fn span(&self) -> Option<Range<usize>> {
+        match &self.kind {
+            SassErrorKind::ParseError { offsets, .. } => Some(offsets.clone()),
+            _ => None,
+        }
+    }
+
+    /// Record the Sass call stack at the point this error was raised
+    ///
+    /// Only the innermost call to this method has an effect, as
+    /// that is where the stack is deepest
+    pub(crate) fn with_trace(mut self: Box<Self>, stack: &[StackFrame]) -> Box<Self> {
+        if let SassErrorKind::Raw {
+            trace: trace @ None,
+            ..
+        } = &mut self.kind
+        {
+            *trace = Some(stack.to_vec());
+        }
+        self
+    }
+}
+
+/// A call to a mixin, function, or stylesheet that
+/// is currently being evaluated
+#[derive(Debug, Clone)]
+pub(crate) struct StackFrame {
+    /// The name shown in stack traces, e.g. `foo()` or `@import`
+    pub name: String,
+
+    /// The location of the call
+    pub span: Span,
+}
+
+impl StackFrame {
+    /// Whether this frame is for loading another stylesheet
+    pub fn is_load(&self) -> bool {
+        matches!(self.name.as_str(), "@import" | "@use")
+    }
+}
+
+/// A single line of a stack trace with its location resolved, e.g.
+/// `input.scss 3:5  foo()`
+#[derive(Debug, Clone)]
+pub(crate) struct TraceLine {
+    member: String,
+    file: String,
+    line: usize,
+    column: usize,
+}
+
+/// Resolve the stack trace for something happening at `span` while
+/// `stack` is being evaluated
+///
+/// The innermost location comes first, and the last line is always
+/// the root stylesheet.
+pub(crate) fn resolve_trace(stack: &[StackFrame], span: Span, map: &CodeMap) -> Vec<TraceLine> {
+    let members = stack
+        .iter()
+        .rev()
+        .map(|frame| frame.name.as_str())
+        .chain(std::iter::once("root stylesheet"));
+
+    let spans = std::iter::once(span).chain(stack.iter().rev().map(|frame| frame.span));
+
+    spans
+        .zip(members)
+        .map(|(span, member)| {
+            let loc = map.look_up_span(span);
+            TraceLine {
+                member: member.to_owned(),
+                file: loc.file.name().to_owned(),
+                line: loc.begin.line + 1,
+                column: loc.begin.column + 1,
+            }
+        })
+        .collect()
+}
+
+/// Write each line of a stack trace, aligning the member names
+pub(crate) fn write_trace(
+    f: &mut impl fmt::Write,
+    trace: &[TraceLine],
+    indent: &str,
+) -> fmt::Result {
+    let locations: Vec<String> = trace
+        .iter()
+        .map(|line| format!("{} {}:{}", line.file, line.line, line.column))
+        .collect();
+
+    let width = locations.iter().map(String::len).max().unwrap_or(0);
+
+    for (location, line) in locations.iter().zip(trace) {
+        writeln!(
+            f,
+            "{}{:<width$}  {}",
+            indent,
+            location,
+            line.member,
+            width = width
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The kind of problem that stopped a compilation, returned by
+/// [`Error::kind`](crate::Error::kind)
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A problem with a stylesheet, such as invalid syntax, an undefined
+    /// variable, or an `@error` rule, which has a location in that stylesheet
+    Stylesheet,
+
+    /// A file could not be read
+    Io,
+
+    /// A file is not valid UTF-8
+    Encoding,
+
+    /// Compilation was cancelled or ran past its
+    /// [timeout](crate::Options::timeout)
+    Cancelled,
 }
 
 #[derive(Debug, Clone)]
 enum SassErrorKind {
     /// A raw error with no additional metadata
-    /// It contains only a `String` message and
-    /// a span
-    Raw(String, Span),
+    /// It contains only a `String` message, a span,
+    /// and the call stack at the point it was raised
+    Raw {
+        message: String,
+        span: Span,
+        trace: Option<Vec<StackFrame>>,
+    },
     ParseError {
         message: String,
         loc: SpanLoc,
+        /// The byte offsets of the span within its file
+        offsets: Range<usize>,
+        /// Empty unless the error occurred inside
+        /// a mixin, function, or imported file
+        trace: Vec<TraceLine>,
         unicode: bool,
+        color: bool,
     },
-    // we put IoErrors in an `Rc` to allow it to be
+    // we put IoErrors in an `Arc` to allow it to be
     // cloneable
-    IoError(Rc<io::Error>),
+    IoError(Arc<io::Error>),
     FromUtf8Error(String),
+    /// Compilation was cancelled or timed out
+    Cancelled(&'static str),
+}
+
+/// The number of lines of source shown before and after
+/// the lines containing the span of an error
+const CONTEXT_LINES: usize = 1;
+
+/// The characters used to draw the source excerpt of an error
+struct Glyphs {
+    top: char,
+    vertical: char,
+    bottom: char,
+    top_left: char,
+    bottom_left: char,
+    horizontal: char,
+}
+
+impl Glyphs {
+    const UNICODE: Glyphs = Glyphs {
+        top: '╷',
+        vertical: '│',
+        bottom: '╵',
+        top_left: '┌',
+        bottom_left: '└',
+        horizontal: '─',
+    };
+
+    const ASCII: Glyphs = Glyphs {
+        top: '|',
+        vertical: '|',
+        bottom: '|',
+        top_left: ',',
+        bottom_left: '\'',
+        horizontal: '-',
+    };
+}
+
+/// The ANSI escape codes used to color the source excerpt of an error
+struct Colors {
+    /// The line numbers and the lines around the excerpt
+    gutter: &'static str,
+    /// The span the error was raised for, and the marks underlining it
+    highlight: &'static str,
+    reset: &'static str,
+}
+
+impl Colors {
+    const ANSI: Colors = Colors {
+        gutter: "\x1b[34m",
+        highlight: "\x1b[31m",
+        reset: "\x1b[0m",
+    };
+
+    const NONE: Colors = Colors {
+        gutter: "",
+        highlight: "",
+        reset: "",
+    };
+}
+
+/// `line` with the characters from `start` up to `end` highlighted
+fn highlight(line: &str, start: usize, end: usize, colors: &Colors) -> String {
+    let byte_index = |column: usize| {
+        line.char_indices()
+            .nth(column)
+            .map_or(line.len(), |(idx, ..)| idx)
+    };
+
+    let (start, end) = (byte_index(start), byte_index(end));
+
+    if colors.highlight.is_empty() || start >= end {
+        return line.to_owned();
+    }
+
+    format!(
+        "{}{}{}{}{}",
+        &line[..start],
+        colors.highlight,
+        &line[start..end],
+        colors.reset,
+        &line[end..]
+    )
+}
+
+/// Render the lines of source covered by `loc`, along with `CONTEXT_LINES`
+/// lines on either side, underlining the span itself
+///
+/// A span on a single line is underlined with carets,
+///```scss
+///   ╷
+/// 2 │   color: unit(foo);
+///   │               ^^^
+///   ╵
+///```
+/// while a span across multiple lines is marked in the gutter
+///```scss
+///   ╷
+/// 1 │ ┌ a {
+/// 2 │ │   color: red
+///   │ └─^
+///   ╵
+///```
+/// With `color`, the gutter is blue and the span and its marks are red.
+fn write_source_excerpt(
+    f: &mut fmt::Formatter<'_>,
+    loc: &SpanLoc,
+    unicode: bool,
+    color: bool,
+) -> fmt::Result {
+    let glyphs = if unicode {
+        &Glyphs::UNICODE
+    } else {
+        &Glyphs::ASCII
+    };
+
+    let colors = if color { &Colors::ANSI } else { &Colors::NONE };
+
+    let first_line = loc.begin.line;
+    // a span ending at the very start of a line does not include that line
+    let last_line = if loc.end.line > first_line && loc.end.column == 0 {
+        loc.end.line - 1
+    } else {
+        loc.end.line.max(first_line)
+    };
+    let is_multiline = last_line > first_line;
+
+    let context_start = first_line.saturating_sub(CONTEXT_LINES);
+    let context_end = (last_line + CONTEXT_LINES).min(loc.file.num_lines().saturating_sub(1));
+
+    let padding = " ".repeat((context_end + 1).to_string().len() + 1);
+
+    // the line number and bar that start every line of the excerpt
+    let gutter = |line: Option<usize>, glyph: char| match line {
+        Some(line) => format!(
+            "{}{:<width$}{}{}",
+            colors.gutter,
+            line + 1,
+            glyph,
+            colors.reset,
+            width = padding.len()
+        ),
+        None => format!("{}{}{}{}", colors.gutter, padding, glyph, colors.reset),
+    };
+
+    let write_line = |f: &mut fmt::Formatter<'_>,
+                      line: usize,
+                      marker: &str,
+                      highlighted: Option<(usize, usize)>|
+     -> fmt::Result {
+        let source = loc.file.source_line(line).trim_end();
+        let source = match highlighted {
+            Some((start, end)) => highlight(source, start, end, colors),
+            None => source.to_owned(),
+        };
+
+        let content = format!("{}{}", marker, source);
+        let content = content.trim_end();
+
+        if content.is_empty() {
+            writeln!(f, "{}", gutter(Some(line), glyphs.vertical))
+        } else {
+            writeln!(f, "{} {}", gutter(Some(line), glyphs.vertical), content)
+        }
+    };
+
+    writeln!(f, "{}", gutter(None, glyphs.top))?;
+
+    let gutter_blank = if is_multiline { "  " } else { "" };
+
+    for line in context_start..first_line {
+        write_line(f, line, gutter_blank, None)?;
+    }
+
+    if is_multiline {
+        let end_column = if loc.end.line > last_line {
+            loc.file.source_line(last_line).chars().count()
+        } else {
+            loc.end.column
+        };
+
+        write_line(
+            f,
+            first_line,
+            &format!("{}{}{} ", colors.highlight, glyphs.top_left, colors.reset),
+            Some((loc.begin.column, usize::MAX)),
+        )?;
+        for line in first_line + 1..=last_line {
+            write_line(
+                f,
+                line,
+                &format!("{}{}{} ", colors.highlight, glyphs.vertical, colors.reset),
+                Some((
+                    0,
+                    if line == last_line {
+                        end_column
+                    } else {
+                        usize::MAX
+                    },
+                )),
+            )?;
+        }
+
+        writeln!(
+            f,
+            "{} {}{}{}^{}",
+            gutter(None, glyphs.vertical),
+            colors.highlight,
+            glyphs.bottom_left,
+            glyphs.horizontal.to_string().repeat(end_column),
+            colors.reset,
+        )?;
+    } else {
+        let start_column = loc.begin.column.min(loc.end.column);
+        let width = (loc.end.column.max(loc.begin.column) - start_column).max(1);
+
+        write_line(
+            f,
+            first_line,
+            "",
+            Some((start_column, start_column + width)),
+        )?;
+
+        writeln!(
+            f,
+            "{} {}{}{}{}",
+            gutter(None, glyphs.vertical),
+            " ".repeat(start_column),
+            colors.highlight,
+            "^".repeat(width),
+            colors.reset,
+        )?;
+    }
+
+    for line in last_line + 1..=context_end {
+        write_line(f, line, gutter_blank, None)?;
+    }
+
+    writeln!(f, "{}", gutter(None, glyphs.bottom))
 }
 
 impl Display for SassError {
     // TODO: trim whitespace from start of line shown in error
-    // TODO: color errors
-    // TODO: integrate with codemap-diagnostics
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (message, loc, unicode) = match &self.kind {
+        let (message, loc, trace, unicode, color) = match &self.kind {
             SassErrorKind::ParseError {
                 message,
                 loc,
+                trace,
                 unicode,
-            } => (message, loc, *unicode),
+                color,
+                ..
+            } => (message, loc, trace, *unicode, *color),
             SassErrorKind::FromUtf8Error(s) => return writeln!(f, "Error: {}", s),
             SassErrorKind::IoError(s) => return writeln!(f, "Error: {}", s),
-            SassErrorKind::Raw(..) => todo!(),
+            SassErrorKind::Cancelled(s) => return writeln!(f, "Error: {}", s),
+            SassErrorKind::Raw { .. } => todo!(),
         };
 
-        let first_bar = if unicode { '╷' } else { '|' };
-        let second_bar = if unicode { '│' } else { '|' };
-        let third_bar = if unicode { '│' } else { '|' };
-        let fourth_bar = if unicode { '╵' } else { '|' };
-
-        let line = loc.begin.line + 1;
-        let col = loc.begin.column + 1;
         writeln!(f, "Error: {}", message)?;
-        let padding = vec![' '; format!("{}", line).len() + 1]
-            .iter()
-            .collect::<String>();
-        writeln!(f, "{}{}", padding, first_bar)?;
+        write_source_excerpt(f, loc, unicode, color)?;
         writeln!(
             f,
-            "{} {} {}",
-            line,
-            second_bar,
-            loc.file.source_line(loc.begin.line)
+            "./{}:{}:{}",
+            loc.file.name(),
+            loc.begin.line + 1,
+            loc.begin.column + 1
         )?;
-        writeln!(
-            f,
-            "{}{} {}{}",
-            padding,
-            third_bar,
-            vec![' '; loc.begin.column].iter().collect::<String>(),
-            vec!['^'; loc.end.column.max(loc.begin.column) - loc.begin.column.min(loc.end.column)]
-                .iter()
-                .collect::<String>()
-        )?;
-        writeln!(f, "{}{}", padding, fourth_bar)?;
-        writeln!(f, "./{}:{}:{}", loc.file.name(), line, col)?;
+
+        if trace.len() > 1 {
+            write_trace(f, trace, "  ")?;
+        }
+
         Ok(())
     }
 }
@@ -128,7 +607,7 @@ impl From<io::Error> for Box<SassError> {
     #[inline]
     fn from(error: io::Error) -> Box<SassError> {
         Box::new(SassError {
-            kind: SassErrorKind::IoError(Rc::new(error)),
+            kind: SassErrorKind::IoError(Arc::new(error)),
         })
     }
 }
@@ -149,7 +628,11 @@ impl From<(&str, Span)> for Box<SassError> {
     #[inline]
     fn from(error: (&str, Span)) -> Box<SassError> {
         Box::new(SassError {
-            kind: SassErrorKind::Raw(error.0.to_owned(), error.1),
+            kind: SassErrorKind::Raw {
+                message: error.0.to_owned(),
+                span: error.1,
+                trace: None,
+            },
         })
     }
 }
@@ -158,7 +641,11 @@ impl From<(String, Span)> for Box<SassError> {
     #[inline]
     fn from(error: (String, Span)) -> Box<SassError> {
         Box::new(SassError {
-            kind: SassErrorKind::Raw(error.0, error.1),
+            kind: SassErrorKind::Raw {
+                message: error.0,
+                span: error.1,
+                trace: None,
+            },
         })
     }
 }
@@ -1,5 +1,11 @@
+#[cfg(any(feature = "axum", feature = "watch"))]
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::io::{Error, ErrorKind, Result};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+#[cfg(any(feature = "axum", feature = "watch"))]
+use std::time::SystemTime;
 
 /// A trait to allow replacing the file system lookup mechanisms.
 ///
@@ -9,13 +15,36 @@ use std::path::Path;
 /// trait (`is_absolute`, `parent`, `join`, *&c.*); but that would infect too many other APIs to be
 /// desirable, so we live with it as it is—which is also acceptable, because the motivating example
 /// use case is mostly using this as an optimisation over the real platform underneath.
-pub trait Fs: std::fmt::Debug {
+///
+/// Implementations must be `Sync` so that [`Options`](crate::Options) can be shared between
+/// threads compiling stylesheets at the same time.
+pub trait Fs: std::fmt::Debug + Sync {
     /// Returns `true` if the path exists on disk and is pointing at a directory.
     fn is_dir(&self, path: &Path) -> bool;
     /// Returns `true` if the path exists on disk and is pointing at a regular file.
     fn is_file(&self, path: &Path) -> bool;
     /// Read the entire contents of a file into a bytes vector.
     fn read(&self, path: &Path) -> Result<Vec<u8>>;
+    /// Returns the canonical form of a path, with symbolic links resolved,
+    /// such that every path to the same file has the same canonical form.
+    ///
+    /// This is used to avoid loading a module more than once. By default,
+    /// `path` is returned unchanged.
+    #[inline]
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        Ok(path.to_path_buf())
+    }
+    /// Returns the paths of the entries in a directory, in any order.
+    ///
+    /// This is only used to expand wildcards in `@import` URLs with the
+    /// `glob-imports` feature. By default, it fails.
+    #[inline]
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        Err(Error::new(
+            ErrorKind::Other,
+            format!("Can't list the contents of {}", path.display()),
+        ))
+    }
 }
 
 /// Use [`std::fs`] to read any files from disk.
@@ -39,6 +68,18 @@ impl Fs for StdFs {
     fn read(&self, path: &Path) -> Result<Vec<u8>> {
         std::fs::read(path)
     }
+
+    #[inline]
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+
+    #[inline]
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }
 }
 
 /// A file system implementation that acts like it’s completely empty.
@@ -68,3 +109,152 @@ impl Fs for NullFs {
         ))
     }
 }
+
+/// Wraps another file system, reading each file from it at most once.
+///
+/// This is used to share file reads between compilations of the same
+/// stylesheets, as in [`compile_themes`][crate::compile_themes].
+#[derive(Debug)]
+pub(crate) struct CachedFs<'a> {
+    fs: &'a dyn Fs,
+    files: Mutex<HashMap<PathBuf, Vec<u8>>>,
+}
+
+impl<'a> CachedFs<'a> {
+    pub fn new(fs: &'a dyn Fs) -> Self {
+        Self {
+            fs,
+            files: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn files(&self) -> MutexGuard<'_, HashMap<PathBuf, Vec<u8>>> {
+        self.files.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Fs for CachedFs<'_> {
+    fn is_file(&self, path: &Path) -> bool {
+        self.files().contains_key(path) || self.fs.is_file(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.fs.is_dir(path)
+    }
+
+    fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        if let Some(contents) = self.files().get(path) {
+            return Ok(contents.clone());
+        }
+
+        let contents = self.fs.read(path)?;
+        self.files().insert(path.to_path_buf(), contents.clone());
+        Ok(contents)
+    }
+
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        self.fs.canonicalize(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        self.fs.read_dir(path)
+    }
+}
+
+/// Wraps another file system, remembering every path it was asked about.
+///
+/// This is used to find the files a compilation depends on, including files
+/// that were looked for but didn't exist, since creating one of them may
+/// change which file an `@import` or `@use` loads.
+#[cfg(any(feature = "axum", feature = "watch"))]
+#[derive(Debug)]
+pub(crate) struct RecordingFs<'a> {
+    fs: &'a dyn Fs,
+    paths: Mutex<BTreeSet<PathBuf>>,
+}
+
+#[cfg(any(feature = "axum", feature = "watch"))]
+impl<'a> RecordingFs<'a> {
+    pub fn new(fs: &'a dyn Fs) -> Self {
+        Self {
+            fs,
+            paths: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    fn record(&self, path: &Path) {
+        self.paths
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.to_path_buf());
+    }
+
+    /// The paths that were asked about, along with their current
+    /// modification times
+    pub fn into_dependencies(self) -> Dependencies {
+        Dependencies(
+            self.paths
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner)
+                .into_iter()
+                .map(|path| {
+                    let modified = modified_time(&path);
+                    (path, modified)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(any(feature = "axum", feature = "watch"))]
+impl Fs for RecordingFs<'_> {
+    fn is_dir(&self, path: &Path) -> bool {
+        self.fs.is_dir(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.record(path);
+        self.fs.is_file(path)
+    }
+
+    fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        self.record(path);
+        self.fs.read(path)
+    }
+
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        self.fs.canonicalize(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        // new files matching a wildcard import should be noticed too
+        self.record(path);
+        self.fs.read_dir(path)
+    }
+}
+
+/// The files a compilation depended on, with their modification times, or
+/// `None` for files that didn't exist
+#[cfg(any(feature = "axum", feature = "watch"))]
+#[derive(Debug)]
+pub(crate) struct Dependencies(Vec<(PathBuf, Option<SystemTime>)>);
+
+#[cfg(any(feature = "axum", feature = "watch"))]
+impl Dependencies {
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Whether none of the files have been changed, created, or deleted
+    /// since they were recorded
+    pub fn are_unchanged(&self) -> bool {
+        self.0
+            .iter()
+            .all(|(path, modified)| &modified_time(path) == modified)
+    }
+}
+
+#[cfg(any(feature = "axum", feature = "watch"))]
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -0,0 +1,53 @@
+/// A trait to allow rewriting declarations as they are written to the output,
+/// for lightweight transformations such as adding vendor prefixes or
+/// rewriting units, without parsing the CSS again afterwards.
+///
+/// Like [`Fs`](crate::Fs), implementations must be `Sync` so that
+/// [`Options`](crate::Options) can be shared between threads.
+///
+/// ```
+/// use grass::{DeclarationHook, Options};
+///
+/// #[derive(Debug)]
+/// struct Prefixer;
+///
+/// impl DeclarationHook for Prefixer {
+///     fn declaration(
+///         &self,
+///         _selector: Option<&str>,
+///         property: &str,
+///         value: &str,
+///     ) -> Vec<(String, String)> {
+///         let mut declarations = Vec::new();
+///         if property == "user-select" {
+///             declarations.push(("-webkit-user-select".to_owned(), value.to_owned()));
+///         }
+///         declarations.push((property.to_owned(), value.to_owned()));
+///         declarations
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let css = grass::from_string(
+///         "a { user-select: none; }".to_owned(),
+///         &Options::default().declaration_hook(&Prefixer),
+///     )?;
+///     assert_eq!(css, "a {\n  -webkit-user-select: none;\n  user-select: none;\n}\n");
+///     Ok(())
+/// }
+/// ```
+pub trait DeclarationHook: std::fmt::Debug + Sync {
+    /// Called with each declaration that is about to be written, returning the
+    /// `(property, value)` pairs to write in its place, in order.
+    ///
+    /// `selector` is the selector of the enclosing style rule or keyframe
+    /// block, or `None` for declarations directly inside an at-rule such as
+    /// `@font-face`. `value` has already been serialized for the output
+    /// style in use. Returning an empty list removes the declaration.
+    fn declaration(
+        &self,
+        selector: Option<&str>,
+        property: &str,
+        value: &str,
+    ) -> Vec<(String, String)>;
+}
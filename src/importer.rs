@@ -0,0 +1,64 @@
+use std::io::Result;
+
+/// A trait to allow loading stylesheets from somewhere other than the
+/// [`Fs`](crate::Fs), such as a database or assets embedded in the binary.
+///
+/// Importers are registered with [`Options::importer`](crate::Options::importer)
+/// and are asked to resolve each `@import`, `@use`, and `meta.load-css()` URL
+/// that isn't relative to the importing file, before `load_paths` are
+/// searched. A URL in a stylesheet that was loaded by an importer is first
+/// resolved relative to it by the same importer.
+///
+/// Like [`Fs`](crate::Fs), implementations must be `Sync` so that
+/// [`Options`](crate::Options) can be shared between threads.
+///
+/// ```
+/// use std::{collections::HashMap, io};
+///
+/// use grass::{Importer, Options};
+///
+/// #[derive(Debug)]
+/// struct Embedded(HashMap<&'static str, &'static str>);
+///
+/// impl Importer for Embedded {
+///     fn canonicalize(&self, url: &str, _base: Option<&str>) -> Option<String> {
+///         let name = url.strip_prefix("embedded:")?;
+///         self.0
+///             .contains_key(name)
+///             .then(|| format!("embedded:{}", name))
+///     }
+///
+///     fn load(&self, canonical_url: &str) -> io::Result<String> {
+///         let name = canonical_url.trim_start_matches("embedded:");
+///         Ok(self.0[name].to_owned())
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let importer = Embedded(HashMap::from([("colors", "$primary: red;")]));
+///
+///     let css = grass::from_string(
+///         "@use \"embedded:colors\";\na { color: colors.$primary; }".to_owned(),
+///         &Options::default().importer(&importer),
+///     )?;
+///     assert_eq!(css, "a {\n  color: red;\n}\n");
+///     Ok(())
+/// }
+/// ```
+pub trait Importer: std::fmt::Debug + Sync {
+    /// Returns the canonical URL of the stylesheet `url` refers to, or `None`
+    /// if this importer can't load it, in which case the next importer or
+    /// load path is tried.
+    ///
+    /// `base` is the canonical URL of the importing stylesheet if it was loaded
+    /// by this importer, for resolving relative URLs. Every URL that refers to
+    /// the same stylesheet must have the same canonical URL, which is used to
+    /// load each module only once and appears in error messages. To avoid
+    /// clashing with paths on the file system, it should start with a scheme
+    /// such as `db:`.
+    fn canonicalize(&self, url: &str, base: Option<&str>) -> Option<String>;
+
+    /// Returns the contents of the stylesheet with a canonical URL that was
+    /// returned by [`Importer::canonicalize`].
+    fn load(&self, canonical_url: &str) -> Result<String>;
+}
@@ -62,6 +62,11 @@ impl<'a> Lexer<'a> {
     pub fn cursor(&self) -> usize {
         self.cursor
     }
+
+    /// The most recently consumed token
+    pub fn prev(&self) -> Option<Token> {
+        self.buf.get(self.cursor.checked_sub(1)?).copied()
+    }
 }
 
 impl<'a> Iterator for Lexer<'a> {
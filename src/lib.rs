@@ -97,48 +97,75 @@ grass input.scss
 #![cfg_attr(feature = "nightly", feature(track_caller))]
 #![cfg_attr(feature = "profiling", inline(never))]
 
-use std::path::Path;
+use std::{
+    collections::HashSet,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[cfg(feature = "wasm-exports")]
 use wasm_bindgen::prelude::*;
 
 pub(crate) use beef::lean::Cow;
 
-use codemap::CodeMap;
+use codemap::{CodeMap, File};
 
-pub use crate::error::{SassError as Error, SassResult as Result};
+pub use crate::cancellation::CancellationToken;
+pub use crate::compat::{Compat, Version};
+pub use crate::error::{ErrorKind, SassError as Error, SassResult as Result};
+use crate::fs::CachedFs;
 pub use crate::fs::{Fs, NullFs, StdFs};
+pub use crate::hook::DeclarationHook;
+pub use crate::importer::Importer;
+pub use crate::logger::{Logger, NullLogger, StdLogger};
+pub use crate::number::{Number, ParseNumberError, Unit};
+pub use crate::stats::{FileStats, Stats};
 pub(crate) use crate::token::Token;
 use crate::{
     builtin::modules::{ModuleConfig, Modules},
+    compat::{Deprecation, DeprecationStatus},
+    error::resolve_trace,
     lexer::Lexer,
     output::{AtRuleContext, Css},
     parse::{
         common::{ContextFlags, NeverEmptyVec},
-        Parser,
+        LoadedFiles, Parser,
     },
     scope::{Scope, Scopes},
     selector::{ExtendedSelector, Extender, SelectorList},
+    utils::{decode_stylesheet, path_to_url, url_to_path},
 };
 
 mod args;
 mod atrule;
+#[cfg(feature = "axum")]
+pub mod axum;
 mod builtin;
+mod cancellation;
 mod color;
 mod common;
+mod compat;
 mod error;
 mod fs;
+mod hook;
+mod importer;
 mod interner;
 mod lexer;
+mod logger;
+mod number;
 mod output;
 mod parse;
 mod scope;
 mod selector;
+mod stats;
 mod style;
 mod token;
 mod unit;
 mod utils;
 mod value;
+#[cfg(feature = "watch")]
+pub mod watch;
 
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug)]
@@ -157,14 +184,51 @@ pub enum OutputStyle {
 /// The simplest usage is `grass::Options::default()`;
 /// however, a builder pattern is also exposed to offer
 /// more control.
+///
+/// ```
+/// # use std::path::Path;
+/// # fn main() -> Result<(), Box<grass::Error>> {
+/// let options = grass::Options::default()
+///     .style(grass::OutputStyle::Compressed)
+///     .load_path(Path::new("scss"))
+///     .quiet(true);
+///
+/// let css = grass::from_string("a { b: (1 / 3) }".to_owned(), &options)?;
+/// assert_eq!(css, "a{b:.3333333333}");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// As in `dart-sass`, numbers are always written with up to 10 digits after
+/// the decimal point. Unlike `libsass`, there is no option to change this
+/// precision.
+///
+/// `Options` are `Send` and `Sync`, so one configuration
+/// can be shared by compilations running on several threads.
 #[derive(Debug)]
 pub struct Options<'a> {
     fs: &'a dyn Fs,
     style: OutputStyle,
     load_paths: Vec<&'a Path>,
+    importers: Vec<&'a dyn Importer>,
     allows_charset: bool,
     unicode_error_messages: bool,
+    color_error_messages: bool,
     quiet: bool,
+    verbose: bool,
+    unique_id_prefix: &'a str,
+    unique_id_length: usize,
+    unique_id_seed: Option<u64>,
+    max_import_depth: usize,
+    compat: Option<Compat>,
+    libsass_compat: bool,
+    fatal_warnings: bool,
+    canonicalize_imports: bool,
+    declaration_hook: Option<&'a dyn DeclarationHook>,
+    logger: &'a dyn Logger,
+    preserve_color_format: bool,
+    cancellation: Option<&'a CancellationToken>,
+    timeout: Option<Duration>,
 }
 
 impl Default for Options<'_> {
@@ -174,9 +238,25 @@ impl Default for Options<'_> {
             fs: &StdFs,
             style: OutputStyle::Expanded,
             load_paths: Vec::new(),
+            importers: Vec::new(),
             allows_charset: true,
             unicode_error_messages: true,
+            color_error_messages: false,
             quiet: false,
+            verbose: false,
+            unique_id_prefix: "u",
+            unique_id_length: 7,
+            unique_id_seed: None,
+            max_import_depth: 100,
+            compat: None,
+            libsass_compat: false,
+            fatal_warnings: false,
+            canonicalize_imports: true,
+            declaration_hook: None,
+            logger: &StdLogger,
+            preserve_color_format: false,
+            cancellation: None,
+            timeout: None,
         }
     }
 }
@@ -221,6 +301,19 @@ impl<'a> Options<'a> {
         self
     }
 
+    /// Print every warning, even one that has already been printed for the
+    /// same location, e.g. by a `@warn` rule in a mixin that is included
+    /// several times.
+    ///
+    /// By default, this is false, and each warning is only printed once per
+    /// location in a compilation.
+    #[must_use]
+    #[inline]
+    pub const fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
     /// All Sass implementations allow users to provide
     /// load paths: paths on the filesystem that Sass
     /// will look in when locating modules. For example,
@@ -233,6 +326,10 @@ impl<'a> Options<'a> {
     /// ensures that you can't accidentally mess up your relative
     /// imports when you add a new library.
     ///
+    /// Load paths are searched in the order they were added. Within each,
+    /// `@import "foo"` tries `foo.scss`, `_foo.scss`, `foo/index.scss`, and
+    /// `foo/_index.scss` in turn.
+    ///
     /// This method will append a single path to the list.
     #[must_use]
     #[inline]
@@ -253,6 +350,21 @@ impl<'a> Options<'a> {
         self
     }
 
+    /// Adds a custom [`Importer`](Importer), to load stylesheets from
+    /// somewhere other than the file system, such as a database or assets
+    /// embedded in the binary.
+    ///
+    /// Importers are tried in the order they are added, after URLs relative
+    /// to the importing file and before `load_paths`.
+    ///
+    /// By default, there are no custom importers.
+    #[must_use]
+    #[inline]
+    pub fn importer(mut self, importer: &'a dyn Importer) -> Self {
+        self.importers.push(importer);
+        self
+    }
+
     /// This flag tells Sass whether to emit a `@charset`
     /// declaration or a UTF-8 byte-order mark.
     ///
@@ -281,25 +393,320 @@ impl<'a> Options<'a> {
         self
     }
 
+    /// This flag tells Sass to color the source excerpt of error messages
+    /// with ANSI escape codes, underlining the offending code in red, for
+    /// display in a terminal.
+    ///
+    /// By default, error messages are not colored.
+    ///
+    /// This flag does not affect the CSS output.
+    #[must_use]
+    #[inline]
+    pub const fn color_error_messages(mut self, color_error_messages: bool) -> Self {
+        self.color_error_messages = color_error_messages;
+        self
+    }
+
+    /// The text that every identifier returned by `unique-id()` starts with.
+    ///
+    /// The generated identifiers are always valid CSS identifiers: if the prefix
+    /// is empty or `-`, the first random character is a letter. `unique-id()`
+    /// is an error if the prefix can't start an identifier, such as `"1a"` or
+    /// `"a.b"`, or if it is empty or `-` and
+    /// [`Options::unique_id_length`](Options::unique_id_length) is `0`.
+    ///
+    /// By default, this is `"u"`, matching `dart-sass`.
+    ///
+    /// This option only has an effect when the `random` feature is enabled.
+    #[must_use]
+    #[inline]
+    pub const fn unique_id_prefix(mut self, unique_id_prefix: &'a str) -> Self {
+        self.unique_id_prefix = unique_id_prefix;
+        self
+    }
+
+    /// The number of random characters that `unique-id()` appends to
+    /// [`Options::unique_id_prefix`](Options::unique_id_prefix).
+    ///
+    /// Longer identifiers make collisions less likely when generating
+    /// a large number of names, such as for `@keyframes`.
+    ///
+    /// By default, 7 characters are generated.
+    ///
+    /// This option only has an effect when the `random` feature is enabled.
+    #[must_use]
+    #[inline]
+    pub const fn unique_id_length(mut self, unique_id_length: usize) -> Self {
+        self.unique_id_length = unique_id_length;
+        self
+    }
+
+    /// Generate the identifiers returned by `unique-id()` from a fixed seed
+    /// rather than from the thread-local random number generator.
+    ///
+    /// Compiling the same input with the same seed always produces the same
+    /// sequence of identifiers, so output can be compared byte-for-byte, e.g.
+    /// to verify reproducible builds in CI.
+    ///
+    /// By default, no seed is set and identifiers differ between compilations.
+    ///
+    /// This option only has an effect when the `random` feature is enabled.
+    #[must_use]
+    #[inline]
+    pub const fn unique_id_seed(mut self, unique_id_seed: Option<u64>) -> Self {
+        self.unique_id_seed = unique_id_seed;
+        self
+    }
+
+    /// The maximum number of stylesheets that may be nested inside one another
+    /// through `@import`, `@use`, and `meta.load-css()`.
+    ///
+    /// Exceeding this limit is an error whose stack trace lists the chain of
+    /// imports, rather than running out of stack space, e.g. when a file
+    /// accidentally imports itself.
+    ///
+    /// By default, this is 100.
+    #[must_use]
+    #[inline]
+    pub const fn max_import_depth(mut self, max_import_depth: usize) -> Self {
+        self.max_import_depth = max_import_depth;
+        self
+    }
+
+    /// Reproduce the behavior of a specific release of another Sass
+    /// implementation where it has changed between releases.
+    ///
+    /// For `dart-sass`, this controls:
+    ///
+    /// - using `/` for division outside of `calc()`, which warns starting with
+    ///   1.33.0 and is an error starting with 2.0.0
+    /// - passing a `$limit` with units to `random()`, which warns that the units
+    ///   are ignored starting with 1.54.5 and keeps them starting with 2.0.0
+    /// - selectors with leading or trailing combinators, or with multiple
+    ///   combinators in a row, which warn starting with 1.54.0 and are an error
+    ///   starting with 2.0.0
+    /// - passing the name of a function to `call()` rather than a function
+    ///   from `get-function()`, which warns starting with 1.0.0 and is an
+    ///   error starting with 2.0.0
+    /// - writing `@elseif` rather than `@else if`, which warns starting with
+    ///   1.3.2 and is an error starting with 2.0.0
+    /// - writing `!default` or `!global` more than once for a variable, which
+    ///   warns starting with 1.62.0 and is an error starting with 2.0.0
+    /// - passing a number to a `sass:color` function such as `color.invert()`
+    ///   to emit a plain CSS function, which warns starting with 1.23.0 and
+    ///   is an error starting with 2.0.0
+    ///
+    /// By default, no release is targeted, and all of the above are accepted
+    /// silently.
+    #[must_use]
+    #[inline]
+    pub const fn compat(mut self, compat: Compat) -> Self {
+        self.compat = Some(compat);
+        self
+    }
+
+    /// Reproduce a handful of `libsass` (and therefore `node-sass`) behaviors
+    /// that differ from `dart-sass`, so that the output of stylesheets being
+    /// migrated away from `node-sass` can be compared more easily.
+    ///
+    /// This enables:
+    ///
+    /// - extending compound selectors, e.g. `@extend .a.b`, which extends only
+    ///   selectors that contain every simple selector of the target, rather
+    ///   than erroring
+    /// - resolving imports in the order `libsass` does: in each directory, the
+    ///   exact file name is tried before partials, and partials before other
+    ///   files (`_index.scss` before `index.scss`), and the current working
+    ///   directory is searched after the directory of the importing file but
+    ///   before any load paths
+    ///
+    /// By default, this is false.
+    #[must_use]
+    #[inline]
+    pub const fn libsass_compat(mut self, libsass_compat: bool) -> Self {
+        self.libsass_compat = libsass_compat;
+        self
+    }
+
+    /// Treat warnings, whether from `@warn` or about deprecated behavior, as
+    /// errors that stop compilation.
+    ///
+    /// This is useful in CI, to keep stylesheets free of warnings. It takes
+    /// precedence over [`Options::quiet`](Options::quiet).
+    ///
+    /// By default, this is false.
+    #[must_use]
+    #[inline]
+    pub const fn fatal_warnings(mut self, fatal_warnings: bool) -> Self {
+        self.fatal_warnings = fatal_warnings;
+        self
+    }
+
+    /// Resolve symbolic links, and on case-insensitive file systems the case
+    /// of file names, using [`Fs::canonicalize`](Fs::canonicalize) when
+    /// deciding whether two imports refer to the same file.
+    ///
+    /// A module is only loaded once per compilation, however many times it is
+    /// loaded with `@use`, so with this enabled, a module reached through two
+    /// different paths also has its CSS emitted only once. A warning is
+    /// emitted when the same file is imported through paths that differ only
+    /// by case, since these fail to resolve on case-sensitive file systems.
+    ///
+    /// Disable this to compare the paths as they are written instead.
+    ///
+    /// By default, this is true.
+    #[must_use]
+    #[inline]
+    pub const fn canonicalize_imports(mut self, canonicalize_imports: bool) -> Self {
+        self.canonicalize_imports = canonicalize_imports;
+        self
+    }
+
+    /// Rewrite each declaration as it is written to the output, using the
+    /// given [`DeclarationHook`](DeclarationHook).
+    ///
+    /// The hook sees the final property name and serialized value of every
+    /// declaration, along with the selector it appears under, and may replace
+    /// it with any number of declarations, e.g. to add vendor prefixes.
+    ///
+    /// By default, declarations are written as they are.
+    #[must_use]
+    #[inline]
+    pub fn declaration_hook(mut self, declaration_hook: &'a dyn DeclarationHook) -> Self {
+        self.declaration_hook = Some(declaration_hook);
+        self
+    }
+
+    /// Pass the messages printed while compiling, the output of `@debug`
+    /// rules and warnings, to the given [`Logger`](Logger) rather than writing
+    /// them to stderr.
+    ///
+    /// [`Options::quiet`](Options::quiet) still silences these messages
+    /// before they reach the logger.
+    ///
+    /// By default, this uses [`StdLogger`], which writes to stderr.
+    #[must_use]
+    #[inline]
+    pub fn logger(mut self, logger: &'a dyn Logger) -> Self {
+        self.logger = logger;
+        self
+    }
+
+    /// Emit colors in the notation they were written in, unless they are
+    /// modified, e.g. by color arithmetic or by functions such as `lighten()`.
+    ///
+    /// Hex colors and color names already keep their case in expanded
+    /// output. With this enabled, they are also left alone in compressed
+    /// output, rather than being shortened, and colors created with `rgb()`,
+    /// `rgba()`, `hsl()`, or `hsla()` are emitted using the same function,
+    /// rather than being converted to a hex color or color name.
+    ///
+    /// By default, this is false.
+    #[must_use]
+    #[inline]
+    pub const fn preserve_color_format(mut self, preserve_color_format: bool) -> Self {
+        self.preserve_color_format = preserve_color_format;
+        self
+    }
+
+    /// Stop compiling once `cancellation` is cancelled, failing with an error
+    /// for which [`Error::is_cancelled`] returns `true`
+    ///
+    /// This is checked before each statement is evaluated, so compilation
+    /// stops soon after the token is cancelled, even in the middle of a long
+    /// `@while` loop.
+    ///
+    /// By default, compilation can't be cancelled.
+    #[must_use]
+    #[inline]
+    pub const fn cancellation(mut self, cancellation: &'a CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Stop compiling once `timeout` has passed since compilation started,
+    /// failing with an error for which [`Error::is_cancelled`] returns `true`
+    ///
+    /// Like [`Options::cancellation`], this is checked before each statement
+    /// is evaluated.
+    ///
+    /// By default, there is no timeout.
+    #[must_use]
+    #[inline]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn deprecation_status(&self, deprecation: Deprecation) -> DeprecationStatus {
+        deprecation.status(self.compat)
+    }
+
     pub(crate) fn is_compressed(&self) -> bool {
         matches!(self.style, OutputStyle::Compressed)
     }
 }
 
-fn raw_to_parse_error(map: &CodeMap, err: Error, unicode: bool) -> Box<Error> {
-    let (message, span) = err.raw();
-    Box::new(Error::from_loc(message, map.look_up_span(span), unicode))
+fn raw_to_parse_error(map: &CodeMap, err: Error, options: &Options) -> Box<Error> {
+    let (message, span, stack) = match err.raw() {
+        Ok(raw) => raw,
+        Err(err) => return Box::new(err),
+    };
+    let trace = if stack.is_empty() {
+        Vec::new()
+    } else {
+        resolve_trace(&stack, span, map)
+    };
+    let file_start = map.find_file(span.low()).span.low();
+    Box::new(Error::from_loc(
+        message,
+        map.look_up_span(span),
+        (span.low() - file_start) as usize..(span.high() - file_start) as usize,
+        trace,
+        options.unicode_error_messages,
+        options.color_error_messages,
+    ))
 }
 
-fn from_string_with_file_name(input: String, file_name: &str, options: &Options) -> Result<String> {
+fn from_string_with_file_name(
+    input: String,
+    file_name: &Path,
+    options: &Options,
+    stats: Option<&mut Stats>,
+    config: &[(&str, &str)],
+) -> Result<String> {
     let mut map = CodeMap::new();
-    let file = map.add_file(file_name.to_owned(), input);
+    let file = map.add_file(path_to_url(file_name), input);
+
+    compile_file(
+        &mut map,
+        &file,
+        file_name,
+        options,
+        stats,
+        config,
+        &mut LoadedFiles::default(),
+    )
+}
+
+/// Compiles `file`, which has been added to `map`, using the modules in
+/// `loaded_files` that were shared by earlier compilations
+fn compile_file(
+    map: &mut CodeMap,
+    file: &Arc<File>,
+    file_name: &Path,
+    options: &Options,
+    stats: Option<&mut Stats>,
+    config: &[(&str, &str)],
+    loaded_files: &mut LoadedFiles,
+) -> Result<String> {
     let empty_span = file.span.subspan(0, 0);
+    let mut extender = Extender::new(empty_span);
 
     let stmts = Parser {
-        toks: &mut Lexer::new_from_file(&file),
-        map: &mut map,
-        path: file_name.as_ref(),
+        toks: &mut Lexer::new_from_file(file),
+        map,
+        path: file_name,
         scopes: &mut Scopes::new(),
         global_scope: &mut Scope::new(),
         super_selectors: &mut NeverEmptyVec::new(ExtendedSelector::new(SelectorList::new(
@@ -310,24 +717,41 @@ fn from_string_with_file_name(input: String, file_name: &str, options: &Options)
         flags: ContextFlags::empty(),
         at_root: true,
         at_root_has_selector: false,
-        extender: &mut Extender::new(empty_span),
-        content_scopes: &mut Scopes::new(),
+        extender: &mut extender,
         options,
         modules: &mut Modules::default(),
         module_config: &mut ModuleConfig::default(),
+        forwarded_scope: &mut Scope::new(),
+        call_stack: &mut Vec::new(),
+        unique_id_count: &mut 0,
+        loaded_files,
+        emitted_warnings: &mut HashSet::new(),
+        deadline: options.timeout.map(|timeout| Instant::now() + timeout),
     }
-    .parse()
-    .map_err(|e| raw_to_parse_error(&map, *e, options.unicode_error_messages))?;
+    .parse_with_config(config)
+    .map_err(|e| raw_to_parse_error(map, *e, options))?;
+
+    extender
+        .check_unsatisfied_extensions()
+        .map_err(|e| raw_to_parse_error(map, *e, options))?;
+
+    extender
+        .apply_compound_extensions()
+        .map_err(|e| raw_to_parse_error(map, *e, options))?;
 
     Css::from_stmts(stmts, AtRuleContext::None, options.allows_charset)
-        .map_err(|e| raw_to_parse_error(&map, *e, options.unicode_error_messages))?
-        .pretty_print(&map, options.style)
-        .map_err(|e| raw_to_parse_error(&map, *e, options.unicode_error_messages))
+        .map_err(|e| raw_to_parse_error(map, *e, options))?
+        .pretty_print(map, options, stats)
+        .map_err(|e| raw_to_parse_error(map, *e, options))
 }
 
 /// Compile CSS from a path
 ///
-/// n.b. grass does not currently support files or paths that are not valid UTF-8
+/// n.b. grass does not currently support paths that are not valid UTF-8
+///
+/// Both `/` and `\` are accepted as separators in `p` on every platform.
+/// The file itself may be encoded as UTF-8, UTF-16, or `windows-1252`; see
+/// the CSS rules for [determining the encoding of a stylesheet](https://drafts.csswg.org/css-syntax-3/#input-byte-stream).
 ///
 /// ```
 /// fn main() -> Result<(), Box<grass::Error>> {
@@ -338,13 +762,42 @@ fn from_string_with_file_name(input: String, file_name: &str, options: &Options)
 #[cfg_attr(feature = "profiling", inline(never))]
 #[cfg_attr(not(feature = "profiling"), inline)]
 pub fn from_path(p: &str, options: &Options) -> Result<String> {
+    let path = url_to_path(p);
     from_string_with_file_name(
-        String::from_utf8(options.fs.read(Path::new(p))?)?,
-        p,
+        decode_stylesheet(options.fs.read(&path)?)?,
+        &path,
         options,
+        None,
+        &[],
     )
 }
 
+/// Compile CSS from a path, also returning statistics about the output
+///
+/// See [`from_path`] for how `p` is read, and [`Stats`] for what is counted.
+///
+/// ```
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let (css, stats) = grass::from_path_with_stats("input.scss", &grass::Options::default())?;
+///     assert_eq!(stats.bytes(), css.len());
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+pub fn from_path_with_stats(p: &str, options: &Options) -> Result<(String, Stats)> {
+    let path = url_to_path(p);
+    let mut stats = Stats::default();
+    let css = from_string_with_file_name(
+        decode_stylesheet(options.fs.read(&path)?)?,
+        &path,
+        options,
+        Some(&mut stats),
+        &[],
+    )?;
+    Ok((css, stats))
+}
+
 /// Compile CSS from a string
 ///
 /// ```
@@ -357,7 +810,100 @@ pub fn from_path(p: &str, options: &Options) -> Result<String> {
 #[cfg_attr(feature = "profiling", inline(never))]
 #[cfg_attr(not(feature = "profiling"), inline)]
 pub fn from_string(input: String, options: &Options) -> Result<String> {
-    from_string_with_file_name(input, "stdin", options)
+    from_string_with_file_name(input, Path::new("stdin"), options, None, &[])
+}
+
+/// Compile CSS from a string, also returning statistics about the output
+///
+/// The input string is named `stdin` in the returned [`Stats`].
+///
+/// ```
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let (css, stats) = grass::from_string_with_stats(
+///         "a, b { color: red; }".to_string(),
+///         &grass::Options::default(),
+///     )?;
+///     let file = stats.file("stdin").unwrap();
+///     assert_eq!(file.rules(), 1);
+///     assert_eq!(file.selectors(), 2);
+///     assert_eq!(file.declarations(), 1);
+///     assert_eq!(stats.bytes(), css.len());
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+pub fn from_string_with_stats(input: String, options: &Options) -> Result<(String, Stats)> {
+    let mut stats = Stats::default();
+    let css =
+        from_string_with_file_name(input, Path::new("stdin"), options, Some(&mut stats), &[])?;
+    Ok((css, stats))
+}
+
+/// Compile the stylesheet at a path once for each of several themes
+///
+/// Each theme is a list of variable names and SassScript values that
+/// configure the stylesheet's `!default` variables, as though it were loaded
+/// with `@use ... with (...)`. This includes `!default` variables in files
+/// that it `@import`s. Returns the CSS for each theme, in order.
+///
+/// The stylesheet itself is evaluated once per theme, as is every module that
+/// emits CSS, is configured by the theme directly or through `@forward`, or
+/// uses a module that is.
+/// Other modules, such as those that only define variables, functions and
+/// mixins, are parsed and evaluated once and shared by every theme. Each file
+/// is read from [`Options::fs`] once however many themes there are. See
+/// [`from_path`] for how `p` is read.
+///
+/// ```no_run
+/// // themes.scss: `$primary: black !default; a { color: $primary; }`
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let themes = grass::compile_themes(
+///         "themes.scss",
+///         &[&[("primary", "red")], &[("primary", "#00f")]],
+///         &grass::Options::default(),
+///     )?;
+///     assert_eq!(themes, ["a {\n  color: red;\n}\n", "a {\n  color: #00f;\n}\n"]);
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "profiling", inline(never))]
+#[cfg_attr(not(feature = "profiling"), inline)]
+pub fn compile_themes(
+    p: &str,
+    themes: &[&[(&str, &str)]],
+    options: &Options,
+) -> Result<Vec<String>> {
+    let fs = CachedFs::new(options.fs);
+    let options = Options {
+        fs: &fs,
+        load_paths: options.load_paths.clone(),
+        importers: options.importers.clone(),
+        ..*options
+    };
+
+    let path = url_to_path(p);
+    let input = decode_stylesheet(options.fs.read(&path)?)?;
+
+    let mut map = CodeMap::new();
+    let file = map.add_file(path_to_url(&path), input);
+    let mut loaded_files = LoadedFiles::default();
+
+    themes
+        .iter()
+        .map(|config| {
+            loaded_files.next_compilation();
+            compile_file(
+                &mut map,
+                &file,
+                &path,
+                &options,
+                None,
+                config,
+                &mut loaded_files,
+            )
+        })
+        .collect()
 }
 
 #[cfg(feature = "wasm-exports")]
@@ -0,0 +1,87 @@
+/// A trait to allow capturing the messages Sass prints while compiling, such
+/// as warnings and the output of `@debug` rules, rather than writing them to
+/// stderr.
+///
+/// Like [`Fs`](crate::Fs), implementations must be `Sync` so that
+/// [`Options`](crate::Options) can be shared between threads.
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use grass::{Logger, Options};
+///
+/// #[derive(Debug, Default)]
+/// struct Collector(Mutex<Vec<String>>);
+///
+/// impl Logger for Collector {
+///     fn debug(&self, file: &str, line: usize, message: &str) {
+///         self.0
+///             .lock()
+///             .unwrap()
+///             .push(format!("{}:{} {}", file, line, message));
+///     }
+///
+///     fn warn(&self, message: &str, _trace: &str) {
+///         self.0.lock().unwrap().push(format!("Warning: {}", message));
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<grass::Error>> {
+///     let collector = Collector::default();
+///     grass::from_string(
+///         "@debug 1 + 1;\n@warn \"deprecated\";".to_owned(),
+///         &Options::default().logger(&collector),
+///     )?;
+///     assert_eq!(*collector.0.lock().unwrap(), vec!["stdin:1 2", "Warning: deprecated"]);
+///     Ok(())
+/// }
+/// ```
+pub trait Logger: std::fmt::Debug + Sync {
+    /// Called with the message of each `@debug` rule, along with the name of
+    /// the file it is in and its line number, counting from 1.
+    fn debug(&self, file: &str, line: usize, message: &str);
+
+    /// Called with each warning, whether from a `@warn` rule or about the use
+    /// of deprecated behavior, and the stack trace of where it was emitted.
+    ///
+    /// `trace` has a line for each mixin and function being evaluated, then
+    /// one for the root stylesheet, each ending in a newline.
+    fn warn(&self, message: &str, trace: &str);
+}
+
+/// Write messages to stderr, as `file:line DEBUG: message` for `@debug`, and
+/// as `Warning: message` followed by the indented stack trace for warnings.
+///
+/// This is the default logger implementation.
+#[derive(Debug)]
+pub struct StdLogger;
+
+impl Logger for StdLogger {
+    #[inline]
+    fn debug(&self, file: &str, line: usize, message: &str) {
+        eprintln!("{}:{} DEBUG: {}", file, line, message);
+    }
+
+    #[inline]
+    fn warn(&self, message: &str, trace: &str) {
+        let mut warning = format!("Warning: {}\n", message);
+        for line in trace.lines() {
+            warning.push_str("    ");
+            warning.push_str(line);
+            warning.push('\n');
+        }
+        eprint!("{}", warning);
+    }
+}
+
+/// A logger implementation that discards every message.
+#[derive(Debug)]
+pub struct NullLogger;
+
+impl Logger for NullLogger {
+    #[inline]
+    fn debug(&self, _file: &str, _line: usize, _message: &str) {}
+
+    #[inline]
+    fn warn(&self, _message: &str, _trace: &str) {}
+}
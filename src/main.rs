@@ -1,13 +1,24 @@
 use std::{
-    fs::OpenOptions,
-    io::{stdin, stdout, BufWriter, Read, Write},
-    path::Path,
+    ffi::OsStr,
+    fs::{self, File},
+    io::{self, stdin, stdout, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    process,
 };
 
 use clap::{arg_enum, App, AppSettings, Arg};
 
+#[cfg(feature = "watch")]
+use std::time::Duration;
+
+#[cfg(feature = "watch")]
+use grass::watch::{Backend, Watcher};
 use grass::{from_path, from_string, Options, OutputStyle};
 
+/// How often `--poll` checks for changes
+#[cfg(feature = "watch")]
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 // TODO remove this
 arg_enum! {
     #[derive(PartialEq, Debug)]
@@ -114,21 +125,27 @@ fn main() -> std::io::Result<()> {
         .arg(
             Arg::with_name("WATCH")
                 .long("watch")
-                .hidden(true)
+                .hidden(!cfg!(feature = "watch"))
                 .help("Watch stylesheets and recompile when they change."),
         )
         .arg(
             Arg::with_name("POLL")
                 .long("poll")
-                .hidden(true)
+                .hidden(!cfg!(feature = "watch"))
                 .help("Manually check for changes rather than using a native watcher. Only valid with --watch.")
                 .requires("WATCH"),
         )
+        .arg(
+            Arg::with_name("STOP_ON_ERROR")
+                .long("stop-on-error")
+                .help("Don't compile more files once an error is encountered.")
+                .overrides_with("NO_STOP_ON_ERROR")
+        )
         .arg(
             Arg::with_name("NO_STOP_ON_ERROR")
                 .long("no-stop-on-error")
-                .hidden(true)
-                .help("Continue to compile more files after error is encountered.")
+                .help("Continue to compile more files after an error is encountered. This is the default.")
+                .overrides_with("STOP_ON_ERROR")
         )
         .arg(
             Arg::with_name("INTERACTIVE")
@@ -137,12 +154,17 @@ fn main() -> std::io::Result<()> {
                 .hidden(true)
                 .help("Run an interactive SassScript shell.")
         )
+        .arg(
+            Arg::with_name("COLOR")
+                .long("color")
+                .help("Use terminal colors for messages.")
+        )
         .arg(
             Arg::with_name("NO_COLOR")
                 .short("c")
                 .long("no-color")
-                .hidden(true)
-                .help("Whether to use terminal colors for messages.")
+                .overrides_with("COLOR")
+                .help("Don't use terminal colors for messages.")
         )
         .arg(
             Arg::with_name("NO_UNICODE")
@@ -156,13 +178,21 @@ fn main() -> std::io::Result<()> {
                 .help("Don't print warnings."),
         )
         .arg(
-            Arg::with_name("INPUT")
-                .required_unless("STDIN")
-                .help("SCSS files"),
+            Arg::with_name("VERBOSE")
+                .long("verbose")
+                .help("Print all warnings, even those that have already been printed."),
         )
         .arg(
-            Arg::with_name("OUTPUT")
-                .help("Output SCSS file")
+            Arg::with_name("FATAL_WARNINGS")
+                .long("fatal-warnings")
+                .help("Treat warnings and deprecations as errors."),
+        )
+        .arg(
+            Arg::with_name("INPUT")
+                .required_unless("STDIN")
+                .multiple(true)
+                .value_name("INPUT [OUTPUT] | SOURCE:DESTINATION...")
+                .help("A SCSS file and the CSS file to write, or any number of SCSS files or directories and where to write them, as SOURCE:DESTINATION"),
         )
 
         // Hidden, legacy arguments
@@ -188,44 +218,218 @@ fn main() -> std::io::Result<()> {
         .load_paths(&load_paths)
         .style(style)
         .quiet(matches.is_present("QUIET"))
+        .verbose(matches.is_present("VERBOSE"))
+        .fatal_warnings(matches.is_present("FATAL_WARNINGS"))
         .unicode_error_messages(!matches.is_present("NO_UNICODE"))
+        .color_error_messages(matches.is_present("COLOR"))
         .allows_charset(!matches.is_present("NO_CHARSET"));
 
-    let (mut stdout_write, mut file_write);
-    let buf_out: &mut dyn Write = if let Some(path) = matches.value_of("OUTPUT") {
-        file_write = BufWriter::new(
-            OpenOptions::new()
-                .create(true)
-                .write(true)
-                .truncate(true)
-                .open(path)?,
-        );
-        &mut file_write
-    } else {
-        stdout_write = BufWriter::new(stdout());
-        &mut stdout_write
-    };
+    let inputs: Vec<&str> = matches
+        .values_of("INPUT")
+        .map_or_else(Vec::new, Iterator::collect);
 
-    buf_out.write_all(
-        if let Some(name) = matches.value_of("INPUT") {
-            from_path(name, options)
-        } else if matches.is_present("STDIN") {
-            from_string(
-                {
-                    let mut buffer = String::new();
-                    stdin().read_to_string(&mut buffer)?;
-                    buffer
-                },
-                options,
-            )
+    let compilations = compilations(&inputs, matches.is_present("STDIN")).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(64)
+    });
+
+    #[cfg(feature = "watch")]
+    if matches.is_present("WATCH") {
+        let (name, output) = match compilations.as_slice() {
+            [Compilation {
+                input: Some(input),
+                output,
+            }] => (input, output.as_deref()),
+            [Compilation { input: None, .. }] => {
+                eprintln!("--watch is not allowed when reading from stdin.");
+                process::exit(64)
+            }
+            _ => {
+                eprintln!("--watch only supports compiling a single stylesheet.");
+                process::exit(64)
+            }
+        };
+
+        let backend = if matches.is_present("POLL") {
+            Backend::Poll(POLL_INTERVAL)
         } else {
-            unreachable!()
-        }
-        .unwrap_or_else(|e| {
+            Backend::Native
+        };
+
+        return Watcher::new(name).backend(backend).run(options, |result| {
+            if let Err(e) = result
+                .map_err(|e| e.to_string())
+                .and_then(|css| write_css(output, &css).map_err(|e| e.to_string()))
+            {
+                eprintln!("{}", e);
+            }
+
+            true
+        });
+    }
+
+    let stop_on_error = matches.is_present("STOP_ON_ERROR");
+    let mut failed = false;
+
+    for Compilation { input, output } in &compilations {
+        let result = match input {
+            Some(path) => from_path(&path.to_string_lossy(), options),
+            None => {
+                let mut buffer = String::new();
+                stdin().read_to_string(&mut buffer)?;
+                from_string(buffer, options)
+            }
+        };
+
+        let written = result
+            .map_err(|e| e.to_string())
+            .and_then(|css| write_css(output.as_deref(), &css).map_err(|e| e.to_string()));
+
+        if let Err(e) = written {
             eprintln!("{}", e);
-            std::process::exit(1)
-        })
-        .as_bytes(),
-    )?;
+            failed = true;
+
+            if stop_on_error {
+                break;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1)
+    }
+
+    Ok(())
+}
+
+/// A stylesheet to compile, and where to write its CSS
+#[derive(Debug)]
+struct Compilation {
+    /// The stylesheet, or `None` to read it from stdin
+    input: Option<PathBuf>,
+
+    /// The file to write, or `None` to write to stdout
+    output: Option<PathBuf>,
+}
+
+/// The stylesheets to compile for the positional arguments, which are either
+/// an input and an optional output file, or any number of
+/// `SOURCE:DESTINATION` pairs of files or directories
+fn compilations(args: &[&str], stdin: bool) -> Result<Vec<Compilation>, String> {
+    if stdin {
+        return match args {
+            [] => Ok(vec![Compilation {
+                input: None,
+                output: None,
+            }]),
+            [output] if split_pair(output).is_none() => Ok(vec![Compilation {
+                input: None,
+                output: Some(PathBuf::from(output)),
+            }]),
+            _ => Err("Only one output file may be passed when reading from stdin.".to_owned()),
+        };
+    }
+
+    if !args.iter().any(|arg| split_pair(arg).is_some()) {
+        return match args {
+            [input] => Ok(vec![Compilation {
+                input: Some(PathBuf::from(input)),
+                output: None,
+            }]),
+            [input, output] => Ok(vec![Compilation {
+                input: Some(PathBuf::from(input)),
+                output: Some(PathBuf::from(output)),
+            }]),
+            _ => Err(
+                "Only two positional arguments may be passed. To compile several stylesheets, pass them as SOURCE:DESTINATION."
+                    .to_owned(),
+            ),
+        };
+    }
+
+    let mut compilations = Vec::new();
+
+    for arg in args {
+        let (source, destination) = split_pair(arg).ok_or_else(|| {
+            "Positional and colon-separated arguments may not both be passed.".to_owned()
+        })?;
+
+        let source = Path::new(source);
+
+        if source.is_dir() {
+            add_directory(source, Path::new(destination), &mut compilations)
+                .map_err(|e| format!("{}: {}", source.display(), e))?;
+        } else {
+            compilations.push(Compilation {
+                input: Some(source.to_path_buf()),
+                output: Some(PathBuf::from(destination)),
+            });
+        }
+    }
+
+    Ok(compilations)
+}
+
+/// Splits a `SOURCE:DESTINATION` argument, ignoring the colon of a Windows
+/// drive letter such as `C:\`
+fn split_pair(arg: &str) -> Option<(&str, &str)> {
+    let bytes = arg.as_bytes();
+
+    let is_drive_letter = |i: usize| {
+        i == 1 && bytes[0].is_ascii_alphabetic() && matches!(bytes.get(2), Some(b'\\') | Some(b'/'))
+    };
+
+    let colon = (0..bytes.len()).find(|&i| bytes[i] == b':' && !is_drive_letter(i))?;
+
+    Some((&arg[..colon], &arg[colon + 1..]))
+}
+
+/// Adds every SCSS file in `source` and its subdirectories other than
+/// partials, to be written to the same relative path in `destination`
+fn add_directory(
+    source: &Path,
+    destination: &Path,
+    compilations: &mut Vec<Compilation>,
+) -> io::Result<()> {
+    let mut entries = fs::read_dir(source)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+
+    for entry in entries {
+        let path = entry.path();
+        let name = entry.file_name();
+
+        if path.is_dir() {
+            add_directory(&path, &destination.join(&name), compilations)?;
+        } else if !name.to_string_lossy().starts_with('_')
+            && path.extension() == Some(OsStr::new("scss"))
+        {
+            compilations.push(Compilation {
+                input: Some(path),
+                output: Some(destination.join(name).with_extension("css")),
+            });
+        }
+    }
+
     Ok(())
 }
+
+/// Writes `css` to `output`, creating its parent directories, or to stdout if
+/// it is `None`
+fn write_css(output: Option<&Path>, css: &str) -> io::Result<()> {
+    match output {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = BufWriter::new(File::create(path)?);
+            file.write_all(css.as_bytes())?;
+            file.flush()
+        }
+        None => {
+            let mut out = BufWriter::new(stdout());
+            out.write_all(css.as_bytes())?;
+            out.flush()
+        }
+    }
+}
@@ -0,0 +1,511 @@
+//! Sass numbers and their units, for code that computes values to pass into
+//! or read out of a compilation.
+//!
+//! These use the same exact arithmetic and unit conversions as the compiler
+//! itself, so a value computed here matches what Sass would compute.
+//! Only numbers with at most a single unit are supported: operations whose
+//! result would need a unit such as `px*px` or `em/px` return `None`.
+
+use std::{cmp::Ordering, convert::TryFrom, fmt, ops::Neg, str::FromStr};
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{pow, Zero};
+
+use crate::{unit, value};
+
+macro_rules! units {
+    ($($(#[$attr:meta])* $name:ident,)*) => {
+        /// The unit of a [`Number`]
+        ///
+        /// Units are parsed case-insensitively with [`str::parse`], so
+        /// `"PX".parse::<Unit>()` is [`Unit::Px`]. Any other name is an
+        /// [`Unit::Unknown`] unit, which is only comparable to itself.
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum Unit {
+            $($(#[$attr])* $name,)*
+            /// Any other unit, e.g. `foo` in `1foo`
+            Unknown(String),
+            /// No unit
+            None,
+        }
+
+        impl Unit {
+            fn to_internal(&self) -> unit::Unit {
+                match self {
+                    $(Unit::$name => unit::Unit::$name,)*
+                    Unit::Unknown(name) => unit::Unit::from(name.clone()),
+                    Unit::None => unit::Unit::None,
+                }
+            }
+
+            fn from_internal(unit: unit::Unit) -> Self {
+                match unit {
+                    $(unit::Unit::$name => Unit::$name,)*
+                    unit::Unit::None => Unit::None,
+                    unit => Unit::Unknown(unit.to_string()),
+                }
+            }
+        }
+    };
+}
+
+units! {
+    /// Pixels
+    Px,
+    /// Millimeters
+    Mm,
+    /// Inches
+    In,
+    /// Centimeters
+    Cm,
+    /// Quarter-millimeters
+    Q,
+    /// Points
+    Pt,
+    /// Picas
+    Pc,
+    /// Font size of the parent element
+    Em,
+    /// Font size of the root element
+    Rem,
+    /// Line height of the element
+    Lh,
+    /// x-height of the element's font
+    Ex,
+    /// Width of the glyph "0" of the element's font
+    Ch,
+    /// Height of capital letters of the element's font
+    Cap,
+    /// Width of the glyph "水" of the element's font
+    Ic,
+    /// Line height of the root element
+    Rlh,
+    /// 1% of the viewport's width
+    Vw,
+    /// 1% of the viewport's height
+    Vh,
+    /// 1% of the viewport's smaller dimension
+    Vmin,
+    /// 1% of the viewport's larger dimension
+    Vmax,
+    /// 1% of the viewport's size in the inline direction
+    Vi,
+    /// 1% of the viewport's size in the block direction
+    Vb,
+    /// Degrees
+    Deg,
+    /// Gradians
+    Grad,
+    /// Radians
+    Rad,
+    /// Turns
+    Turn,
+    /// Seconds
+    S,
+    /// Milliseconds
+    Ms,
+    /// Hertz
+    Hz,
+    /// Kilohertz
+    Khz,
+    /// Dots per inch
+    Dpi,
+    /// Dots per centimeter
+    Dpcm,
+    /// Dots per `px` unit
+    Dppx,
+    /// Alias for `dppx`
+    X,
+    /// Fraction of the available space in a grid container
+    Fr,
+    /// Percent
+    Percent,
+}
+
+impl Unit {
+    /// Whether numbers with these units can be added, subtracted, and
+    /// compared, e.g. `px` and `in`
+    ///
+    /// Numbers without a unit are comparable to all others.
+    #[must_use]
+    #[inline]
+    pub fn is_comparable_to(&self, other: &Unit) -> bool {
+        self.to_internal().comparable(&other.to_internal())
+    }
+}
+
+impl FromStr for Unit {
+    type Err = std::convert::Infallible;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "" => Unit::None,
+            "%" => Unit::Percent,
+            _ => Unit::from_internal(unit::Unit::from(s.to_owned())),
+        })
+    }
+}
+
+impl fmt::Display for Unit {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Unknown(name) => f.write_str(name),
+            unit => write!(f, "{}", unit.to_internal()),
+        }
+    }
+}
+
+/// A Sass number, with an optional unit
+///
+/// Numbers are written the way Sass writes them in expanded output, so they
+/// may be passed back into a compilation, e.g. as the value of a theme
+/// variable for [`compile_themes`](crate::compile_themes).
+///
+/// ```
+/// use grass::{Number, Unit};
+///
+/// let gap = Number::new(12.0, Unit::Px);
+/// let inches = gap.convert(&Unit::In).unwrap();
+/// assert_eq!(inches.to_string(), "0.125in");
+///
+/// let total = gap.checked_add(&"0.5in".parse().unwrap()).unwrap();
+/// assert_eq!(total.to_string(), "60px");
+/// assert_eq!(total.checked_div(&Number::unitless(4.0)).unwrap().to_string(), "15px");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Number {
+    num: value::Number,
+    unit: Unit,
+}
+
+impl Number {
+    /// A number with the given value and unit
+    ///
+    /// # Panics
+    ///
+    /// If `value` is infinite or `NaN`. Use [`Number::try_new`] for values
+    /// that are computed at runtime.
+    #[must_use]
+    #[inline]
+    pub fn new(value: f64, unit: Unit) -> Self {
+        Self::try_new(value, unit).expect("Sass numbers must be finite")
+    }
+
+    /// A number with the given value and unit, or `None` if `value` is
+    /// infinite or `NaN`
+    #[must_use]
+    #[inline]
+    pub fn try_new(value: f64, unit: Unit) -> Option<Self> {
+        Some(Self {
+            num: value::Number::new_big(BigRational::from_float(value)?),
+            unit,
+        })
+    }
+
+    /// A number without a unit
+    ///
+    /// # Panics
+    ///
+    /// If `value` is infinite or `NaN`. Use [`Number::try_unitless`] for
+    /// values that are computed at runtime.
+    #[must_use]
+    #[inline]
+    pub fn unitless(value: f64) -> Self {
+        Self::new(value, Unit::None)
+    }
+
+    /// A number without a unit, or `None` if `value` is infinite or `NaN`
+    #[must_use]
+    #[inline]
+    pub fn try_unitless(value: f64) -> Option<Self> {
+        Self::try_new(value, Unit::None)
+    }
+
+    /// The value of this number, ignoring its unit
+    #[must_use]
+    #[inline]
+    pub fn value(&self) -> f64 {
+        self.num.clone().as_float().unwrap_or(f64::NAN)
+    }
+
+    /// The unit of this number
+    #[must_use]
+    #[inline]
+    pub const fn unit(&self) -> &Unit {
+        &self.unit
+    }
+
+    /// This number in the given unit, or `None` if the units aren't
+    /// comparable
+    ///
+    /// A number without a unit is given the new unit unchanged, and
+    /// converting to [`Unit::None`] removes the unit.
+    #[must_use]
+    #[inline]
+    pub fn convert(&self, unit: &Unit) -> Option<Number> {
+        let from = self.unit.to_internal();
+        let to = unit.to_internal();
+
+        if !from.comparable(&to) {
+            return None;
+        }
+
+        let num = if from == to || from == unit::Unit::None || to == unit::Unit::None {
+            self.num.clone()
+        } else {
+            self.num.clone().convert(&from, &to)
+        };
+
+        Some(Number {
+            num,
+            unit: unit.clone(),
+        })
+    }
+
+    /// The other number converted to the unit of this one, or to its own unit
+    /// if this number has none
+    fn coerce(&self, other: &Number) -> Option<(value::Number, Unit)> {
+        if self.unit == Unit::None {
+            return Some((other.num.clone(), other.unit.clone()));
+        }
+
+        other
+            .convert(&self.unit)
+            .map(|other| (other.num, self.unit.clone()))
+    }
+
+    /// The sum of two numbers, in the unit of this one, or `None` if their
+    /// units aren't comparable
+    #[must_use]
+    #[inline]
+    pub fn checked_add(&self, other: &Number) -> Option<Number> {
+        let (other, unit) = self.coerce(other)?;
+
+        Some(Number {
+            num: self.num.clone() + other,
+            unit,
+        })
+    }
+
+    /// The difference of two numbers, in the unit of this one, or `None` if
+    /// their units aren't comparable
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(&self, other: &Number) -> Option<Number> {
+        let (other, unit) = self.coerce(other)?;
+
+        Some(Number {
+            num: self.num.clone() - other,
+            unit,
+        })
+    }
+
+    /// The product of two numbers, or `None` if both have a unit
+    #[must_use]
+    #[inline]
+    pub fn checked_mul(&self, other: &Number) -> Option<Number> {
+        let unit = match (&self.unit, &other.unit) {
+            (Unit::None, unit) | (unit, Unit::None) => unit.clone(),
+            _ => return None,
+        };
+
+        Some(Number {
+            num: self.num.clone() * other.num.clone(),
+            unit,
+        })
+    }
+
+    /// The quotient of two numbers, or `None` if `other` is zero or the
+    /// result would have a compound unit such as `em/px`
+    ///
+    /// Dividing numbers with comparable units gives a number without a unit.
+    #[must_use]
+    #[inline]
+    pub fn checked_div(&self, other: &Number) -> Option<Number> {
+        if other.num.is_zero() {
+            return None;
+        }
+
+        let (other, unit) = match (&self.unit, &other.unit) {
+            (unit, Unit::None) => (other.num.clone(), unit.clone()),
+            (Unit::None, _) => return None,
+            _ => (other.convert(&self.unit)?.num, Unit::None),
+        };
+
+        Some(Number {
+            num: self.num.clone() / other,
+            unit,
+        })
+    }
+}
+
+impl Neg for Number {
+    type Output = Number;
+
+    #[inline]
+    fn neg(self) -> Number {
+        Number {
+            num: -self.num,
+            unit: self.unit,
+        }
+    }
+}
+
+/// Numbers are equal if they are equal once converted to the same unit, so
+/// `1in == 96px`, but as in Sass a number without a unit is never equal to
+/// one with a unit, so `1 != 1px`
+impl PartialEq for Number {
+    #[inline]
+    fn eq(&self, other: &Number) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Numbers are only ordered if their units are comparable and either both
+/// or neither of them have a unit
+impl PartialOrd for Number {
+    #[inline]
+    fn partial_cmp(&self, other: &Number) -> Option<Ordering> {
+        if (self.unit == Unit::None) != (other.unit == Unit::None) {
+            return None;
+        }
+
+        let (other, _) = self.coerce(other)?;
+        Some(self.num.cmp(&other))
+    }
+}
+
+impl fmt::Display for Number {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.num.to_string(false), self.unit)
+    }
+}
+
+/// The error returned when parsing a [`Number`] fails
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseNumberError;
+
+impl fmt::Display for ParseNumberError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Expected a number.")
+    }
+}
+
+impl std::error::Error for ParseNumberError {}
+
+/// Parses a number as written in Sass, e.g. `1.5rem`, `-.5`, `1e3px`, or
+/// `50%`, without rounding its value
+///
+/// As in Sass source, exponents may have at most two digits.
+impl FromStr for Number {
+    type Err = ParseNumberError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let (is_negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let whole_len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (whole, s) = s.split_at(whole_len);
+
+        let (fraction, s) = match s.strip_prefix('.') {
+            Some(s) => {
+                let len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+                if len == 0 {
+                    return Err(ParseNumberError);
+                }
+                s.split_at(len)
+            }
+            None => ("", s),
+        };
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(ParseNumberError);
+        }
+
+        let (exponent, unit) = parse_exponent(s)?;
+
+        if !is_unit(unit) {
+            return Err(ParseNumberError);
+        }
+
+        let digits: BigInt = format!("0{}{}", whole, fraction)
+            .parse()
+            .map_err(|_| ParseNumberError)?;
+
+        let exponent = exponent - fraction.len() as i64;
+        let scale = pow(
+            BigInt::from(10),
+            usize::try_from(exponent.abs()).map_err(|_| ParseNumberError)?,
+        );
+
+        let mut num = if exponent < 0 {
+            BigRational::new(digits, scale)
+        } else {
+            BigRational::from_integer(digits * scale)
+        };
+
+        if is_negative {
+            num = -num;
+        }
+
+        Ok(Number {
+            num: value::Number::new_big(num),
+            unit: unit.parse().map_err(|_| ParseNumberError)?,
+        })
+    }
+}
+
+/// Splits off the exponent of scientific notation, e.g. `e-3` in `1e-3px`
+fn parse_exponent(s: &str) -> Result<(i64, &str), ParseNumberError> {
+    let rest = match s.strip_prefix(|c| c == 'e' || c == 'E') {
+        Some(rest) => rest,
+        None => return Ok((0, s)),
+    };
+
+    let (sign, digits) = match rest.strip_prefix('-') {
+        Some(digits) => (-1, digits),
+        None => (1, rest.strip_prefix('+').unwrap_or(rest)),
+    };
+
+    let len = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+
+    // `1em` is a number with a unit, not an exponent
+    if len == 0 {
+        return Ok((0, s));
+    }
+
+    // the same limit as Sass source, where longer exponents are "too large"
+    // or "too negative"
+    if len > 2 {
+        return Err(ParseNumberError);
+    }
+
+    let exponent: i64 = digits[..len].parse().map_err(|_| ParseNumberError)?;
+
+    Ok((sign * exponent, &digits[len..]))
+}
+
+fn is_unit(unit: &str) -> bool {
+    unit.is_empty()
+        || unit == "%"
+        || (unit
+            .chars()
+            .next()
+            .map_or(false, |c| c.is_alphabetic() || c == '_')
+            && unit
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '-'))
+}
@@ -1,7 +1,9 @@
 //! # Convert from SCSS AST to CSS
 use std::{io::Write, mem};
 
-use codemap::CodeMap;
+use codemap::{CodeMap, Span, Spanned};
+
+use num_traits::Zero;
 
 use crate::{
     atrule::{
@@ -9,11 +11,15 @@ use crate::{
         media::MediaRule,
         SupportsRule, UnknownAtRule,
     },
+    common::QuoteKind,
     error::SassResult,
+    interner::InternedString,
     parse::Stmt,
     selector::{ComplexSelector, ComplexSelectorComponent, Selector},
     style::Style,
-    OutputStyle,
+    unit::Unit,
+    value::Value,
+    Cow, DeclarationHook, Options, OutputStyle, Stats,
 };
 
 #[derive(Debug, Clone)]
@@ -65,7 +71,7 @@ impl Toplevel {
     pub fn is_invisible(&self) -> bool {
         match self {
             Toplevel::RuleSet { selector, body, .. } => selector.is_empty() || body.is_empty(),
-            Toplevel::Media { body, .. } => body.is_empty(),
+            Toplevel::Media { body, .. } => body.iter().all(is_invisible_stmt),
             Toplevel::Empty => true,
             _ => false,
         }
@@ -90,6 +96,19 @@ impl Toplevel {
     }
 }
 
+/// Whether `stmt` produces no output, such as a style rule containing only
+/// `@extend`s or a rule whose selector is made up entirely of placeholders
+fn is_invisible_stmt(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::RuleSet { selector, body } => {
+            selector.as_selector_list().is_invisible() || body.iter().all(is_invisible_stmt)
+        }
+        Stmt::Media(m) => m.body.iter().all(is_invisible_stmt),
+        Stmt::AtRoot { body, .. } => body.iter().all(is_invisible_stmt),
+        _ => false,
+    }
+}
+
 fn set_group_end(group: &mut [Toplevel]) {
     match group.last_mut() {
         Some(Toplevel::RuleSet { is_group_end, .. })
@@ -110,6 +129,12 @@ enum BlockEntry {
 }
 
 impl BlockEntry {
+    /// Whether this entry is left out of compressed output, as all comments
+    /// other than `/*! ... */` are
+    fn is_invisible_when_compressed(&self) -> bool {
+        matches!(self, BlockEntry::MultilineComment(s) if !is_preserved_comment(s))
+    }
+
     pub fn to_string(&self) -> SassResult<String> {
         match self {
             BlockEntry::Style(s) => s.to_string(),
@@ -259,7 +284,7 @@ impl Css {
                             }
                         }
                         Stmt::Return(..) => unreachable!(),
-                        Stmt::AtRoot { body } => {
+                        Stmt::AtRoot { body, .. } => {
                             body.into_iter().try_for_each(|r| -> SassResult<()> {
                                 let mut stmts = self.parse_stmt(r)?;
 
@@ -327,7 +352,7 @@ impl Css {
                 }))]
             }
             Stmt::Return(..) => unreachable!("@return: {:?}", stmt),
-            Stmt::AtRoot { body } => body
+            Stmt::AtRoot { body, .. } => body
                 .into_iter()
                 .map(|r| self.parse_stmt(r))
                 .collect::<SassResult<Vec<Vec<Toplevel>>>>()?
@@ -369,15 +394,84 @@ impl Css {
         Ok(self)
     }
 
-    pub fn pretty_print(self, map: &CodeMap, style: OutputStyle) -> SassResult<String> {
+    /// Replaces each declaration with those returned by `hook`
+    fn apply_declaration_hook(
+        mut self,
+        hook: Option<&dyn DeclarationHook>,
+        is_compressed: bool,
+    ) -> SassResult<Self> {
+        let hook = match hook {
+            Some(hook) => hook,
+            None => return Ok(self),
+        };
+
+        let mut blocks = Vec::with_capacity(self.blocks.len());
+
+        for block in self.blocks {
+            match block {
+                Toplevel::RuleSet {
+                    selector,
+                    body,
+                    is_group_end,
+                } => {
+                    let context = selector.to_string();
+                    blocks.push(Toplevel::RuleSet {
+                        body: hook_block(hook, &context, body, is_compressed)?,
+                        selector,
+                        is_group_end,
+                    });
+                }
+                Toplevel::KeyframesRuleSet(selector, body) => {
+                    let context = selector
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    blocks.push(Toplevel::KeyframesRuleSet(
+                        selector,
+                        hook_block(hook, &context, body, is_compressed)?,
+                    ));
+                }
+                Toplevel::Style(style) => blocks.extend(
+                    hook_style(hook, None, style, is_compressed)?
+                        .into_iter()
+                        .map(Toplevel::Style),
+                ),
+                block => blocks.push(block),
+            }
+        }
+
+        self.blocks = blocks;
+
+        Ok(self)
+    }
+
+    /// Writes out the CSS, recording what was written in `stats` if it's
+    /// present
+    pub fn pretty_print(
+        self,
+        map: &CodeMap,
+        options: &Options,
+        mut stats: Option<&mut Stats>,
+    ) -> SassResult<String> {
         let mut buf = Vec::new();
         let allows_charset = self.allows_charset;
+        let style = options.style;
         match style {
             OutputStyle::Compressed => {
-                CompressedFormatter::default().write_css(&mut buf, self, map)?;
+                CompressedFormatter {
+                    stats: stats.as_deref_mut(),
+                    hook: options.declaration_hook,
+                }
+                .write_css(&mut buf, self, map)?;
             }
             OutputStyle::Expanded => {
-                ExpandedFormatter::default().write_css(&mut buf, self, map)?;
+                ExpandedFormatter {
+                    nesting: 0,
+                    stats: stats.as_deref_mut(),
+                    hook: options.declaration_hook,
+                }
+                .write_css(&mut buf, self, map)?;
 
                 if !buf.is_empty() {
                     writeln!(buf)?;
@@ -388,14 +482,165 @@ impl Css {
         // TODO: check for this before writing
         let show_charset = allows_charset && buf.iter().any(|s| !s.is_ascii());
         let out = unsafe { String::from_utf8_unchecked(buf) };
-        Ok(if show_charset {
+        let out = if show_charset {
             match style {
                 OutputStyle::Compressed => format!("\u{FEFF}{}", out),
                 OutputStyle::Expanded => format!("@charset \"UTF-8\";\n{}", out),
             }
         } else {
             out
+        };
+
+        if let Some(stats) = stats {
+            stats.set_bytes(out.len());
+        }
+
+        Ok(out)
+    }
+}
+
+/// Replaces `style` with the declarations returned by `hook`
+fn hook_style(
+    hook: &dyn DeclarationHook,
+    selector: Option<&str>,
+    style: Style,
+    is_compressed: bool,
+) -> SassResult<Vec<Style>> {
+    let span = style.value.span;
+    let value = if is_compressed {
+        compressed_value(&style)?
+    } else {
+        style.value.node.to_css_string(span, false)?
+    };
+
+    Ok(hook
+        .declaration(selector, &style.property.to_string(), &value)
+        .into_iter()
+        .map(|(property, value)| Style {
+            property: InternedString::get_or_intern(property),
+            value: Box::new(Spanned {
+                node: Value::String(value, QuoteKind::None),
+                span,
+            }),
+        })
+        .collect())
+}
+
+/// Replaces each declaration in the body of a style rule with the
+/// declarations returned by `hook`
+fn hook_block(
+    hook: &dyn DeclarationHook,
+    selector: &str,
+    body: Vec<BlockEntry>,
+    is_compressed: bool,
+) -> SassResult<Vec<BlockEntry>> {
+    let mut entries = Vec::with_capacity(body.len());
+
+    for entry in body {
+        match entry {
+            BlockEntry::Style(style) => entries.extend(
+                hook_style(hook, Some(selector), style, is_compressed)?
+                    .into_iter()
+                    .map(BlockEntry::Style),
+            ),
+            entry => entries.push(entry),
+        }
+    }
+
+    Ok(entries)
+}
+
+/// The value of `style` as it's written in compressed output, where zero
+/// lengths are written without their unit when that doesn't change their
+/// meaning
+fn compressed_value(style: &Style) -> SassResult<Cow<'static, str>> {
+    let value = &style.value.node;
+    let span = style.value.span;
+
+    // a unitless `0` is a flex factor rather than a basis in `flex`, and custom
+    // properties may be used in `calc()`, where `0` isn't a length
+    let property = style.property.resolve_ref();
+    if property == "flex" || property == "flex-basis" || property.starts_with("--") {
+        return value.to_css_string(span, true);
+    }
+
+    if has_zero_length(value) {
+        strip_zero_length_units(value.clone()).to_css_string(span, true)
+    } else {
+        value.to_css_string(span, true)
+    }
+}
+
+fn is_zero_length(value: &Value) -> bool {
+    matches!(value, Value::Dimension(Some(n), unit, _) if n.is_zero() && unit.is_length())
+}
+
+fn has_zero_length(value: &Value) -> bool {
+    match value {
+        Value::List(elems, ..) => elems.iter().any(has_zero_length),
+        value => is_zero_length(value),
+    }
+}
+
+fn strip_zero_length_units(value: Value) -> Value {
+    match value {
+        Value::Dimension(n, _, should_divide) if is_zero_length(&value) => {
+            Value::Dimension(n, Unit::None, should_divide)
+        }
+        Value::List(elems, sep, brackets) => Value::List(
+            elems.into_iter().map(strip_zero_length_units).collect(),
+            sep,
+            brackets,
+        ),
+        value => value,
+    }
+}
+
+/// Records a style rule that took up `bytes` bytes of output, along with
+/// its declarations
+///
+/// The rule is attributed to the file containing `span`, if there is one,
+/// or otherwise to the file of its first declaration.
+fn record_rule(
+    stats: Option<&mut Stats>,
+    map: &CodeMap,
+    span: Option<Span>,
+    selectors: usize,
+    body: &[BlockEntry],
+    bytes: usize,
+) {
+    let stats = match stats {
+        Some(stats) => stats,
+        None => return,
+    };
+
+    let span = span.or_else(|| {
+        body.iter().find_map(|entry| match entry {
+            BlockEntry::Style(style) => Some(style.value.span),
+            _ => None,
         })
+    });
+
+    if let Some(span) = span {
+        stats
+            .file_mut(map.look_up_span(span).file.name())
+            .add_rule(selectors, bytes);
+    }
+
+    for entry in body {
+        if let BlockEntry::Style(style) = entry {
+            record_declaration(Some(&mut *stats), map, style, 0);
+        }
+    }
+}
+
+/// Records a declaration that took up `bytes` bytes of output outside of
+/// any style rule
+fn record_declaration(stats: Option<&mut Stats>, map: &CodeMap, style: &Style, bytes: usize) {
+    if let Some(stats) = stats {
+        stats
+            .file_mut(map.look_up_span(style.value.span).file.name())
+            .add_declaration(bytes);
     }
 }
 
@@ -404,56 +649,102 @@ trait Formatter {
 }
 
 #[derive(Debug, Default)]
-struct CompressedFormatter {}
+struct CompressedFormatter<'a> {
+    stats: Option<&'a mut Stats>,
+    hook: Option<&'a dyn DeclarationHook>,
+}
 
-impl Formatter for CompressedFormatter {
+impl Formatter for CompressedFormatter<'_> {
     fn write_css(&mut self, buf: &mut Vec<u8>, css: Css, map: &CodeMap) -> SassResult<()> {
+        let css = css.apply_declaration_hook(self.hook, true)?;
+
+        // whether the previous child must be separated from the next one by a
+        // semicolon, which is omitted after the last child
+        let mut requires_semicolon = false;
+
         for block in css.blocks {
             match block {
                 Toplevel::RuleSet { selector, body, .. } => {
-                    if body.is_empty() {
+                    if body.iter().all(BlockEntry::is_invisible_when_compressed) {
                         continue;
                     }
 
+                    write_separator(buf, &mut requires_semicolon);
+
+                    let start = buf.len();
+                    let mut selectors = 0;
+
                     let mut complexes = selector.0.components.iter().filter(|c| !c.is_invisible());
                     if let Some(complex) = complexes.next() {
                         self.write_complex(buf, complex)?;
+                        selectors += 1;
                     }
                     for complex in complexes {
                         write!(buf, ",")?;
                         self.write_complex(buf, complex)?;
+                        selectors += 1;
                     }
 
                     write!(buf, "{{")?;
                     self.write_block_entry(buf, &body)?;
                     write!(buf, "}}")?;
+
+                    record_rule(
+                        self.stats.as_deref_mut(),
+                        map,
+                        Some(selector.0.span),
+                        selectors,
+                        &body,
+                        buf.len() - start,
+                    );
                 }
                 Toplevel::KeyframesRuleSet(selectors, styles) => {
-                    if styles.is_empty() {
+                    if styles.iter().all(BlockEntry::is_invisible_when_compressed) {
                         continue;
                     }
 
-                    let mut selectors = selectors.iter();
-                    if let Some(selector) = selectors.next() {
+                    write_separator(buf, &mut requires_semicolon);
+
+                    let start = buf.len();
+
+                    let mut selectors_iter = selectors.iter();
+                    if let Some(selector) = selectors_iter.next() {
                         write!(buf, "{}", selector)?;
                     }
-                    for selector in selectors {
+                    for selector in selectors_iter {
                         write!(buf, ",{}", selector)?;
                     }
 
                     write!(buf, "{{")?;
                     self.write_block_entry(buf, &styles)?;
                     write!(buf, "}}")?;
+
+                    record_rule(
+                        self.stats.as_deref_mut(),
+                        map,
+                        None,
+                        selectors.len(),
+                        &styles,
+                        buf.len() - start,
+                    );
+                }
+                Toplevel::MultilineComment(s) if is_preserved_comment(&s) => {
+                    write_separator(buf, &mut requires_semicolon);
+                    write!(buf, "/*{}*/", s)?;
                 }
                 Toplevel::Empty | Toplevel::MultilineComment(..) => continue,
                 Toplevel::Import(s) => {
-                    write!(buf, "@import {};", s)?;
+                    write_separator(buf, &mut requires_semicolon);
+                    write!(buf, "@import {}", s)?;
+                    requires_semicolon = true;
                 }
                 Toplevel::UnknownAtRule(u) => {
                     let ToplevelUnknownAtRule {
                         params, name, body, ..
                     } = *u;
 
+                    write_separator(buf, &mut requires_semicolon);
+
                     if params.is_empty() {
                         write!(buf, "@{}", name)?;
                     } else {
@@ -461,7 +752,7 @@ impl Formatter for CompressedFormatter {
                     }
 
                     if body.is_empty() {
-                        write!(buf, ";")?;
+                        requires_semicolon = true;
                         continue;
                     }
 
@@ -473,6 +764,8 @@ impl Formatter for CompressedFormatter {
                 Toplevel::Keyframes(k) => {
                     let Keyframes { rule, name, body } = *k;
 
+                    write_separator(buf, &mut requires_semicolon);
+
                     write!(buf, "@{}", rule)?;
 
                     if !name.is_empty() {
@@ -490,6 +783,8 @@ impl Formatter for CompressedFormatter {
                     write!(buf, "}}")?;
                 }
                 Toplevel::Supports { params, body, .. } => {
+                    write_separator(buf, &mut requires_semicolon);
+
                     if params.is_empty() {
                         write!(buf, "@supports")?;
                     } else {
@@ -497,7 +792,7 @@ impl Formatter for CompressedFormatter {
                     }
 
                     if body.is_empty() {
-                        write!(buf, ";")?;
+                        requires_semicolon = true;
                         continue;
                     }
 
@@ -507,18 +802,25 @@ impl Formatter for CompressedFormatter {
                     write!(buf, "}}")?;
                 }
                 Toplevel::Media { query, body, .. } => {
-                    if body.is_empty() {
+                    if body.iter().all(is_invisible_stmt) {
                         continue;
                     }
 
+                    write_separator(buf, &mut requires_semicolon);
+
                     write!(buf, "@media {}{{", query)?;
                     let css = Css::from_stmts(body, AtRuleContext::Media, css.allows_charset)?;
                     self.write_css(buf, css, map)?;
                     write!(buf, "}}")?;
                 }
                 Toplevel::Style(style) => {
-                    let value = style.value.node.to_css_string(style.value.span, true)?;
-                    write!(buf, "{}:{};", style.property, value)?;
+                    write_separator(buf, &mut requires_semicolon);
+
+                    let start = buf.len();
+                    write!(buf, "{}:{}", style.property, compressed_value(&style)?)?;
+                    record_declaration(self.stats.as_deref_mut(), map, &style, buf.len() - start);
+
+                    requires_semicolon = true;
                 }
             }
         }
@@ -526,9 +828,22 @@ impl Formatter for CompressedFormatter {
     }
 }
 
+/// Writes the semicolon that separates the previous child from the next one,
+/// if it needs one
+fn write_separator(buf: &mut Vec<u8>, requires_semicolon: &mut bool) {
+    if mem::take(requires_semicolon) {
+        buf.push(b';');
+    }
+}
+
+/// Whether a comment is kept in compressed output, i.e. is written `/*! ... */`
+fn is_preserved_comment(comment: &str) -> bool {
+    comment.starts_with('!')
+}
+
 // this could be a trait implemented on value itself
 #[allow(clippy::unused_self)]
-impl CompressedFormatter {
+impl CompressedFormatter<'_> {
     fn write_complex(&self, buf: &mut Vec<u8>, complex: &ComplexSelector) -> SassResult<()> {
         let mut was_compound = false;
         for component in &complex.components {
@@ -543,38 +858,41 @@ impl CompressedFormatter {
     }
 
     fn write_block_entry(&self, buf: &mut Vec<u8>, styles: &[BlockEntry]) -> SassResult<()> {
-        let mut styles = styles.iter();
-
-        for style in &mut styles {
-            match style {
-                BlockEntry::Style(s) => {
-                    let value = s.value.node.to_css_string(s.value.span, true)?;
-                    write!(buf, "{}:{}", s.property, value)?;
-                    break;
-                }
-                BlockEntry::MultilineComment(..) => continue,
-                b @ BlockEntry::UnknownAtRule(_) => write!(buf, "{}", b.to_string()?)?,
-            }
-        }
+        let mut requires_semicolon = false;
 
         for style in styles {
             match style {
                 BlockEntry::Style(s) => {
-                    let value = s.value.node.to_css_string(s.value.span, true)?;
-
-                    write!(buf, ";{}:{}", s.property, value)?;
+                    write_separator(buf, &mut requires_semicolon);
+                    write!(buf, "{}:{}", s.property, compressed_value(s)?)?;
+                }
+                BlockEntry::MultilineComment(s) if is_preserved_comment(s) => {
+                    write_separator(buf, &mut requires_semicolon);
+                    write!(buf, "/*{}*/", s)?;
+                    continue;
                 }
                 BlockEntry::MultilineComment(..) => continue,
-                b @ BlockEntry::UnknownAtRule(_) => write!(buf, "{}", b.to_string()?)?,
+                BlockEntry::UnknownAtRule(BlockEntryUnknownAtRule { name, params }) => {
+                    write_separator(buf, &mut requires_semicolon);
+                    if params.is_empty() {
+                        write!(buf, "@{}", name)?;
+                    } else {
+                        write!(buf, "@{} {}", name, params)?;
+                    }
+                }
             }
+
+            requires_semicolon = true;
         }
         Ok(())
     }
 }
 
 #[derive(Debug, Default)]
-struct ExpandedFormatter {
+struct ExpandedFormatter<'a> {
     nesting: usize,
+    stats: Option<&'a mut Stats>,
+    hook: Option<&'a dyn DeclarationHook>,
 }
 
 #[derive(Clone, Copy)]
@@ -592,8 +910,10 @@ pub(crate) enum AtRuleContext {
     None,
 }
 
-impl Formatter for ExpandedFormatter {
+impl Formatter for ExpandedFormatter<'_> {
     fn write_css(&mut self, buf: &mut Vec<u8>, css: Css, map: &CodeMap) -> SassResult<()> {
+        let css = css.apply_declaration_hook(self.hook, false)?;
+
         let padding = "  ".repeat(self.nesting);
         self.nesting += 1;
 
@@ -619,19 +939,40 @@ impl Formatter for ExpandedFormatter {
             match block {
                 Toplevel::Empty => continue,
                 Toplevel::RuleSet { selector, body, .. } => {
+                    let start = buf.len();
+
                     writeln!(buf, "{}{} {{", padding, selector)?;
 
-                    for style in body {
+                    for style in &body {
                         writeln!(buf, "{}  {}", padding, style.to_string()?)?;
                     }
 
                     write!(buf, "{}}}", padding)?;
+
+                    let selectors = selector
+                        .0
+                        .components
+                        .iter()
+                        .filter(|complex| !complex.is_invisible())
+                        .count();
+
+                    record_rule(
+                        self.stats.as_deref_mut(),
+                        map,
+                        Some(selector.0.span),
+                        selectors,
+                        &body,
+                        buf.len() - start,
+                    );
                 }
                 Toplevel::KeyframesRuleSet(selector, body) => {
                     if body.is_empty() {
                         continue;
                     }
 
+                    let start = buf.len();
+                    let selectors = selector.len();
+
                     writeln!(
                         buf,
                         "{}{} {{",
@@ -642,10 +983,19 @@ impl Formatter for ExpandedFormatter {
                             .collect::<Vec<String>>()
                             .join(", ")
                     )?;
-                    for style in body {
+                    for style in &body {
                         writeln!(buf, "{}  {}", padding, style.to_string()?)?;
                     }
                     write!(buf, "{}}}", padding)?;
+
+                    record_rule(
+                        self.stats.as_deref_mut(),
+                        map,
+                        None,
+                        selectors,
+                        &body,
+                        buf.len() - start,
+                    );
                 }
                 Toplevel::MultilineComment(s) => {
                     write!(buf, "{}/*{}*/", padding, s)?;
@@ -766,7 +1116,9 @@ impl Formatter for ExpandedFormatter {
                     write!(buf, "\n{}}}", padding)?;
                 }
                 Toplevel::Style(s) => {
+                    let start = buf.len();
                     write!(buf, "{}{}", padding, s.to_string()?)?;
+                    record_declaration(self.stats.as_deref_mut(), map, &s, buf.len() - start);
                 }
             }
 
@@ -1,9 +1,10 @@
-use std::{collections::HashMap, mem};
+use std::mem;
 
 use codemap::Span;
+use indexmap::IndexMap;
 
 use crate::{
-    args::{CallArg, CallArgs, FuncArg, FuncArgs},
+    args::{CallArg, CallArgs, FuncArg, FuncArgs, RequestedNames},
     common::QuoteKind,
     error::SassResult,
     scope::Scope,
@@ -32,6 +33,9 @@ impl<'a, 'b> Parser<'a, 'b> {
                 }
                 _ => return Err(("expected \")\".", pos).into()),
             };
+            if args.iter().any(|arg| arg.name == name.node.as_str().into()) {
+                return Err(("Duplicate argument.", pos.merge(name.span)).into());
+            }
             let mut default: Vec<Token> = Vec::new();
             let mut is_variadic = false;
             self.whitespace_or_comment();
@@ -97,6 +101,10 @@ impl<'a, 'b> Parser<'a, 'b> {
 
                     self.whitespace_or_comment();
 
+                    if self.consume_char_if_exists(',') {
+                        self.whitespace_or_comment();
+                    }
+
                     self.expect_char(')')?;
 
                     is_variadic = true;
@@ -142,21 +150,32 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
 
     pub(super) fn parse_call_args(&mut self) -> SassResult<CallArgs> {
-        let mut args = HashMap::new();
+        let mut args = IndexMap::new();
         self.whitespace_or_comment();
         let mut name = String::new();
 
+        let open_paren = self.toks.prev();
+
         let mut span = self
             .toks
             .peek()
             .ok_or(("expected \")\".", self.span_before))?
             .pos();
 
+        // the arguments span from the opening parenthesis through the closing one
+        if let Some(Token { kind: '(', pos }) = open_paren {
+            span = pos;
+        }
+
         loop {
             self.whitespace_or_comment();
 
             if self.consume_char_if_exists(')') {
-                return Ok(CallArgs(args, span));
+                return Ok(CallArgs(
+                    args,
+                    self.consumed_span(span),
+                    RequestedNames::default(),
+                ));
             }
 
             if self.consume_char_if_exists(',') {
@@ -218,7 +237,11 @@ impl<'a, 'b> Parser<'a, 'b> {
                         },
                         value,
                     );
-                    return Ok(CallArgs(args, span));
+                    return Ok(CallArgs(
+                        args,
+                        self.consumed_span(span),
+                        RequestedNames::default(),
+                    ));
                 }
                 Some(Token { kind: ',', .. }) => {
                     self.toks.next();
@@ -251,10 +274,18 @@ impl<'a, 'b> Parser<'a, 'b> {
 
                     let val = value?;
                     match val.node {
-                        Value::ArgList(v) => {
+                        Value::ArgList(v, keywords) => {
                             for arg in v {
                                 args.insert(CallArg::Positional(args.len()), Ok(arg));
                             }
+                            for (name, arg) in keywords {
+                                if let Value::String(name, ..) = name {
+                                    args.insert(
+                                        CallArg::Named(name.into()),
+                                        Ok(arg.span(val.span)),
+                                    );
+                                }
+                            }
                         }
                         Value::List(v, ..) => {
                             for arg in v {
@@ -330,7 +361,11 @@ impl<'a, 'b> Parser<'a, 'b> {
                     match self.toks.peek() {
                         Some(Token { kind: ')', .. }) => {
                             self.toks.next();
-                            return Ok(CallArgs(args, span));
+                            return Ok(CallArgs(
+                                args,
+                                self.consumed_span(span),
+                                RequestedNames::default(),
+                            ));
                         }
                         Some(Token { kind: ',', pos }) => {
                             span = span.merge(pos);
@@ -372,19 +407,16 @@ impl<'a, 'b> Parser<'a, 'b> {
         mut args: CallArgs,
     ) -> SassResult<Scope> {
         let mut scope = Scope::new();
+        args.verify(fn_args)?;
         if fn_args.0.is_empty() {
-            args.max_args(0)?;
             return Ok(scope);
         }
 
-        if !fn_args.0.iter().any(|arg| arg.is_variadic) {
-            args.max_args(fn_args.len())?;
-        }
-
         self.scopes.enter_new_scope();
         for (idx, arg) in fn_args.0.iter().enumerate() {
             if arg.is_variadic {
-                let arg_list = Value::ArgList(args.get_variadic()?);
+                let keywords = args.get_keywords()?;
+                let arg_list = Value::ArgList(args.get_variadic()?, keywords);
                 scope.insert_var(arg.name, arg_list);
                 break;
             }
@@ -401,6 +433,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                 },
             }?
             .node;
+            // bound immediately so that the defaults of later parameters can refer to it
             self.scopes.insert_var_last(arg.name, val.clone());
             scope.insert_var(arg.name, val);
         }
@@ -0,0 +1,139 @@
+use std::{collections::HashSet, mem};
+
+use crate::{atrule::AtRootQuery, error::SassResult, Token};
+
+use super::{Parser, Stmt};
+
+/// A statement in the body of an at-rule, or a statement nested inside it
+/// that is written outside of it, such as an `@at-root` rule that excludes it
+pub(super) enum BodyChild {
+    Stmt(Stmt),
+    Escaped(Stmt),
+}
+
+/// Whether `body` contains a statement that `escapes`, either directly or
+/// inside a style rule
+pub(super) fn contains_escaping(body: &[Stmt], escapes: &dyn Fn(&Stmt) -> bool) -> bool {
+    body.iter().any(|stmt| match stmt {
+        Stmt::RuleSet { body, .. } => contains_escaping(body, escapes),
+        stmt => escapes(stmt),
+    })
+}
+
+/// Moves the statements nested in `body` that `escapes` out of any style rules
+/// that contain them, splitting those style rules around them
+pub(super) fn split_escaping(
+    body: Vec<Stmt>,
+    escapes: &dyn Fn(&Stmt) -> bool,
+    children: &mut Vec<BodyChild>,
+) {
+    for stmt in body {
+        match stmt {
+            Stmt::RuleSet { selector, body } if contains_escaping(&body, escapes) => {
+                let mut nested = Vec::new();
+                split_escaping(body, escapes, &mut nested);
+
+                let mut rules = Vec::new();
+
+                for child in nested {
+                    match child {
+                        BodyChild::Stmt(stmt) => rules.push(stmt),
+                        BodyChild::Escaped(stmt) => {
+                            if !rules.is_empty() {
+                                children.push(BodyChild::Stmt(Stmt::RuleSet {
+                                    selector: selector.clone(),
+                                    body: mem::take(&mut rules),
+                                }));
+                            }
+                            children.push(BodyChild::Escaped(stmt));
+                        }
+                    }
+                }
+
+                if !rules.is_empty() {
+                    children.push(BodyChild::Stmt(Stmt::RuleSet {
+                        selector,
+                        body: rules,
+                    }));
+                }
+            }
+            stmt if escapes(&stmt) => children.push(BodyChild::Escaped(stmt)),
+            stmt => children.push(BodyChild::Stmt(stmt)),
+        }
+    }
+}
+
+/// Writes the `@at-root` rules in `body` that exclude an at-rule after it
+/// rather than inside it, with the at-rule built by `at_rule` repeated after
+/// them for the rest of its body
+pub(super) fn hoist_at_root(
+    body: Vec<Stmt>,
+    name: &str,
+    at_rule: impl Fn(Vec<Stmt>) -> Stmt,
+) -> Vec<Stmt> {
+    let escapes =
+        |stmt: &Stmt| matches!(stmt, Stmt::AtRoot { query, .. } if query.excludes_name(name));
+
+    if !contains_escaping(&body, &escapes) {
+        return vec![at_rule(body)];
+    }
+
+    let mut children = Vec::new();
+    split_escaping(body, &escapes, &mut children);
+
+    let mut stmts = Vec::new();
+    let mut body = Vec::new();
+
+    for child in children {
+        match child {
+            BodyChild::Stmt(stmt) => body.push(stmt),
+            BodyChild::Escaped(stmt) => {
+                if !body.is_empty() {
+                    stmts.push(at_rule(mem::take(&mut body)));
+                }
+                stmts.push(stmt);
+            }
+        }
+    }
+
+    if !body.is_empty() || stmts.is_empty() {
+        stmts.push(at_rule(body));
+    }
+
+    stmts
+}
+
+impl<'a, 'b> Parser<'a, 'b> {
+    /// Parses a query such as `(without: media supports)`
+    pub(super) fn parse_at_root_query(&mut self) -> SassResult<AtRootQuery> {
+        self.expect_char('(')?;
+        self.whitespace_or_comment();
+
+        let include = if self.scan_identifier("with", true) {
+            true
+        } else if self.scan_identifier("without", true) {
+            false
+        } else {
+            return Err(("Expected \"with\" or \"without\".", self.span_before).into());
+        };
+
+        self.whitespace_or_comment();
+        self.expect_char(':')?;
+        self.whitespace_or_comment();
+
+        let mut names = HashSet::new();
+
+        loop {
+            names.insert(self.parse_identifier()?.node.to_ascii_lowercase());
+            self.whitespace_or_comment();
+
+            if matches!(self.toks.peek(), Some(Token { kind: ')', .. }) | None) {
+                break;
+            }
+        }
+
+        self.expect_char(')')?;
+
+        Ok(AtRootQuery { include, names })
+    }
+}
@@ -3,6 +3,7 @@ use num_traits::cast::ToPrimitive;
 
 use crate::{
     common::Identifier,
+    compat::Deprecation,
     error::SassResult,
     lexer::Lexer,
     parse::{ContextFlags, Parser, Stmt},
@@ -27,10 +28,15 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
     }
 
@@ -48,10 +54,15 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
     }
 
@@ -82,16 +93,34 @@ impl<'a, 'b> Parser<'a, 'b> {
             self.whitespace_or_comment();
 
             let start = self.toks.cursor();
+            let start_span = self.toks.peek().map_or(self.span_before, |tok| tok.pos);
 
-            if !self.consume_char_if_exists('@') || !self.scan_identifier("else", false) {
-                self.toks.set_cursor(start);
+            if !self.consume_char_if_exists('@') {
                 break;
             }
 
-            self.whitespace_or_comment();
-            if let Some(tok) = self.toks.peek() {
-                match tok.kind {
-                    'i' | 'I' | '\\' => {
+            let is_else_if = if self.scan_identifier("elseif", false) {
+                let span = self
+                    .toks
+                    .prev()
+                    .map_or(start_span, |tok| start_span.merge(tok.pos));
+
+                self.deprecation(
+                    Deprecation::Elseif,
+                    "@elseif is deprecated and will not be supported in future Sass versions.\n\nRecommendation: @else if",
+                    "@elseif isn't supported. Use @else if instead.",
+                    span,
+                )?;
+
+                true
+            } else if self.scan_identifier("else", false) {
+                self.whitespace_or_comment();
+
+                match self.toks.peek() {
+                    Some(Token { kind: '{', .. }) => false,
+                    Some(tok @ Token { kind: 'i', .. })
+                    | Some(tok @ Token { kind: 'I', .. })
+                    | Some(tok @ Token { kind: '\\', .. }) => {
                         self.span_before = tok.pos;
                         let mut ident = self.parse_identifier_no_interpolation(false)?;
 
@@ -101,43 +130,46 @@ impl<'a, 'b> Parser<'a, 'b> {
                             return Err(("expected \"{\".", ident.span).into());
                         }
 
-                        let cond = if found_true {
-                            self.throw_away_until_open_curly_brace()?;
-                            false
-                        } else {
-                            let v = self.parse_value(true, &|_| false)?.node.is_true();
-                            self.expect_char('{')?;
-                            v
-                        };
-
-                        if cond {
-                            found_true = true;
-                            self.scopes.enter_new_scope();
-                            body = self.subparser_with_in_control_flow_flag().parse_stmt()?;
-                            self.scopes.exit_scope();
-                        } else {
-                            self.throw_away_until_closing_curly_brace()?;
-                        }
-                        self.whitespace();
-                    }
-                    '{' => {
-                        self.toks.next();
-                        if found_true {
-                            self.throw_away_until_closing_curly_brace()?;
-                            break;
-                        }
-
-                        self.scopes.enter_new_scope();
-                        let tmp = self.subparser_with_in_control_flow_flag().parse_stmt();
-                        self.scopes.exit_scope();
-                        return tmp;
-                    }
-                    _ => {
-                        return Err(("expected \"{\".", tok.pos()).into());
+                        true
                     }
+                    Some(tok) => return Err(("expected \"{\".", tok.pos()).into()),
+                    None => break,
                 }
             } else {
+                self.toks.set_cursor(start);
                 break;
+            };
+
+            if is_else_if {
+                let cond = if found_true {
+                    self.throw_away_until_open_curly_brace()?;
+                    false
+                } else {
+                    let v = self.parse_value(true, &|_| false)?.node.is_true();
+                    self.expect_char('{')?;
+                    v
+                };
+
+                if cond {
+                    found_true = true;
+                    self.scopes.enter_new_scope();
+                    body = self.subparser_with_in_control_flow_flag().parse_stmt()?;
+                    self.scopes.exit_scope();
+                } else {
+                    self.throw_away_until_closing_curly_brace()?;
+                }
+                self.whitespace();
+            } else {
+                self.toks.next();
+                if found_true {
+                    self.throw_away_until_closing_curly_brace()?;
+                    break;
+                }
+
+                self.scopes.enter_new_scope();
+                let tmp = self.subparser_with_in_control_flow_flag().parse_stmt();
+                self.scopes.exit_scope();
+                return tmp;
             }
         }
         self.whitespace();
@@ -193,12 +225,13 @@ impl<'a, 'b> Parser<'a, 'b> {
             return Err(("Expected \"to\" or \"through\".", self.span_before).into());
         };
 
-        let from = match from_val.node {
-            Value::Dimension(Some(n), ..) => match n.to_i32() {
+        // the loop variable has the units of `from`, which `to` is converted to
+        let (from, unit) = match from_val.node {
+            Value::Dimension(Some(n), unit, _) => match n.to_i32() {
                 Some(std::i32::MAX) | Some(std::i32::MIN) | None => {
                     return Err((format!("{} is not an int.", n.inspect()), from_val.span).into())
                 }
-                Some(v) => v,
+                Some(v) => (v, unit),
             },
             Value::Dimension(None, ..) => return Err(("NaN is not an int.", from_val.span).into()),
             v => {
@@ -212,12 +245,26 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         let to_val = self.parse_value(true, &|_| false)?;
         let to = match to_val.node {
-            Value::Dimension(Some(n), ..) => match n.to_i32() {
-                Some(std::i32::MAX) | Some(std::i32::MIN) | None => {
-                    return Err((format!("{} is not an int.", n.inspect()), to_val.span).into())
+            Value::Dimension(Some(n), to_unit, _) => {
+                let n = if unit == Unit::None || to_unit == Unit::None || unit == to_unit {
+                    n
+                } else if unit.comparable(&to_unit) {
+                    n.convert(&to_unit, &unit)
+                } else {
+                    return Err((
+                        format!("Incompatible units {} and {}.", to_unit, unit),
+                        to_val.span,
+                    )
+                        .into());
+                };
+
+                match n.to_i32() {
+                    Some(std::i32::MAX) | Some(std::i32::MIN) | None => {
+                        return Err((format!("{} is not an int.", n.inspect()), to_val.span).into())
+                    }
+                    Some(v) => v,
                 }
-                Some(v) => v,
-            },
+            }
             Value::Dimension(None, ..) => return Err(("NaN is not an int.", from_val.span).into()),
             v => {
                 return Err((
@@ -253,9 +300,10 @@ impl<'a, 'b> Parser<'a, 'b> {
         self.scopes.enter_new_scope();
 
         for i in iter {
+            self.check_cancelled()?;
             self.scopes.insert_var_last(
                 var.node,
-                Value::Dimension(Some(Number::from(i)), Unit::None, true),
+                Value::Dimension(Some(Number::from(i)), unit.clone(), true),
             );
             let mut these_stmts = self
                 .subparser_with_in_control_flow_flag()
@@ -299,6 +347,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         let mut val = self.parse_value_from_vec(&cond, true)?;
         self.scopes.enter_new_scope();
         while val.node.is_true() {
+            self.check_cancelled()?;
             let mut these_stmts = self
                 .subparser_with_in_control_flow_flag()
                 .with_toks(&mut Lexer::new_ref(&body))
@@ -364,6 +413,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         self.scopes.enter_new_scope();
 
         for row in iter {
+            self.check_cancelled()?;
             if vars.len() == 1 {
                 self.scopes.insert_var_last(vars[0].node, row);
             } else {
@@ -1,3 +1,5 @@
+use std::mem;
+
 use codemap::Spanned;
 
 use crate::{
@@ -6,7 +8,6 @@ use crate::{
     common::{unvendor, Identifier},
     error::SassResult,
     lexer::Lexer,
-    scope::Scopes,
     utils::read_until_closing_curly_brace,
     value::{SassFunction, Value},
 };
@@ -60,7 +61,14 @@ impl<'a, 'b> Parser<'a, 'b> {
         });
         self.whitespace();
 
-        let function = Function::new(args, body, self.at_root, span);
+        let scope_len = if self.at_root {
+            0
+        } else {
+            // a scope is created to hold the function if there is none
+            self.scopes.len().max(1)
+        };
+
+        let function = Function::new(args, body, scope_len, span);
 
         let name_as_ident = Identifier::from(name);
 
@@ -94,40 +102,33 @@ impl<'a, 'b> Parser<'a, 'b> {
         let Function {
             body,
             args: fn_args,
-            declared_at_root,
-            ..
+            scope_len,
+            pos,
         } = function;
 
         let scope = self.eval_args(&fn_args, args)?;
 
-        let mut new_scope = Scopes::new();
-        let mut entered_scope = false;
-        if declared_at_root {
-            new_scope.enter_scope(scope);
-        } else {
-            entered_scope = true;
-            self.scopes.enter_scope(scope);
+        let module_scope = match module {
+            Some(module) => Some(self.modules.get(module.node, module.span)?.scope.clone()),
+            None => None,
         };
 
-        if let Some(module) = module {
-            let module = self.modules.get(module.node, module.span)?;
+        // only the scopes where the function was declared are visible in its
+        // body, so the rest are moved out of the way until it returns
+        let scope_len = scope_len.min(self.scopes.len());
+        let (mut scopes, call_scopes) = mem::take(self.scopes).split_off(scope_len);
 
-            if declared_at_root {
-                new_scope.enter_scope(module.scope.clone());
-            } else {
-                self.scopes.enter_scope(module.scope.clone());
-            }
+        scopes.enter_scope(scope);
+
+        if let Some(module_scope) = module_scope {
+            scopes.enter_scope(module_scope);
         }
 
-        let mut return_value = Parser {
+        let return_value = Parser {
             toks: &mut Lexer::new(body),
             map: self.map,
             path: self.path,
-            scopes: if declared_at_root {
-                &mut new_scope
-            } else {
-                self.scopes
-            },
+            scopes: &mut scopes,
             global_scope: self.global_scope,
             super_selectors: self.super_selectors,
             span_before: self.span_before,
@@ -136,28 +137,35 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: false,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
-        .parse_stmt()?;
-
-        if entered_scope {
-            self.scopes.exit_scope();
-        }
+        .parse_stmt();
 
         if module.is_some() {
-            self.scopes.exit_scope();
+            scopes.exit_scope();
         }
 
+        scopes.exit_scope();
+        scopes.merge(call_scopes);
+        *self.scopes = scopes;
+
+        let mut return_value = return_value?;
+
         debug_assert!(
             return_value.len() <= 1,
             "we expect there to be only one return value"
         );
         match return_value
             .pop()
-            .ok_or(("Function finished without @return.", self.span_before))?
+            .ok_or(("Function finished without @return.", pos))?
         {
             Stmt::Return(v) => Ok(*v),
             _ => todo!("should be unreachable"),
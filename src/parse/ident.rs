@@ -1,6 +1,6 @@
 use std::{borrow::Borrow, iter::Iterator};
 
-use codemap::Spanned;
+use codemap::{Span, Spanned};
 
 use crate::{
     common::QuoteKind,
@@ -80,6 +80,14 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(())
     }
 
+    /// The span from `start` through the most recently consumed token
+    pub(crate) fn consumed_span(&self, start: Span) -> Span {
+        match self.toks.prev() {
+            Some(last) => start.merge(last.pos()),
+            None => start,
+        }
+    }
+
     pub(crate) fn parse_escape(&mut self, identifier_start: bool) -> SassResult<String> {
         let mut value = 0;
         let first = match self.toks.peek() {
@@ -137,7 +145,7 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
 
     pub(crate) fn parse_identifier(&mut self) -> SassResult<Spanned<String>> {
-        let Token { kind, pos } = self
+        let Token { kind, pos: start } = self
             .toks
             .peek()
             .ok_or(("Expected identifier.", self.span_before))?;
@@ -152,14 +160,14 @@ impl<'a, 'b> Parser<'a, 'b> {
                     self.interpolated_ident_body(&mut text)?;
                     return Ok(Spanned {
                         node: text,
-                        span: pos,
+                        span: self.consumed_span(start),
                     });
                 }
                 Some(..) => {}
                 None => {
                     return Ok(Spanned {
                         node: text,
-                        span: self.span_before,
+                        span: start,
                     })
                 }
             }
@@ -181,10 +189,11 @@ impl<'a, 'b> Parser<'a, 'b> {
             '#' if matches!(self.toks.peek_forward(1), Some(Token { kind: '{', .. })) => {
                 self.toks.next();
                 self.toks.next();
-                match self.parse_interpolation()?.node {
+                let interpolation = self.parse_interpolation()?;
+                match interpolation.node {
                     Value::String(ref s, ..) => text.push_str(s),
                     v => text.push_str(
-                        v.to_css_string(self.span_before, self.options.is_compressed())?
+                        v.to_css_string(interpolation.span, self.options.is_compressed())?
                             .borrow(),
                     ),
                 }
@@ -195,7 +204,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         self.interpolated_ident_body(&mut text)?;
         Ok(Spanned {
             node: text,
-            span: self.span_before,
+            span: self.consumed_span(start),
         })
     }
 
@@ -203,10 +212,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         &mut self,
         unit: bool,
     ) -> SassResult<Spanned<String>> {
-        let Token {
-            kind,
-            pos: mut span,
-        } = self
+        let Token { kind, pos: span } = self
             .toks
             .peek()
             .ok_or(("Expected identifier.", self.span_before))?;
@@ -220,7 +226,10 @@ impl<'a, 'b> Parser<'a, 'b> {
                     self.toks.next();
                     text.push('-');
                     text.push_str(&self.ident_body_no_interpolation(unit)?.node);
-                    return Ok(Spanned { node: text, span });
+                    return Ok(Spanned {
+                        node: text,
+                        span: self.consumed_span(span),
+                    });
                 }
                 Some(..) => {}
                 None => return Ok(Spanned { node: text, span }),
@@ -240,10 +249,11 @@ impl<'a, 'b> Parser<'a, 'b> {
             return Err(("Expected identifier.", first.pos).into());
         }
 
-        let body = self.ident_body_no_interpolation(unit)?;
-        span = span.merge(body.span);
-        text.push_str(&body.node);
-        Ok(Spanned { node: text, span })
+        text.push_str(&self.ident_body_no_interpolation(unit)?.node);
+        Ok(Spanned {
+            node: text,
+            span: self.consumed_span(span),
+        })
     }
 
     pub(crate) fn parse_quoted_string(&mut self, q: char) -> SassResult<Spanned<Value>> {
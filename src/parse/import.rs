@@ -1,11 +1,19 @@
-use std::{ffi::OsStr, path::Path, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsStr,
+    path::Path,
+    path::PathBuf,
+};
 
 use codemap::{Span, Spanned};
 
 use crate::{
+    builtin::modules::Module,
     common::{ListSeparator::Comma, QuoteKind},
     error::SassResult,
     lexer::Lexer,
+    scope::Scope,
+    utils::{decode_stylesheet, path_to_url, url_to_path},
     value::Value,
     Token,
 };
@@ -26,13 +34,191 @@ fn is_plain_css_import(url: &str) -> bool {
         || lower.starts_with("//")
 }
 
+/// Whether an import URL contains wildcards to be expanded with the
+/// `glob-imports` feature
+#[cfg(feature = "glob-imports")]
+fn is_glob(url: &str) -> bool {
+    url.contains(|c| matches!(c, '*' | '?' | '['))
+}
+
+fn differs_only_by_case(a: &Path, b: &Path) -> bool {
+    a.to_string_lossy().to_lowercase() == b.to_string_lossy().to_lowercase()
+}
+
+/// The stylesheets loaded during a compilation, keyed by the path returned by
+/// `Parser::file_key`
+///
+/// Modules that don't depend on the configuration of the entrypoint are kept
+/// from one compilation to the next, so that compiling the same stylesheet
+/// with [`compile_themes`](crate::compile_themes) only evaluates them once.
+#[derive(Debug, Default)]
+pub(crate) struct LoadedFiles {
+    /// The path through which each file was first loaded
+    paths: HashMap<PathBuf, PathBuf>,
+
+    /// The modules loaded with `@use` or `meta.load-css()`
+    modules: HashMap<PathBuf, Module>,
+
+    /// The index in `Options::importers` of the importer that returned each
+    /// canonical URL
+    importers: HashMap<PathBuf, usize>,
+
+    /// The keys of the modules in `modules` that can be shared with later
+    /// compilations
+    shareable: HashSet<PathBuf>,
+
+    /// The keys of the shareable modules each time one was loaded or used
+    /// again, in order
+    shareable_uses: Vec<PathBuf>,
+
+    /// The number of times a module that can't be shared was loaded or used
+    /// again, so that the modules that use it aren't shared either
+    unshareable_loads: usize,
+
+    /// The modules shared by the compilations that used these `LoadedFiles`
+    shared: HashMap<PathBuf, SharedModule>,
+}
+
+/// A module that emits no CSS and was loaded without configuration, either
+/// directly or by any module it loads
+#[derive(Debug)]
+struct SharedModule {
+    path: PathBuf,
+    module: Module,
+
+    /// The keys of the modules loaded or used again while evaluating this one
+    dependencies: Vec<PathBuf>,
+}
+
+/// The modules loaded so far in a compilation, to find out whether the module
+/// being evaluated can be shared
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LoadCheckpoint {
+    shareable_uses: usize,
+    unshareable_loads: usize,
+}
+
+impl LoadedFiles {
+    /// Starts another compilation, forgetting every file loaded so far except
+    /// for the modules that can be shared
+    pub fn next_compilation(&mut self) {
+        self.paths.clear();
+        self.modules.clear();
+        self.importers.clear();
+        self.shareable.clear();
+        self.shareable_uses.clear();
+        self.unshareable_loads = 0;
+    }
+
+    fn checkpoint(&self) -> LoadCheckpoint {
+        LoadCheckpoint {
+            shareable_uses: self.shareable_uses.len(),
+            unshareable_loads: self.unshareable_loads,
+        }
+    }
+
+    /// Loads the module with `key` and everything it depends on from an
+    /// earlier compilation, if it was shared
+    fn load_shared(&mut self, key: &Path) -> Option<Module> {
+        let shared = self.shared.get(key)?;
+
+        let loaded_elsewhere = shared.dependencies.iter().any(|dependency| {
+            self.modules.contains_key(dependency) && !self.shareable.contains(dependency)
+        });
+
+        if loaded_elsewhere {
+            return None;
+        }
+
+        for dependency in shared
+            .dependencies
+            .iter()
+            .chain(std::iter::once(&key.to_path_buf()))
+        {
+            self.shareable_uses.push(dependency.clone());
+
+            if self.shareable.contains(dependency) {
+                continue;
+            }
+
+            let module = &self.shared[dependency];
+
+            self.paths
+                .entry(dependency.clone())
+                .or_insert_with(|| module.path.clone());
+            self.modules
+                .insert(dependency.clone(), module.module.clone());
+            self.shareable.insert(dependency.clone());
+        }
+
+        Some(shared.module.clone())
+    }
+}
+
+/// Returns `$name` from the enclosing function if it's a file in the
+/// parser's [`Fs`](crate::Fs)
+macro_rules! try_path {
+    ($parser:ident, $name:expr) => {
+        let name = $name;
+        if $parser.options.fs.is_file(&name) {
+            return Some(name);
+        }
+    };
+}
+
 impl<'a, 'b> Parser<'a, 'b> {
-    /// Searches the current directory of the file then searches in `load_paths` directories
-    /// if the import has not yet been found.
+    /// Resolves `url` relative to the current file, then with each of
+    /// `Options::importers`, then in `load_paths` directories.
+    ///
+    /// URLs in a stylesheet that was loaded by an importer are resolved
+    /// relative to it by that importer rather than on the file system.
     ///
     /// <https://sass-lang.com/documentation/at-rules/import#finding-the-file>
     /// <https://sass-lang.com/documentation/at-rules/import#load-paths>
-    pub(super) fn find_import(&self, path: &Path) -> Option<PathBuf> {
+    pub(super) fn find_import(&mut self, url: &str) -> Option<PathBuf> {
+        let path = url_to_path(url);
+
+        if let Some(&index) = self.loaded_files.importers.get(self.path) {
+            let base = self.path.to_string_lossy().into_owned();
+            if let Some(found) = self.canonicalize_import(index, url, Some(&base)) {
+                return Some(found);
+            }
+        } else if !self.options.libsass_compat {
+            if let Some(found) = self.find_relative_import(&path) {
+                return Some(found);
+            }
+        }
+
+        for index in 0..self.options.importers.len() {
+            if let Some(found) = self.canonicalize_import(index, url, None) {
+                return Some(found);
+            }
+        }
+
+        if self.options.libsass_compat {
+            return self.find_import_libsass(&path);
+        }
+
+        self.find_load_path_import(&path)
+    }
+
+    /// Resolves `url` with the importer at `index` in `Options::importers`,
+    /// remembering which importer can load the canonical URL it returns
+    fn canonicalize_import(
+        &mut self,
+        index: usize,
+        url: &str,
+        base: Option<&str>,
+    ) -> Option<PathBuf> {
+        let canonical = PathBuf::from(self.options.importers[index].canonicalize(url, base)?);
+
+        self.loaded_files.importers.insert(canonical.clone(), index);
+
+        Some(canonical)
+    }
+
+    /// Searches for `path` relative to the directory of the current file
+    fn find_relative_import(&self, path: &Path) -> Option<PathBuf> {
         let path_buf = if path.is_absolute() {
             // todo: test for absolute path imports
             path.into()
@@ -45,85 +231,441 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         let name = path_buf.file_name().unwrap_or_else(|| OsStr::new(".."));
 
-        macro_rules! try_path {
-            ($name:expr) => {
-                let name = $name;
-                if self.options.fs.is_file(&name) {
-                    return Some(name);
-                }
-            };
-        }
-
-        try_path!(path_buf.with_file_name(name).with_extension("scss"));
-        try_path!(path_buf
-            .with_file_name(format!("_{}", name.to_str().unwrap()))
-            .with_extension("scss"));
-        try_path!(path_buf.clone());
-        try_path!(path_buf.join("index.scss"));
-        try_path!(path_buf.join("_index.scss"));
-
-        for path in &self.options.load_paths {
-            if self.options.fs.is_dir(path) {
-                try_path!(path
-                    .join(&path_buf)
-                    .with_file_name(name)
-                    .with_extension("scss"));
-                try_path!(path
-                    .join(&path_buf)
-                    .with_file_name(format!("_{}", name.to_str().unwrap()))
-                    .with_extension("scss"));
-                try_path!(path.join(&path_buf).join("index.scss"));
-                try_path!(path.join(&path_buf).join("_index.scss"));
+        try_path!(self, path_buf.with_file_name(name).with_extension("scss"));
+        try_path!(
+            self,
+            path_buf
+                .with_file_name(format!("_{}", name.to_str().unwrap()))
+                .with_extension("scss")
+        );
+        try_path!(self, path_buf.clone());
+        try_path!(self, path_buf.join("index.scss"));
+        try_path!(self, path_buf.join("_index.scss"));
+
+        None
+    }
+
+    /// Searches for `path` in each of `load_paths`
+    ///
+    /// Load paths are searched for the URL as written, not relative to the
+    /// importing file.
+    fn find_load_path_import(&self, path: &Path) -> Option<PathBuf> {
+        let name = path.file_name().unwrap_or_else(|| OsStr::new(".."));
+
+        for load_path in self.load_paths() {
+            if self.options.fs.is_dir(&load_path) {
+                try_path!(
+                    self,
+                    load_path
+                        .join(path)
+                        .with_file_name(name)
+                        .with_extension("scss")
+                );
+                try_path!(
+                    self,
+                    load_path
+                        .join(path)
+                        .with_file_name(format!("_{}", name.to_str().unwrap()))
+                        .with_extension("scss")
+                );
+                try_path!(self, load_path.join(path).join("index.scss"));
+                try_path!(self, load_path.join(path).join("_index.scss"));
             } else {
-                try_path!(path.to_path_buf());
-                try_path!(path.with_file_name(name).with_extension("scss"));
-                try_path!(path
-                    .with_file_name(format!("_{}", name.to_str().unwrap()))
-                    .with_extension("scss"));
-                try_path!(path.join("index.scss"));
-                try_path!(path.join("_index.scss"));
+                try_path!(self, load_path.to_path_buf());
+                try_path!(self, load_path.with_file_name(name).with_extension("scss"));
+                try_path!(
+                    self,
+                    load_path
+                        .with_file_name(format!("_{}", name.to_str().unwrap()))
+                        .with_extension("scss")
+                );
+                try_path!(self, load_path.join("index.scss"));
+                try_path!(self, load_path.join("_index.scss"));
             }
         }
 
         None
     }
 
+    /// Searches for an import in the order `libsass` does, for
+    /// [`Options::libsass_compat`](crate::Options::libsass_compat)
+    ///
+    /// The directory of the current file is searched first, then the current
+    /// working directory, then `load_paths`. Within each directory, the exact
+    /// file name is tried first, and partials are tried before non-partials.
+    fn find_import_libsass(&self, path: &Path) -> Option<PathBuf> {
+        let name = path.file_name()?.to_str()?;
+
+        let dirs = vec![
+            self.path
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .to_path_buf(),
+            PathBuf::new(),
+        ];
+
+        for dir in dirs.into_iter().chain(self.load_paths()) {
+            let path_buf = dir.join(path);
+
+            let mut candidates = vec![
+                path_buf.clone(),
+                path_buf.with_file_name(format!("_{}", name)),
+                path_buf.with_file_name(format!("_{}.scss", name)),
+                path_buf.with_file_name(format!("{}.scss", name)),
+            ];
+
+            // `libsass` doesn't look for index files in directories named like
+            // stylesheets
+            if !name.ends_with(".scss") {
+                candidates.push(path_buf.join("_index.scss"));
+                candidates.push(path_buf.join("index.scss"));
+            }
+
+            if let Some(found) = candidates
+                .into_iter()
+                .find(|candidate| self.options.fs.is_file(candidate))
+            {
+                return Some(found);
+            }
+        }
+
+        None
+    }
+
+    /// `Options::load_paths`, normalized with [`url_to_path`]
+    fn load_paths(&self) -> impl Iterator<Item = PathBuf> + '_ {
+        self.options
+            .load_paths
+            .iter()
+            .map(|path| url_to_path(&path.to_string_lossy()))
+    }
+
+    /// The key under which the file at `path` is stored in
+    /// `Parser::loaded_files`
+    ///
+    /// With [`Options::canonicalize_imports`](crate::Options::canonicalize_imports),
+    /// this is the same for every path that refers to the same file.
+    fn file_key(&self, path: &Path) -> PathBuf {
+        if self.options.canonicalize_imports && !self.loaded_files.importers.contains_key(path) {
+            if let Ok(canonical) = self.options.fs.canonicalize(path) {
+                return canonical;
+            }
+        }
+
+        path.to_path_buf()
+    }
+
+    /// Reads the stylesheet at `path`, which has already been resolved, from
+    /// the importer that resolved it or from the [`Fs`](crate::Fs)
+    pub(super) fn read_import(&self, path: &Path) -> SassResult<String> {
+        match self.loaded_files.importers.get(path) {
+            Some(&index) => Ok(self.options.importers[index].load(&path.to_string_lossy())?),
+            None => decode_stylesheet(self.options.fs.read(path)?),
+        }
+    }
+
+    /// Records that the stylesheet at `path` is being loaded, warning if it
+    /// was previously loaded through a path that differs only by case, and
+    /// returns its key in `Parser::loaded_files`
+    pub(super) fn record_load(&mut self, path: &Path, span: Span) -> SassResult<PathBuf> {
+        let key = self.file_key(path);
+
+        match self.loaded_files.paths.get(&key) {
+            Some(previous) if previous != path && differs_only_by_case(previous, path) => {
+                self.warn(&Spanned {
+                    node: format!(
+                        "\"{}\" was previously loaded as \"{}\", which differs only in case.\nThis will fail on case-sensitive file systems.",
+                        path_to_url(path),
+                        path_to_url(previous)
+                    )
+                    .into(),
+                    span,
+                })?;
+            }
+            Some(..) => {}
+            None => {
+                self.loaded_files
+                    .paths
+                    .insert(key.clone(), path.to_path_buf());
+            }
+        }
+
+        Ok(key)
+    }
+
+    /// The module previously loaded from the file with `key` in this
+    /// compilation, if any
+    pub(super) fn loaded_module(&mut self, key: &Path) -> Option<Module> {
+        let module = self.loaded_files.modules.get(key)?.clone();
+
+        if self.loaded_files.shareable.contains(key) {
+            self.loaded_files.shareable_uses.push(key.to_path_buf());
+        } else {
+            self.loaded_files.unshareable_loads += 1;
+        }
+
+        Some(module)
+    }
+
+    /// The module loaded from the file with `key` in an earlier compilation,
+    /// if it can be used in this one
+    pub(super) fn shared_module(&mut self, key: &Path) -> Option<Module> {
+        self.loaded_files.load_shared(key)
+    }
+
+    /// The modules loaded so far, to pass to `Parser::insert_loaded_module`
+    /// once the module being loaded has been evaluated
+    pub(super) fn load_checkpoint(&self) -> LoadCheckpoint {
+        self.loaded_files.checkpoint()
+    }
+
+    /// Records the module loaded from the file with `key`, sharing it with
+    /// later compilations if it's `shareable` and so is every module loaded
+    /// since `checkpoint`
+    pub(super) fn insert_loaded_module(
+        &mut self,
+        key: PathBuf,
+        module: Module,
+        shareable: bool,
+        checkpoint: LoadCheckpoint,
+    ) {
+        let loaded_files = &mut *self.loaded_files;
+
+        if shareable && loaded_files.unshareable_loads == checkpoint.unshareable_loads {
+            let mut dependencies =
+                loaded_files.shareable_uses[checkpoint.shareable_uses..].to_vec();
+            dependencies.sort();
+            dependencies.dedup();
+
+            loaded_files.shared.insert(
+                key.clone(),
+                SharedModule {
+                    path: loaded_files
+                        .paths
+                        .get(&key)
+                        .cloned()
+                        .unwrap_or_else(|| key.clone()),
+                    module: module.clone(),
+                    dependencies,
+                },
+            );
+            loaded_files.shareable.insert(key.clone());
+            loaded_files.shareable_uses.push(key.clone());
+        } else {
+            loaded_files.unshareable_loads += 1;
+        }
+
+        loaded_files.modules.insert(key, module);
+    }
+
+    /// Ensures that loading another stylesheet at `span` would not exceed
+    /// [`Options::max_import_depth`](crate::Options::max_import_depth)
+    pub(super) fn check_import_depth(&self, span: Span) -> SassResult<()> {
+        let depth = self
+            .call_stack
+            .iter()
+            .filter(|frame| frame.is_load())
+            .count();
+
+        if depth >= self.options.max_import_depth {
+            return Err((
+                format!(
+                    "Maximum import depth of {} exceeded.",
+                    self.options.max_import_depth
+                ),
+                span,
+            )
+                .into());
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn parse_single_import(
         &mut self,
         file_name: &str,
         span: Span,
     ) -> SassResult<Vec<Stmt>> {
-        let path: &Path = file_name.as_ref();
+        #[cfg(feature = "glob-imports")]
+        {
+            if is_glob(file_name) {
+                let mut stmts = Vec::new();
+                for path in self.find_glob_imports(file_name, span)? {
+                    stmts.append(&mut self.import_file(path, span)?);
+                }
+                return Ok(stmts);
+            }
+        }
 
-        if let Some(name) = self.find_import(path) {
-            let file = self.map.add_file(
-                name.to_string_lossy().into(),
-                String::from_utf8(self.options.fs.read(&name)?)?,
-            );
-            return Parser {
+        if let Some(name) = self.find_import(file_name) {
+            return self.import_file(name, span);
+        }
+
+        Err(("Can't find stylesheet to import.", span).into())
+    }
+
+    /// Parses the stylesheet at `name`, which has already been resolved, as
+    /// though its contents were written at `span`
+    fn import_file(&mut self, name: PathBuf, span: Span) -> SassResult<Vec<Stmt>> {
+        self.check_import_depth(span)?;
+        self.record_load(&name, span)?;
+
+        let file = self
+            .map
+            .add_file(path_to_url(&name), self.read_import(&name)?);
+
+        // the members an imported file forwards are visible to the file that
+        // imports it
+        let mut forwarded_scope = Scope::new();
+
+        let stmts = self.with_stack_frame("@import".to_owned(), span, |parser| {
+            Parser {
                 toks: &mut Lexer::new_from_file(&file),
-                map: self.map,
+                map: parser.map,
                 path: &name,
-                scopes: self.scopes,
-                global_scope: self.global_scope,
-                super_selectors: self.super_selectors,
+                scopes: parser.scopes,
+                global_scope: parser.global_scope,
+                super_selectors: parser.super_selectors,
                 span_before: file.span.subspan(0, 0),
-                content: self.content,
-                flags: self.flags,
-                at_root: self.at_root,
-                at_root_has_selector: self.at_root_has_selector,
-                extender: self.extender,
-                content_scopes: self.content_scopes,
-                options: self.options,
-                modules: self.modules,
-                module_config: self.module_config,
+                content: parser.content,
+                flags: parser.flags,
+                at_root: parser.at_root,
+                at_root_has_selector: parser.at_root_has_selector,
+                extender: parser.extender,
+                options: parser.options,
+                modules: parser.modules,
+                module_config: parser.module_config,
+                forwarded_scope: &mut forwarded_scope,
+                call_stack: parser.call_stack,
+                unique_id_count: parser.unique_id_count,
+                loaded_files: parser.loaded_files,
+                emitted_warnings: parser.emitted_warnings,
+                deadline: parser.deadline,
+            }
+            .parse()
+        })?;
+
+        self.global_scope.merge_module_scope(forwarded_scope);
+
+        Ok(stmts)
+    }
+
+    /// Expands an import URL containing wildcards into the paths of the
+    /// stylesheets it matches, sorted so that they're always imported in the
+    /// same order
+    ///
+    /// Like other imports, the URL is resolved relative to the current file,
+    /// then to each of `load_paths` in turn, stopping at the first that has
+    /// any matches. If the last component of the URL has no extension, only
+    /// `.scss` files are matched. `**` matches any number of directories.
+    #[cfg(feature = "glob-imports")]
+    fn find_glob_imports(&self, url: &str, span: Span) -> SassResult<Vec<PathBuf>> {
+        let mut pattern = url_to_path(url);
+        if pattern.file_name().map_or(true, |name| name == "**") {
+            pattern.push("*.scss");
+        } else if pattern.extension().is_none() {
+            pattern.set_extension("scss");
+        }
+
+        let components: Vec<&OsStr> = pattern.iter().collect();
+        let current_file = self.file_key(self.path);
+
+        let dirs = vec![self
+            .path
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .to_path_buf()];
+
+        for dir in dirs.into_iter().chain(self.load_paths()) {
+            let mut matches = Vec::new();
+            self.expand_glob(dir, &components, &mut matches, span)?;
+
+            // a stylesheet importing its own directory shouldn't import itself
+            matches.retain(|path| self.file_key(path) != current_file);
+
+            if !matches.is_empty() {
+                matches.sort();
+                matches.dedup();
+                return Ok(matches);
             }
-            .parse();
         }
 
         Err(("Can't find stylesheet to import.", span).into())
     }
 
+    /// Adds every file in `dir` matching the glob pattern `components` to
+    /// `matches`
+    #[cfg(feature = "glob-imports")]
+    fn expand_glob(
+        &self,
+        dir: PathBuf,
+        components: &[&OsStr],
+        matches: &mut Vec<PathBuf>,
+        span: Span,
+    ) -> SassResult<()> {
+        let (component, rest) = match components.split_first() {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+
+        let component_str = component.to_string_lossy();
+
+        if !is_glob(&component_str) {
+            let path = dir.join(component);
+            if rest.is_empty() {
+                if self.options.fs.is_file(&path) {
+                    matches.push(path);
+                }
+            } else {
+                self.expand_glob(path, rest, matches, span)?;
+            }
+            return Ok(());
+        }
+
+        if !self.options.fs.is_dir(&dir) {
+            return Ok(());
+        }
+
+        let entries = self.options.fs.read_dir(&dir)?;
+
+        if component_str == "**" {
+            self.expand_glob(dir, rest, matches, span)?;
+            for entry in entries {
+                if self.options.fs.is_dir(&entry) {
+                    self.expand_glob(entry, components, matches, span)?;
+                }
+            }
+            return Ok(());
+        }
+
+        let pattern = glob::Pattern::new(&component_str)
+            .map_err(|e| (format!("Invalid glob pattern: {}.", e.msg), span))?;
+        let options = glob::MatchOptions {
+            require_literal_leading_dot: true,
+            ..glob::MatchOptions::new()
+        };
+
+        for entry in entries {
+            let is_match = entry.file_name().map_or(false, |name| {
+                pattern.matches_with(&name.to_string_lossy(), options)
+            });
+
+            if !is_match {
+                continue;
+            }
+
+            if rest.is_empty() {
+                if self.options.fs.is_file(&entry) {
+                    matches.push(entry);
+                }
+            } else {
+                self.expand_glob(entry, rest, matches, span)?;
+            }
+        }
+
+        Ok(())
+    }
+
     pub(super) fn import(&mut self) -> SassResult<Vec<Stmt>> {
         if self.flags.in_function() {
             return Err(("This at-rule is not allowed here.", self.span_before).into());
@@ -170,10 +170,15 @@ impl<'a, 'b> Parser<'a, 'b> {
                         at_root: self.at_root,
                         at_root_has_selector: self.at_root_has_selector,
                         extender: self.extender,
-                        content_scopes: self.content_scopes,
                         options: self.options,
                         modules: self.modules,
                         module_config: self.module_config,
+                        forwarded_scope: self.forwarded_scope,
+                        call_stack: self.call_stack,
+                        unique_id_count: self.unique_id_count,
+                        loaded_files: self.loaded_files,
+                        emitted_warnings: self.emitted_warnings,
+                        deadline: self.deadline,
                     })
                     .parse_keyframes_selector()?;
 
@@ -208,10 +213,15 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: false,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
         .parse_stmt()?;
 
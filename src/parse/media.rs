@@ -98,6 +98,7 @@ impl<'a, 'b> Parser<'a, 'b> {
             buf.push_str(
                 &value
                     .node
+                    .unquote()
                     .to_css_string(value.span, self.options.is_compressed())?,
             );
 
@@ -109,9 +110,10 @@ impl<'a, 'b> Parser<'a, 'b> {
         let next_tok = self.toks.peek();
         let is_angle = next_tok.map_or(false, |t| t.kind == '<' || t.kind == '>');
         if is_angle || matches!(next_tok, Some(Token { kind: '=', .. })) {
-            buf.push(' ');
             // todo: remove this unwrap
-            buf.push(self.toks.next().unwrap().kind);
+            let comparison = self.toks.next().unwrap().kind;
+            buf.push(' ');
+            buf.push(comparison);
             if is_angle && self.consume_char_if_exists('=') {
                 buf.push('=');
             }
@@ -120,6 +122,21 @@ impl<'a, 'b> Parser<'a, 'b> {
             self.whitespace_or_comment();
 
             buf.push_str(&self.expression_until_comparison()?);
+
+            // a range may be bounded on both sides, e.g. `(400px < width < 700px)`,
+            // as long as both comparisons point the same way
+            if is_angle && self.consume_char_if_exists(comparison) {
+                buf.push(' ');
+                buf.push(comparison);
+                if self.consume_char_if_exists('=') {
+                    buf.push('=');
+                }
+                buf.push(' ');
+
+                self.whitespace_or_comment();
+
+                buf.push_str(&self.expression_until_comparison()?);
+            }
         }
 
         self.expect_char(')')?;
@@ -7,7 +7,6 @@ use crate::{
     atrule::mixin::{Content, Mixin, UserDefinedMixin},
     error::SassResult,
     lexer::Lexer,
-    scope::Scopes,
     utils::read_until_closing_curly_brace,
     Token,
 };
@@ -55,7 +54,14 @@ impl<'a, 'b> Parser<'a, 'b> {
         // this is blocked on figuring out just how to check for this. presumably we could have a check
         // not when parsing initially, but rather when `@include`ing to see if an `@content` was found.
 
-        let mixin = Mixin::new_user_defined(args, body, false, self.at_root);
+        let scope_len = if self.at_root {
+            0
+        } else {
+            // a scope is created to hold the mixin if there is none
+            self.scopes.len().max(1)
+        };
+
+        let mixin = Mixin::new_user_defined(args, body, false, scope_len);
 
         if self.at_root {
             self.global_scope.insert_mixin(name, mixin);
@@ -70,6 +76,8 @@ impl<'a, 'b> Parser<'a, 'b> {
             return Err(("This at-rule is not allowed here.", self.span_before).into());
         }
 
+        let include_span = self.span_before;
+
         self.whitespace_or_comment();
         let name = self.parse_identifier()?.map_node(Into::into);
 
@@ -136,58 +144,73 @@ impl<'a, 'b> Parser<'a, 'b> {
         let UserDefinedMixin {
             body,
             args: fn_args,
-            declared_at_root,
+            scope_len,
             ..
         } = match mixin {
             Mixin::UserDefined(u) => u,
             Mixin::Builtin(b) => {
-                return b(args, self);
+                let span = args.span();
+                let named = args.named();
+                let requested = args.requested_names();
+                let stmts = b(args, self)?;
+                CallArgs::verify_named(&named, &requested, span)?;
+                return Ok(stmts);
             }
         };
 
         let scope = self.eval_args(&fn_args, args)?;
 
-        let scope_len = self.scopes.len();
+        let module_scope = match module {
+            Some(module) => Some(self.modules.get(module.node, module.span)?.scope.clone()),
+            None => None,
+        };
 
-        if declared_at_root {
-            mem::swap(self.scopes, self.content_scopes);
-        }
+        // only the scopes where the mixin was declared are visible in its body
+        let scope_len = scope_len.min(self.scopes.len());
+        let (decl_scopes, scopes) = mem::take(self.scopes).split_off(scope_len);
+        *self.scopes = decl_scopes;
 
         self.scopes.enter_scope(scope);
 
-        if let Some(module) = module {
-            let module = self.modules.get(module.node, module.span)?;
-            self.scopes.enter_scope(module.scope.clone());
+        if let Some(module_scope) = module_scope {
+            self.scopes.enter_scope(module_scope);
         }
 
         self.content.push(Content {
             content,
             content_args,
             scope_len,
-            declared_at_root,
+            scopes,
         });
 
-        let body = Parser {
-            toks: &mut Lexer::new(body),
-            map: self.map,
-            path: self.path,
-            scopes: self.scopes,
-            global_scope: self.global_scope,
-            super_selectors: self.super_selectors,
-            span_before: self.span_before,
-            flags: self.flags | ContextFlags::IN_MIXIN,
-            content: self.content,
-            at_root: false,
-            at_root_has_selector: self.at_root_has_selector,
-            extender: self.extender,
-            content_scopes: self.content_scopes,
-            options: self.options,
-            modules: self.modules,
-            module_config: self.module_config,
-        }
-        .parse_stmt()?;
+        let body = self.with_stack_frame(format!("{}()", name.node), include_span, |parser| {
+            Parser {
+                toks: &mut Lexer::new(body),
+                map: parser.map,
+                path: parser.path,
+                scopes: parser.scopes,
+                global_scope: parser.global_scope,
+                super_selectors: parser.super_selectors,
+                span_before: parser.span_before,
+                flags: parser.flags | ContextFlags::IN_MIXIN,
+                content: parser.content,
+                at_root: false,
+                at_root_has_selector: parser.at_root_has_selector,
+                extender: parser.extender,
+                options: parser.options,
+                modules: parser.modules,
+                module_config: parser.module_config,
+                forwarded_scope: parser.forwarded_scope,
+                call_stack: parser.call_stack,
+                unique_id_count: parser.unique_id_count,
+                loaded_files: parser.loaded_files,
+                emitted_warnings: parser.emitted_warnings,
+                deadline: parser.deadline,
+            }
+            .parse_stmt()
+        })?;
 
-        self.content.pop();
+        let content = self.content.pop();
 
         if module.is_some() {
             self.scopes.exit_scope();
@@ -195,8 +218,8 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         self.scopes.exit_scope();
 
-        if declared_at_root {
-            mem::swap(self.scopes, self.content_scopes);
+        if let Some(Content { scopes, .. }) = content {
+            self.scopes.merge(scopes);
         }
 
         Ok(body)
@@ -211,26 +234,24 @@ impl<'a, 'b> Parser<'a, 'b> {
                 .into());
         }
 
-        Ok(if let Some(content) = self.content.pop() {
-            let (mut scope_at_decl, mixin_scope) = if content.declared_at_root {
-                (mem::take(self.content_scopes), Scopes::new())
-            } else {
-                mem::take(self.scopes).split_off(content.scope_len)
-            };
+        self.whitespace_or_comment();
 
-            let mut entered_scope = false;
+        // the arguments are evaluated in the scope of the mixin, before
+        // switching to that of the `@include`
+        let call_args = if self.consume_char_if_exists('(') {
+            self.parse_call_args()?
+        } else {
+            CallArgs::new(self.span_before)
+        };
 
-            self.whitespace_or_comment();
+        Ok(if let Some(mut content) = self.content.pop() {
+            let (mut scope_at_decl, mixin_scope) =
+                mem::take(self.scopes).split_off(content.scope_len);
+            scope_at_decl.merge(mem::take(&mut content.scopes));
 
-            let call_args = if self.consume_char_if_exists('(') {
-                self.parse_call_args()?
-            } else {
-                CallArgs::new(self.span_before)
-            };
+            let mut entered_scope = false;
 
             if let Some(ref content_args) = content.content_args {
-                call_args.max_args(content_args.len())?;
-
                 let scope = self.eval_args(content_args, call_args)?;
                 scope_at_decl.enter_scope(scope);
                 entered_scope = true;
@@ -239,25 +260,33 @@ impl<'a, 'b> Parser<'a, 'b> {
             }
 
             let stmts = if let Some(body) = &content.content {
-                Parser {
-                    toks: &mut Lexer::new_ref(body),
-                    map: self.map,
-                    path: self.path,
-                    scopes: &mut scope_at_decl,
-                    global_scope: self.global_scope,
-                    super_selectors: self.super_selectors,
-                    span_before: self.span_before,
-                    flags: self.flags,
-                    content: self.content,
-                    at_root: self.at_root,
-                    at_root_has_selector: self.at_root_has_selector,
-                    extender: self.extender,
-                    content_scopes: self.scopes,
-                    options: self.options,
-                    modules: self.modules,
-                    module_config: self.module_config,
-                }
-                .parse_stmt()?
+                let span = self.span_before;
+                self.with_stack_frame("@content".to_owned(), span, |parser| {
+                    Parser {
+                        toks: &mut Lexer::new_ref(body),
+                        map: parser.map,
+                        path: parser.path,
+                        scopes: &mut scope_at_decl,
+                        global_scope: parser.global_scope,
+                        super_selectors: parser.super_selectors,
+                        span_before: parser.span_before,
+                        flags: parser.flags,
+                        content: parser.content,
+                        at_root: parser.at_root,
+                        at_root_has_selector: parser.at_root_has_selector,
+                        extender: parser.extender,
+                        options: parser.options,
+                        modules: parser.modules,
+                        module_config: parser.module_config,
+                        forwarded_scope: parser.forwarded_scope,
+                        call_stack: parser.call_stack,
+                        unique_id_count: parser.unique_id_count,
+                        loaded_files: parser.loaded_files,
+                        emitted_warnings: parser.emitted_warnings,
+                        deadline: parser.deadline,
+                    }
+                    .parse_stmt()
+                })?
             } else {
                 Vec::new()
             };
@@ -266,13 +295,10 @@ impl<'a, 'b> Parser<'a, 'b> {
                 scope_at_decl.exit_scope();
             }
 
-            scope_at_decl.merge(mixin_scope);
-
-            if content.declared_at_root {
-                *self.content_scopes = scope_at_decl;
-            } else {
-                *self.scopes = scope_at_decl;
-            }
+            let (mut scopes, include_scopes) = scope_at_decl.split_off(content.scope_len);
+            content.scopes = include_scopes;
+            scopes.merge(mixin_scope);
+            *self.scopes = scopes;
 
             self.content.push(content);
 
@@ -1,16 +1,17 @@
-use std::{convert::TryFrom, path::Path};
+use std::{collections::HashSet, convert::TryFrom, mem, path::Path, time::Instant};
 
 use codemap::{CodeMap, Span, Spanned};
 
 use crate::{
     atrule::{
         keyframes::{Keyframes, KeyframesRuleSet},
-        media::MediaRule,
+        media::{MediaQuery, MediaRule},
         mixin::Content,
-        AtRuleKind, SupportsRule, UnknownAtRule,
+        AtRootQuery, AtRuleKind, SupportsRule, UnknownAtRule,
     },
     builtin::modules::{ModuleConfig, Modules},
-    error::SassResult,
+    compat::{Deprecation, DeprecationStatus},
+    error::{resolve_trace, write_trace, SassError, SassResult, StackFrame},
     lexer::Lexer,
     scope::{Scope, Scopes},
     selector::{
@@ -19,14 +20,17 @@ use crate::{
     style::Style,
     utils::read_until_semicolon_or_closing_curly_brace,
     value::Value,
-    Options, {Cow, Token},
+    CancellationToken, Options, {Cow, Token},
 };
 
+use at_root::{contains_escaping, hoist_at_root, split_escaping, BodyChild};
 use common::{Comment, ContextFlags, NeverEmptyVec, SelectorOrStyle};
+pub(crate) use import::LoadedFiles;
 pub(crate) use value::{HigherIntermediateValue, ValueVisitor};
 use variable::VariableValue;
 
 mod args;
+mod at_root;
 pub mod common;
 mod control_flow;
 mod function;
@@ -37,6 +41,7 @@ mod media;
 mod mixin;
 mod module;
 mod style;
+mod supports;
 mod throw_away;
 mod value;
 mod variable;
@@ -53,6 +58,7 @@ pub(crate) enum Stmt {
     Supports(Box<SupportsRule>),
     AtRoot {
         body: Vec<Stmt>,
+        query: AtRootQuery,
     },
     Comment(String),
     Return(Box<Value>),
@@ -70,7 +76,6 @@ pub(crate) struct Parser<'a, 'b> {
     pub path: &'a Path,
     pub global_scope: &'a mut Scope,
     pub scopes: &'a mut Scopes,
-    pub content_scopes: &'a mut Scopes,
     pub super_selectors: &'a mut NeverEmptyVec<ExtendedSelector>,
     pub span_before: Span,
     pub content: &'a mut Vec<Content>,
@@ -87,6 +92,28 @@ pub(crate) struct Parser<'a, 'b> {
 
     pub modules: &'a mut Modules,
     pub module_config: &'a mut ModuleConfig,
+
+    /// The members this module passes on with `@forward`, which are exported
+    /// along with its global scope but aren't visible within it
+    pub forwarded_scope: &'a mut Scope,
+
+    /// The mixins, functions, and stylesheets currently being
+    /// evaluated, used to produce stack traces
+    pub call_stack: &'a mut Vec<StackFrame>,
+
+    /// The number of identifiers `unique-id()` has generated so far, used
+    /// to derive each identifier from [`Options::unique_id_seed`]
+    pub unique_id_count: &'a mut u64,
+
+    /// The stylesheets loaded so far, used to load each module only once
+    pub loaded_files: &'a mut LoadedFiles,
+
+    /// The warnings printed so far, with where they were emitted, used to
+    /// print each only once unless [`Options::verbose`] is set
+    pub emitted_warnings: &'a mut HashSet<(String, Span)>,
+
+    /// When compilation must stop, if [`Options::timeout`] is set
+    pub deadline: Option<Instant>,
 }
 
 impl<'a, 'b> Parser<'a, 'b> {
@@ -110,6 +137,25 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(stmts)
     }
 
+    /// Evaluate `callback` with a new frame on the call stack
+    ///
+    /// Errors raised inside of `callback` record the stack trace
+    /// at the point they were raised
+    pub fn with_stack_frame<T>(
+        &mut self,
+        name: String,
+        span: Span,
+        callback: impl FnOnce(&mut Self) -> SassResult<T>,
+    ) -> SassResult<T> {
+        self.call_stack.push(StackFrame { name, span });
+
+        let result = callback(self).map_err(|e| e.with_trace(self.call_stack));
+
+        self.call_stack.pop();
+
+        result
+    }
+
     pub fn expect_char(&mut self, c: char) -> SassResult<()> {
         match self.toks.peek() {
             Some(Token { kind, pos }) if kind == c => {
@@ -145,17 +191,42 @@ impl<'a, 'b> Parser<'a, 'b> {
         Err((format!("Expected \"{}\".", ident), this_ident.span).into())
     }
 
+    /// Fail if compilation was cancelled or has run out of time
+    ///
+    /// This is checked before every statement, and before every iteration of
+    /// a loop, in case its body is empty.
+    pub(super) fn check_cancelled(&self) -> SassResult<()> {
+        if self
+            .options
+            .cancellation
+            .map_or(false, CancellationToken::is_cancelled)
+        {
+            return Err(SassError::cancelled("Compilation was cancelled."));
+        }
+
+        if self
+            .deadline
+            .map_or(false, |deadline| Instant::now() >= deadline)
+        {
+            return Err(SassError::cancelled("Compilation timed out."));
+        }
+
+        Ok(())
+    }
+
     fn parse_stmt(&mut self) -> SassResult<Vec<Stmt>> {
         let mut stmts = Vec::new();
         while let Some(Token { kind, pos }) = self.toks.peek() {
             if self.flags.in_function() && !stmts.is_empty() {
                 return Ok(stmts);
             }
+            self.check_cancelled()?;
             self.span_before = pos;
             match kind {
                 '@' => {
-                    self.toks.next();
-                    let kind_string = self.parse_identifier()?;
+                    let at = self.toks.next().unwrap().pos();
+                    let mut kind_string = self.parse_identifier()?;
+                    kind_string.span = at.merge(kind_string.span);
                     self.span_before = kind_string.span;
                     match AtRuleKind::try_from(&kind_string)? {
                         AtRuleKind::Import => stmts.append(&mut self.import()?),
@@ -181,11 +252,12 @@ impl<'a, 'b> Parser<'a, 'b> {
                                     .into());
                             }
 
-                            if self.at_root {
-                                stmts.append(&mut self.parse_at_root()?);
+                            let (body, query) = self.parse_at_root()?;
+
+                            if self.at_root && query == AtRootQuery::default() {
+                                stmts.extend(body);
                             } else {
-                                let body = self.parse_at_root()?;
-                                stmts.push(Stmt::AtRoot { body });
+                                stmts.push(Stmt::AtRoot { body, query });
                             }
                         }
                         AtRuleKind::Error => {
@@ -205,26 +277,38 @@ impl<'a, 'b> Parser<'a, 'b> {
                                 node: message,
                                 span,
                             } = self.parse_value(false, &|_| false)?;
-                            span.merge(kind_string.span);
+                            let span = span.merge(kind_string.span);
 
                             self.consume_char_if_exists(';');
 
+                            // strings are written without their quotes, as in `dart-sass`
+                            let message = match message {
+                                Value::String(s, ..) => Cow::owned(s),
+                                message => message.to_css_string(span, false)?,
+                            };
+
                             self.warn(&Spanned {
-                                node: message.to_css_string(span, false)?,
+                                node: message,
                                 span,
-                            });
+                            })?;
                         }
                         AtRuleKind::Debug => {
                             let Spanned {
                                 node: message,
                                 span,
                             } = self.parse_value(false, &|_| false)?;
-                            span.merge(kind_string.span);
+                            let span = span.merge(kind_string.span);
 
                             self.consume_char_if_exists(';');
 
+                            // strings are written without their quotes, as in `dart-sass`
+                            let message = match message {
+                                Value::String(s, ..) => Cow::owned(s),
+                                message => message.inspect(span)?,
+                            };
+
                             self.debug(&Spanned {
-                                node: message.inspect(span)?,
+                                node: message,
                                 span,
                             });
                         }
@@ -251,9 +335,9 @@ impl<'a, 'b> Parser<'a, 'b> {
 
                             continue;
                         }
-                        AtRuleKind::Media => stmts.push(self.parse_media()?),
+                        AtRuleKind::Media => stmts.append(&mut self.parse_media()?),
                         AtRuleKind::Unknown(_) => {
-                            stmts.push(self.parse_unknown_at_rule(kind_string.node)?);
+                            stmts.append(&mut self.parse_unknown_at_rule(kind_string.node)?);
                         }
                         AtRuleKind::Use => {
                             return Err((
@@ -262,9 +346,15 @@ impl<'a, 'b> Parser<'a, 'b> {
                             )
                                 .into())
                         }
-                        AtRuleKind::Forward => todo!("@forward not yet implemented"),
+                        AtRuleKind::Forward => {
+                            return Err((
+                                "@forward rules must be written before any other rules.",
+                                kind_string.span,
+                            )
+                                .into())
+                        }
                         AtRuleKind::Extend => self.parse_extend()?,
-                        AtRuleKind::Supports => stmts.push(self.parse_supports()?),
+                        AtRuleKind::Supports => stmts.append(&mut self.parse_supports()?),
                         AtRuleKind::Keyframes => {
                             stmts.push(self.parse_keyframes(kind_string.node)?);
                         }
@@ -361,6 +451,21 @@ impl<'a, 'b> Parser<'a, 'b> {
                                     &self.super_selectors.last().clone().into_selector(),
                                     !at_root || self.at_root_has_selector,
                                 )?;
+
+                            for complex in &selector.0.components {
+                                if complex.is_bogus() {
+                                    self.deprecation(
+                                        Deprecation::BogusCombinators,
+                                        &format!(
+                                            "The selector \"{}\" is invalid CSS.\nThis will be an error in Dart Sass 2.0.0.",
+                                            complex
+                                        ),
+                                        &format!("The selector \"{}\" is invalid CSS.", complex),
+                                        selector.0.span,
+                                    )?;
+                                }
+                            }
+
                             self.scopes.enter_new_scope();
 
                             let extended_selector = self.extender.add_selector(selector.0, None);
@@ -387,7 +492,7 @@ impl<'a, 'b> Parser<'a, 'b> {
         &mut self,
         allows_parent: bool,
         from_fn: bool,
-        mut string: String,
+        string: String,
     ) -> SassResult<(Selector, bool)> {
         let mut span = if let Some(tok) = self.toks.peek() {
             tok.pos()
@@ -401,19 +506,28 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         let mut optional = false;
 
-        // we resolve interpolation and strip comments
+        // text parsed by the caller has no better position than the start of the selector
+        let mut sel_toks: Vec<Token> = string.chars().map(|x| Token::new(span, x)).collect();
+
+        // we resolve interpolation and strip comments, keeping the original position
+        // of every token so that errors point into the source rather than at the
+        // selector as a whole
         while let Some(Token { kind, pos }) = self.toks.next() {
             span = span.merge(pos);
             match kind {
                 '#' => {
                     if self.consume_char_if_exists('{') {
-                        string.push_str(
-                            &self
-                                .parse_interpolation()?
-                                .to_css_string(span, self.options.is_compressed())?,
+                        let interpolation = self.parse_interpolation()?;
+                        let interpolation_span = self.consumed_span(pos);
+                        sel_toks.extend(
+                            interpolation
+                                .node
+                                .to_css_string(interpolation.span, self.options.is_compressed())?
+                                .chars()
+                                .map(|x| Token::new(interpolation_span, x)),
                         );
                     } else {
-                        string.push('#');
+                        sel_toks.push(Token::new(pos, '#'));
                     }
                 }
                 '/' => {
@@ -421,7 +535,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                         return Err(("Expected selector.", pos).into());
                     }
                     self.parse_comment()?;
-                    string.push(' ');
+                    sel_toks.push(Token::new(pos, ' '));
                 }
                 '{' => {
                     if from_fn {
@@ -432,9 +546,9 @@ impl<'a, 'b> Parser<'a, 'b> {
                     break;
                 }
                 '\\' => {
-                    string.push('\\');
-                    if let Some(Token { kind, .. }) = self.toks.next() {
-                        string.push(kind);
+                    sel_toks.push(Token::new(pos, '\\'));
+                    if let Some(tok) = self.toks.next() {
+                        sel_toks.push(tok);
                     }
                 }
                 '!' => {
@@ -445,7 +559,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                         return Err(("expected \"{\".", pos).into());
                     }
                 }
-                c => string.push(c),
+                _ => sel_toks.push(Token::new(pos, kind)),
             }
         }
 
@@ -453,8 +567,6 @@ impl<'a, 'b> Parser<'a, 'b> {
             return Err(("expected \"{\".", span).into());
         }
 
-        let sel_toks: Vec<Token> = string.chars().map(|x| Token::new(span, x)).collect();
-
         let mut lexer = Lexer::new(sel_toks);
 
         let selector = SelectorParser::new(
@@ -471,10 +583,15 @@ impl<'a, 'b> Parser<'a, 'b> {
                 at_root: self.at_root,
                 at_root_has_selector: self.at_root_has_selector,
                 extender: self.extender,
-                content_scopes: self.content_scopes,
                 options: self.options,
                 modules: self.modules,
                 module_config: self.module_config,
+                forwarded_scope: self.forwarded_scope,
+                call_stack: self.call_stack,
+                unique_id_count: self.unique_id_count,
+                loaded_files: self.loaded_files,
+                emitted_warnings: self.emitted_warnings,
+                deadline: self.deadline,
             },
             allows_parent,
             true,
@@ -619,7 +736,7 @@ impl<'a, 'b> Parser<'a, 'b> {
 }
 
 impl<'a, 'b> Parser<'a, 'b> {
-    fn parse_unknown_at_rule(&mut self, name: String) -> SassResult<Stmt> {
+    fn parse_unknown_at_rule(&mut self, name: String) -> SassResult<Vec<Stmt>> {
         if self.flags.in_function() {
             return Err(("This at-rule is not allowed here.", self.span_before).into());
         }
@@ -635,13 +752,13 @@ impl<'a, 'b> Parser<'a, 'b> {
                 }
                 Some(Token { kind: ';', .. }) | Some(Token { kind: '}', .. }) | None => {
                     self.consume_char_if_exists(';');
-                    return Ok(Stmt::UnknownAtRule(Box::new(UnknownAtRule {
+                    return Ok(vec![Stmt::UnknownAtRule(Box::new(UnknownAtRule {
                         name,
                         super_selector: Selector::new(self.span_before),
                         has_body: false,
                         params: params.trim().to_owned(),
                         body: Vec::new(),
-                    })));
+                    }))]);
                 }
                 Some(Token { kind: '#', .. }) => {
                     self.toks.next();
@@ -689,16 +806,21 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         body.append(&mut rules);
 
-        Ok(Stmt::UnknownAtRule(Box::new(UnknownAtRule {
-            name,
-            super_selector: Selector::new(self.span_before),
-            params: params.trim().to_owned(),
-            has_body: true,
-            body,
-        })))
+        let span = self.span_before;
+        let params = params.trim().to_owned();
+
+        Ok(hoist_at_root(body, &name.to_ascii_lowercase(), |body| {
+            Stmt::UnknownAtRule(Box::new(UnknownAtRule {
+                name: name.clone(),
+                super_selector: Selector::new(span),
+                params: params.clone(),
+                has_body: true,
+                body,
+            }))
+        }))
     }
 
-    fn parse_media(&mut self) -> SassResult<Stmt> {
+    fn parse_media(&mut self) -> SassResult<Vec<Stmt>> {
         if self.flags.in_function() {
             return Err(("This at-rule is not allowed here.", self.span_before).into());
         }
@@ -730,17 +852,95 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         body.append(&mut rules);
 
-        Ok(Stmt::Media(Box::new(MediaRule {
-            super_selector: Selector::new(self.span_before),
-            query,
-            body,
-        })))
+        let span = self.span_before;
+        let media_rule = |query, body| {
+            Stmt::Media(Box::new(MediaRule {
+                super_selector: Selector::new(span),
+                query,
+                body,
+            }))
+        };
+
+        // nested `@media` rules are written after the rule they are nested in,
+        // with their queries merged with its query, as are `@at-root` rules
+        // that exclude it
+        let escapes = |stmt: &Stmt| match stmt {
+            Stmt::Media(..) => true,
+            Stmt::AtRoot { query, .. } => query.excludes_name("media"),
+            _ => false,
+        };
+
+        if !contains_escaping(&body, &escapes) {
+            return Ok(vec![media_rule(query, body)]);
+        }
+
+        let outer = MediaQuery::parse_list(&query);
+
+        let mut children = Vec::new();
+        split_escaping(body, &escapes, &mut children);
+
+        let mut stmts = Vec::new();
+        let mut body = Vec::new();
+
+        for child in children {
+            let media = match child {
+                BodyChild::Stmt(stmt) => {
+                    body.push(stmt);
+                    continue;
+                }
+                BodyChild::Escaped(Stmt::Media(media)) => *media,
+                BodyChild::Escaped(stmt) => {
+                    if !body.is_empty() {
+                        stmts.push(media_rule(query.clone(), mem::take(&mut body)));
+                    }
+                    stmts.push(stmt);
+                    continue;
+                }
+            };
+
+            match outer
+                .as_ref()
+                .zip(MediaQuery::parse_list(&media.query))
+                .and_then(|(outer, inner)| MediaQuery::merge_lists(outer, &inner))
+            {
+                // no media can match both queries, so the nested rule never applies
+                Some(merged) if merged.is_empty() => {}
+                Some(merged) => {
+                    if !body.is_empty() {
+                        stmts.push(media_rule(query.clone(), mem::take(&mut body)));
+                    }
+                    stmts.push(media_rule(MediaQuery::serialize_list(&merged), media.body));
+                }
+                None => body.push(Stmt::Media(Box::new(media))),
+            }
+        }
+
+        if !body.is_empty() || stmts.is_empty() {
+            stmts.push(media_rule(query, body));
+        }
+
+        Ok(stmts)
     }
 
-    fn parse_at_root(&mut self) -> SassResult<Vec<Stmt>> {
+    fn parse_at_root(&mut self) -> SassResult<(Vec<Stmt>, AtRootQuery)> {
         self.whitespace();
         let mut at_root_has_selector = false;
+        let mut query = AtRootQuery::default();
         let at_rule_selector = if self.consume_char_if_exists('{') {
+            self.super_selectors.last().clone()
+        } else if matches!(self.toks.peek(), Some(Token { kind: '(', .. })) {
+            query = self.parse_at_root_query()?;
+            self.whitespace_or_comment();
+            self.expect_char('{')?;
+
+            // when the contents stay inside style rules, they are nested in
+            // the current one as usual
+            if !query.excludes_style_rules()
+                && !self.super_selectors.last().as_selector_list().is_empty()
+            {
+                at_root_has_selector = true;
+            }
+
             self.super_selectors.last().clone()
         } else {
             at_root_has_selector = true;
@@ -772,10 +972,15 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: true,
             at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
         .parse_stmt()?
         .into_iter()
@@ -808,7 +1013,7 @@ impl<'a, 'b> Parser<'a, 'b> {
             raw_stmts
         };
 
-        Ok(stmts)
+        Ok((stmts, query))
     }
 
     fn parse_extend(&mut self) -> SassResult<()> {
@@ -832,10 +1037,15 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
         .parse_selector(false, true, String::new())?;
 
@@ -860,6 +1070,14 @@ impl<'a, 'b> Parser<'a, 'b> {
                 Some(..) | None => todo!(),
             };
             if compound.components.len() != 1 {
+                if self.options.libsass_compat {
+                    self.extender.add_compound_extension(
+                        super_selector.clone().into_selector().0,
+                        compound.clone(),
+                    );
+                    continue;
+                }
+
                 return Err((
                     format!(
                         "compound selectors may no longer be extended.\nConsider `@extend {}` instead.\nSee http://bit.ly/ExtendCompound for details.\n",
@@ -880,16 +1098,17 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(())
     }
 
-    fn parse_supports(&mut self) -> SassResult<Stmt> {
+    fn parse_supports(&mut self) -> SassResult<Vec<Stmt>> {
         if self.flags.in_function() {
             return Err(("This at-rule is not allowed here.", self.span_before).into());
         }
 
-        let params = self.parse_media_args()?;
+        self.whitespace_or_comment();
 
-        if params.is_empty() {
-            return Err(("Expected \"not\".", self.span_before).into());
-        }
+        let params = self.parse_supports_condition()?;
+
+        self.whitespace_or_comment();
+        self.expect_char('{')?;
 
         let raw_body = self.parse_stmt()?;
 
@@ -912,44 +1131,12 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         body.append(&mut rules);
 
-        Ok(Stmt::Supports(Box::new(SupportsRule {
-            params: params.trim().to_owned(),
-            body,
-        })))
-    }
-
-    // todo: we should use a specialized struct to represent these
-    fn parse_media_args(&mut self) -> SassResult<String> {
-        let mut params = String::new();
-        self.whitespace();
-        while let Some(tok) = self.toks.next() {
-            match tok.kind {
-                '{' => break,
-                '#' => {
-                    if let Some(Token { kind: '{', pos }) = self.toks.peek() {
-                        self.toks.next();
-                        self.span_before = pos;
-                        let interpolation = self.parse_interpolation()?;
-                        params.push_str(
-                            &interpolation
-                                .node
-                                .to_css_string(interpolation.span, self.options.is_compressed())?,
-                        );
-                        continue;
-                    }
-
-                    params.push(tok.kind);
-                }
-                '\n' | ' ' | '\t' => {
-                    self.whitespace();
-                    params.push(' ');
-                    continue;
-                }
-                _ => {}
-            }
-            params.push(tok.kind);
-        }
-        Ok(params)
+        Ok(hoist_at_root(body, "supports", |body| {
+            Stmt::Supports(Box::new(SupportsRule {
+                params: params.clone(),
+                body,
+            }))
+        }))
     }
 }
 
@@ -959,25 +1146,63 @@ impl<'a, 'b> Parser<'a, 'b> {
             return;
         }
         let loc = self.map.look_up_span(message.span);
-        eprintln!(
-            "{}:{} DEBUG: {}",
-            loc.file.name(),
-            loc.begin.line + 1,
-            message.node
-        );
+        self.options
+            .logger
+            .debug(loc.file.name(), loc.begin.line + 1, &message.node);
     }
 
-    fn warn(&self, message: &Spanned<Cow<'a, str>>) {
+    /// Prints a warning with the current stack trace, or fails if
+    /// [`Options::fatal_warnings`] is set
+    ///
+    /// A warning that has already been printed for the same location is
+    /// skipped unless [`Options::verbose`] is set.
+    pub(crate) fn warn(&mut self, message: &Spanned<Cow<'a, str>>) -> SassResult<()> {
+        if self.options.fatal_warnings {
+            return Err((message.node.to_string(), message.span).into());
+        }
+
         if self.options.quiet {
-            return;
+            return Ok(());
         }
-        let loc = self.map.look_up_span(message.span);
-        eprintln!(
-            "Warning: {}\n    {} {}:{}  root stylesheet",
-            message.node,
-            loc.file.name(),
-            loc.begin.line + 1,
-            loc.begin.column + 1
+
+        if !self.options.verbose
+            && !self
+                .emitted_warnings
+                .insert((message.node.to_string(), message.span))
+        {
+            return Ok(());
+        }
+
+        let mut trace = String::new();
+
+        // writing to a `String` cannot fail
+        let _ = write_trace(
+            &mut trace,
+            &resolve_trace(self.call_stack, message.span, self.map),
+            "",
         );
+
+        self.options.logger.warn(&message.node, &trace);
+
+        Ok(())
+    }
+
+    /// Reports the use of deprecated behavior at `span` as the release targeted
+    /// by [`Options::compat`] would: not at all, with `warning`, or with `error`
+    pub(crate) fn deprecation(
+        &mut self,
+        deprecation: Deprecation,
+        warning: &str,
+        error: &str,
+        span: Span,
+    ) -> SassResult<()> {
+        match self.options.deprecation_status(deprecation) {
+            DeprecationStatus::Supported => Ok(()),
+            DeprecationStatus::Deprecated => self.warn(&Spanned {
+                node: Cow::owned(warning.to_owned()),
+                span,
+            }),
+            DeprecationStatus::Removed => Err((error, span).into()),
+        }
     }
 }
@@ -1,4 +1,4 @@
-use std::convert::TryFrom;
+use std::{collections::HashSet, convert::TryFrom};
 
 use codemap::Spanned;
 
@@ -13,10 +13,66 @@ use crate::{
     error::SassResult,
     lexer::Lexer,
     parse::{common::Comment, Parser, Stmt, VariableValue},
-    scope::Scope,
+    scope::{Scope, Scopes},
+    utils::path_to_url,
+    value::Value,
     Token,
 };
 
+/// The namespace of a module loaded from `url` without an `as` clause: its
+/// file name, without any scheme, leading underscore or extension
+fn default_namespace(url: &str) -> String {
+    let basename = url
+        .rsplit(|c| c == '/' || c == '\\' || c == ':')
+        .next()
+        .unwrap_or(url);
+    let basename = basename.strip_prefix('_').unwrap_or(basename);
+
+    basename.split('.').next().unwrap_or(basename).to_owned()
+}
+
+/// Whether a member may be used outside of its module
+fn is_public(name: Identifier) -> bool {
+    !name.as_str().starts_with('-')
+}
+
+/// The names listed in the `show` or `hide` clause of an `@forward` rule
+#[derive(Debug, Default)]
+struct ForwardedNames {
+    variables: HashSet<Identifier>,
+
+    /// Mixins and functions share a namespace in these clauses, as they are
+    /// both written without a sigil
+    mixins_and_functions: HashSet<Identifier>,
+}
+
+/// The members of a module that an `@forward` rule passes on, with their
+/// prefix
+#[derive(Debug)]
+enum ForwardVisibility {
+    All,
+    Show(ForwardedNames),
+    Hide(ForwardedNames),
+}
+
+impl ForwardVisibility {
+    fn forwards_variable(&self, name: Identifier) -> bool {
+        match self {
+            Self::All => true,
+            Self::Show(names) => names.variables.contains(&name),
+            Self::Hide(names) => !names.variables.contains(&name),
+        }
+    }
+
+    fn forwards_mixin_or_function(&self, name: Identifier) -> bool {
+        match self {
+            Self::All => true,
+            Self::Show(names) => names.mixins_and_functions.contains(&name),
+            Self::Hide(names) => !names.mixins_and_functions.contains(&name),
+        }
+    }
+}
+
 impl<'a, 'b> Parser<'a, 'b> {
     fn parse_module_alias(&mut self) -> SassResult<Option<String>> {
         if !matches!(
@@ -70,9 +126,13 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         loop {
             self.whitespace_or_comment();
+
+            let start = self.toks.peek().map_or(self.span_before, |tok| tok.pos);
+
             self.expect_char('$')?;
 
-            let name = self.parse_identifier_no_interpolation(false)?;
+            let mut name = self.parse_identifier_no_interpolation(false)?;
+            name.span = start.merge(name.span);
 
             self.whitespace_or_comment();
             self.expect_char(':')?;
@@ -89,7 +149,11 @@ impl<'a, 'b> Parser<'a, 'b> {
 
             match self.toks.next() {
                 Some(Token { kind: ',', .. }) => {
-                    continue;
+                    self.whitespace_or_comment();
+
+                    if self.consume_char_if_exists(')') {
+                        break;
+                    }
                 }
                 Some(Token { kind: ')', .. }) => {
                     break;
@@ -103,10 +167,190 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(config)
     }
 
+    /// Parses a configuration value passed to
+    /// [`Parser::parse_with_config`], which must be a single expression
+    fn parse_config_value(&mut self) -> SassResult<Spanned<Value>> {
+        let value = self.parse_value(false, &|_| false)?;
+
+        self.whitespace_or_comment();
+
+        match self.toks.peek() {
+            Some(tok) => Err(("expected no more input.", tok.pos).into()),
+            None => Ok(value),
+        }
+    }
+
+    /// Parses the `as prefix-*` clause of an `@forward` rule, returning the
+    /// prefix, or an empty string if there is none
+    fn parse_forward_prefix(&mut self) -> SassResult<String> {
+        if !matches!(
+            self.toks.peek(),
+            Some(Token { kind: 'a', .. }) | Some(Token { kind: 'A', .. })
+        ) {
+            return Ok(String::new());
+        }
+
+        let mut ident = self.parse_identifier_no_interpolation(false)?;
+
+        ident.node.make_ascii_lowercase();
+
+        if ident.node != "as" {
+            return Err(("expected \";\".", ident.span).into());
+        }
+
+        self.whitespace_or_comment();
+
+        let prefix = self.parse_identifier_no_interpolation(false)?;
+
+        self.expect_char('*')?;
+
+        Ok(prefix.node.replace('_', "-"))
+    }
+
+    /// Parses the `show` or `hide` clause of an `@forward` rule
+    fn parse_forward_visibility(&mut self) -> SassResult<ForwardVisibility> {
+        if !matches!(
+            self.toks.peek(),
+            Some(Token { kind: 's', .. })
+                | Some(Token { kind: 'S', .. })
+                | Some(Token { kind: 'h', .. })
+                | Some(Token { kind: 'H', .. })
+        ) {
+            return Ok(ForwardVisibility::All);
+        }
+
+        let mut ident = self.parse_identifier_no_interpolation(false)?;
+
+        ident.node.make_ascii_lowercase();
+
+        let is_show = match ident.node.as_str() {
+            "show" => true,
+            "hide" => false,
+            _ => return Err(("expected \";\".", ident.span).into()),
+        };
+
+        let mut names = ForwardedNames::default();
+
+        loop {
+            self.whitespace_or_comment();
+
+            if self.consume_char_if_exists('$') {
+                let name = self.parse_identifier_no_interpolation(false)?;
+                names.variables.insert(name.node.into());
+            } else {
+                let name = self.parse_identifier_no_interpolation(false)?;
+                names.mixins_and_functions.insert(name.node.into());
+            }
+
+            self.whitespace_or_comment();
+
+            if !self.consume_char_if_exists(',') {
+                break;
+            }
+        }
+
+        Ok(if is_show {
+            ForwardVisibility::Show(names)
+        } else {
+            ForwardVisibility::Hide(names)
+        })
+    }
+
+    /// Parses an `@forward` rule, after the name, loading the module and
+    /// adding its members to those this module forwards
+    ///
+    /// Returns the CSS the module emits.
+    fn parse_forward(&mut self) -> SassResult<Vec<Stmt>> {
+        self.whitespace_or_comment();
+
+        let quote = match self.toks.next() {
+            Some(Token { kind: q @ '"', .. }) | Some(Token { kind: q @ '\'', .. }) => q,
+            Some(..) | None => return Err(("Expected string.", self.span_before).into()),
+        };
+
+        let Spanned { node: url, span } = self.parse_quoted_string(quote)?;
+        let url = url
+            .unquote()
+            .to_css_string(span, self.options.is_compressed())?;
+
+        self.whitespace_or_comment();
+
+        let prefix = self.parse_forward_prefix()?;
+
+        self.whitespace_or_comment();
+
+        let visibility = self.parse_forward_visibility()?;
+
+        self.whitespace_or_comment();
+        self.expect_char(';')?;
+
+        self.span_before = span;
+
+        // configuration of this module passes through to the modules it
+        // forwards
+        let mut config = self
+            .module_config
+            .split_forwarded(&prefix, |name| visibility.forwards_variable(name));
+
+        let (module, stmts) = self.load_module(url.as_ref(), &mut config, true)?;
+
+        self.module_config.restore_forwarded(config, &prefix);
+
+        let prefixed = |name: &Identifier| Identifier::from(format!("{}{}", prefix, name));
+
+        for (name, value) in module
+            .scope
+            .vars
+            .iter()
+            .filter(|(name, _)| is_public(**name))
+        {
+            let name = prefixed(name);
+            if visibility.forwards_variable(name) {
+                self.forwarded_scope.insert_var(name, value.clone());
+            }
+        }
+
+        for (name, mixin) in module
+            .scope
+            .mixins
+            .iter()
+            .filter(|(name, _)| is_public(**name))
+        {
+            let name = prefixed(name);
+            if visibility.forwards_mixin_or_function(name) {
+                self.forwarded_scope.insert_mixin(name, mixin.clone());
+            }
+        }
+
+        for (name, function) in module
+            .scope
+            .functions
+            .iter()
+            .filter(|(name, _)| is_public(**name))
+        {
+            let name = prefixed(name);
+            if visibility.forwards_mixin_or_function(name) {
+                self.forwarded_scope.insert_fn(name, function.clone());
+            }
+        }
+
+        Ok(stmts)
+    }
+
+    /// Loads the module at `name`, returning it along with the CSS it emits
+    ///
+    /// `@use` only evaluates each module once per compilation, so when
+    /// `reuse_loaded` is set, a module that has already been loaded is
+    /// returned again without any CSS. `meta.load-css()` evaluates the module
+    /// every time.
+    ///
+    /// Unless `config` was passed on by `@forward`, it's an error for the
+    /// module not to declare every variable in it.
     pub fn load_module(
         &mut self,
         name: &str,
         config: &mut ModuleConfig,
+        reuse_loaded: bool,
     ) -> SassResult<(Module, Vec<Stmt>)> {
         Ok(match name {
             "sass:color" => (declare_module_color(), Vec::new()),
@@ -117,45 +361,91 @@ impl<'a, 'b> Parser<'a, 'b> {
             "sass:selector" => (declare_module_selector(), Vec::new()),
             "sass:string" => (declare_module_string(), Vec::new()),
             _ => {
-                if let Some(import) = self.find_import(name.as_ref()) {
+                if let Some(import) = self.find_import(name) {
+                    self.check_import_depth(self.span_before)?;
+
+                    let key = self.record_load(&import, self.span_before)?;
+
+                    if reuse_loaded {
+                        if let Some(module) = self.loaded_module(&key) {
+                            if !config.is_empty() && !config.is_implicit() {
+                                return Err((
+                                    "This module was already loaded, so it can't be configured using \"with\".",
+                                    self.span_before,
+                                )
+                                    .into());
+                            }
+
+                            return Ok((module, Vec::new()));
+                        }
+
+                        if config.is_empty() {
+                            if let Some(module) = self.shared_module(&key) {
+                                return Ok((module, Vec::new()));
+                            }
+                        }
+                    }
+
+                    let configured = !config.is_empty();
+                    let checkpoint = self.load_checkpoint();
+
                     let mut global_scope = Scope::new();
+                    let mut forwarded_scope = Scope::new();
 
-                    let file = self.map.add_file(
-                        name.to_owned(),
-                        String::from_utf8(self.options.fs.read(&import)?)?,
-                    );
+                    let file = self
+                        .map
+                        .add_file(path_to_url(&import), self.read_import(&import)?);
 
                     let mut modules = Modules::default();
 
-                    let stmts = Parser {
-                        toks: &mut Lexer::new_from_file(&file),
-                        map: self.map,
-                        path: &import,
-                        scopes: self.scopes,
-                        global_scope: &mut global_scope,
-                        super_selectors: self.super_selectors,
-                        span_before: file.span.subspan(0, 0),
-                        content: self.content,
-                        flags: self.flags,
-                        at_root: self.at_root,
-                        at_root_has_selector: self.at_root_has_selector,
-                        extender: self.extender,
-                        content_scopes: self.content_scopes,
-                        options: self.options,
-                        modules: &mut modules,
-                        module_config: config,
+                    let span = self.span_before;
+                    let stmts = self.with_stack_frame("@use".to_owned(), span, |parser| {
+                        Parser {
+                            toks: &mut Lexer::new_from_file(&file),
+                            map: parser.map,
+                            path: &import,
+                            scopes: &mut Scopes::new(),
+                            global_scope: &mut global_scope,
+                            super_selectors: parser.super_selectors,
+                            span_before: file.span.subspan(0, 0),
+                            content: parser.content,
+                            flags: parser.flags,
+                            // the module's variables are global to it even when
+                            // `meta.load-css()` nests its CSS in a style rule
+                            at_root: true,
+                            at_root_has_selector: parser.at_root_has_selector || !parser.at_root,
+                            extender: parser.extender,
+                            options: parser.options,
+                            modules: &mut modules,
+                            module_config: config,
+                            forwarded_scope: &mut forwarded_scope,
+                            call_stack: parser.call_stack,
+                            unique_id_count: parser.unique_id_count,
+                            loaded_files: parser.loaded_files,
+                            emitted_warnings: parser.emitted_warnings,
+                            deadline: parser.deadline,
+                        }
+                        .parse()
+                    })?;
+
+                    if !config.is_empty() && !config.is_implicit() {
+                        return Err(config.undeclared_error(self.span_before));
                     }
-                    .parse()?;
 
-                    if !config.is_empty() {
-                        return Err((
-                            "This variable was not declared with !default in the @used module.",
-                            self.span_before,
-                        )
-                            .into());
+                    // the module's own members take precedence over those it
+                    // forwards
+                    forwarded_scope.merge_module_scope(global_scope);
+
+                    let module = Module::new_from_scope(forwarded_scope, modules, false);
+
+                    if reuse_loaded {
+                        // a module that emits CSS is evaluated again in
+                        // each compilation, since `@extend` may change it
+                        let shareable = !configured && stmts.is_empty();
+                        self.insert_loaded_module(key, module.clone(), shareable, checkpoint);
                     }
 
-                    (Module::new_from_scope(global_scope, modules, false), stmts)
+                    (module, stmts)
                 } else {
                     return Err(("Can't find stylesheet to import.", self.span_before).into());
                 }
@@ -163,6 +453,72 @@ impl<'a, 'b> Parser<'a, 'b> {
         })
     }
 
+    /// Parses the entrypoint as though it were loaded with
+    /// `@use ... with (...)`, configuring its `!default` variables (and those
+    /// of the files it imports) with the given names and SassScript values
+    pub fn parse_with_config(&mut self, config: &[(&str, &str)]) -> SassResult<Vec<Stmt>> {
+        if config.is_empty() {
+            return self.parse();
+        }
+
+        let mut module_config = ModuleConfig::default();
+
+        for (name, value) in config {
+            // each value is parsed on its own so that it can't end the
+            // configuration early or add anything but a single expression
+            let file = self
+                .map
+                .add_file("configuration".to_owned(), (*value).to_owned());
+
+            let value = Parser {
+                toks: &mut Lexer::new_from_file(&file),
+                map: self.map,
+                path: self.path,
+                scopes: self.scopes,
+                global_scope: self.global_scope,
+                super_selectors: self.super_selectors,
+                span_before: file.span.subspan(0, 0),
+                content: self.content,
+                flags: self.flags,
+                at_root: self.at_root,
+                at_root_has_selector: self.at_root_has_selector,
+                extender: self.extender,
+                options: self.options,
+                modules: self.modules,
+                module_config: self.module_config,
+                forwarded_scope: self.forwarded_scope,
+                call_stack: self.call_stack,
+                unique_id_count: self.unique_id_count,
+                loaded_files: self.loaded_files,
+                emitted_warnings: self.emitted_warnings,
+                deadline: self.deadline,
+            }
+            .parse_config_value()?;
+
+            module_config.insert(
+                Spanned {
+                    node: Identifier::from(name.trim_start_matches('$')),
+                    span: file.span,
+                },
+                value,
+            )?;
+        }
+
+        *self.module_config = module_config;
+
+        let stmts = self.parse()?;
+
+        if !self.module_config.is_empty() {
+            return Err((
+                "This variable was not declared with !default in the entrypoint.",
+                self.module_config.first_span().unwrap_or(self.span_before),
+            )
+                .into());
+        }
+
+        Ok(stmts)
+    }
+
     /// Returns any multiline comments that may have been found
     /// while loading modules
     pub(super) fn load_modules(&mut self) -> SassResult<Vec<Stmt>> {
@@ -178,7 +534,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                     self.toks.next();
 
                     if let Some(Token { kind, .. }) = self.toks.peek() {
-                        if !matches!(kind, 'u' | 'U' | '\\') {
+                        if !matches!(kind, 'u' | 'U' | 'f' | 'F' | '\\') {
                             self.toks.set_cursor(start);
                             break;
                         }
@@ -186,9 +542,16 @@ impl<'a, 'b> Parser<'a, 'b> {
 
                     let ident = self.parse_identifier_no_interpolation(false)?;
 
-                    if AtRuleKind::try_from(&ident)? != AtRuleKind::Use {
-                        self.toks.set_cursor(start);
-                        break;
+                    match AtRuleKind::try_from(&ident)? {
+                        AtRuleKind::Use => {}
+                        AtRuleKind::Forward => {
+                            comments.append(&mut self.parse_forward()?);
+                            continue;
+                        }
+                        _ => {
+                            self.toks.set_cursor(start);
+                            break;
+                        }
                     }
 
                     self.whitespace_or_comment();
@@ -216,8 +579,10 @@ impl<'a, 'b> Parser<'a, 'b> {
                     self.whitespace_or_comment();
                     self.expect_char(';')?;
 
+                    self.span_before = span;
+
                     let (module, mut stmts) =
-                        self.load_module(module_name.as_ref(), &mut config)?;
+                        self.load_module(module_name.as_ref(), &mut config, true)?;
 
                     comments.append(&mut stmts);
 
@@ -242,7 +607,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                             "sass:meta" => "meta".to_owned(),
                             "sass:selector" => "selector".to_owned(),
                             "sass:string" => "string".to_owned(),
-                            _ => module_name.into_owned(),
+                            _ => default_namespace(&module_name),
                         },
                     };
 
@@ -127,14 +127,16 @@ impl<'a, 'b> Parser<'a, 'b> {
                             if let Some(toks) =
                                 self.parse_style_value_when_no_space_after_semicolon()
                             {
+                                // the value is followed by `;` or `}` rather
+                                // than `{`, so this can't be a selector and any
+                                // error in the value is reported as is
                                 let len = toks.len();
-                                if let Ok(val) = self.parse_value_from_vec(&toks, false) {
-                                    self.toks.take(len).for_each(drop);
-                                    return Ok(SelectorOrStyle::Style(
-                                        InternedString::get_or_intern(property),
-                                        Some(Box::new(val)),
-                                    ));
-                                }
+                                let val = self.parse_value_from_vec(&toks, false)?;
+                                self.toks.take(len).for_each(drop);
+                                return Ok(SelectorOrStyle::Style(
+                                    InternedString::get_or_intern(property),
+                                    Some(Box::new(val)),
+                                ));
                             }
 
                             if whitespace_after_property {
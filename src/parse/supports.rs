@@ -0,0 +1,130 @@
+use crate::{error::SassResult, Token};
+
+use super::Parser;
+
+impl<'a, 'b> Parser<'a, 'b> {
+    /// Parses the condition of an `@supports` rule, evaluating the names and
+    /// values of the declarations it contains
+    pub(super) fn parse_supports_condition(&mut self) -> SassResult<String> {
+        if self.scan_identifier("not", true) {
+            self.whitespace_or_comment();
+            return Ok(format!(
+                "not {}",
+                self.parse_supports_condition_in_parens()?
+            ));
+        }
+
+        let mut condition = self.parse_supports_condition_in_parens()?;
+        self.whitespace_or_comment();
+
+        // `and` and `or` may not be mixed without parentheses, so once one has
+        // been used the other ends the condition
+        let mut operator = None;
+
+        loop {
+            let next = if operator != Some("or") && self.scan_identifier("and", true) {
+                "and"
+            } else if operator != Some("and") && self.scan_identifier("or", true) {
+                "or"
+            } else {
+                break;
+            };
+
+            operator = Some(next);
+
+            self.whitespace_or_comment();
+
+            condition.push(' ');
+            condition.push_str(next);
+            condition.push(' ');
+            condition.push_str(&self.parse_supports_condition_in_parens()?);
+
+            self.whitespace_or_comment();
+        }
+
+        Ok(condition)
+    }
+
+    fn parse_supports_condition_in_parens(&mut self) -> SassResult<String> {
+        if self.consume_char_if_exists('#') {
+            self.expect_char('{')?;
+            return Ok(self.parse_interpolation_as_string()?.into_owned());
+        }
+
+        // a function such as `selector(a > b)`, whose arguments are left as
+        // they are
+        if self.looking_at_identifier() {
+            let name = self.parse_identifier()?.node;
+            self.expect_char('(')?;
+            let args = self.declaration_value(true, false, true)?;
+            self.expect_char(')')?;
+            return Ok(format!("{}({})", name, args.trim()));
+        }
+
+        self.expect_char('(')?;
+        self.whitespace_or_comment();
+
+        if matches!(self.toks.peek(), Some(Token { kind: '(', .. })) {
+            let condition = self.parse_supports_condition()?;
+            self.whitespace_or_comment();
+            self.expect_char(')')?;
+            return Ok(format!("({})", condition));
+        }
+
+        let start = self.toks.cursor();
+
+        if self.scan_identifier("not", true) && self.whitespace_or_comment() {
+            let condition = self.parse_supports_condition_in_parens()?;
+            self.whitespace_or_comment();
+            self.expect_char(')')?;
+            return Ok(format!("(not {})", condition));
+        }
+
+        self.toks.set_cursor(start);
+
+        self.parse_supports_declaration()
+    }
+
+    /// Parses a declaration such as `(display: grid)`, after its opening
+    /// parenthesis
+    fn parse_supports_declaration(&mut self) -> SassResult<String> {
+        let is_custom_property = matches!(self.toks.peek(), Some(Token { kind: '-', .. }))
+            && matches!(self.toks.peek_n(1), Some(Token { kind: '-', .. }));
+
+        let name = if is_custom_property {
+            self.parse_identifier()?.node
+        } else {
+            let name = self.parse_value(false, &|parser| {
+                matches!(parser.toks.peek(), Some(Token { kind: ':', .. }))
+            })?;
+
+            name.node
+                .to_css_string(name.span, self.options.is_compressed())?
+                .into_owned()
+        };
+
+        self.whitespace_or_comment();
+        self.expect_char(':')?;
+        self.whitespace_or_comment();
+
+        // the values of custom properties are never evaluated
+        let value = if is_custom_property {
+            self.declaration_value(false, false, true)?
+                .trim()
+                .to_owned()
+        } else {
+            let value = self.parse_value(false, &|parser| {
+                matches!(parser.toks.peek(), Some(Token { kind: ')', .. }))
+            })?;
+
+            value
+                .node
+                .to_css_string(value.span, self.options.is_compressed())?
+                .into_owned()
+        };
+
+        self.expect_char(')')?;
+
+        Ok(format!("({}: {})", name, value))
+    }
+}
@@ -0,0 +1,245 @@
+use codemap::Spanned;
+
+use crate::{
+    common::QuoteKind,
+    error::SassResult,
+    value::{CalculationArg, CalculationName, CalculationOperator, SassCalculation, Value},
+    Token,
+};
+
+use super::super::Parser;
+
+impl<'a, 'b> Parser<'a, 'b> {
+    /// Parses the arguments of `calc()`, `min()`, `max()`, or `clamp()`,
+    /// after the opening parenthesis, simplifying the calculation as far as
+    /// possible
+    ///
+    /// Returns `None`, having consumed nothing, if the arguments aren't a valid
+    /// calculation, in which case they should be parsed as they were before
+    /// calculations were first-class values, e.g. `min()` as the Sass function
+    pub(super) fn try_parse_calculation(
+        &mut self,
+        name: CalculationName,
+    ) -> SassResult<Option<Value>> {
+        let start = self.toks.cursor();
+
+        let args = match self.parse_calculation_args(name)? {
+            Some(args) => args,
+            None => {
+                self.toks.set_cursor(start);
+                return Ok(None);
+            }
+        };
+
+        Ok(match name {
+            CalculationName::Calc if args.len() == 1 => {
+                Some(SassCalculation::calc(args.into_iter().next().unwrap()))
+            }
+            // `min()`, `max()`, and `clamp()` are left as written, as Sass has
+            // its own `min()` and `max()` functions for comparing numbers
+            CalculationName::Min | CalculationName::Max => {
+                Some(Value::Calculation(SassCalculation { name, args }))
+            }
+            // a single interpolated argument may hold all three of `clamp()`'s
+            CalculationName::Clamp
+                if args.len() == 3
+                    || matches!(
+                        args.as_slice(),
+                        [CalculationArg::Interpolation(..)] | [CalculationArg::String(..)]
+                    ) =>
+            {
+                Some(Value::Calculation(SassCalculation { name, args }))
+            }
+            _ => {
+                self.toks.set_cursor(start);
+                None
+            }
+        })
+    }
+
+    fn parse_calculation_args(
+        &mut self,
+        name: CalculationName,
+    ) -> SassResult<Option<Vec<CalculationArg>>> {
+        let mut args = Vec::new();
+
+        loop {
+            self.whitespace();
+
+            match self.parse_calculation_sum(name)? {
+                Some(arg) => args.push(arg),
+                None => return Ok(None),
+            }
+
+            self.whitespace();
+
+            if self.consume_char_if_exists(')') {
+                return Ok(Some(args));
+            }
+
+            if !self.consume_char_if_exists(',') {
+                return Ok(None);
+            }
+        }
+    }
+
+    fn parse_calculation_sum(
+        &mut self,
+        name: CalculationName,
+    ) -> SassResult<Option<CalculationArg>> {
+        let start = match self.toks.peek() {
+            Some(tok) => tok.pos,
+            None => return Ok(None),
+        };
+
+        let mut sum = match self.parse_calculation_product(name)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        loop {
+            let had_whitespace = self.whitespace();
+
+            let op = match self.toks.peek() {
+                Some(Token { kind: '+', .. }) => CalculationOperator::Plus,
+                Some(Token { kind: '-', .. }) => CalculationOperator::Minus,
+                _ => return Ok(Some(sum)),
+            };
+
+            // `+` and `-` must be surrounded by whitespace, so that they aren't
+            // confused with the signs of numbers
+            if !had_whitespace {
+                return Ok(None);
+            }
+
+            self.toks.next();
+
+            if !self.whitespace() {
+                return Ok(None);
+            }
+
+            let rhs = match self.parse_calculation_product(name)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+
+            let span = start.merge(self.toks.prev().map_or(start, |tok| tok.pos));
+            sum = CalculationArg::operate(sum, op, rhs, name.is_min_or_max(), span)?;
+        }
+    }
+
+    fn parse_calculation_product(
+        &mut self,
+        name: CalculationName,
+    ) -> SassResult<Option<CalculationArg>> {
+        let start = match self.toks.peek() {
+            Some(tok) => tok.pos,
+            None => return Ok(None),
+        };
+
+        let mut product = match self.parse_calculation_value(name)? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        loop {
+            let before_whitespace = self.toks.cursor();
+            self.whitespace();
+
+            let op = match self.toks.peek() {
+                Some(Token { kind: '*', .. }) => CalculationOperator::Mul,
+                Some(Token { kind: '/', .. }) => CalculationOperator::Div,
+                _ => {
+                    // the whitespace is needed to tell if there is a sum
+                    self.toks.set_cursor(before_whitespace);
+                    return Ok(Some(product));
+                }
+            };
+
+            self.toks.next();
+            self.whitespace();
+
+            let rhs = match self.parse_calculation_value(name)? {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+
+            let span = start.merge(self.toks.prev().map_or(start, |tok| tok.pos));
+            product = CalculationArg::operate(product, op, rhs, name.is_min_or_max(), span)?;
+        }
+    }
+
+    fn parse_calculation_value(
+        &mut self,
+        name: CalculationName,
+    ) -> SassResult<Option<CalculationArg>> {
+        let is_negative = match (self.toks.peek(), self.toks.peek_n(1)) {
+            (Some(Token { kind: '(', .. }), _) => {
+                self.toks.next();
+                self.whitespace();
+
+                let value = self.parse_calculation_sum(name)?;
+
+                self.whitespace();
+
+                return Ok(if self.consume_char_if_exists(')') {
+                    value
+                } else {
+                    None
+                });
+            }
+            (Some(Token { kind: '#', .. }), Some(Token { kind: '{', .. })) => {
+                self.toks.next();
+                self.toks.next();
+
+                return Ok(Some(CalculationArg::Interpolation(
+                    self.parse_interpolation_as_string()?.into_owned(),
+                )));
+            }
+            (Some(Token { kind, .. }), Some(Token { kind: next, .. }))
+                if (kind == '+' || kind == '-') && (next.is_ascii_digit() || next == '.') =>
+            {
+                self.toks.next();
+                kind == '-'
+            }
+            (Some(Token { kind, .. }), _)
+                if kind.is_ascii_digit() || kind == '.' || kind == '$' =>
+            {
+                false
+            }
+            _ if self.looking_at_identifier() => false,
+            _ => return Ok(None),
+        };
+
+        let Spanned { node: value, span } = match self.parse_single_value()? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        Ok(Some(match value {
+            Value::Dimension(Some(num), unit, _) if is_negative => {
+                CalculationArg::Number(-num, unit)
+            }
+            Value::Dimension(Some(num), unit, _) => CalculationArg::Number(num, unit),
+            // a nested `calc()` is just its argument
+            Value::Calculation(calc) if calc.name == CalculationName::Calc => {
+                calc.args.into_iter().next().unwrap()
+            }
+            Value::Calculation(calc) => CalculationArg::Calculation(calc),
+            Value::String(s, QuoteKind::None) if !is_negative => CalculationArg::String(s),
+            // `min()` and `max()` may instead be calls to the Sass functions,
+            // which accept more than calculations do
+            _ if name.is_min_or_max() => return Ok(None),
+            value => {
+                return Err((
+                    format!(
+                        "Value {} can't be used in a calculation.",
+                        value.inspect(span)?
+                    ),
+                    span,
+                )
+                    .into())
+            }
+        }))
+    }
+}
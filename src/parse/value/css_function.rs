@@ -166,21 +166,21 @@ impl<'a, 'b> Parser<'a, 'b> {
                     }
                 }
                 'c' | 'C' => {
-                    if let Some(name) = self.try_parse_min_max_function("calc")? {
+                    if let Some(name) = self.try_parse_min_max_function(&["calc", "clamp"])? {
                         buf.push_str(&name);
                     } else {
                         return Ok(None);
                     }
                 }
                 'e' | 'E' => {
-                    if let Some(name) = self.try_parse_min_max_function("env")? {
+                    if let Some(name) = self.try_parse_min_max_function(&["env"])? {
                         buf.push_str(&name);
                     } else {
                         return Ok(None);
                     }
                 }
                 'v' | 'V' => {
-                    if let Some(name) = self.try_parse_min_max_function("var")? {
+                    if let Some(name) = self.try_parse_min_max_function(&["var"])? {
                         buf.push_str(&name);
                     } else {
                         return Ok(None);
@@ -276,11 +276,16 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(Some(buf))
     }
 
-    fn try_parse_min_max_function(&mut self, fn_name: &'static str) -> SassResult<Option<String>> {
+    /// Parses a call to one of the CSS functions `fn_names` that may appear in
+    /// the CSS `min()` and `max()`, leaving its arguments as they are
+    fn try_parse_min_max_function(
+        &mut self,
+        fn_names: &[&'static str],
+    ) -> SassResult<Option<String>> {
         let mut ident = self.parse_identifier_no_interpolation(false)?.node;
         ident.make_ascii_lowercase();
 
-        if ident != fn_name {
+        if !fn_names.contains(&ident.as_str()) {
             return Ok(None);
         }
 
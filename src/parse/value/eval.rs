@@ -8,9 +8,10 @@ use num_traits::Zero;
 use crate::{
     args::CallArgs,
     common::{Identifier, Op, QuoteKind},
+    compat::Deprecation,
     error::SassResult,
     unit::Unit,
-    value::{SassFunction, Value},
+    value::{Number, SassFunction, Value},
 };
 
 use super::super::Parser;
@@ -18,8 +19,9 @@ use super::super::Parser;
 #[derive(Clone, Debug)]
 pub(crate) enum HigherIntermediateValue {
     Literal(Value),
-    /// A function that hasn't yet been evaluated
-    Function(SassFunction, CallArgs, Option<Spanned<Identifier>>),
+    /// A function that hasn't yet been evaluated, along with the span of the
+    /// call from the start of its name
+    Function(SassFunction, CallArgs, Option<Spanned<Identifier>>, Span),
     BinaryOp(Box<Self>, Op, Box<Self>),
     UnaryOp(Op, Box<Self>),
 }
@@ -36,8 +38,9 @@ impl<'a, 'b> Parser<'a, 'b> {
         function: SassFunction,
         args: CallArgs,
         module: Option<Spanned<Identifier>>,
+        span: Span,
     ) -> SassResult<Value> {
-        function.call(args, module, self)
+        function.call(args, module, span, self)
     }
 }
 
@@ -59,8 +62,8 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
             HigherIntermediateValue::Literal(v) => Ok(v),
             HigherIntermediateValue::BinaryOp(v1, op, v2) => self.bin_op(*v1, op, *v2, in_parens),
             HigherIntermediateValue::UnaryOp(op, val) => self.unary_op(op, *val, in_parens),
-            HigherIntermediateValue::Function(function, args, module) => {
-                self.parser.call_function(function, args, module)
+            HigherIntermediateValue::Function(function, args, module, span) => {
+                self.parser.call_function(function, args, module, span)
             }
         }
     }
@@ -110,7 +113,7 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
             Op::Plus => self.add(val1, val2)?,
             Op::Minus => self.sub(val1, val2)?,
             Op::Mul => self.mul(val1, val2)?,
-            Op::Div => self.div(val1, val2, in_parens)?,
+            Op::Div => self.slash_div(val1, val2, in_parens)?,
             Op::Rem => self.rem(val1, val2)?,
             Op::And => Self::and(val1, val2),
             Op::Or => Self::or(val1, val2),
@@ -150,7 +153,7 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
             Op::Plus => self.add(val1, val2)?,
             Op::Minus => self.sub(val1, val2)?,
             Op::Mul => self.mul(val1, val2)?,
-            Op::Div => self.div(val1, val2, in_parens)?,
+            Op::Div => self.slash_div(val1, val2, in_parens)?,
             Op::Rem => self.rem(val1, val2)?,
             Op::And => Self::and(val1, val2),
             Op::Or => Self::or(val1, val2),
@@ -221,8 +224,10 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
             HigherIntermediateValue::UnaryOp(op, val) => {
                 HigherIntermediateValue::Literal(self.unary_op(op, *val, in_parens)?)
             }
-            HigherIntermediateValue::Function(function, args, module) => {
-                HigherIntermediateValue::Literal(self.parser.call_function(function, args, module)?)
+            HigherIntermediateValue::Function(function, args, module, span) => {
+                HigherIntermediateValue::Literal(
+                    self.parser.call_function(function, args, module, span)?,
+                )
             }
             _ => val,
         })
@@ -249,6 +254,27 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
                 )
                     .into())
             }
+            Value::Calculation(..) => match right {
+                Value::String(s, q) => Value::String(
+                    format!(
+                        "{}{}",
+                        left.to_css_string(self.span, self.parser.options.is_compressed())?,
+                        s
+                    ),
+                    q,
+                ),
+                _ => {
+                    return Err((
+                        format!(
+                            "Undefined operation \"{} + {}\".",
+                            left.inspect(self.span)?,
+                            right.inspect(self.span)?
+                        ),
+                        self.span,
+                    )
+                        .into())
+                }
+            },
             Value::True | Value::False => match right {
                 Value::String(s, QuoteKind::Quoted) => Value::String(
                     format!(
@@ -352,7 +378,7 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
                     )
                         .into())
                 }
-                Value::Color(..) => {
+                Value::Color(..) | Value::Calculation(..) => {
                     return Err((
                         format!(
                             "Undefined operation \"{}{} + {}\".",
@@ -479,7 +505,7 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
                     )
                         .into())
                 }
-                Value::Color(..) => {
+                Value::Color(..) | Value::Calculation(..) => {
                     return Err((
                         format!(
                             "Undefined operation \"{}{} - {}\".",
@@ -577,24 +603,20 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
             v => panic!("{:?}", v),
         };
         Ok(match left {
-            Value::Dimension(None, ..) => todo!(),
-            Value::Dimension(Some(num), unit, _) => match right {
-                Value::Dimension(None, ..) => todo!(),
-                Value::Dimension(Some(num2), unit2, _) => {
-                    if unit == Unit::None {
-                        Value::Dimension(Some(num * num2), unit2, true)
-                    } else if unit2 == Unit::None {
-                        Value::Dimension(Some(num * num2), unit, true)
-                    } else {
-                        Value::Dimension(Some(num * num2), unit * unit2, true)
-                    }
+            Value::Dimension(num, unit, should_divide) => match right {
+                Value::Dimension(num2, unit2, _) => {
+                    let (unit, factor) = unit.multiply(unit2);
+                    Value::Dimension(
+                        num.zip(num2).map(|(num, num2)| num * num2 * factor),
+                        unit,
+                        true,
+                    )
                 }
                 _ => {
                     return Err((
                         format!(
-                            "Undefined operation \"{}{} * {}\".",
-                            num.inspect(),
-                            unit,
+                            "Undefined operation \"{} * {}\".",
+                            Value::Dimension(num, unit, should_divide).inspect(self.span)?,
                             right.inspect(self.span)?
                         ),
                         self.span,
@@ -616,7 +638,61 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
         })
     }
 
-    fn div(
+    /// Division using the `/` operator, which `dart-sass` deprecated in favor
+    /// of `math.div()` and `calc()`
+    fn slash_div(
+        &mut self,
+        left: HigherIntermediateValue,
+        right: HigherIntermediateValue,
+        in_parens: bool,
+    ) -> SassResult<Value> {
+        if let (
+            HigherIntermediateValue::Literal(Value::Dimension(_, _, should_divide1)),
+            HigherIntermediateValue::Literal(Value::Dimension(_, _, should_divide2)),
+        ) = (&left, &right)
+        {
+            if *should_divide1 || *should_divide2 || in_parens {
+                self.parser.deprecation(
+                    Deprecation::SlashDiv,
+                    "Using / for division outside of calc() is deprecated and will be removed in Dart Sass 2.0.0.\n\nRecommendation: math.div() or calc()",
+                    "Using / for division outside of calc() is not supported in Dart Sass 2.0.0. Use math.div() or calc() instead.",
+                    self.span,
+                )?;
+            }
+        }
+
+        self.div(left, right, in_parens)
+    }
+
+    /// Divides two numbers, either of which may be `NaN`
+    fn divide_numbers(
+        &self,
+        num: Option<Number>,
+        unit: Unit,
+        num2: Option<Number>,
+        unit2: Unit,
+    ) -> SassResult<Value> {
+        // `unit(1in / 1px)` => `""`, with the conversion factored in
+        let (unit, factor) = unit.multiply(unit2.invert());
+
+        let (num, num2) = match (num, num2) {
+            (Some(num), Some(num2)) => (num, num2),
+            _ => return Ok(Value::Dimension(None, unit, true)),
+        };
+
+        if num.is_zero() && num2.is_zero() {
+            return Ok(Value::Dimension(None, unit, true));
+        }
+
+        if num2.is_zero() {
+            // todo: Infinity and -Infinity
+            return Err(("Infinity not yet implemented.", self.span).into());
+        }
+
+        Ok(Value::Dimension(Some(num / num2 * factor), unit, true))
+    }
+
+    pub fn div(
         &self,
         left: HigherIntermediateValue,
         right: HigherIntermediateValue,
@@ -638,51 +714,28 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
                 ),
                 QuoteKind::None,
             ),
-            Value::Dimension(None, ..) => todo!(),
+            // `NaN` is only ever produced by a calculation, so it's always divided
+            Value::Dimension(None, unit, _) => match right {
+                Value::Dimension(num2, unit2, _) => self.divide_numbers(None, unit, num2, unit2)?,
+                right => {
+                    let left = Value::Dimension(None, unit, true)
+                        .to_css_string(self.span, self.parser.options.is_compressed())?
+                        .into_owned();
+
+                    return self.div(
+                        HigherIntermediateValue::Literal(Value::String(left, QuoteKind::None)),
+                        HigherIntermediateValue::Literal(right),
+                        in_parens,
+                    );
+                }
+            },
             Value::Dimension(Some(num), unit, should_divide1) => match right {
-                Value::Dimension(None, ..) => todo!(),
+                Value::Dimension(None, unit2, _) => {
+                    self.divide_numbers(Some(num), unit, None, unit2)?
+                }
                 Value::Dimension(Some(num2), unit2, should_divide2) => {
                     if should_divide1 || should_divide2 || in_parens {
-                        if num.is_zero() && num2.is_zero() {
-                            return Ok(Value::Dimension(None, Unit::None, true));
-                        }
-
-                        if num2.is_zero() {
-                            // todo: Infinity and -Infinity
-                            return Err(("Infinity not yet implemented.", self.span).into());
-                        }
-
-                        // `unit(1em / 1em)` => `""`
-                        if unit == unit2 {
-                            Value::Dimension(Some(num / num2), Unit::None, true)
-
-                        // `unit(1 / 1em)` => `"em^-1"`
-                        } else if unit == Unit::None {
-                            Value::Dimension(Some(num / num2), Unit::None / unit2, true)
-
-                        // `unit(1em / 1)` => `"em"`
-                        } else if unit2 == Unit::None {
-                            Value::Dimension(Some(num / num2), unit, true)
-
-                        // `unit(1in / 1px)` => `""`
-                        } else if unit.comparable(&unit2) {
-                            Value::Dimension(
-                                Some(num / num2.convert(&unit2, &unit)),
-                                Unit::None,
-                                true,
-                            )
-                        // `unit(1em / 1px)` => `"em/px"`
-                        // todo: this should probably be its own variant
-                        // within the `Value` enum
-                        } else {
-                            // todo: remember to account for `Mul` and `Div`
-                            // todo!("non-comparable inverse units")
-                            return Err((
-                                "Division of non-comparable units not yet supported.",
-                                self.span,
-                            )
-                                .into());
-                        }
+                        self.divide_numbers(Some(num), unit, Some(num2), unit2)?
                     } else {
                         Value::String(
                             format!(
@@ -712,6 +765,7 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
                 | Value::False
                 | Value::Important
                 | Value::Color(..)
+                | Value::Calculation(..)
                 | Value::ArgList(..) => Value::String(
                     format!(
                         "{}{}/{}",
@@ -773,6 +827,7 @@ impl<'a, 'b: 'a, 'c> ValueVisitor<'a, 'b, 'c> {
                 | Value::Dimension(..)
                 | Value::Color(..)
                 | Value::List(..)
+                | Value::Calculation(..)
                 | Value::ArgList(..) => Value::String(
                     format!(
                         "{}{}{}/{}",
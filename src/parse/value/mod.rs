@@ -1,5 +1,6 @@
 pub(crate) use eval::{HigherIntermediateValue, ValueVisitor};
 
+mod calculation;
 mod css_function;
 mod eval;
 mod parse;
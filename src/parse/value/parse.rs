@@ -14,7 +14,7 @@ use crate::{
     lexer::Lexer,
     unit::Unit,
     utils::{is_name, IsWhitespace, ParsedNumber},
-    value::{Number, SassFunction, SassMap, Value},
+    value::{CalculationName, Number, SassFunction, SassMap, Value},
     Token,
 };
 
@@ -48,6 +48,12 @@ impl IsWhitespace for IntermediateValue {
 /// We parse a value until the predicate returns true
 type Predicate<'a> = &'a dyn Fn(&mut Parser<'_, '_>) -> bool;
 
+/// The span covering every value in `vals`, if there are any
+fn merged_span(vals: &[Spanned<HigherIntermediateValue>]) -> Option<Span> {
+    let first = vals.first()?.span;
+    Some(vals.iter().fold(first, |span, val| span.merge(val.span)))
+}
+
 impl<'a, 'b> Parser<'a, 'b> {
     /// Parse a value from a stream of tokens
     ///
@@ -103,19 +109,15 @@ impl<'a, 'b> Parser<'a, 'b> {
                     if space_separated.len() == 1 {
                         comma_separated.push(space_separated.pop().unwrap());
                     } else {
-                        let mut span = space_separated
-                            .first()
-                            .ok_or(("Expected expression.", val.span))?
-                            .span;
+                        let span = merged_span(&space_separated)
+                            .ok_or(("Expected expression.", val.span))?;
                         comma_separated.push(
                             HigherIntermediateValue::Literal(Value::List(
                                 mem::take(&mut space_separated)
                                     .into_iter()
-                                    .map(move |a| {
-                                        span = span.merge(a.span);
-                                        a.node
+                                    .map(|a| {
+                                        ValueVisitor::new(iter.parser, a.span).eval(a.node, false)
                                     })
-                                    .map(|a| ValueVisitor::new(iter.parser, span).eval(a, in_paren))
                                     .collect::<SassResult<Vec<Value>>>()?,
                                 ListSeparator::Space,
                                 Brackets::None,
@@ -130,37 +132,40 @@ impl<'a, 'b> Parser<'a, 'b> {
         Ok(if !comma_separated.is_empty() {
             if space_separated.len() == 1 {
                 comma_separated.push(space_separated.pop().unwrap());
-            } else if !space_separated.is_empty() {
+            } else if let Some(space_span) = merged_span(&space_separated) {
                 comma_separated.push(
                     HigherIntermediateValue::Literal(Value::List(
                         space_separated
                             .into_iter()
-                            .map(|a| ValueVisitor::new(self, span).eval(a.node, in_paren))
+                            .map(|a| ValueVisitor::new(self, a.span).eval(a.node, false))
                             .collect::<SassResult<Vec<Value>>>()?,
                         ListSeparator::Space,
                         Brackets::None,
                     ))
-                    .span(span),
+                    .span(space_span),
                 );
             }
+            let span = merged_span(&comma_separated).unwrap_or(span);
             Value::List(
                 comma_separated
                     .into_iter()
-                    .map(|a| ValueVisitor::new(self, span).eval(a.node, in_paren))
+                    .map(|a| ValueVisitor::new(self, a.span).eval(a.node, false))
                     .collect::<SassResult<Vec<Value>>>()?,
                 ListSeparator::Comma,
                 Brackets::None,
             )
             .span(span)
         } else if space_separated.len() == 1 {
-            ValueVisitor::new(self, span)
-                .eval(space_separated.pop().unwrap().node, in_paren)?
-                .span(span)
+            let val = space_separated.pop().unwrap();
+            ValueVisitor::new(self, val.span)
+                .eval(val.node, in_paren)?
+                .span(val.span)
         } else {
+            let span = merged_span(&space_separated).unwrap_or(span);
             Value::List(
                 space_separated
                     .into_iter()
-                    .map(|a| ValueVisitor::new(self, span).eval(a.node, in_paren))
+                    .map(|a| ValueVisitor::new(self, a.span).eval(a.node, false))
                     .collect::<SassResult<Vec<Value>>>()?,
                 ListSeparator::Space,
                 Brackets::None,
@@ -187,10 +192,15 @@ impl<'a, 'b> Parser<'a, 'b> {
             at_root: self.at_root,
             at_root_has_selector: self.at_root_has_selector,
             extender: self.extender,
-            content_scopes: self.content_scopes,
             options: self.options,
             modules: self.modules,
             module_config: self.module_config,
+            forwarded_scope: self.forwarded_scope,
+            call_stack: self.call_stack,
+            unique_id_count: self.unique_id_count,
+            loaded_files: self.loaded_files,
+            emitted_warnings: self.emitted_warnings,
+            deadline: self.deadline,
         }
         .parse_value(in_paren, &|_| false)
     }
@@ -219,14 +229,14 @@ impl<'a, 'b> Parser<'a, 'b> {
             let function = self
                 .modules
                 .get(module.node, module.span)?
-                .get_fn(var_or_fn_name)?
-                .ok_or(("Undefined function.", var_or_fn_name.span))?;
+                .get_fn_or_err(var_or_fn_name)?;
 
             self.expect_char('(')?;
 
             let call_args = self.parse_call_args()?;
+            let span = module.span.merge(call_args.span());
 
-            HigherIntermediateValue::Function(function, call_args, Some(module))
+            HigherIntermediateValue::Function(function, call_args, Some(module), span)
         };
 
         Ok(IntermediateValue::Value(value).span(module.span))
@@ -237,14 +247,39 @@ impl<'a, 'b> Parser<'a, 'b> {
         mut s: String,
         lower: String,
     ) -> SassResult<Spanned<IntermediateValue>> {
+        let name_span = self.span_before;
+
+        if lower == "calc" {
+            if let Some(val) = self.try_parse_calculation(CalculationName::Calc)? {
+                return Ok(
+                    IntermediateValue::Value(HigherIntermediateValue::Literal(val))
+                        .span(self.span_before),
+                );
+            }
+        }
+
         if lower == "min" || lower == "max" {
             let start = self.toks.cursor();
             match self.try_parse_min_max(&lower, true)? {
                 Some(val) => {
-                    return Ok(IntermediateValue::Value(HigherIntermediateValue::Literal(
-                        Value::String(val, QuoteKind::None),
-                    ))
-                    .span(self.span_before));
+                    let end = self.toks.cursor();
+                    self.toks.set_cursor(start);
+
+                    // the arguments are those of the CSS function, so they can
+                    // be simplified as a calculation where they are valid ones
+                    let name = CalculationName::from_name(&lower).unwrap();
+                    let val = match self.try_parse_calculation(name)? {
+                        Some(calc) if self.toks.cursor() == end => calc,
+                        _ => {
+                            self.toks.set_cursor(end);
+                            Value::String(val, QuoteKind::None)
+                        }
+                    };
+
+                    return Ok(
+                        IntermediateValue::Value(HigherIntermediateValue::Literal(val))
+                            .span(self.span_before),
+                    );
                 }
                 None => {
                     self.toks.set_cursor(start);
@@ -257,10 +292,14 @@ impl<'a, 'b> Parser<'a, 'b> {
             Some(f) => f,
             None => {
                 if let Some(f) = GLOBAL_FUNCTIONS.get(as_ident.as_str()) {
+                    let call_args = self.parse_call_args()?;
+                    let span = name_span.merge(call_args.span());
+
                     return Ok(IntermediateValue::Value(HigherIntermediateValue::Function(
                         SassFunction::Builtin(f.clone(), as_ident),
-                        self.parse_call_args()?,
+                        call_args,
                         None,
+                        span,
                     ))
                     .span(self.span_before));
                 }
@@ -280,6 +319,13 @@ impl<'a, 'b> Parser<'a, 'b> {
                         ),
                     },
                     "clamp" if lower == "clamp" => {
+                        if let Some(val) = self.try_parse_calculation(CalculationName::Clamp)? {
+                            return Ok(IntermediateValue::Value(HigherIntermediateValue::Literal(
+                                val,
+                            ))
+                            .span(self.span_before));
+                        }
+
                         self.parse_calc_args(&mut s)?;
                     }
                     _ => s.push_str(
@@ -297,10 +343,11 @@ impl<'a, 'b> Parser<'a, 'b> {
         };
 
         let call_args = self.parse_call_args()?;
-        Ok(
-            IntermediateValue::Value(HigherIntermediateValue::Function(func, call_args, None))
-                .span(self.span_before),
-        )
+        let span = name_span.merge(call_args.span());
+        Ok(IntermediateValue::Value(HigherIntermediateValue::Function(
+            func, call_args, None, span,
+        ))
+        .span(self.span_before))
     }
 
     fn parse_ident_value(
@@ -349,8 +396,12 @@ impl<'a, 'b> Parser<'a, 'b> {
 
         // check for named colors
         Ok(if let Some(c) = NAMED_COLORS.get_by_name(lower.as_str()) {
+            let mut color = Color::new(c[0], c[1], c[2], c[3], s);
+            if self.options.preserve_color_format {
+                color = color.as_written();
+            }
             IntermediateValue::Value(HigherIntermediateValue::Literal(Value::Color(Box::new(
-                Color::new(c[0], c[1], c[2], c[3], s),
+                color,
             ))))
         } else {
             // check for keywords
@@ -801,6 +852,23 @@ impl<'a, 'b> Parser<'a, 'b> {
         })
     }
 
+    /// Parses and evaluates a single value, such as a number, variable, or
+    /// function call, without any operators around it
+    ///
+    /// Returns `None` if the next token doesn't begin a value
+    pub(super) fn parse_single_value(&mut self) -> SassResult<Option<Spanned<Value>>> {
+        match self.parse_intermediate_value(&|_| false) {
+            Some(Ok(Spanned {
+                node: IntermediateValue::Value(value),
+                span,
+            })) => Ok(Some(
+                ValueVisitor::new(self, span).eval(value, false)?.span(span),
+            )),
+            Some(Err(e)) => Err(e),
+            _ => Ok(None),
+        }
+    }
+
     fn parse_intermediate_value(
         &mut self,
         predicate: Predicate<'_>,
@@ -889,9 +957,12 @@ impl<'a, 'b> Parser<'a, 'b> {
             }
             '[' => return Some(self.parse_bracketed_list()),
             '$' => {
-                self.toks.next();
+                let dollar = self.toks.next().unwrap().pos();
                 let val = match self.parse_identifier_no_interpolation(false) {
-                    Ok(v) => v.map_node(Into::into),
+                    Ok(v) => Spanned {
+                        node: v.node.into(),
+                        span: dollar.merge(v.span),
+                    },
                     Err(e) => return Some(Err(e)),
                 };
                 IntermediateValue::Value(HigherIntermediateValue::Literal(
@@ -1052,7 +1123,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                 (((v & 0x0f00) >> 8) * 0x11) as u8,
                 (((v & 0x00f0) >> 4) * 0x11) as u8,
                 ((v & 0x000f) * 0x11) as u8,
-                1,
+                0xff,
             ),
             4 => (
                 (((v & 0xf000) >> 12) * 0x11) as u8,
@@ -1064,7 +1135,7 @@ impl<'a, 'b> Parser<'a, 'b> {
                 ((v & 0x00ff_0000) >> 16) as u8,
                 ((v & 0x0000_ff00) >> 8) as u8,
                 (v & 0x0000_00ff) as u8,
-                1,
+                0xff,
             ),
             8 => (
                 ((v & 0xff00_0000) >> 24) as u8,
@@ -1074,7 +1145,10 @@ impl<'a, 'b> Parser<'a, 'b> {
             ),
             _ => return Err(("Expected hex digit.", self.span_before).into()),
         };
-        let color = Color::new(red, green, blue, alpha, s);
+        let mut color = Color::new(red, green, blue, alpha, s);
+        if self.options.preserve_color_format {
+            color = color.as_written();
+        }
         Ok(Value::Color(Box::new(color)).span(self.span_before))
     }
 }
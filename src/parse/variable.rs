@@ -1,6 +1,8 @@
+use std::mem;
+
 use codemap::Spanned;
 
-use crate::{common::Identifier, error::SassResult, value::Value, Token};
+use crate::{common::Identifier, compat::Deprecation, error::SassResult, value::Value, Token};
 
 use super::Parser;
 
@@ -37,52 +39,19 @@ impl<'a, 'b> Parser<'a, 'b> {
         } = self.parse_variable_value()?;
 
         if default {
-            let config_val = self.module_config.get(ident).filter(|v| !v.is_null());
-
-            let value = if (self.at_root && !self.flags.in_control_flow()) || global {
-                if self.global_scope.default_var_exists(ident) {
-                    return Ok(());
-                } else if let Some(value) = config_val {
-                    value
-                } else {
-                    var_value?.node
-                }
-            } else if self.at_root && self.flags.in_control_flow() {
-                if self.global_scope.default_var_exists(ident) {
-                    return Ok(());
-                }
-
-                var_value?.node
-            } else {
-                if self.scopes.default_var_exists(ident) {
+            // a value configured with `@use ... with` takes precedence over the
+            // module's own, even if the variable has already been assigned
+            if self.at_root && !self.flags.in_control_flow() {
+                if let Some(value) = self.module_config.get(ident).filter(|v| !v.is_null()) {
+                    self.global_scope.insert_var(ident, value);
                     return Ok(());
                 }
-
-                var_value?.node
-            };
-
-            if self.at_root && self.global_scope.var_exists(ident) {
-                if !self.global_scope.default_var_exists(ident) {
-                    self.global_scope.insert_var(ident, value.clone());
-                }
-            } else if self.at_root
-                && !self.flags.in_control_flow()
-                && !self.global_scope.default_var_exists(ident)
-            {
-                self.global_scope.insert_var(ident, value.clone());
-            }
-
-            if global {
-                self.global_scope.insert_var(ident, value.clone());
             }
 
-            if self.at_root && !self.flags.in_control_flow() {
+            if self.scopes.default_var_exists(ident) || self.global_scope.default_var_exists(ident)
+            {
                 return Ok(());
             }
-
-            self.scopes.insert_var(ident, value);
-
-            return Ok(());
         }
 
         let value = var_value?.node;
@@ -126,20 +95,28 @@ impl<'a, 'b> Parser<'a, 'b> {
             }
         });
 
-        // todo: it should not be possible to declare the same flag more than once
         while self.consume_char_if_exists('!') {
             let flag = self.parse_identifier_no_interpolation(false)?;
 
-            match flag.node.as_str() {
-                "global" => {
-                    global = true;
-                }
-                "default" => {
-                    default = true;
-                }
+            let is_duplicate = match flag.node.as_str() {
+                "global" => mem::replace(&mut global, true),
+                "default" => mem::replace(&mut default, true),
                 _ => {
                     return Err(("Invalid flag name.", flag.span).into());
                 }
+            };
+
+            if is_duplicate {
+                let message = format!(
+                    "!{} should only be written once for each variable.",
+                    flag.node
+                );
+                self.deprecation(
+                    Deprecation::DuplicateVarFlags,
+                    &format!("{}\nThis will be an error in Dart Sass 2.0.0.", message),
+                    &message,
+                    flag.span,
+                )?;
             }
 
             self.whitespace_or_comment();
@@ -1,34 +1,55 @@
-use std::collections::BTreeMap;
-
 use codemap::Spanned;
+use indexmap::IndexMap;
 
 use crate::{
     atrule::mixin::Mixin,
     builtin::GLOBAL_FUNCTIONS,
     common::Identifier,
-    error::SassResult,
+    error::{SassError, SassResult},
+    utils::closest_match,
     value::{SassFunction, Value},
 };
 
+/// Create the error for a variable, mixin, or function that could not be found,
+/// suggesting the most similar name among `candidates` if one is close enough
+pub(crate) fn undefined_error<'a>(
+    message: &str,
+    sigil: &str,
+    name: Spanned<Identifier>,
+    candidates: impl IntoIterator<Item = &'a Identifier>,
+) -> Box<SassError> {
+    match closest_match(
+        name.node.as_str(),
+        candidates.into_iter().map(Identifier::as_str),
+    ) {
+        Some(suggestion) => (
+            format!("{}\n\nDid you mean {}{}?", message, sigil, suggestion),
+            name.span,
+        )
+            .into(),
+        None => (message, name.span).into(),
+    }
+}
+
 /// A singular scope
 ///
 /// Contains variables, functions, and mixins
 #[derive(Debug, Default, Clone)]
 pub(crate) struct Scope {
-    pub vars: BTreeMap<Identifier, Value>,
-    pub mixins: BTreeMap<Identifier, Mixin>,
-    pub functions: BTreeMap<Identifier, SassFunction>,
+    pub vars: IndexMap<Identifier, Value>,
+    pub mixins: IndexMap<Identifier, Mixin>,
+    pub functions: IndexMap<Identifier, SassFunction>,
 }
 
 impl Scope {
-    // `BTreeMap::new` is not yet const
+    // `IndexMap::new` is not const
     #[allow(clippy::missing_const_for_fn)]
     #[must_use]
     pub fn new() -> Self {
         Self {
-            vars: BTreeMap::new(),
-            mixins: BTreeMap::new(),
-            functions: BTreeMap::new(),
+            vars: IndexMap::new(),
+            mixins: IndexMap::new(),
+            functions: IndexMap::new(),
         }
     }
 
@@ -186,7 +207,20 @@ impl Scopes {
                 return scope.get_var(name);
             }
         }
-        global_scope.get_var(name)
+
+        if global_scope.var_exists(name.node) {
+            return global_scope.get_var(name);
+        }
+
+        Err(undefined_error(
+            "Undefined variable.",
+            "$",
+            name,
+            self.0
+                .iter()
+                .chain(std::iter::once(global_scope))
+                .flat_map(|scope| scope.vars.keys()),
+        ))
     }
 
     pub fn var_exists(&self, name: Identifier, global_scope: &Scope) -> bool {
@@ -222,7 +256,20 @@ impl Scopes {
                 return scope.get_mixin(name);
             }
         }
-        global_scope.get_mixin(name)
+
+        if global_scope.mixin_exists(name.node) {
+            return global_scope.get_mixin(name);
+        }
+
+        Err(undefined_error(
+            "Undefined mixin.",
+            "",
+            name,
+            self.0
+                .iter()
+                .chain(std::iter::once(global_scope))
+                .flat_map(|scope| scope.mixins.keys()),
+        ))
     }
 
     pub fn mixin_exists(&self, name: Identifier, global_scope: &Scope) -> bool {
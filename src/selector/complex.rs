@@ -132,6 +132,22 @@ impl ComplexSelector {
             .any(ComplexSelectorComponent::is_invisible)
     }
 
+    /// Whether this selector has a leading or trailing combinator, or multiple
+    /// combinators in a row, none of which are valid CSS
+    pub fn is_bogus(&self) -> bool {
+        self.components
+            .first()
+            .map_or(false, ComplexSelectorComponent::is_combinator)
+            || self
+                .components
+                .last()
+                .map_or(false, ComplexSelectorComponent::is_combinator)
+            || self
+                .components
+                .windows(2)
+                .any(|pair| pair[0].is_combinator() && pair[1].is_combinator())
+    }
+
     /// Returns whether `self` is a superselector of `other`.
     ///
     /// That is, whether `self` matches every element that `other` matches, as well
@@ -1,34 +1,33 @@
 use std::{
     cell::RefCell,
-    collections::{hash_set::IntoIter, HashSet},
     hash::{Hash, Hasher},
     ops::Deref,
     ptr,
     rc::Rc,
 };
 
+use indexmap::{set::IntoIter, IndexSet};
+
 use crate::selector::{Selector, SelectorList};
 
 #[derive(Debug, Clone)]
 pub(crate) struct ExtendedSelector(Rc<RefCell<SelectorList>>);
 
+/// Each `ExtendedSelector` is a distinct style rule's selector, so two rules
+/// with the same selector are not equal, and are both extended
 impl PartialEq for ExtendedSelector {
     fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        Rc::ptr_eq(&self.0, &other.0)
     }
 }
 
 impl Eq for ExtendedSelector {}
 
 impl Hash for ExtendedSelector {
-    // We hash the ptr here for efficiency.
-    // TODO: is this an issue? it probably is,
-    // but I haven't managed to find a test case
-    // that exhibits it.
+    // hashed by address to agree with `PartialEq`, which is also cheaper than
+    // hashing the selector itself
     fn hash<H: Hasher>(&self, state: &mut H) {
         ptr::hash(&*self.0, state);
-        // in case we need to hash the actual value:
-        // self.0.borrow().hash(state);
     }
 }
 
@@ -53,24 +52,26 @@ impl ExtendedSelector {
     }
 }
 
-/// There is the potential for danger here by modifying the hash
-/// through `RefCell`, but I haven't come up with a good solution
-/// for this yet (we can't just use a `Vec` because linear insert)
-/// is too big of a penalty
+/// Selectors are hashed and compared by address, so they may be modified
+/// through their `RefCell` while in the set
 ///
-/// In pratice, I have yet to find a test case that can demonstrate
-/// an issue with storing a `RefCell`.
+/// This is insertion-ordered in order for them to always be extended in the
+/// same order
 #[derive(Clone, Debug)]
-pub(crate) struct SelectorHashSet(HashSet<ExtendedSelector>);
+pub(crate) struct SelectorHashSet(IndexSet<ExtendedSelector>);
 
 impl SelectorHashSet {
     pub fn new() -> Self {
-        Self(HashSet::new())
+        Self(IndexSet::new())
     }
 
     pub fn insert(&mut self, selector: ExtendedSelector) {
         self.0.insert(selector);
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ExtendedSelector> {
+        self.0.iter()
+    }
 }
 
 impl IntoIterator for SelectorHashSet {
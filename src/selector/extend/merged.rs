@@ -61,7 +61,7 @@ impl MergedExtension {
                 None => right.media_context,
             },
             specificity: left.specificity,
-            is_optional: true,
+            is_optional: left.is_optional && right.is_optional,
             is_original: false,
             left: None,
             right: None,
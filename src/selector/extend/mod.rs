@@ -101,6 +101,14 @@ pub(crate) struct Extender {
     /// [first law of extend]: https://github.com/sass/sass/issues/324#issuecomment-4607184
     originals: ComplexSelectorHashSet,
 
+    /// Extensions of compound selectors, which are only allowed with
+    /// `Options::libsass_compat`, as pairs of the extending selector and the
+    /// target.
+    ///
+    /// Unlike other extensions, these are applied all at once after the whole
+    /// stylesheet has been parsed.
+    compound_extensions: Vec<(SelectorList, SelectorList)>,
+
     /// The mode that controls this extender's behavior.
     mode: ExtendMode,
 
@@ -130,6 +138,7 @@ impl Extender {
             media_contexts: HashMap::new(),
             source_specificity: HashMap::new(),
             originals: ComplexSelectorHashSet::new(),
+            compound_extensions: Vec::new(),
             mode: ExtendMode::Normal,
             span,
         }
@@ -971,8 +980,8 @@ impl Extender {
                 // If there's already an extend from `extender` to `target`, we don't need
                 // to re-run the extension. We may need to mark the extension as
                 // mandatory, though.
-                let mut new_val = MergedExtension::merge(existing_state.clone(), state).unwrap();
-                sources.get_mut(&complex).replace(&mut new_val);
+                let new_val = MergedExtension::merge(existing_state.clone(), state).unwrap();
+                sources.insert(complex, new_val);
                 continue;
             }
 
@@ -1023,6 +1032,80 @@ impl Extender {
         }
     }
 
+    /// Adds an extension of a compound selector, as allowed by `libsass`.
+    ///
+    /// Selectors are only extended if they contain every simple selector in
+    /// `target`. This takes effect once `Extender::apply_compound_extensions`
+    /// is called.
+    pub fn add_compound_extension(&mut self, extender: SelectorList, target: CompoundSelector) {
+        let span = extender.span;
+        self.compound_extensions.push((
+            extender,
+            SelectorList {
+                components: vec![ComplexSelector::new(
+                    vec![ComplexSelectorComponent::Compound(target)],
+                    false,
+                )],
+                span,
+            },
+        ));
+    }
+
+    /// Applies the extensions added by `Extender::add_compound_extension` to
+    /// every selector in the stylesheet.
+    pub fn apply_compound_extensions(&self) -> SassResult<()> {
+        if self.compound_extensions.is_empty() {
+            return Ok(());
+        }
+
+        let mut seen = HashSet::new();
+
+        for selectors in self.selectors.values() {
+            for selector in selectors.iter() {
+                if !seen.insert(selector.clone()) {
+                    continue;
+                }
+
+                let mut list = selector.as_selector_list().clone();
+                for (extender, target) in &self.compound_extensions {
+                    list = Extender::extend(list, extender.clone(), target.clone(), self.span)?;
+                }
+
+                selector.clone().set_inner(list);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error for the first mandatory extension whose target doesn't
+    /// appear in any selector in the stylesheet.
+    pub fn check_unsatisfied_extensions(&self) -> SassResult<()> {
+        let unsatisfied = self
+            .extensions
+            .iter()
+            .filter(|(target, ..)| !self.selectors.contains_key(target))
+            .flat_map(|(target, sources)| {
+                sources
+                    .values()
+                    .filter(|extension| !extension.is_optional)
+                    .map(move |extension| (target, extension))
+            })
+            .min_by_key(|(.., extension)| extension.span.low());
+
+        match unsatisfied {
+            Some((target, extension)) => Err((
+                format!(
+                    "The target selector was not found.\nUse \"@extend {} !optional\" to avoid this error.",
+                    target
+                ),
+                extension.span,
+            )
+                .into()),
+            None => Ok(()),
+        }
+    }
+
     /// Extend `extensions` using `new_extensions`.
     ///
     /// Note that this does duplicate some work done by
@@ -1047,11 +1130,8 @@ impl Extender {
             HashMap<SimpleSelector, IndexMap<ComplexSelector, Extension>>,
         > = None;
         for extension in extensions {
-            let mut sources = self
-                .extensions
-                .get(&extension.target.clone().unwrap())
-                .unwrap()
-                .clone();
+            let target = extension.target.clone().unwrap();
+            let mut sources = self.extensions.get(&target).unwrap().clone();
 
             // `extend_existing_selectors` would have thrown already.
             let selectors: Vec<ComplexSelector> = if let Some(v) = self.extend_complex(
@@ -1087,14 +1167,12 @@ impl Extender {
                 let with_extender = extension.clone().with_extender(complex.clone());
                 let existing_extension = sources.get(&complex);
                 if let Some(existing_extension) = existing_extension.cloned() {
-                    sources.get_mut(&complex).replace(
-                        &mut MergedExtension::merge(existing_extension.clone(), with_extender)
-                            .unwrap(),
+                    sources.insert(
+                        complex.clone(),
+                        MergedExtension::merge(existing_extension, with_extender).unwrap(),
                     );
                 } else {
-                    sources
-                        .get_mut(&complex)
-                        .replace(&mut with_extender.clone());
+                    sources.insert(complex.clone(), with_extender.clone());
 
                     for component in complex.components.clone() {
                         if let ComplexSelectorComponent::Compound(component) = component {
@@ -1107,10 +1185,10 @@ impl Extender {
                         }
                     }
 
-                    if new_extensions.contains_key(&extension.target.clone().unwrap()) {
+                    if new_extensions.contains_key(&target) {
                         additional_extensions
                             .get_or_insert_with(HashMap::new)
-                            .entry(extension.target.clone().unwrap())
+                            .entry(target.clone())
                             .or_insert_with(IndexMap::new)
                             .insert(complex.clone(), with_extender.clone());
                     }
@@ -1120,8 +1198,10 @@ impl Extender {
             // was replaced due to :not() expansion, we must get rid of the old
             // version.
             if !contains_extension {
-                sources.remove(&extension.extender);
+                sources.shift_remove(&extension.extender);
             }
+
+            self.extensions.insert(target, sources);
         }
         additional_extensions
     }
@@ -1179,14 +1259,11 @@ fn map_add_all_2<K1: Hash + Eq, K2: Hash + Eq, V>(
     destination: &mut HashMap<K1, IndexMap<K2, V>>,
     source: HashMap<K1, IndexMap<K2, V>>,
 ) {
-    for (key, mut inner) in source {
-        if destination.contains_key(&key) {
-            destination
-                .get_mut(&key)
-                .get_or_insert(&mut IndexMap::new())
-                .extend(inner);
+    for (key, inner) in source {
+        if let Some(inner_destination) = destination.get_mut(&key) {
+            inner_destination.extend(inner);
         } else {
-            destination.get_mut(&key).replace(&mut inner);
+            destination.insert(key, inner);
         }
     }
 }
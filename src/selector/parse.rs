@@ -73,12 +73,17 @@ impl<'a, 'b, 'c> SelectorParser<'a, 'b, 'c> {
 
     pub fn parse(mut self) -> SassResult<SelectorList> {
         let tmp = self.parse_selector_list()?;
-        if self.parser.toks.peek().is_some() {
-            return Err(("expected selector.", self.span).into());
+        if let Some(tok) = self.parser.toks.peek() {
+            return Err(("expected selector.", tok.pos()).into());
         }
         Ok(tmp)
     }
 
+    /// The span of the next token, falling back to the span of the whole selector
+    fn peek_span(&self) -> Span {
+        self.parser.toks.peek().map_or(self.span, |tok| tok.pos())
+    }
+
     fn parse_selector_list(&mut self) -> SassResult<SelectorList> {
         let mut components = vec![self.parse_complex_selector(false)?];
 
@@ -160,7 +165,7 @@ impl<'a, 'b, 'c> SelectorParser<'a, 'b, 'c> {
                         self.parse_compound_selector()?,
                     ));
                     if let Some(Token { kind: '&', .. }) = self.parser.toks.peek() {
-                        return Err(("\"&\" may only used at the beginning of a compound selector.", self.span).into());
+                        return Err(("\"&\" may only used at the beginning of a compound selector.", self.peek_span()).into());
                     }
                 }
                 Some(..) => {
@@ -171,7 +176,7 @@ impl<'a, 'b, 'c> SelectorParser<'a, 'b, 'c> {
                         self.parse_compound_selector()?,
                     ));
                     if let Some(Token { kind: '&', .. }) = self.parser.toks.peek() {
-                        return Err(("\"&\" may only used at the beginning of a compound selector.", self.span).into());
+                        return Err(("\"&\" may only used at the beginning of a compound selector.", self.peek_span()).into());
                     }
                 }
                 None => break,
@@ -214,7 +219,11 @@ impl<'a, 'b, 'c> SelectorParser<'a, 'b, 'c> {
             Some(Token { kind: '#', .. }) => self.parse_id_selector(),
             Some(Token { kind: '%', .. }) => {
                 if !self.allows_placeholder {
-                    return Err(("Placeholder selectors aren't allowed here.", self.span).into());
+                    return Err((
+                        "Placeholder selectors aren't allowed here.",
+                        self.peek_span(),
+                    )
+                        .into());
                 }
                 self.parse_placeholder_selector()
             }
@@ -222,7 +231,7 @@ impl<'a, 'b, 'c> SelectorParser<'a, 'b, 'c> {
             Some(Token { kind: '&', .. }) => {
                 let allows_parent = allows_parent.unwrap_or(self.allows_parent);
                 if !allows_parent {
-                    return Err(("Parent selectors aren't allowed here.", self.span).into());
+                    return Err(("Parent selectors aren't allowed here.", self.peek_span()).into());
                 }
 
                 self.parse_parent_selector()
@@ -0,0 +1,114 @@
+use indexmap::IndexMap;
+
+/// Statistics about the CSS emitted by a compilation, returned by
+/// [`from_path_with_stats`](crate::from_path_with_stats) and
+/// [`from_string_with_stats`](crate::from_string_with_stats)
+///
+/// These are collected while the CSS is written, so tools such as size
+/// budgets can check for regressions without parsing the output again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    bytes: usize,
+    files: IndexMap<String, FileStats>,
+}
+
+impl Stats {
+    /// The total size of the output in bytes
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// The statistics for each input file that contributed to the output,
+    /// in the order in which they first contributed
+    pub fn files(&self) -> impl Iterator<Item = &FileStats> {
+        self.files.values()
+    }
+
+    /// The statistics for the input file with the given name, as it appears
+    /// in error messages
+    #[must_use]
+    pub fn file(&self, name: &str) -> Option<&FileStats> {
+        self.files.get(name)
+    }
+
+    pub(crate) fn set_bytes(&mut self, bytes: usize) {
+        self.bytes = bytes;
+    }
+
+    pub(crate) fn file_mut(&mut self, name: &str) -> &mut FileStats {
+        if !self.files.contains_key(name) {
+            self.files.insert(
+                name.to_owned(),
+                FileStats {
+                    name: name.to_owned(),
+                    ..FileStats::default()
+                },
+            );
+        }
+
+        self.files.get_mut(name).unwrap()
+    }
+}
+
+/// The part of the output that came from a single input file
+///
+/// A style rule is attributed to the file its selector was written in, and a
+/// declaration to the file its value was written in, so the declarations of a
+/// rule may be attributed to a different file than the rule itself if they
+/// were included from a mixin.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileStats {
+    name: String,
+    rules: usize,
+    selectors: usize,
+    declarations: usize,
+    bytes: usize,
+}
+
+impl FileStats {
+    /// The name of the file, as it appears in error messages
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The number of style rules emitted, including those inside `@keyframes`
+    #[must_use]
+    pub const fn rules(&self) -> usize {
+        self.rules
+    }
+
+    /// The number of complex selectors in the emitted style rules, e.g. 2 for
+    /// `a b, c {}`
+    #[must_use]
+    pub const fn selectors(&self) -> usize {
+        self.selectors
+    }
+
+    /// The number of declarations emitted
+    #[must_use]
+    pub const fn declarations(&self) -> usize {
+        self.declarations
+    }
+
+    /// The number of bytes taken up by the emitted style rules, and by
+    /// declarations outside of style rules (e.g. in `@font-face`), not
+    /// including the at-rules that contain them or the whitespace between
+    /// them
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub(crate) fn add_rule(&mut self, selectors: usize, bytes: usize) {
+        self.rules += 1;
+        self.selectors += selectors;
+        self.bytes += bytes;
+    }
+
+    pub(crate) fn add_declaration(&mut self, bytes: usize) {
+        self.declarations += 1;
+        self.bytes += bytes;
+    }
+}
@@ -13,64 +13,64 @@ pub(crate) static UNIT_CONVERSION_TABLE: Lazy<HashMap<Unit, HashMap<Unit, Number
     Lazy::new(|| {
         let mut from_in = HashMap::new();
         from_in.insert(Unit::In, Number::one());
-        from_in.insert(Unit::Cm, Number::one() / Number::from(2.54));
+        from_in.insert(Unit::Cm, Number::one() / Number::small_ratio(127, 50));
         from_in.insert(Unit::Pc, Number::small_ratio(1, 6));
-        from_in.insert(Unit::Mm, Number::one() / Number::from(25.4));
-        from_in.insert(Unit::Q, Number::one() / Number::from(101.6));
+        from_in.insert(Unit::Mm, Number::one() / Number::small_ratio(127, 5));
+        from_in.insert(Unit::Q, Number::one() / Number::small_ratio(508, 5));
         from_in.insert(Unit::Pt, Number::small_ratio(1, 72));
         from_in.insert(Unit::Px, Number::small_ratio(1, 96));
 
         let mut from_cm = HashMap::new();
-        from_cm.insert(Unit::In, Number::from(2.54));
+        from_cm.insert(Unit::In, Number::small_ratio(127, 50));
         from_cm.insert(Unit::Cm, Number::one());
-        from_cm.insert(Unit::Pc, Number::from(2.54) / Number::from(6));
+        from_cm.insert(Unit::Pc, Number::small_ratio(127, 50) / Number::from(6));
         from_cm.insert(Unit::Mm, Number::small_ratio(1, 10));
         from_cm.insert(Unit::Q, Number::small_ratio(1, 40));
-        from_cm.insert(Unit::Pt, Number::from(2.54) / Number::from(72));
-        from_cm.insert(Unit::Px, Number::from(2.54) / Number::from(96));
+        from_cm.insert(Unit::Pt, Number::small_ratio(127, 50) / Number::from(72));
+        from_cm.insert(Unit::Px, Number::small_ratio(127, 50) / Number::from(96));
 
         let mut from_pc = HashMap::new();
         from_pc.insert(Unit::In, Number::from(6));
-        from_pc.insert(Unit::Cm, Number::from(6) / Number::from(2.54));
+        from_pc.insert(Unit::Cm, Number::from(6) / Number::small_ratio(127, 50));
         from_pc.insert(Unit::Pc, Number::one());
-        from_pc.insert(Unit::Mm, Number::from(6) / Number::from(25.4));
-        from_pc.insert(Unit::Q, Number::from(6) / Number::from(101.6));
+        from_pc.insert(Unit::Mm, Number::from(6) / Number::small_ratio(127, 5));
+        from_pc.insert(Unit::Q, Number::from(6) / Number::small_ratio(508, 5));
         from_pc.insert(Unit::Pt, Number::small_ratio(1, 12));
         from_pc.insert(Unit::Px, Number::small_ratio(1, 16));
 
         let mut from_mm = HashMap::new();
-        from_mm.insert(Unit::In, Number::from(25.4));
+        from_mm.insert(Unit::In, Number::small_ratio(127, 5));
         from_mm.insert(Unit::Cm, Number::from(10));
-        from_mm.insert(Unit::Pc, Number::from(25.4) / Number::from(6));
+        from_mm.insert(Unit::Pc, Number::small_ratio(127, 5) / Number::from(6));
         from_mm.insert(Unit::Mm, Number::one());
         from_mm.insert(Unit::Q, Number::small_ratio(1, 4));
-        from_mm.insert(Unit::Pt, Number::from(25.4) / Number::from(72));
-        from_mm.insert(Unit::Px, Number::from(25.4) / Number::from(96));
+        from_mm.insert(Unit::Pt, Number::small_ratio(127, 5) / Number::from(72));
+        from_mm.insert(Unit::Px, Number::small_ratio(127, 5) / Number::from(96));
 
         let mut from_q = HashMap::new();
-        from_q.insert(Unit::In, Number::from(101.6));
+        from_q.insert(Unit::In, Number::small_ratio(508, 5));
         from_q.insert(Unit::Cm, Number::from(40));
-        from_q.insert(Unit::Pc, Number::from(101.6) / Number::from(6));
+        from_q.insert(Unit::Pc, Number::small_ratio(508, 5) / Number::from(6));
         from_q.insert(Unit::Mm, Number::from(4));
         from_q.insert(Unit::Q, Number::one());
-        from_q.insert(Unit::Pt, Number::from(101.6) / Number::from(72));
-        from_q.insert(Unit::Px, Number::from(101.6) / Number::from(96));
+        from_q.insert(Unit::Pt, Number::small_ratio(508, 5) / Number::from(72));
+        from_q.insert(Unit::Px, Number::small_ratio(508, 5) / Number::from(96));
 
         let mut from_pt = HashMap::new();
         from_pt.insert(Unit::In, Number::from(72));
-        from_pt.insert(Unit::Cm, Number::from(72) / Number::from(2.54));
+        from_pt.insert(Unit::Cm, Number::from(72) / Number::small_ratio(127, 50));
         from_pt.insert(Unit::Pc, Number::from(12));
-        from_pt.insert(Unit::Mm, Number::from(72) / Number::from(25.4));
-        from_pt.insert(Unit::Q, Number::from(72) / Number::from(101.6));
+        from_pt.insert(Unit::Mm, Number::from(72) / Number::small_ratio(127, 5));
+        from_pt.insert(Unit::Q, Number::from(72) / Number::small_ratio(508, 5));
         from_pt.insert(Unit::Pt, Number::one());
         from_pt.insert(Unit::Px, Number::small_ratio(3, 4));
 
         let mut from_px = HashMap::new();
         from_px.insert(Unit::In, Number::from(96));
-        from_px.insert(Unit::Cm, Number::from(96) / Number::from(2.54));
+        from_px.insert(Unit::Cm, Number::from(96) / Number::small_ratio(127, 50));
         from_px.insert(Unit::Pc, Number::from(16));
-        from_px.insert(Unit::Mm, Number::from(96) / Number::from(25.4));
-        from_px.insert(Unit::Q, Number::from(96) / Number::from(101.6));
+        from_px.insert(Unit::Mm, Number::from(96) / Number::small_ratio(127, 5));
+        from_px.insert(Unit::Q, Number::from(96) / Number::small_ratio(508, 5));
         from_px.insert(Unit::Pt, Number::small_ratio(4, 3));
         from_px.insert(Unit::Px, Number::one());
 
@@ -116,18 +116,24 @@ pub(crate) static UNIT_CONVERSION_TABLE: Lazy<HashMap<Unit, HashMap<Unit, Number
 
         let mut from_dpi = HashMap::new();
         from_dpi.insert(Unit::Dpi, Number::one());
-        from_dpi.insert(Unit::Dpcm, Number::from(2.54));
+        from_dpi.insert(Unit::Dpcm, Number::small_ratio(127, 50));
         from_dpi.insert(Unit::Dppx, Number::from(96));
+        from_dpi.insert(Unit::X, Number::from(96));
 
         let mut from_dpcm = HashMap::new();
-        from_dpcm.insert(Unit::Dpi, Number::one() / Number::from(2.54));
+        from_dpcm.insert(Unit::Dpi, Number::one() / Number::small_ratio(127, 50));
         from_dpcm.insert(Unit::Dpcm, Number::one());
-        from_dpcm.insert(Unit::Dppx, Number::from(96) / Number::from(2.54));
+        from_dpcm.insert(Unit::Dppx, Number::from(96) / Number::small_ratio(127, 50));
+        from_dpcm.insert(Unit::X, Number::from(96) / Number::small_ratio(127, 50));
 
         let mut from_dppx = HashMap::new();
         from_dppx.insert(Unit::Dpi, Number::small_ratio(1, 96));
-        from_dppx.insert(Unit::Dpcm, Number::from(2.54) / Number::from(96));
+        from_dppx.insert(Unit::Dpcm, Number::small_ratio(127, 50) / Number::from(96));
         from_dppx.insert(Unit::Dppx, Number::one());
+        from_dppx.insert(Unit::X, Number::one());
+
+        // `x` is an alias for `dppx`
+        let from_x = from_dppx.clone();
 
         let mut m = HashMap::new();
         m.insert(Unit::In, from_in);
@@ -152,6 +158,7 @@ pub(crate) static UNIT_CONVERSION_TABLE: Lazy<HashMap<Unit, HashMap<Unit, Number
         m.insert(Unit::Dpi, from_dpi);
         m.insert(Unit::Dpcm, from_dpcm);
         m.insert(Unit::Dppx, from_dppx);
+        m.insert(Unit::X, from_x);
 
         m
     });
@@ -1,9 +1,8 @@
-use std::{
-    fmt,
-    ops::{Div, Mul},
-};
+use std::fmt;
 
-use crate::interner::InternedString;
+use num_traits::One;
+
+use crate::{interner::InternedString, value::Number};
 
 pub(crate) use conversion::UNIT_CONVERSION_TABLE;
 
@@ -113,6 +112,22 @@ pub(crate) enum Unit {
     /// Units divided by each other
     Div(Box<DivUnit>),
 }
+/// Moves each of `units` into `numer`, unless it cancels out a comparable unit
+/// in `denom`, in which case `factor` is scaled by the ratio between the two
+fn cancel(units: Vec<Unit>, denom: &mut Vec<Unit>, numer: &mut Vec<Unit>, factor: &mut Number) {
+    for unit in units {
+        match denom.iter().position(|d| unit.comparable(d)) {
+            Some(idx) => {
+                let cancelled = denom.remove(idx);
+                if cancelled != unit {
+                    *factor *= Number::one().convert(&unit, &cancelled);
+                }
+            }
+            None => numer.push(unit),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub(crate) enum UnitKind {
     Absolute,
@@ -140,105 +155,87 @@ impl DivUnit {
 
 impl fmt::Display for DivUnit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.numer == Unit::None {
-            write!(f, "{}^-1", self.denom)
-        } else {
-            write!(f, "{}/{}", self.numer, self.denom)
+        match (&self.numer, &self.denom) {
+            (Unit::None, Unit::Mul(..)) => write!(f, "({})^-1", self.denom),
+            (Unit::None, _) => write!(f, "{}^-1", self.denom),
+            _ => write!(f, "{}/{}", self.numer, self.denom),
         }
     }
 }
 
-#[allow(clippy::match_same_arms)]
-impl Mul<Unit> for DivUnit {
-    type Output = Unit;
-    fn mul(self, rhs: Unit) -> Self::Output {
-        match rhs {
-            Unit::Mul(..) => todo!(),
-            Unit::Div(..) => todo!(),
-            Unit::None => todo!(),
-            _ => {
-                if self.denom == rhs {
-                    self.numer
-                } else {
-                    match self.denom {
-                        Unit::Mul(..) => todo!(),
-                        Unit::Div(..) => unreachable!(),
-                        _ => match self.numer {
-                            Unit::Mul(..) => todo!(),
-                            Unit::Div(..) => unreachable!(),
-                            Unit::None => {
-                                let numer = Unit::Mul(Box::new(vec![rhs]));
-                                Unit::Div(Box::new(DivUnit::new(numer, self.denom)))
-                            }
-                            _ => {
-                                let numer = Unit::Mul(Box::new(vec![self.numer, rhs]));
-                                Unit::Div(Box::new(DivUnit::new(numer, self.denom)))
-                            }
-                        },
-                    }
-                }
+impl Unit {
+    /// The units multiplied together in the numerator and in the denominator
+    /// of this unit
+    fn into_parts(self) -> (Vec<Unit>, Vec<Unit>) {
+        match self {
+            Unit::None => (Vec::new(), Vec::new()),
+            Unit::Mul(units) => (*units, Vec::new()),
+            Unit::Div(div) => {
+                let DivUnit { numer, denom } = *div;
+                (numer.into_parts().0, denom.into_parts().0)
             }
+            unit => (vec![unit], Vec::new()),
         }
     }
-}
 
-// impl Div<Unit> for DivUnit {
-//     type Output = Unit;
-//     fn div(self, rhs: Unit) -> Self::Output {
-//         todo!()
-//     }
-// }
+    fn from_parts(numer: Vec<Unit>, denom: Vec<Unit>) -> Unit {
+        fn product(mut units: Vec<Unit>) -> Unit {
+            match units.len() {
+                0 => Unit::None,
+                1 => units.pop().unwrap(),
+                _ => Unit::Mul(Box::new(units)),
+            }
+        }
 
-impl Mul<Unit> for Unit {
-    type Output = Unit;
-    fn mul(self, rhs: Unit) -> Self::Output {
-        match self {
-            Unit::Mul(u) => match rhs {
-                Unit::Mul(u2) => {
-                    let mut unit1 = *u;
-                    unit1.extend_from_slice(&*u2);
-                    Unit::Mul(Box::new(unit1))
-                }
-                Unit::Div(..) => todo!(),
-                _ => {
-                    let mut unit1 = *u;
-                    unit1.push(rhs);
-                    Unit::Mul(Box::new(unit1))
-                }
-            },
-            Unit::Div(div) => *div * rhs,
-            _ => match rhs {
-                Unit::Mul(u2) => {
-                    let mut unit1 = vec![self];
-                    unit1.extend_from_slice(&*u2);
-                    Unit::Mul(Box::new(unit1))
-                }
-                Unit::Div(..) => todo!(),
-                _ => Unit::Mul(Box::new(vec![self, rhs])),
-            },
+        if denom.is_empty() {
+            return product(numer);
         }
+
+        Unit::Div(Box::new(DivUnit::new(product(numer), product(denom))))
     }
-}
 
-impl Div<Unit> for Unit {
-    type Output = Unit;
-    #[allow(clippy::if_same_then_else)]
-    fn div(self, rhs: Unit) -> Self::Output {
-        if let Unit::Div(..) = self {
-            todo!()
-        } else if let Unit::Div(..) = rhs {
-            todo!()
-        } else {
-            Unit::Div(Box::new(DivUnit::new(self, rhs)))
-        }
+    /// The reciprocal of this unit, e.g. `px^-1` for `px`
+    pub fn invert(self) -> Unit {
+        let (numer, denom) = self.into_parts();
+        Unit::from_parts(denom, numer)
     }
-}
 
-impl Unit {
+    /// The unit of the product of a number with this unit and a number with
+    /// `other`, along with the factor the product has to be multiplied by
+    ///
+    /// A unit in the numerator of one side cancels out a comparable unit in
+    /// the denominator of the other, e.g. `px * s/px` is `s`, and `in * px^-1`
+    /// is unitless with a factor of `96`.
+    pub fn multiply(self, other: Unit) -> (Unit, Number) {
+        let (numer1, mut denom1) = self.into_parts();
+        let (numer2, mut denom2) = other.into_parts();
+
+        let mut factor = Number::one();
+        let mut numer = Vec::new();
+
+        cancel(numer1, &mut denom2, &mut numer, &mut factor);
+        cancel(numer2, &mut denom1, &mut numer, &mut factor);
+
+        denom1.append(&mut denom2);
+
+        (Unit::from_parts(numer, denom1), factor)
+    }
+
+    /// Whether a number with this unit can be converted to `other`
+    ///
+    /// Unitless numbers are comparable to every unit. Compound units are
+    /// comparable when each unit in the numerator and in the denominator of
+    /// one can be converted to a distinct unit in the same position of the
+    /// other, e.g. `px/s` and `in/ms`.
     pub fn comparable(&self, other: &Unit) -> bool {
-        if other == &Unit::None {
+        if self == &Unit::None || other == &Unit::None {
             return true;
         }
+
+        if self.is_compound() || other.is_compound() {
+            return self.conversion_factor(other).is_some();
+        }
+
         match self.kind() {
             UnitKind::FontRelative | UnitKind::ViewportRelative | UnitKind::Other => self == other,
             UnitKind::None => true,
@@ -246,6 +243,77 @@ impl Unit {
         }
     }
 
+    /// Whether a number with this unit could be added to one with `other` in
+    /// a calculation once the browser knows what they refer to
+    ///
+    /// Lengths may all turn out to be compatible, as may a unit whose kind
+    /// isn't known to Sass, such as `%`. A number without a unit is only
+    /// compatible with another number without one.
+    pub(crate) fn is_possibly_compatible(&self, other: &Unit) -> bool {
+        fn kind(unit: &Unit) -> UnitKind {
+            match unit.kind() {
+                UnitKind::FontRelative | UnitKind::ViewportRelative => UnitKind::Absolute,
+                kind => kind,
+            }
+        }
+
+        match (kind(self), kind(other)) {
+            (UnitKind::None, kind) | (kind, UnitKind::None) => kind == UnitKind::None,
+            (UnitKind::Other, _) | (_, UnitKind::Other) => true,
+            (kind1, kind2) => kind1 == kind2,
+        }
+    }
+
+    /// Whether this unit is made up of more than one unit, such as `px*s` or
+    /// `px/s`
+    pub(crate) fn is_compound(&self) -> bool {
+        matches!(self, Unit::Mul(..) | Unit::Div(..))
+    }
+
+    /// The ratio a number with this unit has to be multiplied by to express
+    /// it in `to`, or `None` if the two are not comparable
+    pub(crate) fn conversion_factor(&self, to: &Unit) -> Option<Number> {
+        if self == to || self == &Unit::None || to == &Unit::None {
+            return Some(Number::one());
+        }
+
+        if !self.is_compound() && !to.is_compound() {
+            return UNIT_CONVERSION_TABLE
+                .get(to)
+                .and_then(|from| from.get(self))
+                .cloned();
+        }
+
+        let (from_numer, from_denom) = self.clone().into_parts();
+        let (mut to_numer, mut to_denom) = to.clone().into_parts();
+
+        let mut factor = Number::one();
+
+        for unit in from_numer {
+            let idx = to_numer.iter().position(|u| unit.comparable(u))?;
+            factor *= unit.conversion_factor(&to_numer.remove(idx))?;
+        }
+
+        for unit in from_denom {
+            let idx = to_denom.iter().position(|u| unit.comparable(u))?;
+            factor *= to_denom.remove(idx).conversion_factor(&unit)?;
+        }
+
+        if to_numer.is_empty() && to_denom.is_empty() {
+            Some(factor)
+        } else {
+            None
+        }
+    }
+
+    /// Whether this unit measures a distance, such as `px` or `em`
+    pub fn is_length(&self) -> bool {
+        matches!(
+            self.kind(),
+            UnitKind::Absolute | UnitKind::FontRelative | UnitKind::ViewportRelative
+        )
+    }
+
     /// Used internally to determine if two units are comparable or not
     fn kind(&self) -> UnitKind {
         match self {
@@ -0,0 +1,97 @@
+use std::char::{decode_utf16, REPLACEMENT_CHARACTER};
+
+use crate::error::SassResult;
+
+/// The encodings a stylesheet may be declared in, other than UTF-8
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    Utf16Be,
+    Utf16Le,
+    Windows1252,
+}
+
+/// Decodes the contents of a stylesheet read from disk, determining its
+/// encoding the way browsers do for CSS
+///
+/// A byte-order mark takes precedence, followed by a `@charset` rule at the
+/// very beginning of the file. Otherwise, the stylesheet is assumed to be
+/// UTF-8. The byte-order mark is removed; the `@charset` rule is left in
+/// place and is removed during parsing.
+///
+/// <https://drafts.csswg.org/css-syntax-3/#input-byte-stream>
+pub(crate) fn decode_stylesheet(bytes: Vec<u8>) -> SassResult<String> {
+    let encoding = match bytes.as_slice() {
+        [0xEF, 0xBB, 0xBF, ..] => {
+            let mut s = String::from_utf8(bytes)?;
+            s.remove(0);
+            return Ok(s);
+        }
+        [0xFE, 0xFF, ..] => return Ok(decode_utf_16(&bytes[2..], Encoding::Utf16Be)),
+        [0xFF, 0xFE, ..] => return Ok(decode_utf_16(&bytes[2..], Encoding::Utf16Le)),
+        _ => charset_rule_encoding(&bytes),
+    };
+
+    match encoding {
+        // A stylesheet that could be read as ASCII to find its `@charset` rule
+        // can't be UTF-16, so `@charset "UTF-16"` is treated as UTF-8
+        None | Some(Encoding::Utf16Be) | Some(Encoding::Utf16Le) => Ok(String::from_utf8(bytes)?),
+        Some(Encoding::Windows1252) => Ok(bytes.into_iter().map(windows_1252_char).collect()),
+    }
+}
+
+/// Finds the encoding named by a `@charset` rule at the very beginning of
+/// `bytes`, if it names an encoding we know of
+fn charset_rule_encoding(bytes: &[u8]) -> Option<Encoding> {
+    const PREFIX: &[u8] = b"@charset \"";
+
+    let rest = bytes.get(..1024.min(bytes.len()))?.strip_prefix(PREFIX)?;
+    let end = rest.windows(2).position(|w| w == b"\";")?;
+
+    let label = std::str::from_utf8(&rest[..end]).ok()?;
+
+    encoding_for_label(label)
+}
+
+/// <https://encoding.spec.whatwg.org/#concept-encoding-get>
+fn encoding_for_label(label: &str) -> Option<Encoding> {
+    Some(match label.trim().to_ascii_lowercase().as_str() {
+        "unicodefffe" | "utf-16be" => Encoding::Utf16Be,
+        "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+        | "utf-16le" => Encoding::Utf16Le,
+        "ansi_x3.4-1968" | "ascii" | "cp1252" | "cp819" | "csisolatin1" | "ibm819"
+        | "iso-8859-1" | "iso-ir-100" | "iso8859-1" | "iso88591" | "iso_8859-1"
+        | "iso_8859-1:1987" | "l1" | "latin1" | "us-ascii" | "windows-1252" | "x-cp1252" => {
+            Encoding::Windows1252
+        }
+        _ => return None,
+    })
+}
+
+fn decode_utf_16(bytes: &[u8], encoding: Encoding) -> String {
+    let units = bytes.chunks(2).map(|chunk| match *chunk {
+        [a, b] if encoding == Encoding::Utf16Be => u16::from_be_bytes([a, b]),
+        [a, b] => u16::from_le_bytes([a, b]),
+        // a trailing odd byte is an incomplete code unit
+        _ => REPLACEMENT_CHARACTER as u16,
+    });
+
+    decode_utf16(units)
+        .map(|c| c.unwrap_or(REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// <https://encoding.spec.whatwg.org/index-windows-1252.txt>
+fn windows_1252_char(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}',
+        '\u{8F}', '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}',
+        '\u{2014}', '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}',
+        '\u{178}',
+    ];
+
+    match byte {
+        0x80..=0x9F => HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
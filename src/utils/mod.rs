@@ -1,11 +1,15 @@
 pub(crate) use chars::*;
 pub(crate) use comment_whitespace::*;
+pub(crate) use encoding::*;
 pub(crate) use number::*;
+pub(crate) use path::*;
 pub(crate) use read_until::*;
 pub(crate) use strings::*;
 
 mod chars;
 mod comment_whitespace;
+mod encoding;
 mod number;
+mod path;
 mod read_until;
 mod strings;
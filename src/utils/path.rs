@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+
+/// Interprets the URL of an `@import` or `@use`, or a load path, as a path on
+/// the current platform
+///
+/// Both `/` and `\` are accepted as separators, in any combination, so that
+/// stylesheets resolve identically on Windows and Unix. Drive letters (`C:/`)
+/// and UNC prefixes (`//server/share`) are kept as-is, and so are only
+/// absolute on Windows.
+pub(crate) fn url_to_path(url: &str) -> PathBuf {
+    PathBuf::from(
+        url.chars()
+            .map(|c| {
+                if c == '/' || c == '\\' {
+                    MAIN_SEPARATOR
+                } else {
+                    c
+                }
+            })
+            .collect::<String>(),
+    )
+}
+
+/// Formats `path` with forward slashes, as used for file names in spans and
+/// error messages regardless of platform
+pub(crate) fn path_to_url(path: &Path) -> String {
+    let path = path.to_string_lossy();
+
+    if MAIN_SEPARATOR == '\\' {
+        path.replace('\\', "/")
+    } else {
+        path.into_owned()
+    }
+}
@@ -31,3 +31,72 @@ pub(crate) fn is_ident(s: &str) -> bool {
     }
     true
 }
+
+/// The number of single-character insertions, deletions, substitutions, or
+/// transpositions of adjacent characters required to turn `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // distances[i][j] is the distance between the first `i` chars of `a`
+    // and the first `j` chars of `b`
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution_cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance = (distances[i - 1][j - 1] + substitution_cost)
+                .min(distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// Find the candidate most similar to `name`, if any is close enough
+/// to plausibly be a typo of it
+///
+/// This is used to suggest alternatives when a variable, function, or mixin
+/// is not found. Ties are broken by the order of `candidates`.
+pub(crate) fn closest_match<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    let mut best: Option<(usize, &'a str)> = None;
+
+    for candidate in candidates {
+        if candidate == name {
+            continue;
+        }
+
+        let distance = edit_distance(name, candidate);
+
+        if distance > max_distance {
+            continue;
+        }
+
+        if best.map_or(true, |(best_distance, _)| distance < best_distance) {
+            best = Some((distance, candidate));
+        }
+    }
+
+    best.map(|(_, candidate)| candidate)
+}
@@ -0,0 +1,316 @@
+use codemap::Span;
+use num_traits::Zero;
+
+use crate::{error::SassResult, unit::Unit, value::Number, value::Value};
+
+/// The name of a calculation, which is written before its arguments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CalculationName {
+    Calc,
+    Min,
+    Max,
+    Clamp,
+}
+
+impl CalculationName {
+    /// The calculation called `lower`, if it is one
+    pub fn from_name(lower: &str) -> Option<Self> {
+        Some(match lower {
+            "calc" => Self::Calc,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            "clamp" => Self::Clamp,
+            _ => return None,
+        })
+    }
+
+    /// Whether this is `min()` or `max()`, which may also be calls to the
+    /// Sass functions of the same name
+    pub fn is_min_or_max(self) -> bool {
+        matches!(self, Self::Min | Self::Max)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Calc => "calc",
+            Self::Min => "min",
+            Self::Max => "max",
+            Self::Clamp => "clamp",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CalculationOperator {
+    Plus,
+    Minus,
+    Mul,
+    Div,
+}
+
+impl CalculationOperator {
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Plus | Self::Minus => 1,
+            Self::Mul | Self::Div => 2,
+        }
+    }
+
+    fn as_char(self) -> char {
+        match self {
+            Self::Plus => '+',
+            Self::Minus => '-',
+            Self::Mul => '*',
+            Self::Div => '/',
+        }
+    }
+}
+
+/// An argument to a calculation, or an operand of one of its operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CalculationArg {
+    Number(Number, Unit),
+    Calculation(SassCalculation),
+    /// An unquoted string, such as `var(--gap)`
+    String(String),
+    /// The result of interpolation, which is parenthesized when it is the
+    /// operand of an operation, as it may contain operators of its own
+    Interpolation(String),
+    Operation(
+        Box<CalculationArg>,
+        CalculationOperator,
+        Box<CalculationArg>,
+    ),
+}
+
+impl CalculationArg {
+    /// `left op right`, which is evaluated if both are numbers whose units
+    /// allow it, and otherwise kept to be written out as it is
+    ///
+    /// Adding or subtracting numbers whose units can never be compatible, such
+    /// as `1px + 2s`, is an error. Within `min()` and `max()`, a number without
+    /// a unit may be added to one with a unit, as the Sass functions allow.
+    pub fn operate(
+        left: Self,
+        op: CalculationOperator,
+        right: Self,
+        in_min_max: bool,
+        span: Span,
+    ) -> SassResult<Self> {
+        if let (Self::Number(num, unit), Self::Number(num2, unit2)) = (&left, &right) {
+            if let Some((num, unit)) = operate_numbers(num, unit, op, num2, unit2, in_min_max) {
+                return Ok(Self::Number(num, unit));
+            }
+
+            if matches!(op, CalculationOperator::Plus | CalculationOperator::Minus) {
+                verify_compatible_numbers(num, unit, num2, unit2, span)?;
+            }
+        }
+
+        Ok(Self::Operation(Box::new(left), op, Box::new(right)))
+    }
+
+    fn write(&self, buf: &mut String, span: Span, is_compressed: bool) -> SassResult<()> {
+        match self {
+            Self::Number(num, unit) => buf.push_str(
+                &Value::Dimension(Some(num.clone()), unit.clone(), true)
+                    .to_css_string(span, is_compressed)?,
+            ),
+            Self::Calculation(calc) => buf.push_str(&calc.to_css_string(span, is_compressed)?),
+            Self::String(s) | Self::Interpolation(s) => buf.push_str(s),
+            Self::Operation(left, op, right) => {
+                let parenthesize_left = match &**left {
+                    Self::Interpolation(..) => true,
+                    Self::Operation(_, left_op, _) => left_op.precedence() < op.precedence(),
+                    _ => false,
+                };
+
+                let parenthesize_right = match &**right {
+                    Self::Interpolation(..) => true,
+                    Self::Operation(_, right_op, _) => match op {
+                        CalculationOperator::Div => true,
+                        CalculationOperator::Plus => false,
+                        CalculationOperator::Minus | CalculationOperator::Mul => {
+                            right_op.precedence() == 1
+                        }
+                    },
+                    _ => false,
+                };
+
+                left.write_operand(buf, parenthesize_left, span, is_compressed)?;
+
+                // in compressed mode, `+` and `-` still need whitespace around
+                // them to not be parsed as signs
+                if !is_compressed || op.precedence() == 1 {
+                    buf.push(' ');
+                    buf.push(op.as_char());
+                    buf.push(' ');
+                } else {
+                    buf.push(op.as_char());
+                }
+
+                right.write_operand(buf, parenthesize_right, span, is_compressed)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_operand(
+        &self,
+        buf: &mut String,
+        parenthesize: bool,
+        span: Span,
+        is_compressed: bool,
+    ) -> SassResult<()> {
+        if parenthesize {
+            buf.push('(');
+        }
+
+        self.write(buf, span, is_compressed)?;
+
+        if parenthesize {
+            buf.push(')');
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors if numbers with `unit` and `unit2` can never be added together in
+/// CSS, whatever the browser resolves their units to
+fn verify_compatible_numbers(
+    num: &Number,
+    unit: &Unit,
+    num2: &Number,
+    unit2: &Unit,
+    span: Span,
+) -> SassResult<()> {
+    let inspect = |num: &Number, unit: &Unit| {
+        Value::Dimension(Some(num.clone()), unit.clone(), true).inspect(span)
+    };
+
+    for (num, unit) in [(num, unit), (num2, unit2)] {
+        if unit.is_compound() {
+            return Err((
+                format!(
+                    "Number {} isn't compatible with CSS calculations.",
+                    inspect(num, unit)?
+                ),
+                span,
+            )
+                .into());
+        }
+    }
+
+    if !unit.is_possibly_compatible(unit2) {
+        return Err((
+            format!(
+                "{} and {} are incompatible.",
+                inspect(num, unit)?,
+                inspect(num2, unit2)?
+            ),
+            span,
+        )
+            .into());
+    }
+
+    Ok(())
+}
+
+/// Evaluates an operation between two numbers, unless their units can't be
+/// combined, or the divisor is zero
+fn operate_numbers(
+    num: &Number,
+    unit: &Unit,
+    op: CalculationOperator,
+    num2: &Number,
+    unit2: &Unit,
+    in_min_max: bool,
+) -> Option<(Number, Unit)> {
+    Some(match op {
+        CalculationOperator::Plus | CalculationOperator::Minus => {
+            let (num2, unit) = if unit == unit2 {
+                (num2.clone(), unit.clone())
+            } else if in_min_max && unit == &Unit::None {
+                (num2.clone(), unit2.clone())
+            } else if in_min_max && unit2 == &Unit::None {
+                (num2.clone(), unit.clone())
+            } else if unit != &Unit::None && unit2 != &Unit::None && unit.comparable(unit2) {
+                (num2.clone().convert(unit2, unit), unit.clone())
+            } else {
+                return None;
+            };
+
+            if op == CalculationOperator::Plus {
+                (num.clone() + num2, unit)
+            } else {
+                (num.clone() - num2, unit)
+            }
+        }
+        CalculationOperator::Mul => {
+            let (unit, factor) = unit.clone().multiply(unit2.clone());
+
+            (num.clone() * num2.clone() * factor, unit)
+        }
+        CalculationOperator::Div => {
+            if num2.is_zero() {
+                return None;
+            }
+
+            if unit == unit2 {
+                (num.clone() / num2.clone(), Unit::None)
+            } else if unit == &Unit::None {
+                (num.clone() / num2.clone(), unit2.clone().invert())
+            } else if unit2 == &Unit::None {
+                (num.clone() / num2.clone(), unit.clone())
+            } else if unit.comparable(unit2) {
+                (num.clone() / num2.clone().convert(unit2, unit), Unit::None)
+            } else {
+                return None;
+            }
+        }
+    })
+}
+
+/// A call to `calc()`, `min()`, `max()`, or `clamp()` that is written out to
+/// the CSS, such as `calc(100% - 10px)`, with its arguments simplified as far
+/// as they can be before the browser knows the values they refer to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SassCalculation {
+    pub name: CalculationName,
+    pub args: Vec<CalculationArg>,
+}
+
+impl SassCalculation {
+    /// `calc(arg)`, which is just a number if `arg` is one
+    pub fn calc(arg: CalculationArg) -> Value {
+        match arg {
+            CalculationArg::Number(num, unit) => Value::Dimension(Some(num), unit, true),
+            CalculationArg::Calculation(calc) => Value::Calculation(calc),
+            arg => Value::Calculation(SassCalculation {
+                name: CalculationName::Calc,
+                args: vec![arg],
+            }),
+        }
+    }
+
+    pub fn to_css_string(&self, span: Span, is_compressed: bool) -> SassResult<String> {
+        let mut buf = String::with_capacity(16);
+
+        buf.push_str(self.name.as_str());
+        buf.push('(');
+
+        for (idx, arg) in self.args.iter().enumerate() {
+            if idx != 0 {
+                buf.push_str(if is_compressed { "," } else { ", " });
+            }
+
+            arg.write(&mut buf, span, is_compressed)?;
+        }
+
+        buf.push(')');
+
+        Ok(buf)
+    }
+}
@@ -14,11 +14,15 @@ use crate::{
     {Cow, Token},
 };
 
+pub(crate) use calculation::{
+    CalculationArg, CalculationName, CalculationOperator, SassCalculation,
+};
 use css_function::is_special_function;
 pub(crate) use map::SassMap;
 pub(crate) use number::Number;
 pub(crate) use sass_function::SassFunction;
 
+mod calculation;
 pub(crate) mod css_function;
 mod map;
 mod number;
@@ -36,9 +40,12 @@ pub(crate) enum Value {
     Color(Box<Color>),
     String(String, QuoteKind),
     Map(SassMap),
-    ArgList(Vec<Spanned<Value>>),
+    /// The rest parameter of a callable, along with any keyword arguments
+    /// that were not bound to another parameter
+    ArgList(Vec<Spanned<Value>>, SassMap),
     /// Returned by `get-function()`
     FunctionRef(SassFunction),
+    Calculation(SassCalculation),
 }
 
 impl PartialEq for Value {
@@ -103,8 +110,15 @@ impl PartialEq for Value {
                     false
                 }
             }
-            Value::ArgList(list1) => match other {
-                Value::ArgList(list2) => list1 == list2,
+            Value::Calculation(calc1) => {
+                if let Value::Calculation(calc2) = other {
+                    calc1 == calc2
+                } else {
+                    false
+                }
+            }
+            Value::ArgList(list1, ..) => match other {
+                Value::ArgList(list2, ..) => list1 == list2,
                 Value::List(list2, ListSeparator::Comma, ..) => {
                     if list1.len() != list2.len() {
                         return false;
@@ -264,7 +278,11 @@ impl Value {
                         }),
                 )),
             },
+            Value::Color(c) if is_compressed && !c.is_as_written() => {
+                Cow::owned(c.to_compressed_string())
+            }
             Value::Color(c) => Cow::owned(c.to_string()),
+            Value::Calculation(calc) => Cow::owned(calc.to_css_string(span, is_compressed)?),
             Value::String(string, QuoteKind::None) => {
                 let mut after_newline = false;
                 let mut buf = String::with_capacity(string.len());
@@ -295,10 +313,10 @@ impl Value {
             Value::True => Cow::const_str("true"),
             Value::False => Cow::const_str("false"),
             Value::Null => Cow::const_str(""),
-            Value::ArgList(args) if args.is_empty() => {
+            Value::ArgList(args, ..) if args.is_empty() => {
                 return Err(("() isn't a valid CSS value.", span).into());
             }
-            Value::ArgList(args) => Cow::owned(
+            Value::ArgList(args, ..) => Cow::owned(
                 args.iter()
                     .filter(|x| !x.is_null())
                     .map(|a| a.node.to_css_string(span, is_compressed))
@@ -337,6 +355,7 @@ impl Value {
             Value::Dimension(..) => "number",
             Value::List(..) => "list",
             Value::FunctionRef(..) => "function",
+            Value::Calculation(..) => "calculation",
             Value::ArgList(..) => "arglist",
             Value::True | Value::False => "bool",
             Value::Null => "null",
@@ -351,6 +370,7 @@ impl Value {
     pub fn is_special_function(&self) -> bool {
         match self {
             Value::String(s, QuoteKind::None) => is_special_function(s),
+            Value::Calculation(..) => true,
             _ => false,
         }
     }
@@ -455,30 +475,34 @@ impl Value {
                 Brackets::None => Cow::const_str("()"),
                 Brackets::Bracketed => Cow::const_str("[]"),
             },
-            Value::List(v, sep, brackets) if v.len() == 1 => match brackets {
-                Brackets::None => match sep {
-                    ListSeparator::Space => v[0].inspect(span)?,
-                    ListSeparator::Comma => Cow::owned(format!("({},)", v[0].inspect(span)?)),
-                },
-                Brackets::Bracketed => match sep {
-                    ListSeparator::Space => Cow::owned(format!("[{}]", v[0].inspect(span)?)),
-                    ListSeparator::Comma => Cow::owned(format!("[{},]", v[0].inspect(span)?)),
-                },
-            },
-            Value::List(vals, sep, brackets) => Cow::owned(match brackets {
-                Brackets::None => vals
+            Value::List(v, sep, brackets) if v.len() == 1 => {
+                let element = v[0].inspect_list_element(*sep, span)?;
+
+                match brackets {
+                    Brackets::None => match sep {
+                        ListSeparator::Space => element,
+                        ListSeparator::Comma => Cow::owned(format!("({},)", element)),
+                        ListSeparator::Slash => Cow::owned(format!("({}/)", element)),
+                    },
+                    Brackets::Bracketed => match sep {
+                        ListSeparator::Space => Cow::owned(format!("[{}]", element)),
+                        ListSeparator::Comma => Cow::owned(format!("[{},]", element)),
+                        ListSeparator::Slash => Cow::owned(format!("[{}/]", element)),
+                    },
+                }
+            }
+            Value::List(vals, sep, brackets) => {
+                let elements = vals
                     .iter()
-                    .map(|x| x.inspect(span))
+                    .map(|x| x.inspect_list_element(*sep, span))
                     .collect::<SassResult<Vec<Cow<'static, str>>>>()?
-                    .join(sep.as_str()),
-                Brackets::Bracketed => format!(
-                    "[{}]",
-                    vals.iter()
-                        .map(|x| x.inspect(span))
-                        .collect::<SassResult<Vec<Cow<'static, str>>>>()?
-                        .join(sep.as_str()),
-                ),
-            }),
+                    .join(sep.as_str());
+
+                Cow::owned(match brackets {
+                    Brackets::None => elements,
+                    Brackets::Bracketed => format!("[{}]", elements),
+                })
+            }
             Value::FunctionRef(f) => Cow::owned(format!("get-function(\"{}\")", f.name())),
             Value::Null => Cow::const_str("null"),
             Value::Map(map) => Cow::owned(format!(
@@ -492,19 +516,19 @@ impl Value {
                 Cow::owned(format!("{}{}", num.inspect(), unit))
             }
             Value::Dimension(None, unit, ..) => Cow::owned(format!("NaN{}", unit)),
-            Value::ArgList(args) if args.is_empty() => Cow::const_str("()"),
-            Value::ArgList(args) if args.len() == 1 => Cow::owned(format!(
+            Value::ArgList(args, ..) if args.is_empty() => Cow::const_str("()"),
+            Value::ArgList(args, ..) if args.len() == 1 => Cow::owned(format!(
                 "({},)",
                 args.iter()
                     .filter(|x| !x.is_null())
-                    .map(|a| a.node.inspect(span))
+                    .map(|a| a.node.inspect_list_element(ListSeparator::Comma, span))
                     .collect::<SassResult<Vec<Cow<'static, str>>>>()?
                     .join(", "),
             )),
-            Value::ArgList(args) => Cow::owned(
+            Value::ArgList(args, ..) => Cow::owned(
                 args.iter()
                     .filter(|x| !x.is_null())
-                    .map(|a| a.node.inspect(span))
+                    .map(|a| a.node.inspect_list_element(ListSeparator::Comma, span))
                     .collect::<SassResult<Vec<Cow<'static, str>>>>()?
                     .join(", "),
             ),
@@ -512,15 +536,46 @@ impl Value {
             | Value::True
             | Value::False
             | Value::Color(..)
+            | Value::Calculation(..)
             | Value::String(..) => self.to_css_string(span, false)?,
         })
     }
 
+    /// Inspects this value as an element of a list separated by `separator`,
+    /// parenthesizing it if it is a list whose elements would otherwise be
+    /// read back as elements of the outer list
+    fn inspect_list_element(
+        &self,
+        separator: ListSeparator,
+        span: Span,
+    ) -> SassResult<Cow<'static, str>> {
+        let needs_parens = match self {
+            Value::List(v, inner, Brackets::None) if v.len() > 1 => match separator {
+                ListSeparator::Comma => *inner == ListSeparator::Comma,
+                ListSeparator::Slash => {
+                    matches!(inner, ListSeparator::Comma | ListSeparator::Slash)
+                }
+                ListSeparator::Space => true,
+            },
+            // argument lists are always comma separated
+            Value::ArgList(v, ..) => v.len() > 1,
+            _ => false,
+        };
+
+        let inspected = self.inspect(span)?;
+
+        Ok(if needs_parens {
+            Cow::owned(format!("({})", inspected))
+        } else {
+            inspected
+        })
+    }
+
     pub fn as_list(self) -> Vec<Value> {
         match self {
             Value::List(v, ..) => v,
             Value::Map(m) => m.as_list(),
-            Value::ArgList(v) => v.into_iter().map(|val| val.node).collect(),
+            Value::ArgList(v, ..) => v.into_iter().map(|val| val.node).collect(),
             v => vec![v],
         }
     }
@@ -561,10 +616,15 @@ impl Value {
             at_root: parser.at_root,
             at_root_has_selector: parser.at_root_has_selector,
             extender: parser.extender,
-            content_scopes: parser.content_scopes,
             options: parser.options,
             modules: parser.modules,
             module_config: parser.module_config,
+            forwarded_scope: parser.forwarded_scope,
+            call_stack: parser.call_stack,
+            unique_id_count: parser.unique_id_count,
+            loaded_files: parser.loaded_files,
+            emitted_warnings: parser.emitted_warnings,
+            deadline: parser.deadline,
         }
         .parse_selector(allows_parent, true, String::new())?
         .0)
@@ -599,6 +659,7 @@ impl Value {
                             }
                         }
                     }
+                    ListSeparator::Slash => return Ok(None),
                 }
 
                 result.join(sep.as_str())
@@ -11,7 +11,7 @@ use num_traits::{
     CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Num, One, Signed, ToPrimitive, Zero,
 };
 
-use crate::unit::{Unit, UNIT_CONVERSION_TABLE};
+use crate::unit::Unit;
 
 use integer::Integer;
 
@@ -152,24 +152,6 @@ impl Number {
         )?)))
     }
 
-    pub fn ln(self) -> Option<Self> {
-        Some(Number::Big(Box::new(BigRational::from_float(
-            self.as_float()?.ln(),
-        )?)))
-    }
-
-    pub fn log(self, base: Number) -> Option<Self> {
-        Some(Number::Big(Box::new(BigRational::from_float(
-            self.as_float()?.log(base.as_float()?),
-        )?)))
-    }
-
-    pub fn pow(self, exponent: Self) -> Option<Self> {
-        Some(Number::Big(Box::new(BigRational::from_float(
-            self.as_float()?.powf(exponent.as_float()?),
-        )?)))
-    }
-
     pub fn pi() -> Self {
         Number::from(std::f64::consts::PI)
     }
@@ -183,7 +165,10 @@ impl Number {
     /// Invariants: `from.comparable(&to)` must be true
     pub fn convert(self, from: &Unit, to: &Unit) -> Self {
         debug_assert!(from.comparable(to));
-        self * UNIT_CONVERSION_TABLE[to][from].clone()
+        match from.conversion_factor(to) {
+            Some(factor) => self * factor,
+            None => self,
+        }
     }
 }
 
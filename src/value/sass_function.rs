@@ -11,11 +11,16 @@
 
 use std::fmt;
 
-use codemap::Spanned;
+use codemap::{Span, Spanned};
 
 use crate::{
-    args::CallArgs, atrule::Function, builtin::Builtin, common::Identifier, error::SassResult,
-    parse::Parser, value::Value,
+    args::CallArgs,
+    atrule::Function,
+    builtin::Builtin,
+    common::{Identifier, QuoteKind},
+    error::SassResult,
+    parse::Parser,
+    value::Value,
 };
 
 /// A Sass function
@@ -31,15 +36,19 @@ pub(crate) enum SassFunction {
         function: Box<Function>,
         name: Identifier,
     },
+    /// Returned by `get-function()` with `$css: true`, and written out as a
+    /// call to the plain CSS function of the same name
+    Plain(String),
 }
 
 impl SassFunction {
     /// Get the name of the function referenced
     ///
     /// Used mainly in debugging and `inspect()`
-    pub fn name(&self) -> &Identifier {
+    pub fn name(&self) -> &str {
         match self {
-            Self::Builtin(_, name) | Self::UserDefined { name, .. } => name,
+            Self::Builtin(_, name) | Self::UserDefined { name, .. } => name.as_str(),
+            Self::Plain(name) => name,
         }
     }
 
@@ -50,6 +59,7 @@ impl SassFunction {
         match &self {
             Self::Builtin(..) => "Builtin",
             Self::UserDefined { .. } => "UserDefined",
+            Self::Plain(..) => "Plain",
         }
     }
 
@@ -57,11 +67,31 @@ impl SassFunction {
         self,
         args: CallArgs,
         module: Option<Spanned<Identifier>>,
+        span: Span,
         parser: &mut Parser,
     ) -> SassResult<Value> {
         match self {
-            Self::Builtin(f, ..) => f.0(args, parser),
-            Self::UserDefined { function, .. } => parser.eval_function(*function, args, module),
+            Self::Builtin(f, ..) => {
+                let span = args.span();
+                let named = args.named();
+                let requested = args.requested_names();
+                let val = f.0(args, parser)?;
+                CallArgs::verify_named(&named, &requested, span)?;
+                Ok(val)
+            }
+            Self::UserDefined { function, name } => {
+                parser.with_stack_frame(format!("{}()", name), span, |parser| {
+                    parser.eval_function(*function, args, module)
+                })
+            }
+            Self::Plain(name) => Ok(Value::String(
+                format!(
+                    "{}{}",
+                    name,
+                    args.to_css_string(parser.options.is_compressed())?.node
+                ),
+                QuoteKind::None,
+            )),
         }
     }
 }
@@ -0,0 +1,205 @@
+//! # Recompiling stylesheets when they change
+//!
+//! [`Watcher`] compiles a stylesheet, then compiles it again each time one of
+//! the files it depends on changes, is created, or is deleted. That includes
+//! files that were looked for but not found, so that adding a partial which
+//! shadows one on a load path is noticed too.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use grass::watch::{Backend, Watcher};
+//!
+//! fn main() -> std::io::Result<()> {
+//!     Watcher::new("input.scss")
+//!         .backend(Backend::Poll(Duration::from_secs(1)))
+//!         .run(&grass::Options::default(), |result| {
+//!             match result {
+//!                 Ok(css) => std::fs::write("output.css", css).unwrap(),
+//!                 Err(e) => eprintln!("{}", e),
+//!             }
+//!             // keep watching
+//!             true
+//!         })
+//! }
+//! ```
+
+use std::{
+    collections::BTreeSet,
+    io,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher as _};
+
+use crate::{
+    fs::{Dependencies, RecordingFs},
+    Options, Result,
+};
+
+/// How long to wait after a change is noticed before compiling again, so
+/// that a file being written in several steps is only compiled once
+const DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// How a [`Watcher`] finds out that files have changed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Use the notifications of the operating system, such as `inotify` on
+    /// Linux or `FSEvents` on macOS
+    Native,
+
+    /// Check the modification times of the files a stylesheet depends on at
+    /// the given interval
+    ///
+    /// This notices changes more slowly, but works where native notifications
+    /// are not delivered, such as on network file systems and in some Docker
+    /// volumes.
+    Poll(Duration),
+}
+
+impl Default for Backend {
+    #[inline]
+    fn default() -> Self {
+        Self::Native
+    }
+}
+
+/// Compiles a stylesheet, then compiles it again whenever a file it depends on
+/// changes
+///
+/// Changes are found by comparing modification times on the local file
+/// system, so this is only useful with an [`Options::fs`] that reads from it.
+#[derive(Clone, Debug)]
+pub struct Watcher {
+    path: PathBuf,
+    backend: Backend,
+}
+
+impl Watcher {
+    /// Watch the stylesheet at `path`
+    #[inline]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            backend: Backend::default(),
+        }
+    }
+
+    /// How to find out that files have changed
+    ///
+    /// By default, this is [`Backend::Native`].
+    #[must_use]
+    #[inline]
+    pub const fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Compile the stylesheet, passing the result to `on_compile`, and do so
+    /// again each time a file it depends on changes
+    ///
+    /// Compilation errors are passed to `on_compile` as well, and the files
+    /// read before the error are still watched. This only returns once
+    /// `on_compile` returns `false`, or if the files can't be watched.
+    #[inline]
+    pub fn run<F>(&self, options: &Options, mut on_compile: F) -> io::Result<()>
+    where
+        F: FnMut(Result<String>) -> bool,
+    {
+        loop {
+            let fs = RecordingFs::new(options.fs);
+
+            let result = crate::from_path(
+                &self.path.to_string_lossy(),
+                &Options {
+                    fs: &fs,
+                    load_paths: options.load_paths.clone(),
+                    importers: options.importers.clone(),
+                    ..*options
+                },
+            );
+
+            let dependencies = fs.into_dependencies();
+
+            if !on_compile(result) {
+                return Ok(());
+            }
+
+            self.wait_for_change(&dependencies)?;
+        }
+    }
+
+    fn wait_for_change(&self, dependencies: &Dependencies) -> io::Result<()> {
+        match self.backend {
+            Backend::Native => wait_for_notification(dependencies)?,
+            Backend::Poll(interval) => {
+                while dependencies.are_unchanged() {
+                    thread::sleep(interval);
+                }
+            }
+        }
+
+        thread::sleep(DEBOUNCE);
+
+        Ok(())
+    }
+}
+
+fn wait_for_notification(dependencies: &Dependencies) -> io::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+
+    let mut watcher = RecommendedWatcher::new(sender, Config::default()).map_err(to_io_error)?;
+
+    // files are watched through their directories, so that creating a file
+    // that didn't exist is noticed
+    let mut directories = BTreeSet::new();
+
+    for path in dependencies.paths() {
+        if path.is_dir() {
+            directories.insert(path);
+        }
+
+        match path.parent() {
+            Some(parent) if parent.as_os_str().is_empty() => {
+                directories.insert(Path::new("."));
+            }
+            Some(parent) if parent.is_dir() => {
+                directories.insert(parent);
+            }
+            Some(..) | None => {}
+        }
+    }
+
+    for directory in directories {
+        watcher
+            .watch(directory, RecursiveMode::NonRecursive)
+            .map_err(to_io_error)?;
+    }
+
+    // a file may have changed while the stylesheet was being compiled, before
+    // it was watched
+    while dependencies.are_unchanged() {
+        match receiver.recv() {
+            Ok(Ok(..)) => {}
+            Ok(Err(e)) => return Err(to_io_error(e)),
+            Err(..) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "Stopped receiving file change notifications.",
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn to_io_error(err: notify::Error) -> io::Error {
+    match err.kind {
+        notify::ErrorKind::Io(err) => err,
+        kind => io::Error::new(io::ErrorKind::Other, notify::Error::new(kind)),
+    }
+}
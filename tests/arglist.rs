@@ -92,3 +92,57 @@ test!(
     }",
     ""
 );
+test!(
+    keywords_are_not_positional_args,
+    "@mixin foo($args...) {
+        length: length($args);
+        first: nth($args, 1);
+    }
+    a {
+        @include foo(1px, $color: red);
+    }",
+    "a {\n  length: 1;\n  first: 1px;\n}\n"
+);
+test!(
+    keywords_looked_up_by_name,
+    "@use 'sass:map';
+    @mixin foo($args...) {
+        color: map.get(keywords($args), color);
+    }
+    a {
+        @include foo($color: red);
+    }",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    keywords_in_at_each_keep_order,
+    "@mixin foo($args...) {
+        @each $name, $value in keywords($args) {
+            #{$name}: $value;
+        }
+    }
+    a {
+        @include foo($z-index: 1, $color: red, $display: block);
+    }",
+    "a {\n  z-index: 1;\n  color: red;\n  display: block;\n}\n"
+);
+test!(
+    keywords_only_include_unbound_args,
+    "@function foo($a, $rest...) {
+        @return inspect(keywords($rest));
+    }
+    a {
+        color: foo($a: 1, $b: 2);
+    }",
+    "a {\n  color: (b: 2);\n}\n"
+);
+error!(
+    keywords_of_list,
+    "@function foo($args...) {
+        @return keywords(join($args, ()));
+    }
+    a {
+        color: foo($a: 1);
+    }",
+    "Error: $args: () is not an argument list."
+);
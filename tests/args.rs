@@ -47,6 +47,31 @@ test!(
     "@function foo($a, $b: $a) {\n    @return $b;\n}\n\na {\n    color: foo(2);\n}\n",
     "a {\n  color: 2;\n}\n"
 );
+test!(
+    mixin_default_arg_references_earlier_param,
+    "@mixin pad($x, $y: $x) {\n  padding: $x $y;\n}\na {\n  @include pad(1px);\n}\n",
+    "a {\n  padding: 1px 1px;\n}\n"
+);
+test!(
+    default_arg_references_earlier_default,
+    "@function foo($a, $b: $a * 2, $c: $b + 1) {\n  @return $a $b $c;\n}\na {\n  color: foo(1);\n}\n",
+    "a {\n  color: 1 2 3;\n}\n"
+);
+test!(
+    default_arg_prefers_earlier_param_over_global,
+    "$x: global;\n@mixin foo($x, $y: $x) {\n  color: $y;\n}\na {\n  @include foo($x: local);\n}\n",
+    "a {\n  color: local;\n}\n"
+);
+test!(
+    content_default_arg_references_earlier_param,
+    "@mixin foo {\n  @content(1);\n}\na {\n  @include foo using ($a, $b: $a * 3) {\n    color: $a $b;\n  }\n}\n",
+    "a {\n  color: 1 3;\n}\n"
+);
+error!(
+    default_arg_cannot_reference_later_param,
+    "@function foo($a: $b, $b: 2) {\n  @return $a;\n}\na {\n  color: foo();\n}\n",
+    "Error: Undefined variable."
+);
 test!(
     arg_errors_are_lazy_for_if,
     "a {\n  color: if(false, unit(foo), red);\n}\n",
@@ -275,3 +300,189 @@ error!(
     }",
     "Error: expected \")\"."
 );
+test!(
+    builtin_color_fns_accept_keyword_args,
+    "a {\n  color: rgb($red: 1, $green: 2, $blue: 3);\n  color: rgba($color: red, $alpha: 0.5);\n  color: hsl($hue: 1, $saturation: 2%, $lightness: 3%);\n  color: mix($color1: red, $color2: blue, $weight: 50%);\n  color: invert($color: red, $weight: 50%);\n  color: change-color($color: red, $blue: 10);\n}\n",
+    "a {\n  color: #010203;\n  color: rgba(255, 0, 0, 0.5);\n  color: #080807;\n  color: purple;\n  color: gray;\n  color: #ff000a;\n}\n"
+);
+test!(
+    builtin_list_fns_accept_keyword_args,
+    "a {\n  color: nth($list: 1 2, $n: 1);\n  color: set-nth($list: 1 2, $n: 1, $value: 3);\n  color: append($list: 1 2, $val: 3, $separator: comma);\n  color: join($list1: 1 2, $list2: 3, $separator: comma, $bracketed: true);\n  color: index($list: 1 2, $value: 2);\n}\n",
+    "a {\n  color: 1;\n  color: 3 2;\n  color: 1, 2, 3;\n  color: [1, 2, 3];\n  color: 2;\n}\n"
+);
+test!(
+    builtin_map_fns_accept_keyword_args,
+    "a {\n  color: map-get($map: (a: 1), $key: a);\n  color: map-has-key($map: (a: 1), $key: a);\n  color: inspect(map-merge($map1: (a: 1), $map2: (b: 2)));\n}\n",
+    "a {\n  color: 1;\n  color: true;\n  color: (a: 1, b: 2);\n}\n"
+);
+test!(
+    builtin_math_fns_accept_keyword_args,
+    "@use \"sass:math\";\na {\n  color: percentage($number: 0.5) round($number: 1.5) abs($number: -1);\n  color: comparable($number1: 1, $number2: 2) math.div($number1: 1, $number2: 2);\n  color: math.clamp($min: 1, $number: 2, $max: 3) math.pow($base: 2, $exponent: 2) math.atan2($y: 1, $x: 1);\n}\n",
+    "a {\n  color: 50% 2 1;\n  color: true 0.5;\n  color: 2 4 45deg;\n}\n"
+);
+test!(
+    builtin_string_fns_accept_keyword_args,
+    "a {\n  color: str-slice($string: abc, $start-at: 2, $end-at: 2);\n  color: str-index($string: abc, $substring: b);\n  color: str-insert($string: abc, $insert: x, $index: 1);\n}\n",
+    "a {\n  color: b;\n  color: 2;\n  color: xabc;\n}\n"
+);
+test!(
+    builtin_meta_and_selector_fns_accept_keyword_args,
+    "a {\n  color: if($condition: true, $if-true: 1, $if-false: 2);\n  color: call($function: get-function($name: \"rgb\"), 1, 2, 3);\n  color: selector-unify($selector1: a, $selector2: a);\n  color: is-superselector($super: a, $sub: a);\n}\n",
+    "a {\n  color: 1;\n  color: #010203;\n  color: a;\n  color: true;\n}\n"
+);
+error!(
+    user_fn_arg_passed_by_position_and_name,
+    "@function foo($a) {\n  @return $a;\n}\na {\n  color: foo(1, $a: 2);\n}\n",
+    "Error: Argument $a was passed both by position and by name."
+);
+error!(
+    user_fn_unknown_named_arg,
+    "@function foo($a) {\n  @return $a;\n}\na {\n  color: foo(1, $b: 2);\n}\n",
+    "Error: No argument named $b."
+);
+error!(
+    user_fn_two_unknown_named_args,
+    "@function foo($a) {\n  @return $a;\n}\na {\n  color: foo(1, $c: 2, $b: 3);\n}\n",
+    "Error: No arguments named $b or $c."
+);
+error!(
+    user_fn_three_unknown_named_args,
+    "@function foo($a) {\n  @return $a;\n}\na {\n  color: foo(1, $b: 2, $c: 3, $d: 4);\n}\n",
+    "Error: No arguments named $b, $c, or $d."
+);
+error!(
+    user_fn_missing_arg_reported_before_unknown_arg,
+    "@function foo($a, $b) {\n  @return $a;\n}\na {\n  color: foo(1, $c: 2);\n}\n",
+    "Error: Missing argument $b."
+);
+error!(
+    user_fn_too_many_positional_with_named,
+    "@function foo($a, $b: 1) {\n  @return $a;\n}\na {\n  color: foo(1, 2, 3, $b: 2);\n}\n",
+    "Error: Argument $b was passed both by position and by name."
+);
+error!(
+    user_fn_too_many_positional_args_with_unknown_named,
+    "@function foo($a) {\n  @return $a;\n}\na {\n  color: foo(1, 2, $c: 2);\n}\n",
+    "Error: Only 1 positional argument allowed, but 2 were passed."
+);
+error!(
+    mixin_arg_passed_by_position_and_name,
+    "@mixin foo($a) {\n  color: $a;\n}\na {\n  @include foo(1, $a: 2);\n}\n",
+    "Error: Argument $a was passed both by position and by name."
+);
+error!(
+    mixin_unknown_named_arg,
+    "@mixin foo($a) {\n  color: $a;\n}\na {\n  @include foo($b: 1, $a: 2);\n}\n",
+    "Error: No argument named $b."
+);
+error!(
+    content_missing_arg_reported_before_unknown_arg,
+    "@mixin foo {\n  @content($b: 1);\n}\na {\n  @include foo using ($a) {\n    color: $a;\n  }\n}\n",
+    "Error: Missing argument $a."
+);
+error!(
+    builtin_arg_passed_by_position_and_name,
+    "a {\n  color: nth(1 2, 1, $list: 3);\n}\n",
+    "Error: Argument $list was passed both by position and by name."
+);
+error!(
+    builtin_unknown_named_arg,
+    "a {\n  color: str-length(\"a\", $foo: 1);\n}\n", "Error: No argument named $foo."
+);
+error!(
+    builtin_unknown_named_arg_with_defaults,
+    "a {\n  color: join(1, 2, $foo: 3);\n}\n", "Error: No argument named $foo."
+);
+error!(
+    builtin_module_fn_unknown_named_arg,
+    "@use \"sass:math\";\na {\n  color: math.div(1, 2, $foo: 3);\n}\n",
+    "Error: No argument named $foo."
+);
+error!(
+    builtin_variadic_unknown_named_args,
+    "a {\n  color: zip(1 2, $foo: 3, $bar: 4);\n}\n", "Error: No arguments named $bar or $foo."
+);
+error!(
+    if_unknown_named_arg,
+    "a {\n  color: if(true, 1, 2, $foo: 3);\n}\n", "Error: No argument named $foo."
+);
+test!(
+    variadic_function_collects_keyword_args,
+    "@function foo($a, $args...) {\n  @return inspect(keywords($args));\n}\na {\n  color: foo(1, $b: 2, $a-b: 3);\n}\n",
+    "a {\n  color: (b: 2, a-b: 3);\n}\n"
+);
+test!(
+    variadic_mixin_collects_keyword_args,
+    "@mixin foo($args...) {\n  color: inspect(keywords($args));\n  length: length($args);\n}\na {\n  @include foo(1, 2, $c: 3);\n}\n",
+    "a {\n  color: (c: 3);\n  length: 2;\n}\n"
+);
+test!(
+    variadic_keyword_args_bound_to_param_not_collected,
+    "@function foo($a, $args...) {\n  @return inspect(keywords($args));\n}\na {\n  color: foo($a: 1);\n}\n",
+    "a {\n  color: ();\n}\n"
+);
+test!(
+    splat_arglist_forwards_keyword_args,
+    "@function foo($a, $b) {\n  @return $a $b;\n}\n@function bar($args...) {\n  @return foo($args...);\n}\na {\n  color: bar(1, $b: 2);\n}\n",
+    "a {\n  color: 1 2;\n}\n"
+);
+test!(
+    splat_arglist_forwards_keyword_args_to_mixin,
+    "@mixin inner($a, $b: 2, $c: 3) {\n  color: $a $b $c;\n}\n@mixin outer($args...) {\n  @include inner($args...);\n}\na {\n  @include outer(1, $c: 30);\n}\n",
+    "a {\n  color: 1 2 30;\n}\n"
+);
+test!(
+    splat_arglist_forwards_keyword_args_through_many_mixins,
+    "@mixin inner($a, $b) {\n  color: $a $b;\n}\n@mixin middle($args...) {\n  @include inner($args...);\n}\n@mixin outer($args...) {\n  @include middle($args...);\n}\na {\n  @include outer($b: 2, $a: 1);\n}\n",
+    "a {\n  color: 1 2;\n}\n"
+);
+test!(
+    splat_arglist_forwards_keyword_args_to_call,
+    "@function foo($a, $b) {\n  @return $a - $b;\n}\n@function bar($args...) {\n  @return call(get-function(foo), $args...);\n}\na {\n  color: bar($b: 1, $a: 5);\n}\n",
+    "a {\n  color: 4;\n}\n"
+);
+test!(
+    splat_list_and_map,
+    "@function foo($a, $b) {\n  @return $a - $b;\n}\na {\n  color: foo((5,)..., (b: 1)...);\n}\n",
+    "a {\n  color: 4;\n}\n"
+);
+error!(
+    splat_arglist_forwards_duplicate_arg,
+    "@function foo($a) {\n  @return $a;\n}\n@function bar($args...) {\n  @return foo($args...);\n}\na {\n  color: bar(1, $a: 2);\n}\n",
+    "Error: Argument $a was passed both by position and by name."
+);
+test!(
+    keyword_arg_names_are_normalized_in_arglist,
+    "@function foo($args...) {\n  @return inspect(keywords($args));\n}\na {\n  color: foo($c_d: 1);\n}\n",
+    "a {\n  color: (c-d: 1);\n}\n"
+);
+test!(
+    multiline_call_args_with_trailing_comma,
+    "@mixin grid($cols, $gutter: 0) {\n  color: $cols $gutter;\n}\na {\n  @include grid(\n    $cols: 12,\n    $gutter: 16px,\n  );\n}\n",
+    "a {\n  color: 12 16px;\n}\n"
+);
+test!(
+    multiline_call_args_with_comments,
+    "@function foo($a, $b) {\n  @return $a $b;\n}\na {\n  color: foo(\n    1, // first\n    2 /* second */,\n  );\n}\n",
+    "a {\n  color: 1 2;\n}\n"
+);
+test!(
+    multiline_declaration_args_with_trailing_comma_and_comments,
+    "@mixin foo(\n  $a, // first\n  $b: 2, /* second */\n) {\n  color: $a $b;\n}\na {\n  @include foo(1);\n}\n",
+    "a {\n  color: 1 2;\n}\n"
+);
+test!(
+    trailing_comma_after_rest_param,
+    "@function foo(\n  $args...,\n) {\n  @return length($args);\n}\na {\n  color: foo(1, 2, 3);\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    trailing_comma_after_splat,
+    "@function foo($args...) {\n  @return length($args);\n}\na {\n  color: foo((1, 2)..., );\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    trailing_comma_in_content_args,
+    "@mixin foo {\n  @content(1, );\n}\na {\n  @include foo using ($a, ) {\n    color: $a;\n  }\n}\n",
+    "a {\n  color: 1;\n}\n"
+);
@@ -0,0 +1,70 @@
+use std::io::Write;
+
+use grass::{NullLogger, Options};
+
+#[macro_use]
+mod macros;
+
+mod logger;
+
+use logger::debug_messages;
+
+test!(
+    debug_does_not_affect_output,
+    "a {\n  @debug hi;\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n",
+    grass::Options::default().logger(&NullLogger)
+);
+
+#[test]
+fn debug_evaluates_expression() {
+    assert_eq!(
+        debug_messages("@debug 1 + 1;", Options::default()),
+        vec!["stdin:1 2"]
+    );
+}
+
+#[test]
+fn debug_unquotes_strings() {
+    assert_eq!(
+        debug_messages("@debug \"hi\";", Options::default()),
+        vec!["stdin:1 hi"]
+    );
+}
+
+#[test]
+fn debug_line_number() {
+    assert_eq!(
+        debug_messages(
+            "a {\n  color: red;\n  @debug foo;\n}\n\n@debug bar;\n",
+            Options::default()
+        ),
+        vec!["stdin:3 foo", "stdin:6 bar"]
+    );
+}
+
+#[test]
+fn debug_in_function_and_mixin() {
+    assert_eq!(
+        debug_messages(
+            "@function foo($a) {\n  @debug $a;\n  @return $a;\n}\n\n@mixin bar {\n  @debug mixin;\n}\n\na {\n  @include bar;\n  color: foo(red);\n}\n",
+            Options::default()
+        ),
+        vec!["stdin:7 mixin", "stdin:2 red"]
+    );
+}
+
+#[test]
+fn debug_in_imported_file() {
+    let input = "@import \"debug_in_imported_file\";";
+    tempfile!("debug_in_imported_file.scss", "\n@debug imported;");
+    assert_eq!(
+        debug_messages(input, Options::default()),
+        vec!["debug_in_imported_file.scss:2 imported"]
+    );
+}
+
+#[test]
+fn quiet_silences_debug() {
+    assert!(debug_messages("@debug hi;", Options::default().quiet(true)).is_empty());
+}
@@ -1,6 +1,20 @@
+use std::io::Write;
+
 #[macro_use]
 mod macros;
 
+/// The stack trace at the end of the error `input` fails with
+fn error_trace(input: &str) -> Vec<String> {
+    grass::from_string(input.to_owned(), &grass::Options::default())
+        .expect_err(input)
+        .to_string()
+        .lines()
+        .skip_while(|line| !line.starts_with("./"))
+        .skip(1)
+        .map(str::to_owned)
+        .collect()
+}
+
 error!(
     error_dblquoted_string,
     "a {\n  @error \"hi\";\n}\n", "Error: \"hi\""
@@ -15,3 +29,62 @@ error!(
     error_is_inspected,
     "a {\n  @error null;\n}\n", "Error: null"
 );
+
+#[test]
+fn error_trace_through_nested_functions() {
+    assert_eq!(
+        error_trace(
+            "@function inner() {\n  @error \"boom\";\n}\n\n@function outer() {\n  @return inner();\n}\n\na {\n  color: outer();\n}\n"
+        ),
+        vec![
+            "  stdin 2:3    inner()",
+            "  stdin 6:11   outer()",
+            "  stdin 10:10  root stylesheet",
+        ]
+    );
+}
+
+#[test]
+fn error_trace_through_nested_mixins() {
+    assert_eq!(
+        error_trace(
+            "@mixin inner {\n  @error \"boom\";\n}\n\n@mixin outer {\n  @include inner;\n}\n\na {\n  @include outer;\n}\n"
+        ),
+        vec![
+            "  stdin 2:3   inner()",
+            "  stdin 6:3   outer()",
+            "  stdin 10:3  root stylesheet",
+        ]
+    );
+}
+
+#[test]
+fn error_trace_through_content_block() {
+    assert_eq!(
+        error_trace(
+            "@function foo() {\n  @error \"boom\";\n}\n\n@mixin bar {\n  @content;\n}\n\na {\n  @include bar {\n    color: foo();\n  }\n}\n"
+        ),
+        vec![
+            "  stdin 2:3    foo()",
+            "  stdin 11:12  @content",
+            "  stdin 6:3    bar()",
+            "  stdin 10:3   root stylesheet",
+        ]
+    );
+}
+
+#[test]
+fn error_trace_names_imported_file() {
+    let input = "@import \"error_trace_names_imported_file\";\na {\n  @include foo;\n}\n";
+    tempfile!(
+        "error_trace_names_imported_file.scss",
+        "@mixin foo {\n  @error \"boom\";\n}\n"
+    );
+    assert_eq!(
+        error_trace(input),
+        vec![
+            "  error_trace_names_imported_file.scss 2:3  foo()",
+            "  stdin 3:3                                 root stylesheet",
+        ]
+    );
+}
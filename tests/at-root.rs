@@ -136,3 +136,71 @@ error!(
     style_at_toplevel_without_selector,
     "@at-root { color: red; }", "Error: Found style at the toplevel inside @at-root."
 );
+test!(
+    without_media,
+    "@media screen {\n  a {\n    color: red;\n\n    @at-root (without: media) {\n      color: blue;\n    }\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\na {\n  color: blue;\n}\n"
+);
+test!(
+    without_media_keeps_nested_selectors,
+    "@media screen {\n  a {\n    @at-root (without: media) {\n      b {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "a b {\n  color: red;\n}\n"
+);
+test!(
+    without_media_splits_media,
+    "@media screen {\n  a {\n    color: red;\n  }\n\n  @at-root (without: media) {\n    b {\n      color: blue;\n    }\n  }\n\n  c {\n    color: green;\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\nb {\n  color: blue;\n}\n\n@media screen {\n  c {\n    color: green;\n  }\n}\n"
+);
+test!(
+    with_media,
+    "@media screen {\n  a {\n    @at-root (with: media) {\n      b {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "@media screen {\n  b {\n    color: red;\n  }\n}\n"
+);
+test!(
+    with_rule,
+    "@media screen {\n  a {\n    @at-root (with: rule) {\n      color: red;\n    }\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    without_all,
+    "@media screen {\n  a {\n    @at-root (without: all) {\n      b {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "b {\n  color: red;\n}\n"
+);
+test!(
+    without_rule_is_default,
+    "@media screen {\n  a {\n    @at-root (without: rule) {\n      b {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "@media screen {\n  b {\n    color: red;\n  }\n}\n"
+);
+test!(
+    without_supports,
+    "@supports (display: grid) {\n  a {\n    @at-root (without: supports) {\n      color: red;\n    }\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    without_unknown_at_rule,
+    "@foo {\n  a {\n    @at-root (without: foo) {\n      color: red;\n    }\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    without_multiple_names,
+    "@media screen {\n  @supports (display: grid) {\n    a {\n      @at-root (without: media supports) {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    without_other_at_rule_stays_inside,
+    "@media screen {\n  a {\n    @at-root (without: supports) {\n      color: red;\n    }\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    query_is_case_insensitive,
+    "@media screen {\n  a {\n    @at-root (WITHOUT: Media) {\n      color: red;\n    }\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+error!(
+    query_missing_with_or_without,
+    "a {\n  @at-root (foo: media) {}\n}\n", "Error: Expected \"with\" or \"without\"."
+);
+error!(
+    query_missing_colon,
+    "a {\n  @at-root (without media) {}\n}\n", "Error: expected \":\"."
+);
@@ -0,0 +1,148 @@
+use grass::Options;
+
+#[macro_use]
+mod macros;
+
+mod logger;
+
+use logger::warnings;
+
+test!(
+    warn_does_not_affect_output,
+    "a {\n  @warn hi;\n  color: red;\n}\n",
+    "a {\n  color: red;\n}\n",
+    grass::Options::default().quiet(true)
+);
+error!(
+    fatal_warnings_warn_is_error,
+    "a {\n  @warn hi;\n  color: red;\n}\n",
+    "Error: hi",
+    grass::Options::default().fatal_warnings(true)
+);
+error!(
+    fatal_warnings_takes_precedence_over_quiet,
+    "a {\n  @warn 1 + 1;\n}\n",
+    "Error: 2",
+    grass::Options::default().quiet(true).fatal_warnings(true)
+);
+error!(
+    fatal_warnings_warn_in_function,
+    "@function foo() {\n  @warn hi;\n  @return 1;\n}\n\na {\n  color: foo();\n}\n",
+    "Error: hi",
+    grass::Options::default().fatal_warnings(true)
+);
+error!(
+    fatal_warnings_deprecation_is_error,
+    "a {\n  color: (4px / 2);\n}\n",
+    "Error: Using / for division outside of calc() is deprecated and will be removed in Dart Sass 2.0.0.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 33, 0)))
+        .fatal_warnings(true)
+);
+test!(
+    fatal_warnings_without_warnings,
+    "a {\n  color: (4px / 2);\n}\n",
+    "a {\n  color: 2px;\n}\n",
+    grass::Options::default().fatal_warnings(true)
+);
+
+#[test]
+fn warn_at_root() {
+    assert_eq!(
+        warnings("@warn \"hi\";", Options::default()),
+        vec!["hi\nstdin 1:1  root stylesheet\n"]
+    );
+}
+
+#[test]
+fn warn_evaluates_expression() {
+    assert_eq!(
+        warnings("a {\n  @warn 1 + 1;\n}\n", Options::default()),
+        vec!["2\nstdin 2:3  root stylesheet\n"]
+    );
+}
+
+#[test]
+fn warn_trace_through_mixin_and_function() {
+    assert_eq!(
+        warnings(
+            "@function foo() {\n  @warn hi;\n  @return 1;\n}\n\n@mixin bar {\n  color: foo();\n}\n\na {\n  @include bar;\n}\n",
+            Options::default()
+        ),
+        vec!["hi\nstdin 2:3   foo()\nstdin 7:10  bar()\nstdin 11:3  root stylesheet\n"]
+    );
+}
+
+#[test]
+fn repeated_warning_printed_once() {
+    assert_eq!(
+        warnings(
+            "@mixin foo {\n  @warn hi;\n}\n\na {\n  @include foo;\n  @include foo;\n}\n",
+            Options::default()
+        ),
+        vec!["hi\nstdin 2:3  foo()\nstdin 6:3  root stylesheet\n"]
+    );
+}
+
+#[test]
+fn repeated_warning_in_loop_printed_once() {
+    assert_eq!(
+        warnings(
+            "@for $i from 1 through 3 {\n  @warn hi;\n}\n",
+            Options::default()
+        )
+        .len(),
+        1
+    );
+}
+
+#[test]
+fn different_warnings_at_same_location_printed() {
+    assert_eq!(
+        warnings(
+            "@for $i from 1 through 3 {\n  @warn $i;\n}\n",
+            Options::default()
+        )
+        .len(),
+        3
+    );
+}
+
+#[test]
+fn same_warning_at_different_locations_printed() {
+    assert_eq!(
+        warnings("@warn hi;\n@warn hi;\n", Options::default()).len(),
+        2
+    );
+}
+
+#[test]
+fn verbose_prints_repeated_warnings() {
+    assert_eq!(
+        warnings(
+            "@mixin foo {\n  @warn hi;\n}\n\na {\n  @include foo;\n  @include foo;\n}\n",
+            Options::default().verbose(true)
+        ),
+        vec![
+            "hi\nstdin 2:3  foo()\nstdin 6:3  root stylesheet\n",
+            "hi\nstdin 2:3  foo()\nstdin 7:3  root stylesheet\n",
+        ]
+    );
+}
+
+#[test]
+fn repeated_deprecation_printed_once() {
+    assert_eq!(
+        warnings(
+            "@function half($a) {\n  @return ($a / 2);\n}\n\na {\n  b: half(4px);\n  c: half(6px);\n}\n",
+            Options::default().compat(grass::Compat::DartSass(grass::Version::new(1, 33, 0)))
+        )
+        .len(),
+        1
+    );
+}
+
+#[test]
+fn quiet_silences_warn() {
+    assert!(warnings("@warn hi;", Options::default().quiet(true)).is_empty());
+}
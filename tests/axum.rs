@@ -0,0 +1,196 @@
+#![cfg(feature = "axum")]
+
+use std::{fs, path::Path, sync::Arc, thread, time::Duration};
+
+use grass::{axum::CompileService, OutputStyle};
+use http::{header, Request, Response, StatusCode};
+use tempfile::tempdir;
+use tower_service::Service;
+
+use logger::Collector;
+
+mod logger;
+
+fn get(service: &CompileService, uri: &str) -> Response<String> {
+    service.respond(&Request::get(uri).body(()).unwrap())
+}
+
+/// Rewrites a file, making sure that its modification time changes even on
+/// file systems with a coarse timestamp resolution
+fn touch(path: &Path, contents: &str) {
+    let before = fs::metadata(path).and_then(|m| m.modified()).ok();
+    loop {
+        fs::write(path, contents).unwrap();
+        if fs::metadata(path).and_then(|m| m.modified()).ok() != before {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn compiles_scss_for_css_request() {
+    let dir = tempdir().unwrap();
+    fs::create_dir(dir.path().join("site")).unwrap();
+    fs::write(dir.path().join("site/main.scss"), "a { b { color: red; } }").unwrap();
+
+    let service = CompileService::new(dir.path());
+    let response = get(&service, "/site/main.css");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/css; charset=utf-8"
+    );
+    assert_eq!(response.body(), "a b {\n  color: red;\n}\n");
+}
+
+#[test]
+fn keeps_dots_in_file_name() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("main.min.scss"), "a { color: red; }").unwrap();
+
+    let service = CompileService::new(dir.path()).style(OutputStyle::Compressed);
+    assert_eq!(get(&service, "/main.min.css").body(), "a{color:red}");
+}
+
+#[test]
+fn not_found() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("_partial.scss"), "a { color: red; }").unwrap();
+    fs::write(dir.path().join("main.scss"), "a { color: red; }").unwrap();
+
+    let service = CompileService::new(dir.path().join("sub"));
+    fs::create_dir(dir.path().join("sub")).unwrap();
+
+    for uri in &[
+        "/missing.css",
+        "/_partial.css",
+        "/../main.css",
+        "/main.scss",
+    ] {
+        assert_eq!(
+            get(&service, uri).status(),
+            StatusCode::NOT_FOUND,
+            "{}",
+            uri
+        );
+    }
+}
+
+#[test]
+fn compilation_error() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("main.scss"), "a { color: $missing; }").unwrap();
+
+    let response = get(&CompileService::new(dir.path()), "/main.css");
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(response.body().contains("Undefined variable."));
+}
+
+#[test]
+fn sends_warnings_to_logger() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("main.scss"), "@warn hi;\na { color: red; }").unwrap();
+
+    let collector = Arc::new(Collector::default());
+    let service = CompileService::new(dir.path()).logger(Arc::clone(&collector) as _);
+
+    assert_eq!(get(&service, "/main.css").status(), StatusCode::OK);
+    let warnings = collector.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("hi\n"));
+}
+
+#[test]
+fn method_not_allowed() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("main.scss"), "a { color: red; }").unwrap();
+
+    let response =
+        CompileService::new(dir.path()).respond(&Request::post("/main.css").body(()).unwrap());
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[test]
+fn not_modified_when_etag_matches() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("main.scss"), "a { color: red; }").unwrap();
+
+    let service = CompileService::new(dir.path());
+    let etag = get(&service, "/main.css").headers()[header::ETAG].clone();
+
+    let response = service.respond(
+        &Request::get("/main.css")
+            .header(header::IF_NONE_MATCH, etag.clone())
+            .body(())
+            .unwrap(),
+    );
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag);
+    assert_eq!(response.body(), "");
+}
+
+#[test]
+fn recompiles_when_dependency_changes() {
+    let dir = tempdir().unwrap();
+    let partial = dir.path().join("_colors.scss");
+    fs::write(&partial, "$c: red;").unwrap();
+    fs::write(
+        dir.path().join("main.scss"),
+        "@import \"colors\";\na { color: $c; }",
+    )
+    .unwrap();
+
+    let service = CompileService::new(dir.path());
+    let first = get(&service, "/main.css");
+    assert_eq!(first.body(), "a {\n  color: red;\n}\n");
+    assert_eq!(
+        get(&service, "/main.css").headers()[header::ETAG],
+        first.headers()[header::ETAG]
+    );
+
+    touch(&partial, "$c: blue;");
+
+    let second = get(&service, "/main.css");
+    assert_eq!(second.body(), "a {\n  color: blue;\n}\n");
+    assert_ne!(
+        second.headers()[header::ETAG],
+        first.headers()[header::ETAG]
+    );
+}
+
+#[test]
+fn recompiles_when_shadowing_file_is_created() {
+    let dir = tempdir().unwrap();
+    fs::create_dir(dir.path().join("lib")).unwrap();
+    fs::write(dir.path().join("lib/_colors.scss"), "$c: red;").unwrap();
+    fs::write(
+        dir.path().join("main.scss"),
+        "@import \"colors\";\na { color: $c; }",
+    )
+    .unwrap();
+
+    let service = CompileService::new(dir.path()).load_path(dir.path().join("lib"));
+    assert_eq!(get(&service, "/main.css").body(), "a {\n  color: red;\n}\n");
+
+    // files next to the importing file take precedence over load paths
+    fs::write(dir.path().join("_colors.scss"), "$c: blue;").unwrap();
+    assert_eq!(
+        get(&service, "/main.css").body(),
+        "a {\n  color: blue;\n}\n"
+    );
+}
+
+#[test]
+fn implements_service() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("main.scss"), "a { color: red; }").unwrap();
+
+    let mut service = CompileService::new(dir.path());
+    let response = service
+        .call(Request::get("/main.css").body(()).unwrap())
+        .into_inner()
+        .unwrap();
+    assert_eq!(response.body(), "a {\n  color: red;\n}\n");
+}
@@ -0,0 +1,121 @@
+use std::{thread, time::Duration};
+
+use grass::{CancellationToken, Options};
+
+const INFINITE_LOOP: &str = "$i: 0;\n@while true { $i: $i + 1; }\na { color: red; }";
+
+#[test]
+fn cancelled_before_compilation() {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let err = grass::from_string(
+        "a { color: red; }".to_owned(),
+        &Options::default().cancellation(&token),
+    )
+    .unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(err.to_string(), "Error: Compilation was cancelled.\n");
+}
+
+#[test]
+fn not_cancelled() {
+    let token = CancellationToken::new();
+
+    assert_eq!(
+        grass::from_string(
+            "a { color: red; }".to_owned(),
+            &Options::default().cancellation(&token),
+        )
+        .unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+    assert!(!token.is_cancelled());
+}
+
+#[test]
+fn cancelled_from_another_thread() {
+    let token = CancellationToken::new();
+
+    let canceller = token.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        canceller.cancel();
+    });
+
+    let err = grass::from_string(
+        INFINITE_LOOP.to_owned(),
+        &Options::default().cancellation(&token),
+    )
+    .unwrap_err();
+    assert!(err.is_cancelled());
+}
+
+#[test]
+fn cancelled_inside_empty_loop() {
+    let token = CancellationToken::new();
+
+    let canceller = token.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        canceller.cancel();
+    });
+
+    let err = grass::from_string(
+        "@for $i from 1 through 1000000000 {}".to_owned(),
+        &Options::default().cancellation(&token),
+    )
+    .unwrap_err();
+    assert!(err.is_cancelled());
+}
+
+#[test]
+fn cancelled_inside_function() {
+    let token = CancellationToken::new();
+
+    let canceller = token.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        canceller.cancel();
+    });
+
+    let err = grass::from_string(
+        "@function f() { @while true {} @return 1; }\na { color: f(); }".to_owned(),
+        &Options::default().cancellation(&token),
+    )
+    .unwrap_err();
+    assert!(err.is_cancelled());
+}
+
+#[test]
+fn timed_out() {
+    let err = grass::from_string(
+        INFINITE_LOOP.to_owned(),
+        &Options::default().timeout(Duration::from_millis(50)),
+    )
+    .unwrap_err();
+    assert!(err.is_cancelled());
+    assert_eq!(err.to_string(), "Error: Compilation timed out.\n");
+}
+
+#[test]
+fn finishes_before_timeout() {
+    assert_eq!(
+        grass::from_string(
+            "a { color: red; }".to_owned(),
+            &Options::default().timeout(Duration::from_secs(60)),
+        )
+        .unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn other_errors_are_not_cancelled() {
+    let err = grass::from_string(
+        "a { color: $a; }".to_owned(),
+        &Options::default().timeout(Duration::from_secs(60)),
+    )
+    .unwrap_err();
+    assert!(!err.is_cancelled());
+}
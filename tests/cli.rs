@@ -0,0 +1,153 @@
+#![cfg(feature = "commandline")]
+
+use std::{fs, path::Path, process::Command};
+
+use tempfile::tempdir;
+
+fn grass(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_grass"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// A directory with two valid stylesheets, one invalid one between them, a
+/// partial, and a nested directory
+fn sources(dir: &Path) {
+    fs::create_dir_all(dir.join("src/nested")).unwrap();
+    fs::write(dir.join("src/a.scss"), "a { color: red; }").unwrap();
+    fs::write(dir.join("src/b.scss"), "b { color: $undefined; }").unwrap();
+    fs::write(dir.join("src/c.scss"), "c { color: blue; }").unwrap();
+    fs::write(dir.join("src/_partial.scss"), "$x: 1;").unwrap();
+    fs::write(dir.join("src/nested/d.scss"), "d { color: green; }").unwrap();
+}
+
+#[test]
+fn input_and_output() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(dir.path(), &["src/a.scss", "out.css"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.path().join("out.css")).unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn source_destination_pairs() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(
+        dir.path(),
+        &["src/a.scss:out/a.css", "src/nested/d.scss:out/deep/d.css"],
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(dir.path().join("out/a.css")).unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.path().join("out/deep/d.css")).unwrap(),
+        "d {\n  color: green;\n}\n"
+    );
+}
+
+#[test]
+fn directory_keeps_going_after_error() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(dir.path(), &["src:out"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Undefined variable."));
+    assert!(dir.path().join("out/a.css").exists());
+    assert!(!dir.path().join("out/b.css").exists());
+    assert!(dir.path().join("out/c.css").exists());
+    assert!(dir.path().join("out/nested/d.css").exists());
+    assert!(!dir.path().join("out/_partial.css").exists());
+}
+
+#[test]
+fn reports_every_failure() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+    fs::write(
+        dir.path().join("src/e.scss"),
+        "e { color: $also-undefined; }",
+    )
+    .unwrap();
+
+    let output = grass(dir.path(), &["src:out"]);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("$undefined"));
+    assert!(stderr.contains("$also-undefined"));
+}
+
+#[test]
+fn stop_on_error() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(dir.path(), &["--stop-on-error", "src:out"]);
+
+    assert!(!output.status.success());
+    assert!(dir.path().join("out/a.css").exists());
+    assert!(!dir.path().join("out/c.css").exists());
+    assert!(!dir.path().join("out/nested/d.css").exists());
+}
+
+#[test]
+fn no_stop_on_error_overrides_stop_on_error() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(
+        dir.path(),
+        &["--stop-on-error", "--no-stop-on-error", "src:out"],
+    );
+
+    assert!(!output.status.success());
+    assert!(dir.path().join("out/c.css").exists());
+}
+
+#[test]
+fn positional_and_pairs_are_not_mixed() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(dir.path(), &["src/a.scss:out/a.css", "src/c.scss"]);
+
+    assert_eq!(output.status.code(), Some(64));
+    assert!(!dir.path().join("out/a.css").exists());
+}
+
+#[test]
+fn color() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(dir.path(), &["--color", "src/b.scss"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("\x1b[31m$undefined\x1b[0m"));
+}
+
+#[test]
+fn no_color_overrides_color() {
+    let dir = tempdir().unwrap();
+    sources(dir.path());
+
+    let output = grass(dir.path(), &["--color", "--no-color", "src/b.scss"]);
+
+    assert!(!output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains('\x1b'));
+}
@@ -234,6 +234,35 @@ error!(
     "a {\n  color: invert(1, 50%);\n}\n",
     "Error: Only one argument may be passed to the plain-CSS invert() function."
 );
+test!(
+    plain_invert_default_weight,
+    "a {\n  color: invert(1, 100%);\n}\n",
+    "a {\n  color: invert(1);\n}\n"
+);
+error!(
+    plain_invert_unitless_weight,
+    "a {\n  color: invert(1, 100);\n}\n",
+    "Error: Only one argument may be passed to the plain-CSS invert() function."
+);
+test!(
+    module_invert_number,
+    "@use \"sass:color\";\na {\n  color: color.invert(10%);\n}\n",
+    "a {\n  color: invert(10%);\n}\n"
+);
+error!(
+    module_invert_number_deprecated,
+    "@use \"sass:color\";\na {\n  color: color.invert(10%);\n}\n",
+    "Error: Passing a number (10%) to color.invert() is deprecated.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 23, 0)))
+        .fatal_warnings(true)
+);
+error!(
+    module_invert_number_removed,
+    "@use \"sass:color\";\na {\n  color: color.invert(10%);\n}\n",
+    "Error: $color: 10% is not a color.",
+    grass::Options::default().compat(grass::Compat::DartSass(grass::Version::new(2, 0, 0)))
+);
 test!(
     invert_weight_percent,
     "a {\n  color: invert(white, 20%);\n}\n",
@@ -270,6 +299,11 @@ test!(
     "a {\n  color: opacify(rgba(0, 0, 17, 0.8), 0.2);\n}\n",
     "a {\n  color: #000011;\n}\n"
 );
+test!(
+    fade_in_alias,
+    "a {\n  color: fade-in(rgba(0, 0, 0, 0.5), 0.2);\n}\n",
+    "a {\n  color: rgba(0, 0, 0, 0.7);\n}\n"
+);
 test!(
     grayscale_1,
     "a {\n  color: grayscale(plum);\n}\n",
@@ -280,6 +314,19 @@ test!(
     "a {\n  color: grayscale(red);\n}\n",
     "a {\n  color: gray;\n}\n"
 );
+test!(
+    module_grayscale_number,
+    "@use \"sass:color\";\na {\n  color: color.grayscale(15%);\n}\n",
+    "a {\n  color: grayscale(15%);\n}\n"
+);
+error!(
+    module_grayscale_number_deprecated,
+    "@use \"sass:color\";\na {\n  color: color.grayscale(15%);\n}\n",
+    "Error: Passing a number (15%) to color.grayscale() is deprecated.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 23, 0)))
+        .fatal_warnings(true)
+);
 test!(
     grayscale_number,
     "a {\n  color: grayscale(15%);\n}\n",
@@ -365,6 +412,84 @@ test!(
     "a {\n  color: scale-color(sienna, $alpha: -70%);\n}\n",
     "a {\n  color: rgba(160, 82, 45, 0.3);\n}\n"
 );
+test!(
+    adjust_color_hsl,
+    "a {\n  color: adjust-color(#102030, $hue: 30deg, $saturation: -10%, $lightness: 15%);\n}\n",
+    "a {\n  color: #2a2a62;\n}\n"
+);
+test!(
+    adjust_color_clamps_channels,
+    "a {\n  color: adjust-color(#f0f0f0, $red: 100);\n  color: adjust-color(red, $alpha: -1);\n}\n",
+    "a {\n  color: #fff0f0;\n  color: rgba(255, 0, 0, 0);\n}\n"
+);
+test!(
+    scale_color_saturation_and_lightness,
+    "a {\n  color: scale-color(#102030, $saturation: 50%, $lightness: -50%);\n}\n",
+    "a {\n  color: #04101c;\n}\n"
+);
+test!(
+    change_color_hue_only,
+    "a {\n  color: change-color(red, $hue: 120);\n}\n",
+    "a {\n  color: lime;\n}\n"
+);
+test!(
+    update_color_no_channels,
+    "a {\n  color: change-color(red);\n  color: adjust-color(red);\n  color: scale-color(red);\n}\n",
+    "a {\n  color: red;\n  color: red;\n  color: red;\n}\n"
+);
+error!(
+    adjust_color_rgb_and_hsl,
+    "a {\n  color: adjust-color(red, $red: 10, $hue: 10);\n}\n",
+    "Error: RGB parameters may not be passed along with HSL parameters."
+);
+error!(
+    change_color_rgb_and_hsl,
+    "a {\n  color: change-color(red, $blue: 10, $lightness: 10%);\n}\n",
+    "Error: RGB parameters may not be passed along with HSL parameters."
+);
+error!(
+    scale_color_rgb_and_hsl,
+    "a {\n  color: scale-color(red, $green: 10%, $saturation: 10%);\n}\n",
+    "Error: RGB parameters may not be passed along with HSL parameters."
+);
+error!(
+    adjust_color_two_positional_args,
+    "a {\n  color: adjust-color(red, 10);\n}\n",
+    "Error: Only one positional argument is allowed. All other arguments must be passed by name."
+);
+error!(
+    scale_color_two_positional_args,
+    "a {\n  color: scale-color(red, 10%);\n}\n",
+    "Error: Only one positional argument is allowed. All other arguments must be passed by name."
+);
+error!(
+    scale_color_hue,
+    "a {\n  color: scale-color(red, $hue: 10%);\n}\n", "Error: No argument named $hue."
+);
+error!(
+    scale_color_unitless,
+    "a {\n  color: scale-color(red, $red: 10);\n}\n",
+    "Error: $red: Expected 10 to have unit \"%\"."
+);
+error!(
+    scale_color_out_of_range,
+    "a {\n  color: scale-color(red, $lightness: 120%);\n}\n",
+    "Error: $lightness: Expected 120% to be within -100% and 100%."
+);
+error!(
+    adjust_color_out_of_range,
+    "a {\n  color: adjust-color(red, $red: -300);\n}\n",
+    "Error: $red: Expected -300 to be within -255 and 255."
+);
+error!(
+    change_color_negative,
+    "a {\n  color: change-color(red, $saturation: -10%);\n}\n",
+    "Error: $saturation: Expected -10% to be within 0% and 100%."
+);
+error!(
+    change_color_alpha_not_a_number,
+    "a {\n  color: change-color(red, $alpha: foo);\n}\n", "Error: $alpha: foo is not a number."
+);
 test!(
     ie_hex_str_hex_3,
     "a {\n  color: ie-hex-str(#abc);\n}\n",
@@ -380,6 +505,35 @@ test!(
     "a {\n  color: ie-hex-str(rgba(0, 255, 0, 0.5));\n}\n",
     "a {\n  color: #8000FF00;\n}\n"
 );
+test!(
+    ie_hex_str_rounds_alpha,
+    "a {\n  color: ie-hex-str(rgba(red, 0.5));\n}\n",
+    "a {\n  color: #80FF0000;\n}\n"
+);
+test!(
+    ie_hex_str_hex_8,
+    "a {\n  color: ie-hex-str(#12345678);\n}\n",
+    "a {\n  color: #78123456;\n}\n"
+);
+test!(
+    ie_hex_str_transparent,
+    "a {\n  color: ie-hex-str($color: transparent);\n}\n",
+    "a {\n  color: #00000000;\n}\n"
+);
+test!(
+    ie_hex_str_is_unquoted_string,
+    "a {\n  color: type-of(ie-hex-str(red));\n  color: quote(ie-hex-str(red));\n}\n",
+    "a {\n  color: string;\n  color: \"#FFFF0000\";\n}\n"
+);
+test!(
+    module_ie_hex_str,
+    "@use \"sass:color\";\na {\n  color: color.ie-hex-str(#abc);\n}\n",
+    "a {\n  color: #FFAABBCC;\n}\n"
+);
+error!(
+    ie_hex_str_not_a_color,
+    "a {\n  color: ie-hex-str(1);\n}\n", "Error: $color: 1 is not a color."
+);
 test!(
     rgba_1_arg,
     "a {\n  color: rgba(74.7% 173 93%);\n}\n",
@@ -489,6 +643,129 @@ test!(
     "a {\n  color: hsla(0deg, 100%, 50%)==red;\n}\n",
     "a {\n  color: true;\n}\n"
 );
+test!(
+    alpha_function_8_hex_low_alpha,
+    "a {\n  color: alpha(#00000001);\n}\n",
+    "a {\n  color: 0.0039215686;\n}\n"
+);
+test!(
+    hex_alpha_ff_equals_opaque_color,
+    "a {\n  color: #ff0000ff == red;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    hex_alpha_01_not_equal_to_opaque_color,
+    "a {\n  color: #00000001 == black;\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    hex_alpha_equals_rgba,
+    "@use \"sass:math\";\na {\n  color: #f008 == rgba(#f00, math.div(136, 255));\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    hex_alpha_4_compressed,
+    "a {\n  color: #F00A;\n}\n",
+    "a{color:#f00a}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    hex_alpha_8_compressed_shortens_to_4,
+    "a {\n  color: #11223344;\n}\n",
+    "a{color:#1234}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    hex_alpha_8_compressed,
+    "a {\n  color: #ff000080;\n}\n",
+    "a{color:#ff000080}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    hex_alpha_transparent_compressed,
+    "a {\n  color: #0000;\n}\n",
+    "a{color:#0000}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    hex_alpha_opaque_compressed,
+    "a {\n  color: #112233ff;\n}\n",
+    "a{color:#123}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    hex_alpha_modified_compressed,
+    "a {\n  color: rgba(#11223344, 0.5);\n}\n",
+    "a{color:rgba(17,34,51,.5)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    named_color_synonym_is_color,
+    "a {\n  color: type-of(cyan);\n  color: type-of(LightSlateGrey);\n}\n",
+    "a {\n  color: color;\n  color: color;\n}\n"
+);
+test!(
+    named_color_synonym_preserved_as_written,
+    "a {\n  color: grey;\n}\n",
+    "a {\n  color: grey;\n}\n"
+);
+test!(
+    named_color_synonyms_equal,
+    "a {\n  color: grey == gray;\n  color: magenta == fuchsia;\n}\n",
+    "a {\n  color: true;\n  color: true;\n}\n"
+);
+test!(
+    modified_named_color_synonym,
+    "a {\n  color: lighten(cyan, 0%);\n  color: darken(darkslategrey, 0%);\n}\n",
+    "a {\n  color: aqua;\n  color: darkslategray;\n}\n"
+);
+test!(
+    modified_color_uses_name_longer_than_hex,
+    "a {\n  color: lighten(#fafad2, 0%);\n}\n",
+    "a {\n  color: lightgoldenrodyellow;\n}\n"
+);
+test!(
+    rebeccapurple,
+    "a {\n  color: darken(rebeccapurple, 0%);\n}\n",
+    "a {\n  color: rebeccapurple;\n}\n"
+);
+test!(
+    compressed_prefers_hex_shorter_than_name,
+    "a {\n  color: rebeccapurple;\n  color: lightgoldenrodyellow;\n}\n",
+    "a{color:#639;color:#fafad2}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    compressed_prefers_name_no_longer_than_hex,
+    "a {\n  color: #ff0000;\n  color: #000080;\n  color: #0ff;\n}\n",
+    "a{color:red;color:navy;color:aqua}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    mix_alpha_blending,
+    "a {\n  color: mix(rgba(255, 0, 0, 0.5), blue);\n}\n",
+    "a {\n  color: rgba(64, 0, 191, 0.75);\n}\n"
+);
+test!(
+    mix_alpha_blending_weighted,
+    "a {\n  color: mix(rgba(255, 0, 0, 0.2), rgba(0, 0, 255, 0.8), 25%);\n}\n",
+    "a {\n  color: rgba(20, 0, 235, 0.65);\n}\n"
+);
+test!(
+    mix_weight_bounds,
+    "a {\n  color: mix(red, blue, 0%);\n  color: mix(red, blue, 100%);\n}\n",
+    "a {\n  color: blue;\n  color: red;\n}\n"
+);
+error!(
+    mix_weight_above_100,
+    "a {\n  color: mix(red, blue, 101%);\n}\n",
+    "Error: $weight: Expected 101% to be within 0% and 100%."
+);
+test!(
+    complement_keeps_alpha,
+    "a {\n  color: complement(rgba(#abc, 0.3));\n}\n",
+    "a {\n  color: rgba(204, 187, 170, 0.3);\n}\n"
+);
 test!(
     alpha_filter_one_arg,
     "a {\n  color: alpha(a=a);\n}\n",
@@ -525,6 +802,83 @@ error!(
     alpha_filter_invalid_non_alphabetic_start,
     "a {\n  color: alpha(1=a);\n}\n", "Error: $color: 1=a is not a color."
 );
+error!(
+    alpha_no_args,
+    "a {\n  color: alpha();\n}\n", "Error: Missing argument $color."
+);
+test!(
+    module_alpha_filter,
+    "@use \"sass:color\";\na {\n  color: color.alpha(opacity=50);\n}\n",
+    "a {\n  color: alpha(opacity=50);\n}\n"
+);
+error!(
+    module_alpha_filter_deprecated,
+    "@use \"sass:color\";\na {\n  color: color.alpha(opacity=50);\n}\n",
+    "Error: Using color.alpha() for a Microsoft filter is deprecated.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 23, 0)))
+        .fatal_warnings(true)
+);
+error!(
+    module_alpha_filter_removed,
+    "@use \"sass:color\";\na {\n  color: color.alpha(opacity=50);\n}\n",
+    "Error: $color: opacity=50 is not a color.",
+    grass::Options::default().compat(grass::Compat::DartSass(grass::Version::new(2, 0, 0)))
+);
+error!(
+    module_alpha_multiple_filters_removed,
+    "@use \"sass:color\";\na {\n  color: color.alpha(a=b, c=d);\n}\n",
+    "Error: Only 1 argument allowed, but 2 were passed.",
+    grass::Options::default().compat(grass::Compat::DartSass(grass::Version::new(2, 0, 0)))
+);
+test!(
+    module_opacity,
+    "@use \"sass:color\";\na {\n  color: color.opacity(rgba(0, 0, 0, 0.3));\n}\n",
+    "a {\n  color: 0.3;\n}\n"
+);
+test!(
+    module_opacity_number,
+    "@use \"sass:color\";\na {\n  color: color.opacity(50%);\n}\n",
+    "a {\n  color: opacity(50%);\n}\n"
+);
+error!(
+    module_opacity_number_deprecated,
+    "@use \"sass:color\";\na {\n  color: color.opacity(50%);\n}\n",
+    "Error: Passing a number (50%) to color.opacity() is deprecated.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 23, 0)))
+        .fatal_warnings(true)
+);
+test!(
+    opacity_nan,
+    "a {\n  color: opacity((0 / 0));\n}\n",
+    "a {\n  color: opacity(NaN);\n}\n"
+);
+error!(
+    opacify_nan_amount,
+    "a {\n  color: opacify(red, (0 / 0));\n}\n",
+    "Error: $amount: Expected NaN to be within 0 and 1."
+);
+error!(
+    module_opacify_removed,
+    "@use \"sass:color\";\na {\n  color: color.opacify(red, 0.1);\n}\n",
+    "Error: The function opacify() isn't in the sass:color module."
+);
+error!(
+    module_fade_in_removed,
+    "@use \"sass:color\";\na {\n  color: color.fade-in(red, 0.1);\n}\n",
+    "Error: The function fade-in() isn't in the sass:color module."
+);
+error!(
+    module_transparentize_removed,
+    "@use \"sass:color\";\na {\n  color: color.transparentize(red, 0.1);\n}\n",
+    "Error: The function transparentize() isn't in the sass:color module."
+);
+error!(
+    module_fade_out_removed,
+    "@use \"sass:color\";\na {\n  color: color.fade-out(red, 0.1);\n}\n",
+    "Error: The function fade-out() isn't in the sass:color module."
+);
 // todo: we need many more of these tests
 test!(
     rgba_special_fn_4th_arg_max,
@@ -551,6 +905,51 @@ test!(
     "a {\n  color: rgb(rgb(1%, 1, 1), var(--foo));;\n}\n",
     "a {\n  color: rgb(3, 1, 1, var(--foo));\n}\n"
 );
+test!(
+    rgb_special_fn_slash_alpha,
+    "a {\n  color: rgb(1 2 3 / var(--alpha));\n}\n",
+    "a {\n  color: rgb(1 2 3/var(--alpha));\n}\n"
+);
+test!(
+    rgb_special_fn_2_channels_slash_alpha,
+    "a {\n  color: rgb(1 var(--foo) / 0.5);\n}\n",
+    "a {\n  color: rgb(1 var(--foo)/0.5);\n}\n"
+);
+test!(
+    rgb_1_arg_slash_alpha,
+    "a {\n  color: rgb(1 2 3 / 0.5);\n}\n",
+    "a {\n  color: rgba(1, 2, 3, 0.5);\n}\n"
+);
+test!(
+    rgba_1_arg_slash_percent_alpha,
+    "a {\n  color: rgba(1 2 3/50%);\n}\n",
+    "a {\n  color: rgba(1, 2, 3, 0.5);\n}\n"
+);
+error!(
+    rgb_1_arg_slash_alpha_with_units,
+    "a {\n  color: rgb(1 2 3 / 2px);\n}\n",
+    "Error: $alpha: Expected 2px to have no units or \"%\"."
+);
+test!(
+    rgb_special_fn_1_arg_list,
+    "a {\n  color: rgb(1 2 var(--foo));\n}\n",
+    "a {\n  color: rgb(1, 2, var(--foo));\n}\n"
+);
+test!(
+    rgb_special_fn_from_variable,
+    "$a: var(--foo);\na {\n  color: rgb($a, 0, 0);\n  color: rgb(#{$a}, 0, 0);\n}\n",
+    "a {\n  color: rgb(var(--foo), 0, 0);\n  color: rgb(var(--foo), 0, 0);\n}\n"
+);
+test!(
+    rgba_special_fn_color,
+    "a {\n  color: rgba(var(--foo), 0.5);\n}\n",
+    "a {\n  color: rgba(var(--foo), 0.5);\n}\n"
+);
+test!(
+    rgb_special_fn_env,
+    "a {\n  color: rgb(env(foo), 2, 3);\n}\n",
+    "a {\n  color: rgb(env(foo), 2, 3);\n}\n"
+);
 test!(
     #[ignore = "we do not check if interpolation occurred"]
     interpolated_named_color_is_not_color,
@@ -587,3 +986,90 @@ test!(
     "a {\n  color: hue(rgb(1, 2, 5));\n}\n",
     "a {\n  color: 225deg;\n}\n"
 );
+test!(
+    preserve_color_format_keeps_rgb_notation,
+    "a {\n  color: rgb(0,0,0);\n  color: rgba(255, 0, 0, 0.5);\n}\n",
+    "a {\n  color: rgb(0, 0, 0);\n  color: rgba(255, 0, 0, 0.5);\n}\n",
+    grass::Options::default().preserve_color_format(true)
+);
+test!(
+    preserve_color_format_keeps_hsl_notation,
+    "a {\n  color: hsl(0, 100%, 50%);\n  color: hsla(120, 50%, 50%, 1);\n}\n",
+    "a {\n  color: hsl(0, 100%, 50%);\n  color: hsla(120, 50%, 50%, 1);\n}\n",
+    grass::Options::default().preserve_color_format(true)
+);
+test!(
+    preserve_color_format_keeps_percentage_channels,
+    "a {\n  color: rgb(10%, 0, 0);\n}\n",
+    "a {\n  color: rgb(10%, 0, 0);\n}\n",
+    grass::Options::default().preserve_color_format(true)
+);
+test!(
+    preserve_color_format_through_variable,
+    "$a: rgb(1, 2, 3);\n$b: #ABCDEF;\na {\n  color: $a;\n  color: $b;\n}\n",
+    "a {\n  color: rgb(1, 2, 3);\n  color: #ABCDEF;\n}\n",
+    grass::Options::default().preserve_color_format(true)
+);
+test!(
+    preserve_color_format_does_not_preserve_modified_color,
+    "a {\n  color: lighten(rgb(0, 0, 0), 0%);\n  color: darken(#ABCDEF, 0%);\n  color: rgba(#ABCDEF, 0.5);\n}\n",
+    "a {\n  color: black;\n  color: #abcdef;\n  color: rgba(171, 205, 239, 0.5);\n}\n",
+    grass::Options::default().preserve_color_format(true)
+);
+test!(
+    preserve_color_format_does_not_preserve_keyword_arguments,
+    "a {\n  color: rgb($red: 1, $green: 2, $blue: 3);\n}\n",
+    "a {\n  color: #010203;\n}\n",
+    grass::Options::default().preserve_color_format(true)
+);
+test!(
+    preserve_color_format_compressed,
+    "a {\n  color: #FFFFFF;\n  color: white;\n  color: rgb(0, 0, 0);\n  color: rgba(0, 0, 0, 0.5);\n}\n",
+    "a{color:#FFFFFF;color:white;color:rgb(0,0,0);color:rgba(0,0,0,.5)}",
+    grass::Options::default()
+        .preserve_color_format(true)
+        .style(grass::OutputStyle::Compressed)
+);
+test!(
+    preserve_color_format_compressed_shortens_modified_color,
+    "a {\n  color: darken(#FFFFFF, 0%);\n}\n",
+    "a{color:#fff}",
+    grass::Options::default()
+        .preserve_color_format(true)
+        .style(grass::OutputStyle::Compressed)
+);
+test!(
+    converts_rgb_to_name_without_preserve_color_format,
+    "a {\n  color: rgb(0,0,0);\n}\n",
+    "a {\n  color: black;\n}\n"
+);
+test!(
+    color_module_adjust,
+    "@use \"sass:color\";\na {\n  color: color.adjust(#102030, $blue: 5);\n}\n",
+    "a {\n  color: #102035;\n}\n"
+);
+test!(
+    color_module_scale,
+    "@use \"sass:color\";\na {\n  color: color.scale(hsl(120, 70%, 80%), $lightness: 50%);\n}\n",
+    "a {\n  color: #d4f7d4;\n}\n"
+);
+test!(
+    color_module_change,
+    "@use \"sass:color\";\na {\n  color: color.change(#102030, $red: 120, $blue: 5);\n}\n",
+    "a {\n  color: #782005;\n}\n"
+);
+test!(
+    color_module_mix,
+    "@use \"sass:color\";\na {\n  color: color.mix(#f00, #00f, 25%);\n}\n",
+    "a {\n  color: #4000bf;\n}\n"
+);
+test!(
+    color_module_matches_global_functions,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $hue: 30deg) == adjust-color(red, $hue: 30deg);\n  color: color.scale(red, $alpha: -50%) == scale-color(red, $alpha: -50%);\n}\n",
+    "a {\n  color: true;\n  color: true;\n}\n"
+);
+error!(
+    color_module_excludes_global_only_names,
+    "@use \"sass:color\";\na {\n  color: color.adjust-color(red, $blue: 5);\n}\n",
+    "Error: Undefined function."
+);
@@ -168,6 +168,19 @@ test!(
     "a {\n  color: saturate($amount: 50%);\n}\n",
     "a {\n  color: saturate(50%);\n}\n"
 );
+test!(
+    saturate_one_arg_special_function,
+    "a {\n  color: saturate(var(--amount));\n}\n",
+    "a {\n  color: saturate(var(--amount));\n}\n"
+);
+error!(
+    saturate_one_arg_not_a_number,
+    "a {\n  color: saturate(red);\n}\n", "Error: $amount: red is not a number."
+);
+error!(
+    saturate_two_args_number_not_a_color,
+    "a {\n  color: saturate(1%, 10%);\n}\n", "Error: $color: 1% is not a color."
+);
 test!(
     saturate_basic,
     "a {\n  color: saturate(hsl(120, 30%, 90%), 20%);\n}\n",
@@ -223,6 +236,30 @@ test!(
     "a {\n  color: hsla(var(--foo));\n}\n",
     "a {\n  color: hsla(var(--foo));\n}\n"
 );
+test!(
+    hsla_special_fn_2_args,
+    "a {\n  color: hsla(var(--foo), 0.5);\n}\n",
+    "a {\n  color: hsla(var(--foo), 0.5);\n}\n"
+);
+test!(
+    hsl_special_fn_2_args_second,
+    "a {\n  color: hsl(1, var(--foo));\n}\n",
+    "a {\n  color: hsl(1, var(--foo));\n}\n"
+);
+error!(
+    hsl_2_args_no_special_fn,
+    "a {\n  color: hsl(1, 2%);\n}\n", "Error: Missing argument $lightness."
+);
+test!(
+    hsl_special_fn_slash_alpha,
+    "a {\n  color: hsl(1 2% 3% / var(--alpha));\n}\n",
+    "a {\n  color: hsl(1 2% 3%/var(--alpha));\n}\n"
+);
+test!(
+    hsl_1_arg_slash_alpha,
+    "a {\n  color: hsl(0 10% 20% / 0.5);\n}\n",
+    "a {\n  color: rgba(56, 46, 46, 0.5);\n}\n"
+);
 test!(
     hue_of_rgb_is_negative,
     "a {\n  color: hue(rgb(255, 0, 1));\n}\n",
@@ -258,3 +295,36 @@ test!(
     "a {\n  color: hsl(8grad, 25%, 50%);\n}\n",
     "a {\n  color: #9f6860;\n}\n"
 );
+error!(
+    module_lighten_removed,
+    "@use \"sass:color\";\na {\n  color: color.lighten(red, 10%);\n}\n",
+    "Error: The function lighten() isn't in the sass:color module."
+);
+error!(
+    module_darken_removed,
+    "@use \"sass:color\";\na {\n  color: color.darken(red, 10%);\n}\n",
+    "Error: The function darken() isn't in the sass:color module."
+);
+error!(
+    module_saturate_removed,
+    "@use \"sass:color\";\na {\n  color: color.saturate(red, 10%);\n}\n",
+    "Error: The function saturate() isn't in the sass:color module."
+);
+error!(
+    module_desaturate_removed,
+    "@use \"sass:color\";\na {\n  color: color.desaturate(red, 10%);\n}\n",
+    "Error: The function desaturate() isn't in the sass:color module."
+);
+error!(
+    module_adjust_hue_removed,
+    "@use \"sass:color\";\na {\n  color: color.adjust-hue(red, 10deg);\n}\n",
+    "Error: The function adjust-hue() isn't in the sass:color module."
+);
+#[test]
+fn module_darken_removed_recommendation() {
+    let input = "@use \"sass:color\";\na {\n  color: color.darken(red, 10%);\n}\n";
+    let err = grass::from_string(input.to_owned(), &grass::Options::default())
+        .expect_err(input)
+        .to_string();
+    assert!(err.contains("Recommendation: color.adjust(red, $lightness: -10%)"));
+}
@@ -81,3 +81,152 @@ error!(
     "@use \"sass:color\";\na {\n  color: color.hwb(0, 0, 100);\n}\n",
     "Error: $whiteness: Expected 0 to have unit \"%\"."
 );
+test!(
+    hwb_channels_list,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0 0% 50%);\n}\n",
+    "a {\n  color: maroon;\n}\n"
+);
+test!(
+    hwb_named_channels,
+    "@use \"sass:color\";\na {\n  color: color.hwb($channels: 0 0% 50%);\n}\n",
+    "a {\n  color: maroon;\n}\n"
+);
+test!(
+    hwb_all_named_args,
+    "@use \"sass:color\";\na {\n  color: color.hwb($hue: 0, $whiteness: 0%, $blackness: 50%, $alpha: 0.5);\n}\n",
+    "a {\n  color: rgba(128, 0, 0, 0.5);\n}\n"
+);
+error!(
+    hwb_channels_too_many_elements,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0 0% 50% 1);\n}\n",
+    "Error: Only 3 elements allowed, but 4 were passed."
+);
+test!(
+    adjust_whiteness,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $whiteness: 10%);\n}\n",
+    "a {\n  color: #ff1a1a;\n}\n"
+);
+test!(
+    scale_blackness,
+    "@use \"sass:color\";\na {\n  color: color.scale(red, $blackness: 50%);\n}\n",
+    "a {\n  color: maroon;\n}\n"
+);
+test!(
+    change_whiteness_and_hue,
+    "@use \"sass:color\";\na {\n  color: color.change(red, $hue: 240, $whiteness: 20%);\n}\n",
+    "a {\n  color: #3333ff;\n}\n"
+);
+test!(
+    global_adjust_color_whiteness_and_alpha,
+    "a {\n  color: adjust-color(red, $blackness: 20%, $alpha: -0.5);\n}\n",
+    "a {\n  color: rgba(204, 0, 0, 0.5);\n}\n"
+);
+error!(
+    adjust_whiteness_unitless,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $whiteness: 10);\n}\n",
+    "Error: $whiteness: Expected 10 to have unit \"%\"."
+);
+error!(
+    change_whiteness_out_of_range,
+    "@use \"sass:color\";\na {\n  color: color.change(red, $whiteness: 120%);\n}\n",
+    "Error: $whiteness: Expected 120% to be within 0% and 100%."
+);
+error!(
+    adjust_rgb_and_hwb,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $red: 1, $whiteness: 10%);\n}\n",
+    "Error: RGB parameters may not be passed along with HWB parameters."
+);
+error!(
+    adjust_hsl_and_hwb,
+    "@use \"sass:color\";\na {\n  color: color.adjust(red, $lightness: 1%, $blackness: 10%);\n}\n",
+    "Error: HSL parameters may not be passed along with HWB parameters."
+);
+test!(
+    hwb_slash_list_alpha,
+    "@use \"sass:color\";\n@use \"sass:list\";\na {\n  color: color.hwb(list.slash(0 10% 20%, 0.5));\n}\n",
+    "a {\n  color: rgba(204, 26, 26, 0.5);\n}\n"
+);
+test!(
+    hwb_slash_list_percent_alpha,
+    "@use \"sass:color\";\n@use \"sass:list\";\na {\n  color: color.hwb(list.slash(0 10% 20%, 50%));\n}\n",
+    "a {\n  color: rgba(204, 26, 26, 0.5);\n}\n"
+);
+test!(
+    hwb_slash_alpha,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0 10% 20% / 0.5);\n}\n",
+    "a {\n  color: rgba(204, 26, 26, 0.5);\n}\n"
+);
+error!(
+    hwb_slash_list_too_many_elements,
+    "@use \"sass:color\";\n@use \"sass:list\";\na {\n  color: color.hwb(list.slash(0, 10%, 20%));\n}\n",
+    "Error: Only 2 slash-separated elements allowed, but 3 were passed."
+);
+error!(
+    hwb_blackness_out_of_range,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0, 10%, 120%);\n}\n",
+    "Error: $blackness: Expected 120% to be within 0% and 100%."
+);
+error!(
+    hwb_whiteness_negative,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0, -1%, 20%);\n}\n",
+    "Error: $whiteness: Expected -1% to be within 0% and 100%."
+);
+error!(
+    hwb_blackness_no_percent,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0, 10%, 20px);\n}\n",
+    "Error: $blackness: Expected 20px to have unit \"%\"."
+);
+error!(
+    hwb_comma_separated_channels,
+    "@use \"sass:color\";\na {\n  color: color.hwb((0, 10%, 20%));\n}\n",
+    "Error: $channels must be a space-separated list."
+);
+error!(
+    hwb_bracketed_channels,
+    "@use \"sass:color\";\na {\n  color: color.hwb([0 10% 20%]);\n}\n",
+    "Error: $channels must be an unbracketed list."
+);
+error!(
+    hwb_single_channel,
+    "@use \"sass:color\";\na {\n  color: color.hwb(0);\n}\n", "Error: Missing element $whiteness."
+);
+error!(
+    hwb_special_fn_channels,
+    "@use \"sass:color\";\na {\n  color: color.hwb(var(--foo));\n}\n",
+    "Error: Expected numeric channels, got \"hwb(var(--foo))\"."
+);
+error!(
+    hwb_special_fn_2_channels,
+    "@use \"sass:color\";\na {\n  color: color.hwb(1 var(--foo));\n}\n",
+    "Error: Expected numeric channels, got \"hwb(1 var(--foo))\"."
+);
+error!(
+    hwb_special_fn_slash_alpha,
+    "@use \"sass:color\";\na {\n  color: color.hwb(1 2% 3% / var(--alpha));\n}\n",
+    "Error: Expected numeric channels, got \"hwb(1 2% 3%/var(--alpha))\"."
+);
+error!(
+    hwb_special_fn_4_args,
+    "@use \"sass:color\";\na {\n  color: color.hwb(1, 2%, 3%, var(--alpha));\n}\n",
+    "Error: $alpha: var(--alpha) is not a number."
+);
+error!(
+    hwb_nan_hue,
+    "@use \"sass:color\";\n@use \"sass:math\";\na {\n  color: color.hwb(math.div(0, 0), 0%, 0%);\n}\n",
+    "Error: $hue: NaN is not a finite number."
+);
+test!(
+    hwb_nan_hue_gray,
+    "@use \"sass:color\";\n@use \"sass:math\";\na {\n  color: color.hwb(math.div(0, 0), 60%, 40%);\n}\n",
+    "a {\n  color: #999999;\n}\n"
+);
+error!(
+    hwb_nan_alpha,
+    "@use \"sass:color\";\n@use \"sass:math\";\na {\n  color: color.hwb(120, 0%, 0%, math.div(0, 0));\n}\n",
+    "Error: $alpha: Expected NaN to be within 0 and 1."
+);
+error!(
+    hwb_nan_hue_with_unit,
+    "@use \"sass:color\";\n@use \"sass:math\";\na {\n  color: color.hwb(math.acos(2), 0%, 0%);\n}\n",
+    "Error: $hue: NaNdeg is not a finite number."
+);
@@ -0,0 +1,112 @@
+use grass::{Compat, Options, Version};
+
+fn dart_sass(major: u32, minor: u32, patch: u32) -> Options<'static> {
+    Options::default()
+        .compat(Compat::DartSass(Version::new(major, minor, patch)))
+        .quiet(true)
+}
+
+fn compile(input: &str, options: &Options) -> Result<String, String> {
+    grass::from_string(input.to_string(), options).map_err(|e| {
+        e.to_string()
+            .chars()
+            .take_while(|c| *c != '\n')
+            .collect::<String>()
+    })
+}
+
+#[test]
+fn slash_div_allowed_by_default() {
+    assert_eq!(
+        Ok("a {\n  color: 2px;\n}\n".to_owned()),
+        compile("$a: 4px;\na {\n  color: $a / 2;\n}\n", &Options::default())
+    );
+}
+
+#[test]
+fn slash_div_deprecated_still_divides() {
+    assert_eq!(
+        Ok("a {\n  color: 2px;\n}\n".to_owned()),
+        compile("$a: 4px;\na {\n  color: $a / 2;\n}\n", &dart_sass(1, 33, 0))
+    );
+}
+
+#[test]
+fn slash_div_error_in_dart_sass_2() {
+    assert_eq!(
+        Err("Error: Using / for division outside of calc() is not supported in Dart Sass 2.0.0. Use math.div() or calc() instead.".to_owned()),
+        compile("$a: 4px;\na {\n  color: $a / 2;\n}\n", &dart_sass(2, 0, 0))
+    );
+}
+
+#[test]
+fn slash_separated_values_unaffected_in_dart_sass_2() {
+    assert_eq!(
+        Ok("a {\n  font: 12px/30px;\n}\n".to_owned()),
+        compile("a {\n  font: 12px/30px;\n}\n", &dart_sass(2, 0, 0))
+    );
+}
+
+#[test]
+fn math_div_allowed_in_dart_sass_2() {
+    assert_eq!(
+        Ok("a {\n  color: 2px;\n}\n".to_owned()),
+        compile(
+            "@use 'sass:math';\na {\n  color: math.div(4px, 2);\n}\n",
+            &dart_sass(2, 0, 0)
+        )
+    );
+}
+
+#[test]
+fn bogus_combinators_allowed_before_deprecation() {
+    assert_eq!(
+        Ok("a > > b {\n  color: red;\n}\n".to_owned()),
+        compile("a > > b {\n  color: red;\n}\n", &dart_sass(1, 53, 0))
+    );
+}
+
+#[test]
+fn bogus_combinators_deprecated_still_emitted() {
+    assert_eq!(
+        Ok("+ a {\n  color: red;\n}\n".to_owned()),
+        compile("+ a {\n  color: red;\n}\n", &dart_sass(1, 54, 0))
+    );
+}
+
+#[test]
+fn bogus_combinators_error_in_dart_sass_2() {
+    assert_eq!(
+        Err("Error: The selector \"a ~\" is invalid CSS.".to_owned()),
+        compile("a ~ {\n  color: red;\n}\n", &dart_sass(2, 0, 0))
+    );
+}
+
+#[test]
+fn nested_leading_combinator_is_not_bogus() {
+    assert_eq!(
+        Ok("a > b {\n  color: red;\n}\n".to_owned()),
+        compile(
+            "a {\n  > b {\n    color: red;\n  }\n}\n",
+            &dart_sass(2, 0, 0)
+        )
+    );
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn random_ignores_units_before_dart_sass_2() {
+    assert_eq!(
+        Ok("a {\n  color: 1;\n}\n".to_owned()),
+        compile("a {\n  color: random(1px);\n}\n", &dart_sass(1, 54, 5))
+    );
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn random_keeps_units_in_dart_sass_2() {
+    assert_eq!(
+        Ok("a {\n  color: 1px;\n}\n".to_owned()),
+        compile("a {\n  color: random(1px);\n}\n", &dart_sass(2, 0, 0))
+    );
+}
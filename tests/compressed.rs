@@ -92,7 +92,6 @@ test!(
     grass::Options::default().style(grass::OutputStyle::Compressed)
 );
 test!(
-    #[ignore = "we do not support compressed colors"]
     removes_leading_zero_in_number_under_1_in_rgba_alpha_channel,
     "a {\n  color: rgba(1, 1, 1, 0.5);\n}\n",
     "a{color:rgba(1,1,1,.5)}",
@@ -116,9 +115,122 @@ test!(
     "a{color:grayscale(0.5)}",
     grass::Options::default().style(grass::OutputStyle::Compressed)
 );
+test!(
+    retains_leading_zero_in_invert,
+    "a {\n  color: invert(0.5);\n}\n",
+    "a{color:invert(0.5)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
 test!(
     retains_zero_without_decimal,
     "a {\n  color: 0.0;\n  color: 0;\n}\n",
     "a{color:0;color:0}",
     grass::Options::default().style(grass::OutputStyle::Compressed)
 );
+test!(
+    shortens_hex_color,
+    "a {\n  color: #ffffff;\n  color: #AABBCC;\n}\n",
+    "a{color:#fff;color:#abc}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    retains_long_hex_color_that_cannot_be_shortened,
+    "a {\n  color: #123456;\n}\n",
+    "a{color:#123456}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    hex_color_to_shorter_name,
+    "a {\n  color: #ff0000;\n  color: #000080;\n}\n",
+    "a{color:red;color:navy}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    name_to_shorter_hex_color,
+    "a {\n  color: white;\n  color: YELLOW;\n}\n",
+    "a{color:#fff;color:#ff0}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    prefers_name_of_same_length_as_hex_color,
+    "a {\n  color: #00f;\n}\n",
+    "a{color:blue}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    compresses_transparent_black,
+    "a {\n  color: rgba(0, 0, 0, 0);\n}\n",
+    "a{color:transparent}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    compresses_color_in_plain_css_function,
+    "a {\n  color: linear-gradient(#ffffff, rgba(0, 0, 0, 0.25));\n}\n",
+    "a{color:linear-gradient(#fff,rgba(0,0,0,.25))}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    removes_space_after_comma_in_plain_css_function,
+    "a {\n  color: foo(1, 2, 3);\n}\n",
+    "a{color:foo(1,2,3)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    removes_unit_from_zero_length,
+    "a {\n  margin: 0px 0em 1px 0vh;\n  width: 0.0rem;\n}\n",
+    "a{margin:0 0 1px 0;width:0}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    retains_unit_of_zero_non_length,
+    "a {\n  transition-delay: 0s;\n  width: 0%;\n  transform: rotate(0deg);\n}\n",
+    "a{transition-delay:0s;width:0%;transform:rotate(0deg)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    retains_unit_of_zero_length_in_function,
+    "a {\n  width: calc(0px + 1em);\n  transform: translate(0px, 1px);\n}\n",
+    "a{width:calc(0px + 1em);transform:translate(0px,1px)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    retains_unit_of_zero_length_in_flex,
+    "a {\n  flex: 1 1 0px;\n  flex-basis: 0px;\n  --gap: 0px;\n}\n",
+    "a{flex:1 1 0px;flex-basis:0px;--gap:0px}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    retains_unit_of_zero_length_when_expanded,
+    "a {\n  color: #ffffff;\n  width: 0px;\n  color: foo(1, 2);\n}\n",
+    "a {\n  color: #ffffff;\n  width: 0px;\n  color: foo(1, 2);\n}\n"
+);
+test!(
+    keeps_preserved_toplevel_comment,
+    "/*! license */\na {\n  color: red;\n}\n",
+    "/*! license */a{color:red}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    keeps_preserved_comment_in_rule,
+    "a {\n  color: red;\n  /*! keep */\n  width: 1px;\n}\n",
+    "a{color:red;/*! keep */width:1px}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    omits_rule_containing_only_comments,
+    "a {\n  /* abc */\n}\nb {\n  color: red;\n}\n",
+    "b{color:red}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    no_trailing_semicolon_in_unknown_at_rule,
+    "@font-face {\n  font-family: x;\n  src: url(x.woff);\n}\n",
+    "@font-face{font-family:x;src:url(x.woff)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    semicolon_between_declaration_and_at_rule_in_rule,
+    "a {\n  color: red;\n  @foo;\n  width: 1px;\n}\n",
+    "a{color:red;@foo;width:1px}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
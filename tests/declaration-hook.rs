@@ -0,0 +1,184 @@
+use std::sync::Mutex;
+
+use grass::{DeclarationHook, Options, OutputStyle};
+
+#[derive(Debug)]
+struct Prefixer;
+
+impl DeclarationHook for Prefixer {
+    fn declaration(
+        &self,
+        _selector: Option<&str>,
+        property: &str,
+        value: &str,
+    ) -> Vec<(String, String)> {
+        let mut declarations = Vec::new();
+        if property == "user-select" {
+            declarations.push(("-webkit-user-select".to_owned(), value.to_owned()));
+        }
+        declarations.push((property.to_owned(), value.to_owned()));
+        declarations
+    }
+}
+
+#[derive(Debug)]
+struct PxToRem;
+
+impl DeclarationHook for PxToRem {
+    fn declaration(
+        &self,
+        _selector: Option<&str>,
+        property: &str,
+        value: &str,
+    ) -> Vec<(String, String)> {
+        let value = match value.strip_suffix("px") {
+            Some(px) => format!("{}rem", px.parse::<f64>().unwrap() / 16.0),
+            None => value.to_owned(),
+        };
+        vec![(property.to_owned(), value)]
+    }
+}
+
+#[derive(Debug)]
+struct RemoveColor;
+
+impl DeclarationHook for RemoveColor {
+    fn declaration(
+        &self,
+        _selector: Option<&str>,
+        property: &str,
+        value: &str,
+    ) -> Vec<(String, String)> {
+        if property == "color" {
+            Vec::new()
+        } else {
+            vec![(property.to_owned(), value.to_owned())]
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Recorder {
+    seen: Mutex<Vec<(Option<String>, String, String)>>,
+}
+
+impl DeclarationHook for Recorder {
+    fn declaration(
+        &self,
+        selector: Option<&str>,
+        property: &str,
+        value: &str,
+    ) -> Vec<(String, String)> {
+        self.seen.lock().unwrap().push((
+            selector.map(str::to_owned),
+            property.to_owned(),
+            value.to_owned(),
+        ));
+        vec![(property.to_owned(), value.to_owned())]
+    }
+}
+
+fn compile(input: &str, options: &Options) -> String {
+    grass::from_string(input.to_owned(), options).unwrap()
+}
+
+#[test]
+fn adds_vendor_prefix() {
+    assert_eq!(
+        compile(
+            "a { user-select: none; color: red; }",
+            &Options::default().declaration_hook(&Prefixer)
+        ),
+        "a {\n  -webkit-user-select: none;\n  user-select: none;\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn adds_vendor_prefix_compressed() {
+    assert_eq!(
+        compile(
+            "a { user-select: none; color: red; }",
+            &Options::default()
+                .declaration_hook(&Prefixer)
+                .style(OutputStyle::Compressed)
+        ),
+        "a{-webkit-user-select:none;user-select:none;color:red}"
+    );
+}
+
+#[test]
+fn rewrites_units() {
+    assert_eq!(
+        compile(
+            "a { width: 8px + 8px; height: 50%; }",
+            &Options::default().declaration_hook(&PxToRem)
+        ),
+        "a {\n  width: 1rem;\n  height: 50%;\n}\n"
+    );
+}
+
+#[test]
+fn applies_inside_media_and_keyframes() {
+    assert_eq!(
+        compile(
+            "@media screen { a { width: 32px; } }\n@keyframes k { to { width: 16px; } }",
+            &Options::default().declaration_hook(&PxToRem)
+        ),
+        "@media screen {\n  a {\n    width: 2rem;\n  }\n}\n@keyframes k {\n  to {\n    width: 1rem;\n  }\n}\n"
+    );
+}
+
+#[test]
+fn removing_every_declaration_removes_rule() {
+    assert_eq!(
+        compile(
+            "a { color: red; }\nb { color: red; width: 1px; }",
+            &Options::default().declaration_hook(&RemoveColor)
+        ),
+        "b {\n  width: 1px;\n}\n"
+    );
+}
+
+#[test]
+fn receives_selector_context() {
+    let recorder = Recorder::default();
+    compile(
+        "a, b { c { color: red; } }\n@font-face { font-family: f; }\n@keyframes k { from, 50% { width: 1px; } }",
+        &Options::default().declaration_hook(&recorder),
+    );
+    assert_eq!(
+        recorder.seen.into_inner().unwrap(),
+        vec![
+            (
+                Some("a c, b c".to_owned()),
+                "color".to_owned(),
+                "red".to_owned()
+            ),
+            (None, "font-family".to_owned(), "f".to_owned()),
+            (
+                Some("from, 50%".to_owned()),
+                "width".to_owned(),
+                "1px".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn receives_compressed_values() {
+    let recorder = Recorder::default();
+    compile(
+        "a { font-family: a, b; }",
+        &Options::default()
+            .declaration_hook(&recorder)
+            .style(OutputStyle::Compressed),
+    );
+    assert_eq!(
+        recorder.seen.into_inner().unwrap(),
+        vec![(
+            Some("a".to_owned()),
+            "font-family".to_owned(),
+            "a,b".to_owned()
+        )]
+    );
+}
@@ -172,3 +172,48 @@ test!(
     "a {\n  color: (0 / 0);\n}\n",
     "a {\n  color: NaN;\n}\n"
 );
+test!(
+    slash_in_parenthesized_space_list_not_divided,
+    "a {\n  font: (bold 15px/30px sans-serif);\n}\n",
+    "a {\n  font: bold 15px/30px sans-serif;\n}\n"
+);
+test!(
+    slash_in_space_list_variable_not_divided,
+    "$a: 1 2/3;\na {\n  color: $a;\n}\n",
+    "a {\n  color: 1 2/3;\n}\n"
+);
+test!(
+    slash_in_comma_list_in_parens_not_divided,
+    "a {\n  color: (1, 2/3);\n}\n",
+    "a {\n  color: 1, 2/3;\n}\n"
+);
+test!(
+    slash_in_space_list_function_arg_not_divided,
+    "a {\n  color: inspect(1 2/3);\n}\n",
+    "a {\n  color: 1 2/3;\n}\n"
+);
+test!(
+    slash_alone_in_variable_divided,
+    "$a: 1/2;\na {\n  color: $a;\n}\n",
+    "a {\n  color: 0.5;\n}\n"
+);
+test!(
+    nan_div_number_keeps_unit,
+    "a {\n  color: ((0px / 0) / 2);\n}\n",
+    "a {\n  color: NaNpx;\n}\n"
+);
+test!(
+    number_div_nan,
+    "a {\n  color: (1 / (0 / 0));\n}\n",
+    "a {\n  color: NaN;\n}\n"
+);
+test!(
+    zero_div_zero_keeps_unit,
+    "a {\n  color: (0px / 0);\n}\n",
+    "a {\n  color: NaNpx;\n}\n"
+);
+test!(
+    nan_div_unquoted_string,
+    "a {\n  color: (0 / 0) / a;\n}\n",
+    "a {\n  color: NaN/a;\n}\n"
+);
@@ -95,6 +95,46 @@ error!(
       }",
     "Error: (a: b) isn't a valid CSS value."
 );
+test!(
+    each_map_preserves_insertion_order,
+    "a {\n  @each $key, $value in (z: 1, b: 2, a: 3, y: 4) {\n    #{$key}: $value;\n  }\n}\n",
+    "a {\n  z: 1;\n  b: 2;\n  a: 3;\n  y: 4;\n}\n"
+);
+test!(
+    each_merged_map_appends_new_keys,
+    "$map: map-merge((z: 1, b: 2), (a: 3, z: 4));\na {\n  @each $key, $value in $map {\n    #{$key}: $value;\n  }\n}\n",
+    "a {\n  z: 4;\n  b: 2;\n  a: 3;\n}\n"
+);
+test!(
+    each_map_without_destructuring_gives_pairs,
+    "a {\n  @each $pair in (b: c, d: e) {\n    color: $pair;\n    length: length($pair);\n  }\n}\n",
+    "a {\n  color: b c;\n  length: 2;\n  color: d e;\n  length: 2;\n}\n"
+);
+test!(
+    each_three_vars_fills_missing_with_null,
+    "a {\n  @each $a, $b, $c in (1 2 3, 4 5, 6) {\n    color: inspect($a) inspect($b) inspect($c);\n  }\n}\n",
+    "a {\n  color: 1 2 3;\n  color: 4 5 null;\n  color: 6 null null;\n}\n"
+);
+test!(
+    each_three_vars_in_map,
+    "a {\n  @each $key, $value, $extra in (b: c) {\n    color: $key $value inspect($extra);\n  }\n}\n",
+    "a {\n  color: b c null;\n}\n"
+);
+test!(
+    each_map_value_is_list,
+    "a {\n  @each $key, $value in (b: 1px 2px, c: (3px, 4px)) {\n    #{$key}: $value;\n  }\n}\n",
+    "a {\n  b: 1px 2px;\n  c: 3px, 4px;\n}\n"
+);
+test!(
+    each_destructures_bracketed_lists,
+    "a {\n  @each $a, $b in [1 2] [3 4] {\n    color: $a $b;\n  }\n}\n",
+    "a {\n  color: 1 2;\n  color: 3 4;\n}\n"
+);
+test!(
+    each_destructuring_variables_do_not_leak,
+    "$a: outer;\na {\n  @each $a, $b in (1 2, 3 4) {\n    color: $a;\n  }\n  color: $a;\n}\n",
+    "a {\n  color: 1;\n  color: 3;\n  color: outer;\n}\n"
+);
 error!(
     missing_closing_curly_brace,
     "@each $i in 1 {", "Error: expected \"}\"."
@@ -0,0 +1,126 @@
+use std::{fs, path::Path};
+
+use tempfile::tempdir;
+
+fn compile_bytes(dir: &Path, name: &str, bytes: &[u8]) -> String {
+    let path = dir.join(name);
+    fs::write(&path, bytes).unwrap();
+    grass::from_path(path.to_str().unwrap(), &grass::Options::default()).unwrap()
+}
+
+fn utf_16(input: &str, big_endian: bool) -> Vec<u8> {
+    let mut bytes = if big_endian {
+        vec![0xFE, 0xFF]
+    } else {
+        vec![0xFF, 0xFE]
+    };
+    for unit in input.encode_utf16() {
+        if big_endian {
+            bytes.extend_from_slice(&unit.to_be_bytes());
+        } else {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+    }
+    bytes
+}
+
+#[test]
+fn utf_8_bom_is_stripped() {
+    let dir = tempdir().unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_bytes(
+            dir.path(),
+            "bom.scss",
+            b"\xEF\xBB\xBFa {\n  color: red;\n}\n"
+        )
+    );
+}
+
+#[test]
+fn utf_16_le() {
+    let dir = tempdir().unwrap();
+    assert_eq!(
+        "@charset \"UTF-8\";\na {\n  content: \"\u{e9}\u{1F600}\";\n}\n",
+        compile_bytes(
+            dir.path(),
+            "utf-16-le.scss",
+            &utf_16("a {\n  content: \"\u{e9}\u{1F600}\";\n}\n", false)
+        )
+    );
+}
+
+#[test]
+fn utf_16_be() {
+    let dir = tempdir().unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_bytes(
+            dir.path(),
+            "utf-16-be.scss",
+            &utf_16("a {\n  color: red;\n}\n", true)
+        )
+    );
+}
+
+#[test]
+fn utf_16_import() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("_imported.scss"), utf_16("$a: red;", false)).unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        compile_bytes(
+            dir.path(),
+            "main.scss",
+            b"@import \"imported\";\na {\n  color: $a;\n}\n"
+        )
+    );
+}
+
+#[test]
+fn charset_windows_1252() {
+    let dir = tempdir().unwrap();
+    assert_eq!(
+        "@charset \"UTF-8\";\na {\n  content: \"\u{e9}\u{20AC}\";\n}\n",
+        compile_bytes(
+            dir.path(),
+            "latin1.scss",
+            b"@charset \"iso-8859-1\";\na {\n  content: \"\xE9\x80\";\n}\n"
+        )
+    );
+}
+
+#[test]
+fn charset_utf_16_is_treated_as_utf_8() {
+    let dir = tempdir().unwrap();
+    assert_eq!(
+        "@charset \"UTF-8\";\na {\n  content: \"\u{e9}\";\n}\n",
+        compile_bytes(
+            dir.path(),
+            "charset-utf-16.scss",
+            "@charset \"utf-16\";\na {\n  content: \"\u{e9}\";\n}\n".as_bytes()
+        )
+    );
+}
+
+#[test]
+fn bom_takes_precedence_over_charset() {
+    let dir = tempdir().unwrap();
+    assert_eq!(
+        "@charset \"UTF-8\";\na {\n  content: \"\u{e9}\";\n}\n",
+        compile_bytes(
+            dir.path(),
+            "bom-and-charset.scss",
+            "\u{FEFF}@charset \"iso-8859-1\";\na {\n  content: \"\u{e9}\";\n}\n".as_bytes()
+        )
+    );
+}
+
+#[test]
+fn invalid_utf_8_is_an_error() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("invalid.scss");
+    fs::write(&path, b"a {\n  content: \"\xE9\";\n}\n").unwrap();
+    let err = grass::from_path(path.to_str().unwrap(), &grass::Options::default()).unwrap_err();
+    assert_eq!(err.kind(), grass::ErrorKind::Encoding);
+}
@@ -1,3 +1,7 @@
+use std::io::Write;
+
+use grass::ErrorKind;
+
 #[macro_use]
 mod macros;
 
@@ -255,3 +259,304 @@ error!(
     unclosed_bracketed_list,
     "a { color: [a", "Error: expected \"]\"."
 );
+
+#[test]
+fn undefined_variable_suggests_similar_name() {
+    let err = grass::from_string(
+        "$primary-color: red;\na {\n  color: $primary-colr;\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.starts_with("Error: Undefined variable.\n\nDid you mean $primary-color?\n"));
+}
+
+#[test]
+fn undefined_variable_suggests_local_name() {
+    let err = grass::from_string(
+        "a {\n  $width: 10px;\n  width: $widht;\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.contains("Did you mean $width?"));
+}
+
+#[test]
+fn undefined_variable_no_suggestion_when_nothing_similar() {
+    let err = grass::from_string(
+        "$primary-color: red;\na {\n  color: $foo;\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(!err.contains("Did you mean"));
+}
+
+#[test]
+fn undefined_mixin_suggests_similar_name() {
+    let err = grass::from_string(
+        "@mixin button-base {}\na {\n  @include buton-base;\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.starts_with("Error: Undefined mixin.\n\nDid you mean button-base?\n"));
+}
+
+#[test]
+fn undefined_module_function_suggests_similar_name() {
+    let err = grass::from_string(
+        "@use \"sass:math\";\na {\n  color: math.flor(1.5);\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.starts_with("Error: Undefined function.\n\nDid you mean floor?\n"));
+}
+
+#[test]
+fn error_shows_surrounding_lines() {
+    let err = grass::from_string(
+        "a {\n  b: c;\n  color: unit(foo);\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert_eq!(
+        err,
+        "Error: $number: foo is not a number.\n  ╷\n2 │   b: c;\n3 │   color: unit(foo);\n  │              ^^^^^\n4 │ }\n  ╵\n./stdin:3:14\n"
+    );
+}
+
+#[test]
+fn error_multiline_span_is_marked_in_gutter() {
+    let err = grass::from_string(
+        "a {\n  @error\n    \"foo\";\n}\n".to_string(),
+        &grass::Options::default().unicode_error_messages(false),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert_eq!(
+        err,
+        "Error: \"foo\"\n  |\n1 |   a {\n2 | ,   @error\n3 | |     \"foo\";\n  | '---------^\n4 |   }\n  |\n./stdin:2:3\n"
+    );
+}
+
+#[test]
+fn error_line_numbers_are_padded_to_widest_line() {
+    let err = grass::from_string(
+        "\n\n\n\n\n\n\n\na {\n  color: $a;\n}\n".to_string(),
+        &grass::Options::default().unicode_error_messages(false),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.contains("\n   |\n9  | a {\n10 |   color: $a;\n"));
+    assert!(err.contains("\n11 | }\n   |\n./stdin:10:10\n"));
+}
+
+#[test]
+fn error_inside_function_and_mixin_has_stack_trace() {
+    let err = grass::from_string(
+        "@function foo($a) {\n  @error \"bad\";\n}\n@mixin bar {\n  color: foo(1);\n}\na {\n  @include bar;\n}\n"
+            .to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    let trace: Vec<&str> = err.lines().skip_while(|l| !l.starts_with("./")).collect();
+
+    assert_eq!(trace.len(), 4);
+    assert!(trace[1].starts_with("  stdin 2:3 "));
+    assert!(trace[1].ends_with("  foo()"));
+    assert!(trace[2].starts_with("  stdin 5:"));
+    assert!(trace[2].ends_with("  bar()"));
+    assert!(trace[3].starts_with("  stdin 8:"));
+    assert!(trace[3].ends_with("  root stylesheet"));
+}
+
+#[test]
+fn toplevel_error_has_no_stack_trace() {
+    let err = grass::from_string(
+        "a {\n  @error \"bad\";\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.ends_with("./stdin:2:3\n"));
+}
+
+#[test]
+fn error_in_interpolation_points_at_expression() {
+    let err = grass::from_string(
+        "a {\n  color: \"#{1px + 1em}\";\n}\n".to_string(),
+        &grass::Options::default().unicode_error_messages(false),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.contains("\n2 |   color: \"#{1px + 1em}\";\n  |             ^^^^^^^^^\n"));
+    assert!(err.contains("./stdin:2:13\n"));
+}
+
+#[test]
+fn error_in_selector_interpolation_points_at_offending_token() {
+    let err = grass::from_string(
+        "#{\"a\"} b&c {\n  color: red;\n}\n".to_string(),
+        &grass::Options::default().unicode_error_messages(false),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(
+        err.contains("\n1 | #{\"a\"} b&c {\n  |         ^\n"),
+        "{}",
+        err
+    );
+    assert!(err.contains("./stdin:1:9\n"), "{}", err);
+}
+
+#[test]
+fn undefined_variable_span_covers_only_the_variable() {
+    let err = grass::from_string(
+        "a {\n  color: 1px + $foo-bar + 2px;\n}\n".to_string(),
+        &grass::Options::default().unicode_error_messages(false),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(err.contains("\n  |                ^^^^^^^^\n"), "{}", err);
+    assert!(err.contains("./stdin:2:16\n"), "{}", err);
+}
+
+#[test]
+fn error_location_is_exposed() {
+    let err = grass::from_string(
+        "a {\n  color: $a;\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .expect_err("did not fail");
+
+    assert_eq!(err.kind(), ErrorKind::Stylesheet);
+    assert_eq!(err.message(), "Undefined variable.");
+    assert_eq!(err.file(), Some("stdin"));
+    assert_eq!(err.line(), Some(2));
+    assert_eq!(err.column(), Some(10));
+    assert_eq!(err.span(), Some(13..15));
+}
+
+#[test]
+fn error_column_counts_characters_and_span_counts_bytes() {
+    let input = "a {\n  content: \"\u{e9}\" $a;\n}\n";
+    let err = grass::from_string(input.to_string(), &grass::Options::default())
+        .expect_err("did not fail");
+
+    assert_eq!(err.column(), Some(16));
+    assert_eq!(&input[err.span().unwrap()], "$a");
+}
+
+#[test]
+fn error_location_in_imported_file() {
+    let input = "@import \"error_location_in_imported_file\";";
+    tempfile!(
+        "error_location_in_imported_file.scss",
+        "a {\n  color: red;\n  @error \"bad\";\n}\n"
+    );
+    let err = grass::from_string(input.to_string(), &grass::Options::default())
+        .expect_err("did not fail");
+
+    assert_eq!(err.message(), "\"bad\"");
+    assert_eq!(err.file(), Some("error_location_in_imported_file.scss"));
+    assert_eq!(err.line(), Some(3));
+    assert_eq!(err.column(), Some(3));
+    assert_eq!(err.span(), Some(20..32));
+}
+
+#[test]
+fn io_error_has_no_location() {
+    let err = grass::from_path("does-not-exist.scss", &grass::Options::default())
+        .expect_err("did not fail");
+
+    assert_eq!(err.kind(), ErrorKind::Io);
+    assert_eq!(err.file(), None);
+    assert_eq!(err.line(), None);
+    assert_eq!(err.column(), None);
+    assert_eq!(err.span(), None);
+}
+
+#[test]
+fn colored_error_highlights_span() {
+    let err = grass::from_string(
+        "a {\n  color: $a;\n}\n".to_string(),
+        &grass::Options::default()
+            .unicode_error_messages(false)
+            .color_error_messages(true),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert_eq!(
+        err,
+        "Error: Undefined variable.\n\
+         \x1b[34m  |\x1b[0m\n\
+         \x1b[34m1 |\x1b[0m a {\n\
+         \x1b[34m2 |\x1b[0m   color: \x1b[31m$a\x1b[0m;\n\
+         \x1b[34m  |\x1b[0m          \x1b[31m^^\x1b[0m\n\
+         \x1b[34m3 |\x1b[0m }\n\
+         \x1b[34m  |\x1b[0m\n\
+         ./stdin:2:10\n"
+    );
+}
+
+#[test]
+fn colored_error_highlights_multiline_span() {
+    let err = grass::from_string(
+        "a {\n  @error\n    \"foo\";\n}\n".to_string(),
+        &grass::Options::default()
+            .unicode_error_messages(false)
+            .color_error_messages(true),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert_eq!(
+        err,
+        "Error: \"foo\"\n\
+         \x1b[34m  |\x1b[0m\n\
+         \x1b[34m1 |\x1b[0m   a {\n\
+         \x1b[34m2 |\x1b[0m \x1b[31m,\x1b[0m   \x1b[31m@error\x1b[0m\n\
+         \x1b[34m3 |\x1b[0m \x1b[31m|\x1b[0m \x1b[31m    \"foo\"\x1b[0m;\n\
+         \x1b[34m  |\x1b[0m \x1b[31m'---------^\x1b[0m\n\
+         \x1b[34m4 |\x1b[0m   }\n\
+         \x1b[34m  |\x1b[0m\n\
+         ./stdin:2:3\n"
+    );
+}
+
+#[test]
+fn colored_error_highlights_non_ascii_span() {
+    let err = grass::from_string(
+        "a {\n  content: \"\u{e9}\" $a;\n}\n".to_string(),
+        &grass::Options::default()
+            .unicode_error_messages(false)
+            .color_error_messages(true),
+    )
+    .expect_err("did not fail")
+    .to_string();
+
+    assert!(
+        err.contains("content: \"\u{e9}\" \x1b[31m$a\x1b[0m;\n"),
+        "{}",
+        err
+    );
+}
@@ -1393,7 +1393,6 @@ test!(
     "@media screen {\n  @unknown {\n    .foo, .bar {\n      a: b;\n    }\n  }\n}\n"
 );
 test!(
-    #[ignore = "media queries are not yet parsed correctly"]
     extend_within_separate_media_queries,
     "@media screen {.foo {a: b}}
     @media screen {.bar {@extend .foo}}
@@ -1401,7 +1400,6 @@ test!(
     "@media screen {\n  .foo, .bar {\n    a: b;\n  }\n}\n"
 );
 test!(
-    #[ignore = "media queries are not yet parsed correctly"]
     extend_within_separate_unknown_at_rules,
     "@unknown {.foo {a: b}}
     @unknown {.bar {@extend .foo}}
@@ -1409,7 +1407,6 @@ test!(
     "@unknown {\n  .foo, .bar {\n    a: b;\n  }\n}\n@unknown {}\n"
 );
 test!(
-    #[ignore = "media queries are not yet parsed correctly"]
     extend_within_separate_nested_at_rules,
     "@media screen {@flooblehoof {.foo {a: b}}}
      @media screen {@flooblehoof {.bar {@extend .foo}}}",
@@ -1435,6 +1432,66 @@ test!(
     ",
     "a.bar {\n  a: b;\n}\n"
 );
+test!(
+    mandatory_extend_succeeds_when_extension_fails,
+    "a.bar {a: b}
+    b.foo {@extend .bar}
+    ",
+    "a.bar {\n  a: b;\n}\n"
+);
+test!(
+    mandatory_extend_succeeds_when_extendee_comes_later,
+    ".foo {@extend .bar}
+    .bar {a: b}
+    ",
+    ".bar, .foo {\n  a: b;\n}\n"
+);
+test!(
+    mandatory_and_optional_extend_of_same_target,
+    ".foo {@extend .bar !optional}
+    .baz {@extend .bar}
+    .bar {a: b}
+    ",
+    ".bar, .foo, .baz {\n  a: b;\n}\n"
+);
+error!(
+    mandatory_extend_fails_when_extendee_doesnt_exist,
+    ".foo {@extend .bar}", "Error: The target selector was not found."
+);
+error!(
+    mandatory_extend_fails_when_extendee_is_only_extended,
+    ".foo {@extend %bar}
+    .baz {@extend .foo}
+    ",
+    "Error: The target selector was not found."
+);
+error!(
+    mandatory_extend_merged_with_mandatory_extend_fails,
+    ".foo {@extend .bar}
+    .foo {@extend .bar}
+    ",
+    "Error: The target selector was not found."
+);
+error!(
+    mandatory_extend_fails_when_only_optional_extend_is_satisfied,
+    ".foo {@extend .bar !optional; @extend .baz}
+    .bar {a: b}
+    ",
+    "Error: The target selector was not found."
+);
+#[test]
+fn mandatory_extend_error_suggests_optional() {
+    let input = ".foo {@extend %bar}";
+    match grass::from_string(input.to_owned(), &grass::Options::default()) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(
+            e.to_string()
+                .starts_with("Error: The target selector was not found.\nUse \"@extend %bar !optional\" to avoid this error.\n"),
+            "{}",
+            e
+        ),
+    }
+}
 test!(
     psuedo_element_superselector_1,
     "%x#bar {a: b} // Add an id to make the results have high specificity
@@ -1561,7 +1618,6 @@ test!(
     ".parent1 .child {\n  a: b;\n}\n"
 );
 test!(
-    #[ignore = "media queries are not yet parsed correctly"]
     extend_inside_double_nested_media,
     "@media all {
         @media (orientation: landscape) {
@@ -1741,7 +1797,6 @@ test!(
     ":not(.c):not(.a):not(.d):not(.b) {\n  a: b;\n}\n"
 );
 test!(
-    #[ignore = "media queries are not yet parsed correctly"]
     does_not_move_page_block_in_media,
     "@media screen {
         a { x:y; }
@@ -1763,7 +1818,6 @@ test!(
     ".foo {\n  escape: none;\n}\n\n\\.foo, .bar {\n  escape: slash dot;\n}\n\n\\.foo, .bar {\n  escape: hex;\n}\n"
 );
 test!(
-    #[ignore = "Rc<RefCell<Selector>>"]
     extend_extender,
     "// For implementations like Dart Sass that process extensions as they occur,
     // extending rules that contain their own extends needs special handling.
@@ -1773,6 +1827,40 @@ test!(
     ",
     ".a, .b, .c {\n  x: y;\n}\n"
 );
+test!(
+    extend_extender_three_levels,
+    ".b {@extend .a}
+    .c {@extend .b}
+    .d {@extend .c}
+    .a {x: y}
+    ",
+    ".a, .b, .c, .d {\n  x: y;\n}\n"
+);
+test!(
+    extend_compound_extender,
+    ".b.d {@extend .a}
+    .c {@extend .b}
+    .a {x: y}
+    ",
+    ".a, .b.d, .d.c {\n  x: y;\n}\n"
+);
+test!(
+    extend_extender_unifies_with_target,
+    ".b {@extend .a}
+    .c {@extend .b}
+    .a.e {x: y}
+    ",
+    ".a.e, .e.b, .e.c {\n  x: y;\n}\n"
+);
+test!(
+    extend_placeholder_extender,
+    "%b {@extend %a}
+    .btn:hover {@extend %b}
+    .nav .link {@extend %b}
+    %a {x: y}
+    ",
+    ".btn:hover, .nav .link {\n  x: y;\n}\n"
+);
 test!(
     extend_result_of_extend,
     "// The result of :not(.c) being extended should itself be extendable.
@@ -1936,6 +2024,61 @@ error!(
     }",
     "Error: Parent selectors aren't allowed here."
 );
+test!(
+    libsass_compat_extends_compound_selector,
+    ".a.b.d {x: y}
+    .a.d {x: z}
+    .c {@extend .a.b}
+    ",
+    ".a.b.d, .d.c {\n  x: y;\n}\n\n.a.d {\n  x: z;\n}\n",
+    grass::Options::default().libsass_compat(true)
+);
+test!(
+    libsass_compat_extends_compound_selector_defined_later,
+    ".c {@extend .a.b}
+    .a.b {x: y}
+    ",
+    ".a.b, .c {\n  x: y;\n}\n",
+    grass::Options::default().libsass_compat(true)
+);
+test!(
+    libsass_compat_extends_compound_placeholder,
+    "%a.b {x: y}
+    .c {@extend %a.b}
+    ",
+    ".c {\n  x: y;\n}\n",
+    grass::Options::default().libsass_compat(true)
+);
+test!(
+    media_containing_only_placeholder_is_omitted,
+    "@media screen {%foo {a: b}}
+    ",
+    ""
+);
+test!(
+    compressed_media_containing_only_extender_is_omitted,
+    "@media screen {.foo {a: b}}
+    @media screen {.bar {@extend .foo}}
+    ",
+    "@media screen{.foo,.bar{a:b}}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
 
 // todo: extend_loop (massive test)
 // todo: extend tests in folders
+
+#[test]
+fn extends_every_rule_with_the_same_selector() {
+    let mut input = String::new();
+    let mut expected = String::new();
+    for i in 0..100 {
+        input.push_str(&format!("a {{ b: {}; }}\n", i));
+        expected.push_str(&format!("a, c {{\n  b: {};\n}}\n\n", i));
+    }
+    input.push_str("c { @extend a; }\n");
+    expected.pop();
+    assert_eq!(
+        expected,
+        grass::from_string(input, &grass::Options::default()).unwrap()
+    );
+}
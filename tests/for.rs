@@ -214,3 +214,42 @@ error!(
     to_and_from_i32_min,
     "@for $i from -2147483648 through -2147483648 {}", "Error: -2147483648 is not an int."
 );
+test!(
+    through_descending,
+    "@for $i from 3 through 1 {\n  a {\n    color: $i;\n  }\n}\n",
+    "a {\n  color: 3;\n}\n\na {\n  color: 2;\n}\n\na {\n  color: 1;\n}\n"
+);
+test!(
+    to_descending_excludes_end,
+    "@for $i from 3 to 1 {\n  a {\n    color: $i;\n  }\n}\n",
+    "a {\n  color: 3;\n}\n\na {\n  color: 2;\n}\n"
+);
+test!(
+    keeps_units_of_from,
+    "a {\n  @for $i from 1px through 3 {\n    width: $i;\n  }\n}\n",
+    "a {\n  width: 1px;\n  width: 2px;\n  width: 3px;\n}\n"
+);
+test!(
+    to_is_converted_to_units_of_from,
+    "a {\n  @for $i from 1in through 192px {\n    width: $i;\n  }\n}\n",
+    "a {\n  width: 1in;\n  width: 2in;\n}\n"
+);
+error!(
+    incompatible_units,
+    "@for $i from 1px through 3em {}", "Error: Incompatible units em and px."
+);
+test!(
+    loop_variable_does_not_leak,
+    "$i: outer;\n\na {\n  @for $i from 1 through 2 {\n    color: $i;\n  }\n  color: $i;\n}\n",
+    "a {\n  color: 1;\n  color: 2;\n  color: outer;\n}\n"
+);
+test!(
+    inside_media,
+    "@media screen {\n  @for $i from 1 through 2 {\n    .a-#{$i} {\n      width: $i;\n    }\n  }\n}\n",
+    "@media screen {\n  .a-1 {\n    width: 1;\n  }\n\n  .a-2 {\n    width: 2;\n  }\n}\n"
+);
+test!(
+    return_from_inside_for_in_function,
+    "@function foo($n) {\n  @for $i from 1 through 10 {\n    @if $i == $n {\n      @return $i * 10;\n    }\n  }\n  @return none;\n}\n\na {\n  color: foo(3);\n  color: foo(20);\n}\n",
+    "a {\n  color: 30;\n  color: none;\n}\n"
+);
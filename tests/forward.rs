@@ -0,0 +1,313 @@
+use std::io::Write;
+
+#[macro_use]
+mod macros;
+
+error!(
+    after_style,
+    "a {}
+    @forward \"foo\";
+    ",
+    "Error: @forward rules must be written before any other rules."
+);
+error!(
+    prefix_without_asterisk,
+    "@forward \"sass:math\" as math-;", "Error: expected \"*\"."
+);
+
+#[test]
+fn forward_variable() {
+    let input = "@use \"forward_variable_lib\" as lib;\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "forward_variable_lib.scss",
+        "@forward \"forward_variable_vars\";"
+    );
+    tempfile!("forward_variable_vars.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_mixin_and_function() {
+    let input = "@use \"forward_mixin_and_function_lib\" as lib;\na {\n @include lib.b;\n width: lib.c();\n}";
+    tempfile!(
+        "forward_mixin_and_function_lib.scss",
+        "@forward \"forward_mixin_and_function_members\";"
+    );
+    tempfile!(
+        "forward_mixin_and_function_members.scss",
+        "@mixin b { color: red; }\n@function c() { @return 1px; }"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  width: 1px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_through_several_modules() {
+    let input = "@use \"forward_through_several_modules_a\" as lib;\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "forward_through_several_modules_a.scss",
+        "@forward \"forward_through_several_modules_b\";"
+    );
+    tempfile!(
+        "forward_through_several_modules_b.scss",
+        "@forward \"forward_through_several_modules_c\";"
+    );
+    tempfile!("forward_through_several_modules_c.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forwarded_members_not_visible_in_forwarding_module() {
+    let input = "@use \"forwarded_members_not_visible_lib\";";
+    tempfile!(
+        "forwarded_members_not_visible_lib.scss",
+        "@forward \"forwarded_members_not_visible_vars\";\na { color: $a; }"
+    );
+    tempfile!("forwarded_members_not_visible_vars.scss", "$a: red;");
+    assert_err!("Error: Undefined variable.", input);
+}
+
+#[test]
+fn forward_and_use_same_module() {
+    let input = "@use \"forward_and_use_same_module_lib\" as lib;\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "forward_and_use_same_module_lib.scss",
+        "@forward \"forward_and_use_same_module_vars\";\n@use \"forward_and_use_same_module_vars\" as vars;\nb { color: vars.$a; }"
+    );
+    tempfile!(
+        "forward_and_use_same_module_vars.scss",
+        "$a: red;\nc { d: e; }"
+    );
+    assert_eq!(
+        "c {\n  d: e;\n}\n\nb {\n  color: red;\n}\n\na {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_show() {
+    let input = "@use \"forward_show_lib\" as lib;\na {\n color: lib.$a;\n @include lib.b;\n}";
+    tempfile!(
+        "forward_show_lib.scss",
+        "@forward \"forward_show_members\" show $a, b;"
+    );
+    tempfile!(
+        "forward_show_members.scss",
+        "$a: red;\n$c: blue;\n@mixin b { width: 1px; }"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  width: 1px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_show_excludes_other_members() {
+    let input = "@use \"forward_show_excludes_lib\" as lib;\na {\n color: lib.$c;\n}";
+    tempfile!(
+        "forward_show_excludes_lib.scss",
+        "@forward \"forward_show_excludes_members\" show $a;"
+    );
+    tempfile!("forward_show_excludes_members.scss", "$a: red;\n$c: blue;");
+    assert_err!("Error: Undefined variable.", input);
+}
+
+#[test]
+fn forward_hide() {
+    let input = "@use \"forward_hide_lib\" as lib;\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "forward_hide_lib.scss",
+        "@forward \"forward_hide_members\" hide $b, c;"
+    );
+    tempfile!(
+        "forward_hide_members.scss",
+        "$a: red;\n$b: blue;\n@function c() { @return 1; }"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_hide_excludes_members() {
+    let input = "@use \"forward_hide_excludes_lib\" as lib;\na {\n @include lib.b;\n}";
+    tempfile!(
+        "forward_hide_excludes_lib.scss",
+        "@forward \"forward_hide_excludes_members\" hide b;"
+    );
+    tempfile!(
+        "forward_hide_excludes_members.scss",
+        "@mixin b { color: red; }"
+    );
+    assert_err!("Error: Undefined mixin.", input);
+}
+
+#[test]
+fn forward_prefix() {
+    let input = "@use \"forward_prefix_lib\" as lib;\na {\n color: lib.$btn-a;\n @include lib.btn-b;\n width: lib.btn-c();\n}";
+    tempfile!(
+        "forward_prefix_lib.scss",
+        "@forward \"forward_prefix_members\" as btn-*;"
+    );
+    tempfile!(
+        "forward_prefix_members.scss",
+        "$a: red;\n@mixin b { height: 2px; }\n@function c() { @return 1px; }"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  height: 2px;\n  width: 1px;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_prefix_with_show() {
+    let input = "@use \"forward_prefix_with_show_lib\" as lib;\na {\n color: lib.$btn-a;\n}";
+    tempfile!(
+        "forward_prefix_with_show_lib.scss",
+        "@forward \"forward_prefix_with_show_members\" as btn-* show $btn-a;"
+    );
+    tempfile!(
+        "forward_prefix_with_show_members.scss",
+        "$a: red;\n$b: blue;"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_private_members_not_forwarded() {
+    let input = "@use \"forward_private_lib\" as lib;\n@use \"sass:meta\";\na {\n color: meta.inspect(meta.module-variables(lib));\n}";
+    tempfile!(
+        "forward_private_lib.scss",
+        "@forward \"forward_private_vars\";"
+    );
+    tempfile!(
+        "forward_private_vars.scss",
+        "$a: red;\n$-b: blue;\n$_c: green;"
+    );
+    assert_eq!(
+        "a {\n  color: (\"a\": red);\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forward_builtin_module() {
+    let input = "@use \"forward_builtin_module_lib\" as lib;\na {\n color: lib.math-div(1, 2);\n}";
+    tempfile!(
+        "forward_builtin_module_lib.scss",
+        "@forward \"sass:math\" as math-*;"
+    );
+    assert_eq!(
+        "a {\n  color: 0.5;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn forwarded_module_css_emitted_once() {
+    let input = "@use \"forwarded_module_css_once_a\";\n@use \"forwarded_module_css_once_b\";";
+    tempfile!(
+        "forwarded_module_css_once_a.scss",
+        "@forward \"forwarded_module_css_once_shared\";"
+    );
+    tempfile!(
+        "forwarded_module_css_once_b.scss",
+        "@forward \"forwarded_module_css_once_shared\";"
+    );
+    tempfile!("forwarded_module_css_once_shared.scss", "a { b: c; }");
+    assert_eq!(
+        "a {\n  b: c;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_configures_forwarded_module() {
+    let input = "@use \"use_with_configures_forwarded_lib\" as lib with ($a: red);\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "use_with_configures_forwarded_lib.scss",
+        "@forward \"use_with_configures_forwarded_vars\";"
+    );
+    tempfile!(
+        "use_with_configures_forwarded_vars.scss",
+        "$a: green !default;"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_configures_forwarded_module_with_prefix() {
+    let input = "@use \"use_with_configures_prefixed_lib\" as lib with ($btn-a: red);\na {\n color: lib.$btn-a;\n}";
+    tempfile!(
+        "use_with_configures_prefixed_lib.scss",
+        "@forward \"use_with_configures_prefixed_vars\" as btn-*;"
+    );
+    tempfile!(
+        "use_with_configures_prefixed_vars.scss",
+        "$a: green !default;"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_configures_forwarding_and_forwarded_modules() {
+    let input = "@use \"use_with_configures_both_lib\" as lib with ($a: red, $b: blue);\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "use_with_configures_both_lib.scss",
+        "@forward \"use_with_configures_both_vars\";\n$b: green !default;\nb { color: $b; }"
+    );
+    tempfile!("use_with_configures_both_vars.scss", "$a: green !default;");
+    assert_eq!(
+        "b {\n  color: blue;\n}\n\na {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_variable_not_forwarded() {
+    let input = "@use \"use_with_variable_not_forwarded_lib\" with ($a: red);";
+    tempfile!(
+        "use_with_variable_not_forwarded_lib.scss",
+        "@forward \"use_with_variable_not_forwarded_vars\" hide $a;"
+    );
+    tempfile!(
+        "use_with_variable_not_forwarded_vars.scss",
+        "$a: green !default;"
+    );
+    assert_err!(
+        "Error: This variable was not declared with !default in the @used module.",
+        input
+    );
+}
+
+#[test]
+fn import_file_with_forward() {
+    let input = "@import \"import_file_with_forward_lib\";\na {\n color: $a;\n}";
+    tempfile!(
+        "import_file_with_forward_lib.scss",
+        "@forward \"import_file_with_forward_vars\";"
+    );
+    tempfile!("import_file_with_forward_vars.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
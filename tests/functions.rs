@@ -361,3 +361,86 @@ test!(
     }",
     "a {\n  color: red;\n}\n"
 );
+test!(
+    user_function_underscore_declaration_hyphen_call,
+    "@function foo_bar($a_b) {\n  @return $a-b;\n}\na {\n  color: foo-bar($a-b: red);\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    default_args_may_refer_to_earlier_args,
+    "@function foo($a, $b: $a * 2) {\n  @return $a $b;\n}\n\na {\n  color: foo(3);\n  color: foo(3, 4);\n}\n",
+    "a {\n  color: 3 6;\n  color: 3 4;\n}\n"
+);
+test!(
+    default_and_rest_args,
+    "@function foo($a, $b: 2, $rest...) {\n  @return $a + $b + length($rest);\n}\n\na {\n  color: foo(1);\n  color: foo(1, 3, x, y);\n  color: foo($a: 5);\n}\n",
+    "a {\n  color: 3;\n  color: 6;\n  color: 7;\n}\n"
+);
+test!(
+    return_from_nested_control_flow,
+    "@function foo() {\n  @if true {\n    @each $i in 1 2 {\n      @while true {\n        @return $i;\n      }\n    }\n  }\n  @return none;\n}\n\na {\n  color: foo();\n}\n",
+    "a {\n  color: 1;\n}\n"
+);
+test!(
+    return_from_for_stops_loop,
+    "@function foo() {\n  $a: 0;\n  @for $i from 1 through 10 {\n    $a: $i;\n    @if $i == 3 {\n      @return $a;\n    }\n  }\n  @return none;\n}\n\na {\n  color: foo();\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+error!(
+    finishes_without_return,
+    "@function foo() {\n  $a: 1;\n}\n\na {\n  color: foo();\n}\n",
+    "Error: Function finished without @return."
+);
+error!(
+    finishes_without_return_when_no_branch_taken,
+    "@function foo($a) {\n  @if $a {\n    @return 1;\n  }\n}\n\na {\n  color: foo(false);\n}\n",
+    "Error: Function finished without @return."
+);
+error!(
+    finishes_without_return_no_whitespace_after_colon,
+    "@function foo() {}\n\na {\n  color:foo();\n}\n", "Error: Function finished without @return."
+);
+error!(
+    error_in_function_no_whitespace_after_colon,
+    "@function foo() {\n  @error \"oops\";\n}\n\na {\n  color:foo();\n}\n", "Error: \"oops\""
+);
+error!(
+    duplicate_argument,
+    "@function foo($a, $b, $a) {\n  @return 1;\n}\n", "Error: Duplicate argument."
+);
+error!(
+    duplicate_argument_hyphen_and_underscore,
+    "@function foo($a-b, $a_b) {\n  @return 1;\n}\n", "Error: Duplicate argument."
+);
+test!(
+    function_decl_in_ruleset_sees_scope_of_declaration_not_call,
+    "a {
+        $x: outer;
+
+        @function foo() {
+            @return $x;
+        }
+
+        @mixin bar($x) {
+            color: foo();
+        }
+
+        @include bar(inner);
+    }",
+    "a {\n  color: outer;\n}\n"
+);
+test!(
+    function_decl_in_ruleset_not_visible_to_global_function,
+    "@function call-foo() {\n  @return foo();\n}\na {\n  @function foo() {\n    @return 3;\n  }\n  color: call-foo();\n}\n",
+    "a {\n  color: foo();\n}\n"
+);
+test!(
+    function_decl_in_ruleset_shadows_global_function,
+    "@function foo() {\n  @return global;\n}\na {\n  @function foo() {\n    @return local;\n  }\n  color: foo();\n}\nb {\n  color: foo();\n}\n",
+    "a {\n  color: local;\n}\n\nb {\n  color: global;\n}\n"
+);
+test!(
+    recursive_function_decl_in_ruleset,
+    "a {\n  @function sum($n) {\n    @if $n == 0 {\n      @return 0;\n    }\n    @return $n + sum($n - 1);\n  }\n  color: sum(3);\n}\n",
+    "a {\n  color: 6;\n}\n"
+);
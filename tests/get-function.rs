@@ -124,3 +124,59 @@ test!(
     "@use 'sass:math';\na {\n  color: call(get-function(cos, $module: math), 2);\n}\n",
     "a {\n  color: -0.4161468365;\n}\n"
 );
+test!(
+    get_function_css,
+    "a {\n  color: call(get-function(foo, $css: true), 1px, red);\n}\n",
+    "a {\n  color: foo(1px, red);\n}\n"
+);
+test!(
+    get_function_css_shadows_builtin,
+    "a {\n  color: call(get-function(lighten, $css: true), red, 5);\n}\n",
+    "a {\n  color: lighten(red, 5);\n}\n"
+);
+test!(
+    get_function_css_keeps_underscores,
+    "a {\n  color: call(get-function(\"a_b\", $css: true));\n}\n",
+    "a {\n  color: a_b();\n}\n"
+);
+test!(
+    inspect_css_function,
+    "a {\n  color: inspect(get-function(foo, $css: true));\n}\n",
+    "a {\n  color: get-function(\"foo\");\n}\n"
+);
+test!(
+    type_of_css_function,
+    "a {\n  color: type-of(get-function(foo, $css: true));\n}\n",
+    "a {\n  color: function;\n}\n"
+);
+error!(
+    get_function_css_keyword_args,
+    "a {\n  color: call(get-function(foo, $css: true), $a: 1);\n}\n",
+    "Error: Plain CSS functions don't support keyword arguments."
+);
+error!(
+    get_function_css_and_module,
+    "@use 'sass:math';\na {\n  color: get-function(cos, $css: true, $module: math);\n}\n",
+    "Error: $css and $module may not both be passed at once."
+);
+test!(
+    call_string_builtin,
+    "a {\n  color: call(lighten, red, 5);\n}\n",
+    "a {\n  color: #ff1a1a;\n}\n"
+);
+test!(
+    call_string_user_defined,
+    "@function foo($a) {\n  @return $a * 2;\n}\n\na {\n  color: call(\"foo\", 2px);\n}\n",
+    "a {\n  color: 4px;\n}\n"
+);
+test!(
+    call_string_unknown_is_css_function,
+    "a {\n  color: call(foo, 1px, 2px);\n}\n",
+    "a {\n  color: foo(1px, 2px);\n}\n"
+);
+error!(
+    call_string_removed_in_dart_sass_2,
+    "a {\n  color: call(lighten, red, 5);\n}\n",
+    "Error: $function: lighten is not a function reference.",
+    grass::Options::default().compat(grass::Compat::DartSass(grass::Version::new(2, 0, 0)))
+);
@@ -260,3 +260,47 @@ test!(
     }",
     "/**/\n"
 );
+test!(
+    elseif_is_else_if,
+    "@if false {\n  a {\n    color: red;\n  }\n} @elseif true {\n  a {\n    color: blue;\n  }\n} @else {\n  a {\n    color: green;\n  }\n}\n",
+    "a {\n  color: blue;\n}\n"
+);
+test!(
+    elseif_after_true_branch,
+    "@if true {\n  a {\n    color: red;\n  }\n} @elseif true {\n  a {\n    color: blue;\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+error!(
+    elseif_deprecated,
+    "@if false {} @elseif true {}",
+    "Error: @elseif is deprecated and will not be supported in future Sass versions.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 3, 2)))
+        .fatal_warnings(true)
+);
+error!(
+    elseif_removed_in_dart_sass_2,
+    "@if false {} @elseif true {}",
+    "Error: @elseif isn't supported. Use @else if instead.",
+    grass::Options::default().compat(grass::Compat::DartSass(grass::Version::new(2, 0, 0)))
+);
+test!(
+    if_else_if_else_in_mixin,
+    "@mixin foo($n) {\n  @if $n == 1 {\n    color: one;\n  } @else if $n == 2 {\n    color: two;\n  } @else {\n    color: other;\n  }\n}\n\na {\n  @include foo(1);\n  @include foo(2);\n  @include foo(3);\n}\n",
+    "a {\n  color: one;\n  color: two;\n  color: other;\n}\n"
+);
+test!(
+    if_inside_media,
+    "@media screen {\n  @if true {\n    a {\n      color: red;\n    }\n  } @else {\n    b {\n      color: red;\n    }\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    assignment_in_if_inside_style_rule_does_not_change_global,
+    "$a: 1;\n\nb {\n  @if true {\n    $a: 2;\n  }\n  color: $a;\n}\n",
+    "b {\n  color: 1;\n}\n"
+);
+test!(
+    assignment_in_if_at_root_changes_global,
+    "$a: 1;\n\n@if true {\n  $a: 2;\n}\n\nb {\n  color: $a;\n}\n",
+    "b {\n  color: 2;\n}\n"
+);
@@ -0,0 +1,183 @@
+use std::{collections::HashMap, io};
+
+use grass::{Importer, Options};
+use tempfile::tempdir;
+
+/// Loads stylesheets from a map of names to contents, resolving relative URLs
+/// against the directory of the importing stylesheet
+#[derive(Debug)]
+struct Database(HashMap<&'static str, &'static str>);
+
+impl Database {
+    fn new(files: &[(&'static str, &'static str)]) -> Self {
+        Self(files.iter().copied().collect())
+    }
+}
+
+impl Importer for Database {
+    fn canonicalize(&self, url: &str, base: Option<&str>) -> Option<String> {
+        let name = match (url.strip_prefix("db:"), base) {
+            (Some(name), _) => name.to_owned(),
+            (None, Some(base)) => match base.trim_start_matches("db:").rsplit_once('/') {
+                Some((dir, _)) => format!("{}/{}", dir, url),
+                None => url.to_owned(),
+            },
+            (None, None) => return None,
+        };
+
+        if self.0.contains_key(name.as_str()) {
+            Some(format!("db:{}", name))
+        } else {
+            None
+        }
+    }
+
+    fn load(&self, canonical_url: &str) -> io::Result<String> {
+        match self.0.get(canonical_url.trim_start_matches("db:")) {
+            Some(contents) => Ok((*contents).to_owned()),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "not in database")),
+        }
+    }
+}
+
+/// Resolves every URL, but fails to load any of them
+#[derive(Debug)]
+struct Unavailable;
+
+impl Importer for Unavailable {
+    fn canonicalize(&self, url: &str, _base: Option<&str>) -> Option<String> {
+        Some(format!("unavailable:{}", url))
+    }
+
+    fn load(&self, _canonical_url: &str) -> io::Result<String> {
+        Err(io::Error::new(io::ErrorKind::Other, "database is offline"))
+    }
+}
+
+fn compile(input: &str, options: &Options) -> grass::Result<String> {
+    grass::from_string(input.to_owned(), options)
+}
+
+#[test]
+fn imports_from_importer() {
+    let db = Database::new(&[("colors", "$primary: red;")]);
+    assert_eq!(
+        compile(
+            "@import \"db:colors\";\na { color: $primary; }",
+            &Options::default().importer(&db)
+        )
+        .unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn uses_module_from_importer() {
+    let db = Database::new(&[("theme/colors", "$primary: red;\nb { c: d; }")]);
+    assert_eq!(
+        compile(
+            "@use \"db:theme/colors\";\na { color: colors.$primary; }",
+            &Options::default().importer(&db)
+        )
+        .unwrap(),
+        "b {\n  c: d;\n}\n\na {\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn loads_css_from_importer() {
+    let db = Database::new(&[("mixins", "b { c: d; }")]);
+    assert_eq!(
+        compile(
+            "@use \"sass:meta\";\na { @include meta.load-css(\"db:mixins\"); }",
+            &Options::default().importer(&db)
+        )
+        .unwrap(),
+        "a b {\n  c: d;\n}\n"
+    );
+}
+
+#[test]
+fn resolves_relative_urls_with_same_importer() {
+    let db = Database::new(&[
+        ("theme/index", "@import \"colors\";\na { color: $primary; }"),
+        ("theme/colors", "$primary: red;"),
+    ]);
+    assert_eq!(
+        compile(
+            "@import \"db:theme/index\";",
+            &Options::default().importer(&db)
+        )
+        .unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn module_loaded_through_different_urls_is_evaluated_once() {
+    let db = Database::new(&[
+        ("theme/index", "@use \"colors\";"),
+        ("theme/colors", "b { c: d; }"),
+    ]);
+    assert_eq!(
+        compile(
+            "@use \"db:theme/index\";\n@use \"db:theme/colors\";",
+            &Options::default().importer(&db)
+        )
+        .unwrap(),
+        "b {\n  c: d;\n}\n"
+    );
+}
+
+#[test]
+fn importers_are_tried_in_order() {
+    let first = Database::new(&[("a", "a { from: first; }")]);
+    let second = Database::new(&[("a", "a { from: second; }"), ("b", "b { from: second; }")]);
+    assert_eq!(
+        compile(
+            "@import \"db:a\", \"db:b\";",
+            &Options::default().importer(&first).importer(&second)
+        )
+        .unwrap(),
+        "a {\n  from: first;\n}\n\nb {\n  from: second;\n}\n"
+    );
+}
+
+#[test]
+fn falls_back_to_load_paths() {
+    let dir = tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("_importer_fallback.scss"),
+        "a { color: red; }",
+    )
+    .unwrap();
+
+    let db = Database::new(&[("theme", "@import \"importer_fallback\";")]);
+    assert_eq!(
+        compile(
+            "@import \"db:theme\";",
+            &Options::default().importer(&db).load_path(dir.path())
+        )
+        .unwrap(),
+        "a {\n  color: red;\n}\n"
+    );
+}
+
+#[test]
+fn load_error_is_reported() {
+    match compile("@import \"a\";", &Options::default().importer(&Unavailable)) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert_eq!(e.to_string(), "Error: database is offline\n"),
+    }
+}
+
+#[test]
+fn unresolved_url_is_not_found() {
+    let db = Database::new(&[]);
+    match compile("@import \"db:missing\";", &Options::default().importer(&db)) {
+        Ok(..) => panic!("did not fail"),
+        Err(e) => assert!(e
+            .to_string()
+            .starts_with("Error: Can't find stylesheet to import.")),
+    }
+}
@@ -185,6 +185,85 @@ fn import_from_path_dir_not_relative() {
     );
 }
 
+#[test]
+fn import_depth_within_limit() {
+    let input = "@import \"import_depth_within_limit_a\";\na {\n color: $a;\n}";
+    tempfile!(
+        "import_depth_within_limit_a.scss",
+        "@import \"import_depth_within_limit_b\";"
+    );
+    tempfile!("import_depth_within_limit_b.scss", "$a: red;");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().max_import_depth(2)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn import_depth_exceeded() {
+    let input = "@import \"import_depth_exceeded_a\";";
+    tempfile!(
+        "import_depth_exceeded_a.scss",
+        "@import \"import_depth_exceeded_b\";"
+    );
+    tempfile!("import_depth_exceeded_b.scss", "$a: red;");
+    match grass::from_string(
+        input.to_string(),
+        &grass::Options::default().max_import_depth(1),
+    ) {
+        Err(e) => {
+            let message = e.to_string();
+            assert!(
+                message.starts_with("Error: Maximum import depth of 1 exceeded.\n"),
+                "{}",
+                message
+            );
+            assert!(
+                message.contains("import_depth_exceeded_a.scss 1:9  @import"),
+                "{}",
+                message
+            );
+        }
+        Ok(..) => panic!("did not fail"),
+    }
+}
+
+#[test]
+fn import_self_is_an_error() {
+    let input = "@import \"import_self_is_an_error\";";
+    tempfile!(
+        "import_self_is_an_error.scss",
+        "@import \"import_self_is_an_error\";"
+    );
+    assert_err!("Error: Maximum import depth of 100 exceeded.", input);
+}
+
+#[test]
+fn use_depth_exceeded() {
+    let input = "@use \"use_depth_exceeded_a\";";
+    tempfile!(
+        "use_depth_exceeded_a.scss",
+        "@use \"use_depth_exceeded_b\";"
+    );
+    tempfile!("use_depth_exceeded_b.scss", "$a: red;");
+    match grass::from_string(
+        input.to_string(),
+        &grass::Options::default().max_import_depth(1),
+    ) {
+        Err(e) => assert!(
+            e.to_string()
+                .starts_with("Error: Maximum import depth of 1 exceeded.\n"),
+            "{}",
+            e
+        ),
+        Ok(..) => panic!("did not fail"),
+    }
+}
+
 error!(
     missing_input_after_import,
     "@import", "Error: expected more input."
@@ -248,6 +327,281 @@ test!(
     "@import url(\"foo.css\");\na {\n  color: red;\n}\n"
 );
 
+#[test]
+fn libsass_compat_finds_partial_before_name_scss() {
+    let input = "@import \"libsass_compat_finds_partial\";";
+    tempfile!("libsass_compat_finds_partial.scss", "a { color: red; }");
+    tempfile!("_libsass_compat_finds_partial.scss", "a { color: blue; }");
+    assert_eq!(
+        "a {\n  color: blue;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().libsass_compat(true)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn libsass_compat_finds_exact_name_first() {
+    let input = "@import \"libsass_compat_finds_exact_name\";";
+    tempfile!("libsass_compat_finds_exact_name", "a { color: red; }");
+    tempfile!("libsass_compat_finds_exact_name.scss", "a { color: blue; }");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().libsass_compat(true)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn libsass_compat_searches_load_paths() {
+    let dir = tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("_libsass_compat_load_path.scss"),
+        "a { color: red; }",
+    )
+    .unwrap();
+    let input = "@import \"libsass_compat_load_path\";";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default()
+                .libsass_compat(true)
+                .load_path(dir.path())
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn import_with_backslash_separators() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("sub").join("nested")).unwrap();
+    std::fs::write(
+        dir.path().join("sub").join("nested").join("_colors.scss"),
+        "$a: red;",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(
+        &main,
+        "@import \"sub\\\\nested/colors\";\na {\n color: $a;\n}",
+    )
+    .unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+}
+
+#[test]
+fn use_with_backslash_separators() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("sub")).unwrap();
+    std::fs::write(dir.path().join("sub").join("_colors.scss"), "$a: red;").unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@use \"sub\\\\colors\";\na {\n color: colors.$a;\n}").unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+}
+
+#[test]
+fn load_path_with_backslash_separators() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("vendor")).unwrap();
+    std::fs::write(dir.path().join("vendor").join("_colors.scss"), "$a: red;").unwrap();
+    let load_path = dir
+        .path()
+        .join("vendor")
+        .to_str()
+        .unwrap()
+        .replace('/', "\\");
+    let input = "@import \"colors\";\na {\n color: $a;\n}";
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().load_path(std::path::Path::new(&load_path))
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn load_path_is_not_relative_to_importing_file() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::create_dir_all(dir.path().join("lib")).unwrap();
+    std::fs::write(dir.path().join("lib").join("_theme.scss"), "$a: red;").unwrap();
+    let entry = dir.path().join("src").join("main.scss");
+    std::fs::write(&entry, "@import \"theme\";\na {\n color: $a;\n}").unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_path(
+            entry.to_str().unwrap(),
+            &grass::Options::default().load_path(&dir.path().join("lib"))
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn load_path_finds_partials_and_index_files() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("forms")).unwrap();
+    std::fs::write(dir.path().join("buttons.scss"), "button { a: b; }").unwrap();
+    std::fs::write(dir.path().join("_alerts.scss"), "alert { a: b; }").unwrap();
+    std::fs::write(
+        dir.path().join("forms").join("_index.scss"),
+        "form { a: b; }",
+    )
+    .unwrap();
+    let input = "@import \"buttons\", \"alerts\", \"forms\";";
+    assert_eq!(
+        "button {\n  a: b;\n}\n\nalert {\n  a: b;\n}\n\nform {\n  a: b;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().load_path(dir.path())
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn load_paths_are_searched_in_order() {
+    let first = tempdir().unwrap();
+    let second = tempdir().unwrap();
+    std::fs::write(first.path().join("_colors.scss"), "$a: red;").unwrap();
+    std::fs::write(second.path().join("_colors.scss"), "$a: blue;").unwrap();
+    std::fs::write(second.path().join("_sizes.scss"), "$b: 1px;").unwrap();
+    let input = "@import \"colors\", \"sizes\";\na {\n color: $a;\n width: $b;\n}";
+    assert_eq!(
+        "a {\n  color: red;\n  width: 1px;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().load_paths(&[first.path(), second.path()])
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn relative_import_takes_precedence_over_load_path() {
+    let dir = tempdir().unwrap();
+    let vendor = tempdir().unwrap();
+    std::fs::write(dir.path().join("_colors.scss"), "$a: red;").unwrap();
+    std::fs::write(vendor.path().join("_colors.scss"), "$a: blue;").unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@import \"colors\";\na {\n color: $a;\n}").unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_path(
+            main.to_str().unwrap(),
+            &grass::Options::default().load_path(vendor.path())
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn error_in_import_uses_forward_slashes() {
+    let dir = tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("sub")).unwrap();
+    std::fs::write(
+        dir.path().join("sub").join("_broken.scss"),
+        "a {\n color: 1 +;\n}",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@import \"sub\\\\broken\";").unwrap();
+    match grass::from_path(
+        &main.to_str().unwrap().replace('/', "\\"),
+        &grass::Options::default(),
+    ) {
+        Err(e) => {
+            let message = e.to_string();
+            assert!(message.contains("sub/_broken.scss"), "{}", message);
+            assert!(!message.contains('\\'), "{}", message);
+        }
+        Ok(..) => panic!("did not fail"),
+    }
+}
+
+#[cfg(feature = "glob-imports")]
+#[test]
+fn glob_import_is_sorted() {
+    let dir = tempdir().unwrap();
+    let components = dir.path().join("components");
+    std::fs::create_dir_all(&components).unwrap();
+    std::fs::write(components.join("_button.scss"), "button { a: b; }").unwrap();
+    std::fs::write(components.join("_alert.scss"), "alert { a: b; }").unwrap();
+    std::fs::write(components.join("card.scss"), "card { a: b; }").unwrap();
+    std::fs::write(components.join("notes.txt"), "not a stylesheet").unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@import \"components/*\";").unwrap();
+    assert_eq!(
+        "alert {\n  a: b;\n}\n\nbutton {\n  a: b;\n}\n\ncard {\n  a: b;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+}
+
+#[cfg(feature = "glob-imports")]
+#[test]
+fn glob_import_recursive() {
+    let dir = tempdir().unwrap();
+    let nested = dir.path().join("components").join("forms");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(nested.join("_input.scss"), "input { a: b; }").unwrap();
+    std::fs::write(
+        dir.path().join("components").join("_card.scss"),
+        "card { a: b; }",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@import \"components/**/*\";").unwrap();
+    assert_eq!(
+        "card {\n  a: b;\n}\n\ninput {\n  a: b;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+}
+
+#[cfg(feature = "glob-imports")]
+#[test]
+fn glob_import_excludes_importing_file() {
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("_variables.scss"), "$a: red;").unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@import \"*\";\na {\n  color: $a;\n}\n").unwrap();
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+}
+
+#[cfg(feature = "glob-imports")]
+#[test]
+fn glob_import_without_matches() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    std::fs::write(&main, "@import \"missing/*\";").unwrap();
+    match grass::from_path(main.to_str().unwrap(), &grass::Options::default()) {
+        Err(e) => assert!(
+            e.to_string()
+                .starts_with("Error: Can't find stylesheet to import.\n"),
+            "{}",
+            e
+        ),
+        Ok(..) => panic!("did not fail"),
+    }
+}
+
 // todo: edge case tests for plain css imports moved to top
 // todo: test for calling paths, e.g. `grass b\index.scss`
 // todo: test for absolute paths (how?)
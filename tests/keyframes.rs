@@ -175,3 +175,28 @@ error!(
     keyframes_nothing_after_selector,
     "@keyframes foo { a", "Error: expected \"{\"."
 );
+test!(
+    keyframes_selectors_not_resolved_against_nested_parents,
+    "a {\n  b {\n    color: red;\n\n    @keyframes foo {\n      from {\n        top: 0;\n      }\n\n      50% {\n        top: 1px;\n      }\n\n      to {\n        top: 2px;\n      }\n    }\n  }\n}\n",
+    "a b {\n  color: red;\n}\n@keyframes foo {\n  from {\n    top: 0;\n  }\n  50% {\n    top: 1px;\n  }\n  to {\n    top: 2px;\n  }\n}\n"
+);
+test!(
+    keyframes_vendor_prefix_inside_ruleset,
+    "a {\n  @-moz-keyframes foo {\n    0%, 100% {\n      top: 0;\n    }\n  }\n}\n",
+    "@-moz-keyframes foo {\n  0%, 100% {\n    top: 0;\n  }\n}\n"
+);
+error!(
+    keyframes_vendor_prefix_invalid_selector,
+    "@-webkit-keyframes foo {\n  a {\n    color: red;\n  }\n}\n",
+    "Error: Expected \"to\" or \"from\"."
+);
+test!(
+    keyframes_inside_media_inside_ruleset,
+    "@media screen {\n  a {\n    @keyframes foo {\n      to {\n        top: 0;\n      }\n    }\n  }\n}\n",
+    "@media screen {\n  @keyframes foo {\n    to {\n      top: 0;\n    }\n  }\n}\n"
+);
+test!(
+    keyframes_selectors_from_mixin,
+    "@mixin frames {\n  from {\n    top: 0;\n  }\n\n  to {\n    top: 1px;\n  }\n}\n\na {\n  @keyframes foo {\n    @include frames;\n  }\n}\n",
+    "@keyframes foo {\n  from {\n    top: 0;\n  }\n  to {\n    top: 1px;\n  }\n}\n"
+);
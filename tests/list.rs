@@ -26,6 +26,31 @@ test!(
     "a {\n  color: nth(a b c, -2);\n}\n",
     "a {\n  color: b;\n}\n"
 );
+error!(
+    nth_zero_index,
+    "a {\n  color: nth(a b c, 0);\n}\n", "Error: $n: List index may not be 0."
+);
+error!(
+    nth_index_out_of_bounds,
+    "a {\n  color: nth(a b c, 4);\n}\n", "Error: $n: Invalid index 4 for a list with 3 elements."
+);
+error!(
+    nth_negative_index_out_of_bounds,
+    "a {\n  color: nth(a b c, -4);\n}\n", "Error: $n: Invalid index -4 for a list with 3 elements."
+);
+error!(
+    nth_non_integer_index,
+    "a {\n  color: nth(a b c, 1.5);\n}\n", "Error: $n: 1.5 is not an int."
+);
+test!(
+    set_nth_negative_index,
+    "a {\n  color: set-nth(a b c, -1, d);\n}\n",
+    "a {\n  color: a b d;\n}\n"
+);
+error!(
+    set_nth_zero_index,
+    "a {\n  color: set-nth(a b c, 0, d);\n}\n", "Error: $n: List index may not be 0."
+);
 test!(
     nth_comma_separated,
     "a {\n  color: nth((a, b, c), 3);\n}\n",
@@ -41,6 +66,26 @@ test!(
     "a {\n  color: nth((c: d, e: f, g: h), 2);\n}\n",
     "a {\n  color: e f;\n}\n"
 );
+test!(
+    nth_map_negative_index,
+    "a {\n  color: nth((c: d, e: f, g: h), -1);\n}\n",
+    "a {\n  color: g h;\n}\n"
+);
+test!(
+    length_map,
+    "a {\n  color: length((c: d, e: f, g: h));\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    nth_of_map_pair,
+    "a {\n  color: nth(nth((c: d, e: f), 2), 2);\n}\n",
+    "a {\n  color: f;\n}\n"
+);
+test!(
+    zip_map,
+    "a {\n  color: inspect(zip((c: d, e: f), 1 2));\n}\n",
+    "a {\n  color: (c d) 1, (e f) 2;\n}\n"
+);
 test!(
     list_separator_space_separated,
     "a {\n  color: list-separator(a b c);\n}\n",
@@ -153,6 +198,16 @@ test!(
     "a {\n  color: append((), a);\n}\n",
     "a {\n  color: a;\n}\n"
 );
+test!(
+    append_map,
+    "a {\n  color: append((c: d, e: f), g h);\n}\n",
+    "a {\n  color: c d, e f, g h;\n}\n"
+);
+test!(
+    append_map_separator_space,
+    "a {\n  color: inspect(append((c: d), e, space));\n}\n",
+    "a {\n  color: (c d) e;\n}\n"
+);
 test!(
     append_bracketed,
     "a {\n  color: append([], 1);\n}\n",
@@ -272,6 +327,41 @@ test!(
     "a {\n  color: [a, b];\n}\n",
     "a {\n  color: [a, b];\n}\n"
 );
+test!(
+    bracketed_grid_line_names,
+    ".grid {\n  grid-template-columns: [full-start] minmax(1em, 1fr) [main-start] minmax(0, 40em) [main-end];\n}\n",
+    ".grid {\n  grid-template-columns: [full-start] minmax(1em, 1fr) [main-start] minmax(0, 40em) [main-end];\n}\n"
+);
+test!(
+    bracketed_nested,
+    "a {\n  color: [[a] [b c]];\n}\n",
+    "a {\n  color: [[a] [b c]];\n}\n"
+);
+test!(
+    bracketed_evaluates_elements,
+    "a {\n  color: [1 + 2 #{a}-b];\n}\n",
+    "a {\n  color: [3 a-b];\n}\n"
+);
+test!(
+    bracketed_join_both_keeps_brackets,
+    "$names: [a b];\na {\n  color: join($names, [c d]);\n}\n",
+    "a {\n  color: [a b c d];\n}\n"
+);
+test!(
+    bracketed_each,
+    "a {\n  @each $name in [b c] {\n    color: $name;\n  }\n}\n",
+    "a {\n  color: b;\n  color: c;\n}\n"
+);
+test!(
+    bracketed_zip_is_unbracketed,
+    "a {\n  color: is-bracketed(zip([a b], [c d]));\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    bracketed_not_equal_to_unbracketed,
+    "a {\n  color: [a b] == (a b);\n}\n",
+    "a {\n  color: false;\n}\n"
+);
 test!(
     bracketed_as_space_list,
     "a {\n  color: [a b] c;\n}\n",
@@ -357,6 +447,26 @@ test!(
     "a {\n  color: length(zip((a, b, c)));\n}\n",
     "a {\n  color: 3;\n}\n"
 );
+test!(
+    zip_truncates_to_shortest,
+    "a {\n  color: zip(1px 2px 3px, solid dashed);\n}\n",
+    "a {\n  color: 1px solid, 2px dashed;\n}\n"
+);
+test!(
+    zip_no_args,
+    "a {\n  color: inspect(zip());\n}\n",
+    "a {\n  color: ();\n}\n"
+);
+test!(
+    index_map_not_found,
+    "a {\n  color: inspect(index((a: 1, b: 2), a 2));\n}\n",
+    "a {\n  color: null;\n}\n"
+);
+test!(
+    is_bracketed,
+    "a {\n  color: is-bracketed([a b]);\n  color: is-bracketed([]);\n  color: is-bracketed(a b);\n  color: is-bracketed(a);\n}\n",
+    "a {\n  color: true;\n  color: true;\n  color: false;\n  color: false;\n}\n"
+);
 test!(
     empty_bracketed_list,
     "a {\n  empty: [];\n}\n",
@@ -412,3 +522,97 @@ error!(
     "a {\n  color: set-nth([], 1px, a);\n}\n",
     "Error: $n: Invalid index 1px for a list with 0 elements."
 );
+test!(
+    list_slash,
+    "@use 'sass:list';\na {\n  color: list.slash(1px, 2px, 3px);\n}\n",
+    "a {\n  color: 1px / 2px / 3px;\n}\n"
+);
+test!(
+    list_slash_inspect,
+    "@use 'sass:list';\na {\n  color: inspect(list.slash(a, b));\n}\n",
+    "a {\n  color: a / b;\n}\n"
+);
+test!(
+    list_slash_separator,
+    "@use 'sass:list';\na {\n  color: list.separator(list.slash(1, 2));\n}\n",
+    "a {\n  color: slash;\n}\n"
+);
+test!(
+    list_slash_nth,
+    "@use 'sass:list';\na {\n  color: list.nth(list.slash(a, b), 2);\n}\n",
+    "a {\n  color: b;\n}\n"
+);
+test!(
+    join_separator_slash,
+    "a {\n  color: join(1 2, 3, slash);\n}\n",
+    "a {\n  color: 1 / 2 / 3;\n}\n"
+);
+test!(
+    append_separator_slash_single_element,
+    "a {\n  color: inspect(append((), 1, $separator: slash));\n}\n",
+    "a {\n  color: (1/);\n}\n"
+);
+error!(
+    list_slash_one_element,
+    "@use 'sass:list';\na {\n  color: list.slash(1);\n}\n",
+    "Error: At least two elements are required."
+);
+test!(
+    list_slash_in_font_shorthand,
+    "@use 'sass:list';\n$size: list.slash(12px, 1.5);\na {\n  font: italic $size sans-serif;\n}\n",
+    "a {\n  font: italic 12px / 1.5 sans-serif;\n}\n"
+);
+test!(
+    list_slash_set_nth,
+    "@use 'sass:list';\na {\n  color: set-nth(list.slash(a, b), 1, c);\n}\n",
+    "a {\n  color: c / b;\n}\n"
+);
+test!(
+    list_slash_append_keeps_separator,
+    "@use 'sass:list';\na {\n  grid-area: append(list.slash(1, 2), 3);\n}\n",
+    "a {\n  grid-area: 1 / 2 / 3;\n}\n"
+);
+test!(
+    list_slash_inspect_parenthesizes_comma_and_slash_elements,
+    "@use 'sass:list';\na {\n  color: inspect(list.slash((a, b), c));\n  color: inspect(list.slash(list.slash(a, b), c));\n  color: inspect(list.slash(a b, c));\n}\n",
+    "a {\n  color: (a, b) / c;\n  color: (a / b) / c;\n  color: a b / c;\n}\n"
+);
+test!(
+    list_slash_inside_space_list_inspect,
+    "@use 'sass:list';\na {\n  color: inspect(list.slash(a, b) c);\n}\n",
+    "a {\n  color: (a / b) c;\n}\n"
+);
+test!(
+    slash_shorthand_survives_as_plain_css,
+    "a {\n  font: italic bold 12px/30px Georgia, serif;\n}\n",
+    "a {\n  font: italic bold 12px/30px Georgia, serif;\n}\n"
+);
+test!(
+    inspect_nested_lists_are_parenthesized,
+    "a {\n  color: inspect((a, b) (c, d));\n  color: inspect((a b) (c d));\n  color: inspect(((a, b), (c, d)));\n  color: inspect((a b, c d));\n}\n",
+    "a {\n  color: (a, b) (c, d);\n  color: (a b) (c d);\n  color: (a, b), (c, d);\n  color: a b, c d;\n}\n"
+);
+test!(
+    inspect_single_element_list_of_list,
+    "a {\n  color: inspect(((a, b),));\n  color: inspect([(a, b) c]);\n}\n",
+    "a {\n  color: ((a, b),);\n  color: [(a, b) c];\n}\n"
+);
+error!(
+    join_invalid_separator,
+    "a {\n  color: join(1, 2, foo);\n}\n",
+    "Error: $separator: Must be \"space\", \"comma\", \"slash\", or \"auto\"."
+);
+error!(
+    list_module_excludes_global_only_names,
+    "@use 'sass:list';\na {\n  color: list.list-separator(1 2);\n}\n", "Error: Undefined function."
+);
+test!(
+    slash_is_module_only,
+    "a {\n  color: slash(1, 2);\n}\n",
+    "a {\n  color: slash(1, 2);\n}\n"
+);
+test!(
+    list_module_functions,
+    "@use 'sass:list';\na {\n  color: list.length(1 2 3) list.nth(1 2 3, 2) list.index(1 2 3, 3);\n  color: list.append(1 2, 3) list.join((1, 2), (3, 4));\n}\n",
+    "a {\n  color: 3 2 3;\n  color: 1 2 3 1, 2, 3, 4;\n}\n"
+);
@@ -0,0 +1,56 @@
+//! A `Logger` that records the messages it is sent, shared by the tests of
+//! `@debug` and `@warn`
+
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use grass::{Logger, Options};
+
+#[derive(Debug, Default)]
+pub struct Collector {
+    debug: Mutex<Vec<String>>,
+    warn: Mutex<Vec<String>>,
+}
+
+impl Logger for Collector {
+    fn debug(&self, file: &str, line: usize, message: &str) {
+        self.debug
+            .lock()
+            .unwrap()
+            .push(format!("{}:{} {}", file, line, message));
+    }
+
+    fn warn(&self, message: &str, trace: &str) {
+        self.warn
+            .lock()
+            .unwrap()
+            .push(format!("{}\n{}", message, trace));
+    }
+}
+
+impl Collector {
+    /// The messages of the `@debug` rules run so far
+    pub fn debug_messages(&self) -> Vec<String> {
+        self.debug.lock().unwrap().clone()
+    }
+
+    /// The warnings emitted so far, each followed by its trace
+    pub fn warnings(&self) -> Vec<String> {
+        self.warn.lock().unwrap().clone()
+    }
+}
+
+/// The messages of the `@debug` rules run while compiling `input`
+pub fn debug_messages(input: &str, options: Options) -> Vec<String> {
+    let collector = Collector::default();
+    grass::from_string(input.to_owned(), &options.logger(&collector)).expect(input);
+    collector.debug.into_inner().unwrap()
+}
+
+/// The warnings emitted while compiling `input`, each followed by its trace
+pub fn warnings(input: &str, options: Options) -> Vec<String> {
+    let collector = Collector::default();
+    grass::from_string(input.to_owned(), &options.logger(&collector)).expect(input);
+    collector.warn.into_inner().unwrap()
+}
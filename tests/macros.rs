@@ -26,11 +26,14 @@ macro_rules! test {
 #[macro_export]
 macro_rules! error {
     ($( #[$attr:meta] ),*$func:ident, $input:expr, $err:expr) => {
+        error!($(#[$attr])* $func, $input, $err, grass::Options::default());
+    };
+    ($( #[$attr:meta] ),*$func:ident, $input:expr, $err:expr, $options:expr) => {
         $(#[$attr])*
         #[test]
         #[allow(non_snake_case)]
         fn $func() {
-            match grass::from_string($input.to_string(), &grass::Options::default()) {
+            match grass::from_string($input.to_string(), &$options) {
                 Ok(..) => panic!("did not fail"),
                 Err(e) => assert_eq!($err, e.to_string()
                                                 .chars()
@@ -140,6 +140,64 @@ test!(
     "@use 'sass:map'; a {b: inspect(map.set((c: 1), c, d, f))}",
     "a {\n  b: (c: (d: f));\n}\n"
 );
+test!(
+    map_set_nested_keeps_siblings,
+    "@use 'sass:map'; a {b: inspect(map.set((c: (d: 1, e: 2), f: 3), c, d, 4))}",
+    "a {\n  b: (c: (d: 4, e: 2), f: 3);\n}\n"
+);
+error!(
+    map_set_no_key,
+    "@use 'sass:map'; a {b: map.set((c: 1))}", "Error: Expected $args to contain a key."
+);
+error!(
+    map_set_no_value,
+    "@use 'sass:map'; a {b: map.set((c: 1), c)}", "Error: Expected $args to contain a value."
+);
+test!(
+    map_get_nested,
+    "@use 'sass:map'; a {b: map.get((c: (d: (e: f))), c, d, e)}",
+    "a {\n  b: f;\n}\n"
+);
+test!(
+    map_get_nested_returns_map,
+    "@use 'sass:map'; a {b: inspect(map.get((c: (d: (e: f))), c, d))}",
+    "a {\n  b: (e: f);\n}\n"
+);
+test!(
+    map_get_nested_key_does_not_exist,
+    "@use 'sass:map'; a {b: inspect(map.get((c: (d: e)), c, f))}",
+    "a {\n  b: null;\n}\n"
+);
+test!(
+    map_get_nested_intermediate_is_not_map,
+    "@use 'sass:map'; a {b: inspect(map.get((c: d), c, d))}",
+    "a {\n  b: null;\n}\n"
+);
+test!(
+    map_get_global_nested,
+    "a {b: map-get((c: (d: e)), c, d)}",
+    "a {\n  b: e;\n}\n"
+);
+test!(
+    map_has_key_nested_true,
+    "@use 'sass:map'; a {b: map.has-key((c: (d: (e: f))), c, d, e)}",
+    "a {\n  b: true;\n}\n"
+);
+test!(
+    map_has_key_nested_false,
+    "@use 'sass:map'; a {b: map.has-key((c: (d: (e: f))), c, d, f)}",
+    "a {\n  b: false;\n}\n"
+);
+test!(
+    map_has_key_nested_intermediate_is_not_map,
+    "@use 'sass:map'; a {b: map.has-key((c: d), c, d)}",
+    "a {\n  b: false;\n}\n"
+);
+test!(
+    map_has_key_nested_value_is_null,
+    "@use 'sass:map'; a {b: map.has-key((c: (d: null)), c, d)}",
+    "a {\n  b: true;\n}\n"
+);
 test!(
     map_merge_merge_into_map_with_many_keys,
     r#"
@@ -227,6 +285,49 @@ test!(
     "a {\n  color: inspect(map-remove((), foo));\n}\n",
     "a {\n  color: ();\n}\n"
 );
+test!(
+    map_remove_no_keys,
+    "a {\n  color: inspect(map-remove((a: b)));\n}\n",
+    "a {\n  color: (a: b);\n}\n"
+);
+test!(
+    map_remove_key_does_not_exist,
+    "a {\n  color: inspect(map-remove((a: b), c));\n}\n",
+    "a {\n  color: (a: b);\n}\n"
+);
+test!(
+    map_remove_preserves_order,
+    "a {\n  color: inspect(map-remove((c: 1, a: 2, d: 3, b: 4), a));\n}\n",
+    "a {\n  color: (c: 1, d: 3, b: 4);\n}\n"
+);
+test!(
+    map_keys_and_values_preserve_order,
+    "$map: (c: 1, a: 2, b: 3);\na {\n  color: map-keys($map);\n  color: map-values($map);\n}\n",
+    "a {\n  color: c, a, b;\n  color: 1, 2, 3;\n}\n"
+);
+test!(
+    map_merge_keeps_position_of_existing_keys,
+    "a {\n  color: inspect(map-merge((c: 1, a: 2, b: 3), (d: 4, a: 5)));\n}\n",
+    "a {\n  color: (c: 1, a: 5, b: 3, d: 4);\n}\n"
+);
+test!(
+    map_get_number_keys_compare_by_value,
+    "a {\n  color: map-get((1: a, 2px: b), 1.0);\n  color: map-get((1: a, 2px: b), 2px);\n  color: inspect(map-get((1: a, 2px: b), 2));\n}\n",
+    "a {\n  color: a;\n  color: b;\n  color: null;\n}\n"
+);
+test!(
+    map_functions_named_args,
+    "a {\n  color: map-get($map: (a: b), $key: a);\n  color: map-has-key($key: a, $map: (a: b));\n  color: map-keys($map: (a: b));\n  color: map-values($map: (a: b));\n  color: inspect(map-merge($map1: (a: b), $map2: (c: d)));\n}\n",
+    "a {\n  color: b;\n  color: true;\n  color: a;\n  color: b;\n  color: (a: b, c: d);\n}\n"
+);
+error!(
+    map_remove_non_map,
+    "a {\n  color: map-remove(1, a);\n}\n", "Error: $map: 1 is not a map."
+);
+error!(
+    map_merge_second_non_map,
+    "a {\n  color: map-merge((a: b), 1);\n}\n", "Error: $map2: 1 is not a map."
+);
 error!(
     duplicate_key_in_declaration,
     "a {\n  $a: (foo: a, foo: b);\n}\n", "Error: Duplicate key."
@@ -314,3 +415,71 @@ error!(
     denies_comma_separated_list_without_parens_as_key,
     "$map: (a: 1, b, c, d: e);", "Error: expected \":\"."
 );
+test!(
+    multiline_nested_map_with_trailing_commas_and_comments,
+    "$a: (\n  b: (\n    c: 1, // trailing\n  ),\n  d: 2, /* trailing */\n);\na {\n  color: inspect($a);\n}\n",
+    "a {\n  color: (b: (c: 1), d: 2);\n}\n"
+);
+test!(
+    map_deep_merge,
+    "@use 'sass:map'; a {b: inspect(map.deep-merge((c: (d: e, f: g), h: i), (c: (d: j, k: l), m: n)))}",
+    "a {\n  b: (c: (d: j, f: g, k: l), h: i, m: n);\n}\n"
+);
+test!(
+    map_deep_merge_several_levels,
+    "@use 'sass:map'; a {b: inspect(map.deep-merge((c: (d: (e: f))), (c: (d: (g: h)))))}",
+    "a {\n  b: (c: (d: (e: f, g: h)));\n}\n"
+);
+test!(
+    map_set_appends_new_key,
+    "@use 'sass:map'; a {b: inspect(map.set((z: 1, b: 2), a, 3))}",
+    "a {\n  b: (z: 1, b: 2, a: 3);\n}\n"
+);
+test!(
+    map_deep_merge_preserves_order,
+    "@use 'sass:map'; a {b: inspect(map.deep-merge((z: (y: 1, a: 2), m: 3), (z: (b: 3), c: 4)))}",
+    "a {\n  b: (z: (y: 1, a: 2, b: 3), m: 3, c: 4);\n}\n"
+);
+test!(
+    map_deep_merge_replaces_non_map,
+    "@use 'sass:map'; a {b: inspect(map.deep-merge((c: d), (c: (e: f))))}",
+    "a {\n  b: (c: (e: f));\n}\n"
+);
+test!(
+    map_deep_merge_empty_map_keeps_value,
+    "@use 'sass:map'; a {b: inspect(map.deep-merge((c: (d: e)), (c: ())))}",
+    "a {\n  b: (c: (d: e));\n}\n"
+);
+test!(
+    map_deep_merge_empty,
+    "@use 'sass:map'; a {b: inspect(map.deep-merge((), (c: d)))}",
+    "a {\n  b: (c: d);\n}\n"
+);
+error!(
+    map_deep_merge_not_map,
+    "@use 'sass:map'; a {b: map.deep-merge((c: d), 1)}", "Error: $map2: 1 is not a map."
+);
+test!(
+    map_deep_remove_top_level,
+    "@use 'sass:map'; a {b: inspect(map.deep-remove((c: d, e: f), c))}",
+    "a {\n  b: (e: f);\n}\n"
+);
+test!(
+    map_deep_remove_nested,
+    "@use 'sass:map'; a {b: inspect(map.deep-remove((c: (d: (e: f, g: h))), c, d, e))}",
+    "a {\n  b: (c: (d: (g: h)));\n}\n"
+);
+test!(
+    map_deep_remove_missing_path,
+    "@use 'sass:map'; a {b: inspect(map.deep-remove((c: (d: e)), f, d))}",
+    "a {\n  b: (c: (d: e));\n}\n"
+);
+test!(
+    map_deep_remove_path_not_map,
+    "@use 'sass:map'; a {b: inspect(map.deep-remove((c: d), c, d))}",
+    "a {\n  b: (c: d);\n}\n"
+);
+error!(
+    map_deep_remove_no_key,
+    "@use 'sass:map'; a {b: map.deep-remove((c: d))}", "Error: Missing argument $key."
+);
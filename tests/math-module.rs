@@ -36,6 +36,46 @@ error!(
     "@use 'sass:math';\na {\n  color: math.clamp(0mm, 1cm, 2);\n}\n",
     "Error: $min has unit mm but $max is unitless. Arguments must all have units or all be unitless."
 );
+error!(
+    clamp_only_max_has_unit,
+    "@use 'sass:math';\na {\n  color: math.clamp(0, 1, 2px);\n}\n",
+    "Error: $min is unitless but $max has unit px. Arguments must all have units or all be unitless."
+);
+error!(
+    clamp_max_units_not_comparable,
+    "@use 'sass:math';\na {\n  color: math.clamp(0px, 1px, 2s);\n}\n",
+    "Error: Incompatible units s and px."
+);
+test!(
+    clamp_min_greater_than_max,
+    "@use 'sass:math';\na {\n  color: math.clamp(2, 3, 1);\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    clamp_min_equal_to_number_keeps_unit_of_min,
+    "@use 'sass:math';\na {\n  color: math.clamp(1in, 96px, 2in);\n}\n",
+    "a {\n  color: 1in;\n}\n"
+);
+test!(
+    clamp_number_above_max_different_unit,
+    "@use 'sass:math';\na {\n  color: math.clamp(1in, 300px, 2in);\n}\n",
+    "a {\n  color: 2in;\n}\n"
+);
+test!(
+    clamp_number_nan,
+    "@use 'sass:math';\na {\n  color: math.clamp(1, (0 / 0), 3);\n}\n",
+    "a {\n  color: NaN;\n}\n"
+);
+test!(
+    clamp_min_nan,
+    "@use 'sass:math';\na {\n  color: math.clamp((0 / 0), 1, 3);\n}\n",
+    "a {\n  color: 1;\n}\n"
+);
+test!(
+    clamp_max_nan,
+    "@use 'sass:math';\na {\n  color: math.clamp(1, 2, (0 / 0));\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
 test!(
     sqrt_zero,
     "@use 'sass:math';\na {\n  color: math.sqrt(0);\n}\n",
@@ -444,6 +484,49 @@ test!(
     "@use 'sass:math';\na {\n  color: math.pow(2, 0);\n}\n",
     "a {\n  color: 1;\n}\n"
 );
+error!(
+    pow_base_with_units,
+    "@use 'sass:math';\na {\n  color: math.pow(2px, 2);\n}\n",
+    "Error: $base: Expected 2px to have no units."
+);
+error!(
+    pow_exponent_with_units,
+    "@use 'sass:math';\na {\n  color: math.pow(2, 2px);\n}\n",
+    "Error: $exponent: Expected 2px to have no units."
+);
+test!(
+    pow_negative_base_decimal_exponent,
+    "@use 'sass:math';\na {\n  color: math.pow(-8, .5);\n}\n",
+    "a {\n  color: NaN;\n}\n"
+);
+error!(
+    pow_zero_negative_exponent,
+    "@use 'sass:math';\na {\n  color: math.pow(0, -1);\n}\n",
+    "Error: Infinity not yet implemented."
+);
+error!(
+    pow_overflow,
+    "@use 'sass:math';\na {\n  color: math.pow(10, 400);\n}\n",
+    "Error: Infinity not yet implemented."
+);
+error!(
+    log_zero_infinity,
+    "@use 'sass:math';\na {\n  color: math.log(0);\n}\n", "Error: Infinity not yet implemented."
+);
+error!(
+    log_with_units,
+    "@use 'sass:math';\na {\n  color: math.log(8px);\n}\n",
+    "Error: $number: Expected 8px to have no units."
+);
+error!(
+    log_base_with_units,
+    "@use 'sass:math';\na {\n  color: math.log(8, 2px);\n}\n",
+    "Error: $base: Expected 2px to have no units."
+);
+error!(
+    log_base_not_number,
+    "@use 'sass:math';\na {\n  color: math.log(8, a);\n}\n", "Error: $base: a is not a number."
+);
 test!(
     hypot_all_same_unit,
     "@use 'sass:math';\na {\n  color: math.hypot(1px, 2px, 3px, 4px, 5px);\n}\n",
@@ -493,6 +576,16 @@ error!(
     "@use 'sass:math';\na {\n  color: math.hypot(1deg, 2deg, (0 / 0));\n}\n",
     "Error: Argument 1 has unit deg but argument 3 is unitless. Arguments must all have units or all be unitless."
 );
+error!(
+    hypot_first_nan_checks_remaining_units,
+    "@use 'sass:math';\na {\n  color: math.hypot((0 / 0), 1px);\n}\n",
+    "Error: Argument 1 is unitless but argument 2 has unit px. Arguments must all have units or all be unitless."
+);
+error!(
+    hypot_nan_checks_remaining_units,
+    "@use 'sass:math';\na {\n  color: math.hypot(1, (0 / 0), 1px);\n}\n",
+    "Error: Argument 1 is unitless but argument 3 has unit px. Arguments must all have units or all be unitless."
+);
 test!(
     atan2_both_positive,
     "@use 'sass:math';\na {\n  color: math.atan2(3, 4);\n}\n",
@@ -598,6 +691,24 @@ test!(
     "@use 'sass:math';\na {\n  color: math.div(\"1\",\"2\");\n}\n",
     "a {\n  color: \"1\"/\"2\";\n}\n"
 );
+test!(
+    div_nan_dividend_keeps_unit,
+    "@use 'sass:math';\na {\n  color: math.div(math.div(0px, 0), 2);\n}\n",
+    "a {\n  color: NaNpx;\n}\n"
+);
+test!(
+    div_nan_divisor,
+    "@use 'sass:math';\na {\n  color: math.div(1, math.div(0, 0));\n}\n",
+    "a {\n  color: NaN;\n}\n"
+);
+error!(
+    div_non_number_deprecated,
+    "@use 'sass:math';\na {\n  color: math.div(a, b);\n}\n",
+    "Error: math.div() will only support number arguments in a future release.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 33, 0)))
+        .fatal_warnings(true)
+);
 test!(
     log_returns_whole_number_for_simple_base,
     "@use 'sass:math';
@@ -607,3 +718,37 @@ test!(
     }",
     "a {\n  color: 3;\n  color: 3;\n}\n"
 );
+test!(
+    pi,
+    "@use 'sass:math';\na {\n  color: math.$pi;\n}\n",
+    "a {\n  color: 3.1415926536;\n}\n"
+);
+test!(
+    e,
+    "@use 'sass:math';\na {\n  color: math.$e;\n}\n",
+    "a {\n  color: 2.7182818285;\n}\n"
+);
+test!(
+    epsilon,
+    "@use 'sass:math';\na {\n  color: math.$epsilon * 1e16;\n  color: 1 + math.$epsilon > 1;\n}\n",
+    "a {\n  color: 2.2204460493;\n  color: true;\n}\n"
+);
+test!(
+    max_safe_integer,
+    "@use 'sass:math';\na {\n  color: math.$max-safe-integer;\n}\n",
+    "a {\n  color: 9007199254740991;\n}\n"
+);
+test!(
+    min_safe_integer,
+    "@use 'sass:math';\na {\n  color: math.$min-safe-integer;\n}\n",
+    "a {\n  color: -9007199254740991;\n}\n"
+);
+error!(
+    cannot_modify_builtin_variable,
+    "@use 'sass:math';\nmath.$pi: 0;\n", "Error: Cannot modify built-in variable."
+);
+test!(
+    compatible_compound_units,
+    "@use 'sass:math';\na {\n  color: math.compatible(math.div(1, 1px), math.div(1, 1in));\n}\n",
+    "a {\n  color: true;\n}\n"
+);
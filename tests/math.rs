@@ -71,6 +71,48 @@ test!(
     "a {\n  color: abs(-10px);\n}\n",
     "a {\n  color: 10px;\n}\n"
 );
+test!(
+    round_keeps_unit,
+    "a {\n  color: round(1.5px) ceil(1.2em) floor(-1.2%);\n}\n",
+    "a {\n  color: 2px 2em -2%;\n}\n"
+);
+test!(
+    round_half_away_from_zero,
+    "a {\n  color: round(2.5) round(-2.5);\n}\n",
+    "a {\n  color: 3 -3;\n}\n"
+);
+error!(
+    percentage_unit,
+    "a {\n  color: percentage(1px);\n}\n", "Error: $number: Expected 1px to have no units."
+);
+error!(
+    round_non_number,
+    "a {\n  color: round(a);\n}\n", "Error: $number: a is not a number."
+);
+test!(
+    min_converts_comparable_units,
+    "$a: 1in;\na {\n  color: min($a, 95px, 3pc);\n}\n",
+    "a {\n  color: 3pc;\n}\n"
+);
+test!(
+    max_converts_comparable_units,
+    "$a: 1in;\na {\n  color: max($a, 95px, 3pc);\n}\n",
+    "a {\n  color: 1in;\n}\n"
+);
+test!(
+    min_unitless_and_unit,
+    "@use 'sass:math';\na {\n  color: math.min(2, 1px);\n}\n",
+    "a {\n  color: 1px;\n}\n"
+);
+error!(
+    min_incompatible_units,
+    "@use 'sass:math';\na {\n  color: math.min(1px, 1em);\n}\n",
+    "Error: Incompatible units px and em."
+);
+error!(
+    max_non_number,
+    "@use 'sass:math';\na {\n  color: math.max(1px, a);\n}\n", "Error: a is not a number."
+);
 test!(
     comparable_unitless,
     "a {\n  color: comparable(1, 2);\n}\n",
@@ -101,13 +143,88 @@ test!(
     "a {\n  color: comparable($number1: 1, $number2: 2);\n}\n",
     "a {\n  color: true;\n}\n"
 );
+test!(
+    comparable_resolution_alias,
+    "a {\n  color: comparable(1x, 2dpi);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    comparable_compound_units,
+    "@use 'sass:math';\na {\n  color: comparable(math.div(1px, 1s), math.div(2in, 1ms));\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    comparable_compound_units_different_order,
+    "a {\n  color: comparable(1px * 1s, 1ms * 1in);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    comparable_compound_and_single_unit,
+    "a {\n  color: comparable(1px * 1s, 1px);\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    comparable_compound_and_unitless,
+    "a {\n  color: comparable(1px * 1s, 1);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    comparable_compound_units_not_comparable,
+    "@use 'sass:math';\na {\n  color: comparable(math.div(1px, 1s), 1em * 1s);\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+error!(
+    comparable_non_number,
+    "a {\n  color: comparable(1px, a);\n}\n", "Error: $number2: a is not a number."
+);
+#[cfg(feature = "random")]
 test!(
     random_limit_one,
     "a {\n  color: random(1);\n}\n",
     "a {\n  color: 1;\n}\n"
 );
+#[cfg(feature = "random")]
 test!(
     random_limit_big_one,
     "a {\n  color: random(1000000000000000001 - 1000000000000000000);\n}\n",
     "a {\n  color: 1;\n}\n"
 );
+#[cfg(feature = "random")]
+#[test]
+fn random_integer_limit_in_range() {
+    for _ in 0..100 {
+        let css = grass::from_string(
+            "a {\n  color: random(3);\n}\n".to_string(),
+            &grass::Options::default(),
+        )
+        .expect("failed to compile random()");
+        assert!(
+            ["1", "2", "3"]
+                .iter()
+                .any(|n| css == format!("a {{\n  color: {};\n}}\n", n)),
+            "{}",
+            css
+        );
+    }
+}
+#[cfg(feature = "random")]
+test!(
+    random_no_limit_is_unitless_decimal,
+    "a {\n  color: unitless(random()) random() < 1 random() >= 0;\n}\n",
+    "a {\n  color: true true true;\n}\n"
+);
+#[cfg(feature = "random")]
+error!(
+    random_decimal_limit,
+    "a {\n  color: random(1.5);\n}\n", "Error: $limit: 1.5 is not an int."
+);
+#[cfg(feature = "random")]
+error!(
+    random_zero_limit,
+    "a {\n  color: random(0);\n}\n", "Error: $limit: Must be greater than 0, was 0."
+);
+#[cfg(feature = "random")]
+error!(
+    random_negative_limit,
+    "a {\n  color: random(-1);\n}\n", "Error: $limit: Must be greater than 0, was -1."
+);
@@ -245,6 +245,40 @@ test!(
     "@media foo {\n  a {\n    color: red;\n  }\n\n  @import \"foo.css\";\n}\n"
 );
 
+test!(
+    feature_value_is_variable,
+    "$bp: 768px;\n@media (min-width: $bp) {\n  a {\n    color: red;\n  }\n}\n",
+    "@media (min-width: 768px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    feature_value_is_function_call,
+    "$bps: (md: 900px);\n@media (min-width: map-get($bps, md)) {\n  a {\n    color: red;\n  }\n}\n",
+    "@media (min-width: 900px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    feature_value_is_arithmetic,
+    "$bp: 768px;\n@media screen and (max-width: $bp - 1px) {\n  a {\n    color: red;\n  }\n}\n",
+    "@media screen and (max-width: 767px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    feature_value_quoted_string_is_unquoted,
+    "$o: \"landscape\";\n@media (orientation: $o) {\n  a {\n    color: red;\n  }\n}\n",
+    "@media (orientation: landscape) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    range_feature_with_variable,
+    "$bp: 768px;\n@media (width >= $bp) {\n  a {\n    color: red;\n  }\n}\n",
+    "@media (width >= 768px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    range_feature_bounded_on_both_sides,
+    "$bps: (sm: 400px, md: 900px);\n@media (map-get($bps, sm) < width <= map-get($bps, md)) {\n  a {\n    color: red;\n  }\n}\n",
+    "@media (400px < width <= 900px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+error!(
+    range_feature_comparisons_in_different_directions,
+    "@media (400px < width > 700px) {}", "Error: expected \")\"."
+);
 error!(
     media_feature_missing_closing_paren,
     "@media foo and (bar:a", "Error: expected \")\"."
@@ -253,3 +287,79 @@ error!(
     media_feature_missing_curly_brace_after_hash,
     "@media foo and # {}", "Error: expected \"{\"."
 );
+test!(
+    nested_media_type_and_condition,
+    "@media screen {\n  @media (min-width: 600px) {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media screen and (min-width: 600px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_conditions,
+    "@media (min-width: 600px) {\n  @media (max-width: 900px) {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media (min-width: 600px) and (max-width: 900px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_three_levels,
+    "@media screen {\n  @media (min-width: 600px) {\n    @media (max-width: 900px) {\n      a {\n        color: red;\n      }\n    }\n  }\n}\n",
+    "@media screen and (min-width: 600px) and (max-width: 900px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_inside_style_rule,
+    "a {\n  @media screen {\n    color: red;\n\n    @media (color) {\n      color: blue;\n    }\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\n@media screen and (color) {\n  a {\n    color: blue;\n  }\n}\n"
+);
+test!(
+    nested_media_in_style_rule_inside_media,
+    "@media screen {\n  a {\n    color: red;\n\n    @media (color) {\n      color: blue;\n    }\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\n@media screen and (color) {\n  a {\n    color: blue;\n  }\n}\n"
+);
+test!(
+    rules_after_nested_media_stay_in_outer_media,
+    "@media screen {\n  a {\n    color: red;\n  }\n\n  @media (color) {\n    b {\n      color: blue;\n    }\n  }\n\n  c {\n    color: green;\n  }\n}\n",
+    "@media screen {\n  a {\n    color: red;\n  }\n}\n@media screen and (color) {\n  b {\n    color: blue;\n  }\n}\n@media screen {\n  c {\n    color: green;\n  }\n}\n"
+);
+test!(
+    nested_media_query_lists,
+    "@media screen, print {\n  @media (color) {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media screen and (color), print and (color) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_with_modifier,
+    "@media only screen {\n  @media (color) {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media only screen and (color) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_all_is_omitted,
+    "@media all {\n  @media (color) {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media (color) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_not_and_other_type,
+    "@media not screen {\n  @media print {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media print {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_different_types_is_dropped,
+    "@media screen {\n  @media print {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    ""
+);
+test!(
+    nested_media_not_same_type_is_dropped,
+    "@media screen {\n  @media not screen {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    ""
+);
+test!(
+    nested_media_unrepresentable_is_not_merged,
+    "@media not screen {\n  @media not print {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media not screen {\n  @media not print {\n    a {\n      color: red;\n    }\n  }\n}\n"
+);
+test!(
+    nested_media_interpolated_query,
+    "$query: \"(min-width: 600px)\";\n@media screen {\n  @media #{$query} {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media screen and (min-width: 600px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_media_compressed,
+    "@media screen {\n  @media (color) {\n    a {\n      color: red;\n    }\n  }\n}\n",
+    "@media screen and (color){a{color:red}}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
@@ -8,11 +8,97 @@ test!(
     "@use 'sass:meta';\na {\n  color: inspect(meta.module-functions(meta));\n}\n",
     "a {\n  color: (\"feature-exists\": get-function(\"feature-exists\"), \"inspect\": get-function(\"inspect\"), \"type-of\": get-function(\"type-of\"), \"keywords\": get-function(\"keywords\"), \"global-variable-exists\": get-function(\"global-variable-exists\"), \"variable-exists\": get-function(\"variable-exists\"), \"function-exists\": get-function(\"function-exists\"), \"mixin-exists\": get-function(\"mixin-exists\"), \"content-exists\": get-function(\"content-exists\"), \"module-variables\": get-function(\"module-variables\"), \"module-functions\": get-function(\"module-functions\"), \"get-function\": get-function(\"get-function\"), \"call\": get-function(\"call\"));\n}\n"
 );
+#[test]
+fn module_functions_order_is_independent_of_previous_compilations() {
+    // intern some of the names in a different order than the module declares them
+    grass::from_string(
+        "$call: 1;\n$type-of: 2;\n$inspect: 3;\n".to_string(),
+        &grass::Options::default(),
+    )
+    .unwrap();
+
+    let css = grass::from_string(
+        "@use 'sass:meta';\na {\n  color: inspect(meta.module-functions(meta));\n}\n".to_string(),
+        &grass::Options::default(),
+    )
+    .unwrap();
+    assert!(
+        css.starts_with(
+            "a {\n  color: (\"feature-exists\": get-function(\"feature-exists\"), \"inspect\""
+        ),
+        "{}",
+        css
+    );
+}
 test!(
     module_variables_builtin,
     "@use 'sass:meta';\n@use 'sass:math';\na {\n  color: inspect(meta.module-variables(math));\n}\n",
-    "a {\n  color: (\"e\": 2.7182818285, \"pi\": 3.1415926536);\n}\n"
+    "a {\n  color: (\"e\": 2.7182818285, \"pi\": 3.1415926536, \"epsilon\": 0, \"max-safe-integer\": 9007199254740991, \"min-safe-integer\": -9007199254740991);\n}\n"
+);
+error!(
+    module_variables_module_dne,
+    "@use 'sass:meta';\na {\n  color: meta.module-variables(foo);\n}\n",
+    "Error: There is no module with the namespace \"foo\"."
+);
+error!(
+    module_functions_non_string,
+    "@use 'sass:meta';\na {\n  color: meta.module-functions(1);\n}\n",
+    "Error: $module: 1 is not a string."
 );
+
+#[test]
+fn module_variables_and_functions_user_defined() {
+    let input = "@use \"sass:meta\";\n@use \"module_variables_and_functions_user_defined\" as lib;\na {\n  color: meta.inspect(meta.module-variables(lib));\n  color: meta.inspect(meta.module-functions(lib));\n}\n";
+    tempfile!(
+        "module_variables_and_functions_user_defined.scss",
+        "$a: 1;\n$-b: 2;\n$c_d: red;\n@function double($x) { @return $x * 2; }\n@function -hidden() { @return 1; }\n@mixin foo {}\n"
+    );
+    assert_eq!(
+        "a {\n  color: (\"a\": 1, \"c-d\": red);\n  color: (\"double\": get-function(\"double\"));\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_variables_reflect_assignment() {
+    let input = "@use \"sass:meta\";\n@use \"module_variables_reflect_assignment\" as lib;\nlib.$a: 2;\na {\n  color: meta.inspect(meta.module-variables(lib));\n}\n";
+    tempfile!("module_variables_reflect_assignment.scss", "$a: 1;");
+    assert_eq!(
+        "a {\n  color: (\"a\": 2);\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_functions_can_be_called() {
+    let input = "@use \"sass:meta\";\n@use \"sass:map\";\n@use \"module_functions_can_be_called\" as lib;\na {\n  color: meta.call(map.get(meta.module-functions(lib), double), 3);\n}\n";
+    tempfile!(
+        "module_functions_can_be_called.scss",
+        "@function double($x) { @return $x * 2; }"
+    );
+    assert_eq!(
+        "a {\n  color: 6;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_variables_and_functions_include_forwarded() {
+    let input = "@use \"sass:meta\";\n@use \"module_variables_and_functions_include_forwarded\" as lib;\na {\n  color: meta.inspect(meta.module-variables(lib));\n  color: meta.inspect(meta.module-functions(lib));\n}\n";
+    tempfile!(
+        "module_variables_and_functions_include_forwarded.scss",
+        "@forward \"_module_variables_and_functions_include_forwarded_lib\";\n$own: 2;\n@function own() { @return 2; }"
+    );
+    tempfile!(
+        "_module_variables_and_functions_include_forwarded_lib.scss",
+        "$a: 1;\n@function foo() { @return 1; }"
+    );
+    assert_eq!(
+        "a {\n  color: (\"a\": 1, \"own\": 2);\n  color: (\"foo\": get-function(\"foo\"), \"own\": get-function(\"own\"));\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
 test!(
     global_var_exists_module,
     "@use 'sass:math';\na {\n  color: global-variable-exists(pi, $module: math);\n}\n",
@@ -71,3 +157,143 @@ fn load_css_non_map_with() {
     let input = "@use \"sass:meta\";\na {\n @include meta.load-css(foo, 2);\n}";
     assert_err!("Error: $with: 2 is not a map.", input);
 }
+
+#[test]
+fn load_css_named_url() {
+    let input = "@use \"sass:meta\";\na {\n @include meta.load-css($url: load_css_named_url, $with: null);\n}";
+    tempfile!("load_css_named_url.scss", "a { color: red; }");
+    assert_eq!(
+        "a a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_with() {
+    let input = "@use \"sass:meta\";\n@include meta.load-css(\"load_css_with\", $with: (\"a\": red, b_c: blue));";
+    tempfile!(
+        "load_css_with.scss",
+        "$a: green !default;\n$b-c: green !default;\na { color: $a; background: $b_c; }"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n  background: blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_with_nested_in_style_rule() {
+    let input = "@use \"sass:meta\";\na {\n @include meta.load-css(\"load_css_with_nested_in_style_rule\", $with: (a: red));\n}";
+    tempfile!(
+        "load_css_with_nested_in_style_rule.scss",
+        "$a: green !default;\nb { color: $a; }"
+    );
+    assert_eq!(
+        "a b {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_variables_stay_in_module() {
+    let input = "@use \"sass:meta\";\na {\n $a: 1;\n @include meta.load-css(\"load_css_variables_stay_in_module\");\n b: $a;\n}";
+    tempfile!(
+        "load_css_variables_stay_in_module.scss",
+        "$a: 2;\nc { d: $a; }"
+    );
+    assert_eq!(
+        "a {\n  b: 1;\n}\na c {\n  d: 2;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_evaluates_module_each_time() {
+    let input = "@use \"sass:meta\";\n@use \"load_css_evaluates_module_each_time\";\n@include meta.load-css(\"load_css_evaluates_module_each_time\", $with: (a: red));";
+    tempfile!(
+        "load_css_evaluates_module_each_time.scss",
+        "$a: green !default;\na { color: $a; }"
+    );
+    assert_eq!(
+        "a {\n  color: green;\n}\n\na {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_with_empty_list() {
+    let input =
+        "@use \"sass:meta\";\n@include meta.load-css(\"load_css_with_empty_list\", $with: ());";
+    tempfile!("load_css_with_empty_list.scss", "a { color: red; }");
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn load_css_with_variable_not_default() {
+    let input = "@use \"sass:meta\";\n@include meta.load-css(\"load_css_with_variable_not_default\", $with: (a: red));";
+    tempfile!("load_css_with_variable_not_default.scss", "$a: green;");
+    assert_err!(
+        "Error: $a was not declared with !default in the @used module.",
+        input
+    );
+}
+
+#[test]
+fn load_css_with_variable_configured_twice() {
+    let input = "@use \"sass:meta\";\n@include meta.load-css(\"load_css_with_variable_configured_twice\", $with: (a-b: red, a_b: blue));";
+    tempfile!(
+        "load_css_with_variable_configured_twice.scss",
+        "$a-b: green !default;"
+    );
+    assert_err!("Error: The variable $a-b was configured twice.", input);
+}
+
+#[test]
+fn load_css_with_non_string_key() {
+    let input = "@use \"sass:meta\";\n@include meta.load-css(\"load_css_with_non_string_key\", $with: (1: red));";
+    tempfile!("load_css_with_non_string_key.scss", "");
+    assert_err!("Error: $with key: 1 is not a string.", input);
+}
+test!(
+    meta_keywords,
+    "@use 'sass:meta';\n@function foo($args...) {\n  @return meta.inspect(meta.keywords($args));\n}\na {\n  color: foo($a: 1);\n}\n",
+    "a {\n  color: (a: 1);\n}\n"
+);
+test!(
+    meta_function_exists_in_module,
+    "@use 'sass:meta';\n@use 'sass:math';\na {\n  color: meta.function-exists(div, math);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    meta_mixin_exists_in_module,
+    "@use 'sass:meta';\na {\n  color: meta.mixin-exists(load-css, meta);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    meta_type_of,
+    "@use 'sass:meta';\na {\n  color: meta.type-of(1px) meta.type-of(a) meta.type-of((a: b)) meta.type-of(null);\n}\n",
+    "a {\n  color: number string map null;\n}\n"
+);
+test!(
+    meta_call,
+    "@use 'sass:meta';\n@function double($n) {\n  @return $n * 2;\n}\na {\n  color: meta.call(meta.get-function(double), 2px);\n}\n",
+    "a {\n  color: 4px;\n}\n"
+);
+test!(
+    meta_call_module_function,
+    "@use 'sass:meta';\n@use 'sass:math';\na {\n  color: meta.call(meta.get-function(div, $module: math), 1, 4);\n}\n",
+    "a {\n  color: 0.25;\n}\n"
+);
+test!(
+    meta_variable_exists,
+    "@use 'sass:meta';\n$a: 1;\na {\n  color: meta.variable-exists(a) meta.variable-exists(b);\n}\n",
+    "a {\n  color: true false;\n}\n"
+);
+test!(
+    meta_function_and_mixin_exist_globally,
+    "@use 'sass:meta';\n@function foo() {\n  @return 1;\n}\n@mixin bar {}\na {\n  color: meta.function-exists(foo) meta.mixin-exists(bar) meta.mixin-exists(foo);\n}\n",
+    "a {\n  color: true true false;\n}\n"
+);
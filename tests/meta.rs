@@ -103,6 +103,40 @@ test!(
     "a {\n  color: unit($number: 1px)\n}\n",
     "a {\n  color: \"px\";\n}\n"
 );
+test!(
+    unit_mul,
+    "a {\n  color: unit(1px * 1s)\n}\n",
+    "a {\n  color: \"px*s\";\n}\n"
+);
+test!(
+    unit_div,
+    "@use 'sass:math';\na {\n  color: unit(math.div(1px, 1s))\n}\n",
+    "a {\n  color: \"px/s\";\n}\n"
+);
+test!(
+    unit_mul_div_mul,
+    "@use 'sass:math';\na {\n  color: unit(math.div(1px * 1s, 1em * 1x))\n}\n",
+    "a {\n  color: \"px*s/em*x\";\n}\n"
+);
+test!(
+    unit_single_denominator,
+    "@use 'sass:math';\na {\n  color: unit(math.div(1, 1px))\n}\n",
+    "a {\n  color: \"px^-1\";\n}\n"
+);
+test!(
+    unit_multiple_denominators,
+    "@use 'sass:math';\na {\n  color: unit(math.div(1, 1px * 1s))\n}\n",
+    "a {\n  color: \"(px*s)^-1\";\n}\n"
+);
+test!(
+    unit_nan_keeps_unit,
+    "@use 'sass:math';\na {\n  color: math.unit(math.div(0px, 0))\n}\n",
+    "a {\n  color: \"px\";\n}\n"
+);
+error!(
+    unit_string,
+    "a {\n  color: unit(foo)\n}\n", "Error: $number: foo is not a number."
+);
 test!(
     type_of_number,
     "a {\n  color: type-of(1)\n}\n",
@@ -213,6 +247,16 @@ test!(
     "a {\n  color: unitless(1)\n}\n",
     "a {\n  color: true;\n}\n"
 );
+test!(
+    unitless_compound_unit,
+    "a {\n  color: unitless(1px * 1s)\n}\n",
+    "a {\n  color: false;\n}\n"
+);
+test!(
+    unitless_cancelled_units,
+    "@use 'sass:math';\na {\n  color: math.is-unitless(math.div(1px, 1px))\n}\n",
+    "a {\n  color: true;\n}\n"
+);
 error!(
     unitless_string,
     "a {\n  color: unitless(foo)\n}\n", "Error: $number: foo is not a number."
@@ -310,3 +354,17 @@ test!(
     }",
     "a {\n  color: true;\n}\n"
 );
+test!(
+    exists_functions_normalize_underscores,
+    "$a_b: red;\n@function c_d() {\n  @return red;\n}\n@mixin e_f {}\na {\n  color: global-variable-exists(a-b) function-exists(\"c_d\") mixin-exists(e-f);\n}\n",
+    "a {\n  color: true true true;\n}\n"
+);
+test!(
+    keywords_empty_arglist,
+    "@function foo($args...) {\n  @return inspect(keywords($args));\n}\na {\n  color: foo(1, 2);\n}\n",
+    "a {\n  color: ();\n}\n"
+);
+error!(
+    keywords_non_arglist,
+    "a {\n  color: keywords(1);\n}\n", "Error: $args: 1 is not an argument list."
+);
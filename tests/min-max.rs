@@ -148,12 +148,12 @@ test!(
 test!(
     min_conains_special_fn_calc_with_div_and_spaces,
     "a {\n  color: min(calc(1 / 2));\n}\n",
-    "a {\n  color: min(calc(1 / 2));\n}\n"
+    "a {\n  color: min(0.5);\n}\n"
 );
 test!(
     min_conains_special_fn_calc_with_div_without_spaces,
     "a {\n  color: min(calc(1/2));\n}\n",
-    "a {\n  color: min(calc(1/2));\n}\n"
+    "a {\n  color: min(0.5);\n}\n"
 );
 test!(
     min_conains_special_fn_calc_with_plus_only,
@@ -200,7 +200,7 @@ test!(
 test!(
     min_parenthesis_around_arg,
     "a {\n  color: min((1));\n}\n",
-    "a {\n  color: min((1));\n}\n"
+    "a {\n  color: min(1);\n}\n"
 );
 error!(
     min_parenthesis_around_arg_with_comma,
@@ -247,3 +247,38 @@ test!(
     "a {\n  color: min(calc());\n}\n",
     "a {\n  color: min(calc());\n}\n"
 );
+test!(
+    min_contains_unit_arithmetic,
+    "a {\n  color: min(100% - 10px, 50vw);\n}\n",
+    "a {\n  color: min(100% - 10px, 50vw);\n}\n"
+);
+test!(
+    min_simplifies_unit_arithmetic,
+    "a {\n  color: min(1px + 2px * 2, 10%);\n}\n",
+    "a {\n  color: min(5px, 10%);\n}\n"
+);
+test!(
+    max_contains_calc_with_incompatible_units,
+    "a {\n  color: max(calc(100% - 10px), 50vw);\n}\n",
+    "a {\n  color: max(100% - 10px, 50vw);\n}\n"
+);
+test!(
+    min_contains_clamp,
+    "a {\n  color: min(clamp(1rem, 2vw, 3rem), 10px);\n}\n",
+    "a {\n  color: min(clamp(1rem, 2vw, 3rem), 10px);\n}\n"
+);
+test!(
+    min_contains_interpolated_variable,
+    "$a: 1px;\na {\n  color: min(#{$a}, var(--b));\n}\n",
+    "a {\n  color: min(1px, var(--b));\n}\n"
+);
+test!(
+    min_css_function_compressed,
+    "a {\n  color: min(1px, 2%);\n}\n",
+    "a{color:min(1px,2%)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+error!(
+    min_variable_with_var,
+    "$a: 1px;\na {\n  color: min($a, var(--b));\n}\n", "Error: var(--b) is not a number."
+);
@@ -603,3 +603,169 @@ error!(
     }",
     "Error: expected \"{\"."
 );
+test!(
+    mixin_underscore_declaration_hyphen_include,
+    "@mixin foo_bar {\n  @content(red);\n}\na {\n  @include foo-bar using ($a_b) {\n    color: $a-b;\n  }\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    content_forwarded_through_recursive_mixin,
+    "@mixin recurse($n) {
+        @if $n > 0 {
+            @include recurse($n - 1) {
+                n: $n;
+                @content;
+            }
+        } @else {
+            @content;
+        }
+    }
+
+    a {
+        @include recurse(2) {
+            done: true;
+        }
+    }",
+    "a {\n  n: 1;\n  n: 2;\n  done: true;\n}\n"
+);
+test!(
+    forwarded_content_args_evaluated_in_mixin_scope,
+    "@mixin inner {
+        @content(1px);
+    }
+
+    @mixin outer($v) {
+        @include inner using ($w) {
+            @content($v, $w);
+        }
+    }
+
+    a {
+        @include outer(2px) using ($a, $b) {
+            a: $a;
+            b: $b;
+        }
+    }",
+    "a {\n  a: 2px;\n  b: 1px;\n}\n"
+);
+test!(
+    forwarded_content_using_local_mixins,
+    "a {
+        @mixin double($v) {
+            @content($v * 2);
+        }
+
+        @mixin forward {
+            $l: 3;
+
+            @include double($l) using ($d) {
+                @content($d);
+            }
+        }
+
+        @include forward using ($x) {
+            x: $x;
+        }
+    }",
+    "a {\n  x: 6;\n}\n"
+);
+test!(
+    nested_include_cannot_access_variables_of_outer_include,
+    "@mixin inner {
+        color: if(variable-exists(secret), leaked, hidden);
+        @content;
+    }
+
+    @mixin outer {
+        @include inner {
+            width: 1px;
+        }
+    }
+
+    a {
+        $secret: 1;
+        @include outer;
+    }",
+    "a {\n  color: hidden;\n  width: 1px;\n}\n"
+);
+test!(
+    content_args_by_name_with_default,
+    "@mixin foo {\n  @content(1, $b: 2);\n}\na {\n  @include foo using ($a, $b, $c: 3) {\n    color: $a $b $c;\n  }\n}\n",
+    "a {\n  color: 1 2 3;\n}\n"
+);
+test!(
+    content_using_rest_arg,
+    "@mixin foo {\n  @content(1, 2, 3);\n}\na {\n  @include foo using ($first, $rest...) {\n    color: $first;\n    background: $rest;\n  }\n}\n",
+    "a {\n  color: 1;\n  background: 2, 3;\n}\n"
+);
+test!(
+    content_args_in_each,
+    "@mixin each($list) {\n  @each $i in $list {\n    @content($i);\n  }\n}\na {\n  @include each(1 2) using ($i) {\n    .b-#{$i} {\n      width: $i;\n    }\n  }\n}\n",
+    "a .b-1 {\n  width: 1;\n}\na .b-2 {\n  width: 2;\n}\n"
+);
+test!(
+    content_args_in_media,
+    "@mixin above($width) {\n  @media (min-width: $width) {\n    @content($width);\n  }\n}\na {\n  @include above(10px) using ($w) {\n    width: $w;\n  }\n}\n",
+    "@media (min-width: 10px) {\n  a {\n    width: 10px;\n  }\n}\n"
+);
+error!(
+    content_args_without_using,
+    "@mixin foo {\n  @content(1);\n}\na {\n  @include foo {\n    color: red;\n  }\n}\n",
+    "Error: Only 0 arguments allowed, but 1 was passed."
+);
+error!(
+    duplicate_argument,
+    "@mixin foo($a, $a) {}\n", "Error: Duplicate argument."
+);
+test!(
+    nested_mixin_sees_scope_of_declaration_not_include,
+    "a {
+        $x: outer;
+
+        @mixin inner {
+            color: $x;
+        }
+
+        @mixin outer($x) {
+            @include inner;
+        }
+
+        @include outer(inner);
+    }",
+    "a {\n  color: outer;\n}\n"
+);
+test!(
+    nested_mixin_sees_later_assignments_in_its_scope,
+    "a {\n  $x: 1;\n  @mixin foo {\n    color: $x;\n    $x: 3;\n  }\n  $x: 2;\n  @include foo;\n  width: $x;\n}\n",
+    "a {\n  color: 2;\n  width: 3;\n}\n"
+);
+test!(
+    nested_mixin_content_sees_scope_of_include,
+    "a {
+        $x: outer;
+
+        @mixin foo {
+            color: $x;
+            @content;
+        }
+
+        b {
+            $y: inner;
+
+            @include foo {
+                width: $y;
+            }
+        }
+    }",
+    "a b {\n  color: outer;\n  width: inner;\n}\n"
+);
+test!(
+    nested_mixin_shadows_global_mixin_in_its_scope,
+    "@mixin foo {\n  color: global;\n}\na {\n  @mixin foo {\n    color: local;\n  }\n  @include foo;\n}\nb {\n  @include foo;\n}\n",
+    "a {\n  color: local;\n}\n\nb {\n  color: global;\n}\n"
+);
+error!(
+    nested_mixin_not_visible_outside_ruleset,
+    "a {\n  @mixin foo {\n    color: red;\n  }\n}\nb {\n  @include foo;\n}\n",
+    "Error: Undefined mixin."
+);
@@ -35,6 +35,7 @@ error!(
     unitless_nan_floor_number,
     "a {\n  color: floor((0/0));\n}\n", "Error: Infinity or NaN toInt"
 );
+#[cfg(feature = "random")]
 error!(
     unitless_nan_random_limit,
     "a {\n  color: random((0/0));\n}\n", "Error: $limit: NaN is not an int."
@@ -114,6 +115,7 @@ test!(
     "@use \"sass:math\";\na {\n  color: abs(math.acos(2));\n}\n",
     "a {\n  color: NaNdeg;\n}\n"
 );
+#[cfg(feature = "random")]
 error!(
     unitful_nan_random,
     "@use \"sass:math\";\na {\n  color: random(math.acos(2));\n}\n",
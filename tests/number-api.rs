@@ -0,0 +1,176 @@
+use grass::{Number, Unit};
+
+fn num(s: &str) -> Number {
+    s.parse().unwrap()
+}
+
+#[test]
+fn display() {
+    assert_eq!(Number::new(1.5, Unit::Rem).to_string(), "1.5rem");
+    assert_eq!(Number::new(50.0, Unit::Percent).to_string(), "50%");
+    assert_eq!(Number::unitless(-0.25).to_string(), "-0.25");
+    assert_eq!(
+        Number::new(1.0, Unit::Unknown("foo".to_owned())).to_string(),
+        "1foo"
+    );
+}
+
+#[test]
+#[should_panic]
+fn new_not_finite() {
+    let _ = Number::new(f64::NAN, Unit::Px);
+}
+
+#[test]
+fn try_new() {
+    assert_eq!(
+        Number::try_new(1.5, Unit::Rem).map(|n| n.to_string()),
+        Some("1.5rem".to_owned())
+    );
+    assert_eq!(
+        Number::try_unitless(-2.0).map(|n| n.to_string()),
+        Some("-2".to_owned())
+    );
+    assert!(Number::try_new(f64::NAN, Unit::Px).is_none());
+    assert!(Number::try_new(f64::INFINITY, Unit::Px).is_none());
+    assert!(Number::try_unitless(f64::NEG_INFINITY).is_none());
+}
+
+#[test]
+fn parse() {
+    assert_eq!(num("1.5rem").unit(), &Unit::Rem);
+    assert_eq!(num("1.5rem").value(), 1.5);
+    assert_eq!(num("-.5").to_string(), "-0.5");
+    assert_eq!(num("+3PX").to_string(), "3px");
+    assert_eq!(num("1e3px").to_string(), "1000px");
+    assert_eq!(num("25e-2").to_string(), "0.25");
+    assert_eq!(num("2em").unit(), &Unit::Em);
+    assert_eq!(num("10%").unit(), &Unit::Percent);
+    assert_eq!(num("1foo").unit(), &Unit::Unknown("foo".to_owned()));
+}
+
+#[test]
+fn parse_invalid() {
+    for s in ["", "px", "1.", ".", "1 px", "1px)", "--1", "1.5.5"] {
+        assert!(s.parse::<Number>().is_err(), "{:?}", s);
+    }
+}
+
+#[test]
+fn parse_exponent_out_of_range() {
+    assert_eq!(num("1e99").to_string(), num("1e+99").to_string());
+    assert_eq!(num("1e-99px").unit(), &Unit::Px);
+    for s in ["1e100", "1e-100", "1e999999999", "1e-9223372036854775808"] {
+        assert!(s.parse::<Number>().is_err(), "{:?}", s);
+    }
+}
+
+#[test]
+fn parse_is_exact() {
+    let third = num("1").checked_div(&num("3")).unwrap();
+    let sum = third
+        .checked_add(&third)
+        .unwrap()
+        .checked_add(&third)
+        .unwrap();
+    assert_eq!(sum, num("1"));
+    assert_eq!(num("0.1").checked_add(&num("0.2")).unwrap(), num("0.3"));
+}
+
+#[test]
+fn parse_unit() {
+    assert_eq!("PX".parse::<Unit>().unwrap(), Unit::Px);
+    assert_eq!("%".parse::<Unit>().unwrap(), Unit::Percent);
+    assert_eq!("".parse::<Unit>().unwrap(), Unit::None);
+    assert_eq!(
+        "foo".parse::<Unit>().unwrap(),
+        Unit::Unknown("foo".to_owned())
+    );
+}
+
+#[test]
+fn convert() {
+    assert_eq!(num("1in").convert(&Unit::Px).unwrap().to_string(), "96px");
+    assert_eq!(
+        num("1turn").convert(&Unit::Deg).unwrap().to_string(),
+        "360deg"
+    );
+    assert_eq!(num("2").convert(&Unit::Rem).unwrap().to_string(), "2rem");
+    assert_eq!(num("2px").convert(&Unit::None).unwrap().to_string(), "2");
+    assert!(num("1px").convert(&Unit::Em).is_none());
+    assert!(num("1em").convert(&Unit::Rem).is_none());
+}
+
+#[test]
+fn comparable() {
+    assert!(Unit::Px.is_comparable_to(&Unit::Cm));
+    assert!(Unit::Px.is_comparable_to(&Unit::None));
+    assert!(Unit::None.is_comparable_to(&Unit::S));
+    assert!(!Unit::Px.is_comparable_to(&Unit::S));
+    assert!(!Unit::Em.is_comparable_to(&Unit::Rem));
+}
+
+#[test]
+fn add_sub() {
+    assert_eq!(
+        num("1px").checked_add(&num("1in")).unwrap().to_string(),
+        "97px"
+    );
+    assert_eq!(
+        num("1in").checked_sub(&num("48px")).unwrap().to_string(),
+        "0.5in"
+    );
+    assert_eq!(
+        num("1").checked_add(&num("2em")).unwrap().to_string(),
+        "3em"
+    );
+    assert_eq!(
+        num("1em").checked_add(&num("2")).unwrap().to_string(),
+        "3em"
+    );
+    assert!(num("1px").checked_add(&num("1s")).is_none());
+    assert!(num("1em").checked_sub(&num("1rem")).is_none());
+}
+
+#[test]
+fn mul_div() {
+    assert_eq!(
+        num("1.5rem").checked_mul(&num("2")).unwrap().to_string(),
+        "3rem"
+    );
+    assert_eq!(num("2").checked_mul(&num("3s")).unwrap().to_string(), "6s");
+    assert!(num("1px").checked_mul(&num("1px")).is_none());
+
+    assert_eq!(
+        num("3rem").checked_div(&num("2")).unwrap().to_string(),
+        "1.5rem"
+    );
+    assert_eq!(
+        num("1in").checked_div(&num("48px")).unwrap().to_string(),
+        "2"
+    );
+    assert!(num("1em").checked_div(&num("1px")).is_none());
+    assert!(num("1").checked_div(&num("1px")).is_none());
+    assert!(num("1px").checked_div(&num("0")).is_none());
+}
+
+#[test]
+fn neg() {
+    assert_eq!((-num("1.5rem")).to_string(), "-1.5rem");
+}
+
+#[test]
+fn compare() {
+    assert_eq!(num("1in"), num("96px"));
+    assert_ne!(num("1px"), num("1s"));
+    assert!(num("1cm") > num("1mm"));
+    assert_eq!(num("1px").partial_cmp(&num("1em")), None);
+}
+
+#[test]
+fn unitless_is_not_equal_to_unit() {
+    assert_ne!(Number::unitless(1.0), Number::new(1.0, Unit::Px));
+    assert_ne!(Number::new(1.0, Unit::Em), Number::unitless(1.0));
+    assert_eq!(num("1px").partial_cmp(&num("2")), None);
+    assert_eq!(Number::unitless(1.0), Number::unitless(1.0));
+}
@@ -83,3 +83,8 @@ test!(
     "a {\n  color: or(foo);\n}\n",
     "a {\n  color: or(foo);\n}\n"
 );
+test!(
+    underscores_in_plain_css_fn_name_are_preserved,
+    "a {\n  color: foo_bar(1);\n}\n",
+    "a {\n  color: foo_bar(1);\n}\n"
+);
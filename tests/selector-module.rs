@@ -0,0 +1,68 @@
+#[macro_use]
+mod macros;
+
+test!(
+    nest,
+    "@use 'sass:selector';\na {\n  color: selector.nest(\"a\", \"&:hover\", \"b\");\n}\n",
+    "a {\n  color: a:hover b;\n}\n"
+);
+test!(
+    append,
+    "@use 'sass:selector';\na {\n  color: selector.append(\"a\", \".b\", \"-c\");\n}\n",
+    "a {\n  color: a.b-c;\n}\n"
+);
+test!(
+    unify,
+    "@use 'sass:selector';\na {\n  color: selector.unify(\"a.b\", \".c\");\n}\n",
+    "a {\n  color: a.b.c;\n}\n"
+);
+test!(
+    unify_no_result,
+    "@use 'sass:selector';\na {\n  color: inspect(selector.unify(\"a\", \"b\"));\n}\n",
+    "a {\n  color: null;\n}\n"
+);
+test!(
+    replace,
+    "@use 'sass:selector';\na {\n  color: selector.replace(\"a.b\", \".b\", \".c\");\n}\n",
+    "a {\n  color: a.c;\n}\n"
+);
+test!(
+    extend,
+    "@use 'sass:selector';\na {\n  color: selector.extend(\"a.b\", \".b\", \".c\");\n}\n",
+    "a {\n  color: a.b, a.c;\n}\n"
+);
+test!(
+    parse,
+    "@use 'sass:selector';\na {\n  color: inspect(selector.parse(\"a b, c\"));\n}\n",
+    "a {\n  color: a b, c;\n}\n"
+);
+test!(
+    parse_returns_list,
+    "@use 'sass:selector';\na {\n  color: length(selector.parse(\"a b, c\")) length(nth(selector.parse(\"a b, c\"), 1));\n}\n",
+    "a {\n  color: 2 2;\n}\n"
+);
+test!(
+    is_superselector,
+    "@use 'sass:selector';\na {\n  color: selector.is-superselector(\"a\", \"a.b\") selector.is-superselector(\"a.b\", \"a\");\n}\n",
+    "a {\n  color: true false;\n}\n"
+);
+test!(
+    simple_selectors,
+    "@use 'sass:selector';\na {\n  color: selector.simple-selectors(\"a.b#c\");\n}\n",
+    "a {\n  color: a, .b, #c;\n}\n"
+);
+test!(
+    same_as_global_functions,
+    "@use 'sass:selector';\na {\n  color: selector.nest(\"a\", \"b\") == selector-nest(\"a\", \"b\");\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+error!(
+    global_name_not_in_module,
+    "@use 'sass:selector';\na {\n  color: selector.selector-nest(\"a\", \"b\");\n}\n",
+    "Error: Undefined function."
+);
+error!(
+    invalid_selector,
+    "@use 'sass:selector';\na {\n  color: selector.parse(\"!!!\");\n}\n",
+    "Error: $selector: expected selector."
+);
@@ -436,6 +436,36 @@ test!(
     "a, %b, c {\n  color: red;\n}\n",
     "a, c {\n  color: red;\n}\n"
 );
+test!(
+    placeholder_with_children,
+    "%a {\n  b {\n    color: red;\n  }\n}\n",
+    ""
+);
+test!(
+    placeholder_as_child,
+    "a {\n  %b {\n    color: red;\n  }\n}\n",
+    ""
+);
+test!(
+    placeholder_in_compound_selector,
+    "a%b:hover {\n  color: red;\n}\n",
+    ""
+);
+test!(
+    placeholder_in_complex_selector,
+    "%a b, c {\n  color: red;\n}\n",
+    "c {\n  color: red;\n}\n"
+);
+test!(
+    extended_placeholder_in_complex_selector,
+    "%a b {\n  color: red;\n}\n\nc {\n  @extend %a;\n}\n",
+    "c b {\n  color: red;\n}\n"
+);
+test!(
+    extended_placeholder_in_media,
+    "@media screen {\n  %a {\n    color: red;\n  }\n\n  b {\n    @extend %a;\n  }\n}\n",
+    "@media screen {\n  b {\n    color: red;\n  }\n}\n"
+);
 test!(
     removes_leading_space,
     "#{&} a {\n  color: red;\n}\n",
@@ -4,7 +4,7 @@ mod macros;
 test!(
     calc_whitespace,
     "a {\n  color: calc(       1      );\n}\n",
-    "a {\n  color: calc( 1 );\n}\n"
+    "a {\n  color: 1;\n}\n"
 );
 test!(
     calc_newline,
@@ -17,9 +17,9 @@ test!(
     "a {\n  color: calc(1, 2, a, b, c);\n}\n"
 );
 test!(
-    calc_does_not_evaluate_arithmetic,
+    calc_evaluates_arithmetic,
     "a {\n  color: calc(1 + 2);\n}\n",
-    "a {\n  color: calc(1 + 2);\n}\n"
+    "a {\n  color: 3;\n}\n"
 );
 test!(
     calc_evaluates_interpolated_arithmetic,
@@ -44,17 +44,17 @@ test!(
 test!(
     calc_invalid_arithmetic,
     "a {\n  color: calc(2px + 2px + 5%);\n}\n",
-    "a {\n  color: calc(2px + 2px + 5%);\n}\n"
+    "a {\n  color: calc(4px + 5%);\n}\n"
 );
 test!(
     calc_uppercase,
     "a {\n  color: CALC(1 + 1);\n}\n",
-    "a {\n  color: calc(1 + 1);\n}\n"
+    "a {\n  color: 2;\n}\n"
 );
 test!(
     calc_mixed_casing,
     "a {\n  color: cAlC(1 + 1);\n}\n",
-    "a {\n  color: calc(1 + 1);\n}\n"
+    "a {\n  color: 2;\n}\n"
 );
 test!(
     calc_browser_prefixed,
@@ -81,6 +81,86 @@ test!(
     "a {\n  color: calc(#);\n}\n",
     "a {\n  color: calc(#);\n}\n"
 );
+test!(
+    calc_simplifies_compatible_units,
+    "a {\n  color: calc(1in + 24px * 2);\n}\n",
+    "a {\n  color: 1.5in;\n}\n"
+);
+test!(
+    calc_incompatible_units,
+    "a {\n  color: calc(100% - 10px);\n}\n",
+    "a {\n  color: calc(100% - 10px);\n}\n"
+);
+test!(
+    calc_variable,
+    "$gap: 2px;\na {\n  color: calc(100% - $gap * 2);\n}\n",
+    "a {\n  color: calc(100% - 4px);\n}\n"
+);
+test!(
+    calc_interpolation_is_parenthesized,
+    "$gap: \"1px + 1px\";\na {\n  color: calc(100% - #{$gap});\n}\n",
+    "a {\n  color: calc(100% - (1px + 1px));\n}\n"
+);
+test!(
+    calc_keeps_needed_parens,
+    "a {\n  color: calc((100% - 10px) / (3em + 1%));\n}\n",
+    "a {\n  color: calc((100% - 10px) / (3em + 1%));\n}\n"
+);
+test!(
+    calc_removes_unneeded_parens,
+    "a {\n  color: calc((1% * 2) + (1px));\n}\n",
+    "a {\n  color: calc(2% + 1px);\n}\n"
+);
+test!(
+    calc_nested_calc,
+    "a {\n  color: calc(calc(100% - 10px) * 2);\n}\n",
+    "a {\n  color: calc((100% - 10px) * 2);\n}\n"
+);
+test!(
+    calc_var,
+    "a {\n  color: calc(var(--width) / 2);\n}\n",
+    "a {\n  color: calc(var(--width) / 2);\n}\n"
+);
+test!(
+    calc_negative_number,
+    "a {\n  color: calc(-1px + 2%);\n}\n",
+    "a {\n  color: calc(-1px + 2%);\n}\n"
+);
+test!(
+    calc_division_by_zero_is_not_simplified,
+    "a {\n  color: calc(1px / 0);\n}\n",
+    "a {\n  color: calc(1px / 0);\n}\n"
+);
+test!(
+    calc_compressed,
+    "a {\n  color: calc((100% - 10px) * 2 / 3);\n}\n",
+    "a{color:calc((100% - 10px)*2/3)}",
+    grass::Options::default().style(grass::OutputStyle::Compressed)
+);
+test!(
+    calc_type_of,
+    "a {\n  color: type-of(calc(100% - 10px));\n}\n",
+    "a {\n  color: calculation;\n}\n"
+);
+test!(
+    calc_equality,
+    "a {\n  color: calc(100% - 10px) == calc(100% - 10px);\n  color: calc(100% - 10px) == calc(100% - 11px);\n}\n",
+    "a {\n  color: true;\n  color: false;\n}\n"
+);
+test!(
+    calc_in_list,
+    "$list: calc(100% - 10px) 1px;\na {\n  color: nth($list, 1);\n}\n",
+    "a {\n  color: calc(100% - 10px);\n}\n"
+);
+error!(
+    calc_invalid_value,
+    "a {\n  color: calc(1px + red);\n}\n", "Error: Value red can't be used in a calculation."
+);
+error!(
+    calc_arithmetic,
+    "a {\n  color: calc(100% - 10px) + 1px;\n}\n",
+    "Error: Undefined operation \"calc(100% - 10px) + 1px\"."
+);
 test!(
     element_whitespace,
     "a {\n  color: element(       1      );\n}\n",
@@ -249,8 +329,70 @@ test!(
     "a {\n  color: clamp(1);\n}\n",
     "a {\n  color: clamp(1);\n}\n"
 );
+test!(
+    clamp_simplifies_args,
+    "a {\n  color: clamp(1px * 2, 2vw + 1rem, 3rem);\n}\n",
+    "a {\n  color: clamp(2px, 2vw + 1rem, 3rem);\n}\n"
+);
+test!(
+    clamp_interpolated_arg,
+    "$min: 1rem;\na {\n  color: clamp(#{$min}, 2vw, 3rem);\n}\n",
+    "a {\n  color: clamp(1rem, 2vw, 3rem);\n}\n"
+);
+test!(
+    clamp_interpolation_followed_by_unit,
+    "$size: 2;\na {\n  color: clamp(1rem, #{$size}vw, 3rem);\n}\n",
+    "a {\n  color: clamp(1rem, 2vw, 3rem);\n}\n"
+);
+test!(
+    clamp_all_args_interpolated,
+    "a {\n  color: clamp(#{1rem, 2vw, 3rem});\n}\n",
+    "a {\n  color: clamp(1rem, 2vw, 3rem);\n}\n"
+);
+test!(
+    clamp_variable_arg,
+    "$min: 1rem;\na {\n  color: clamp($min, 2.5vw, 3rem);\n}\n",
+    "a {\n  color: clamp(1rem, 2.5vw, 3rem);\n}\n"
+);
+test!(
+    clamp_contains_calc,
+    "a {\n  color: clamp(1rem, calc(1rem + 2vw), 3rem);\n}\n",
+    "a {\n  color: clamp(1rem, 1rem + 2vw, 3rem);\n}\n"
+);
+test!(
+    clamp_uppercase,
+    "a {\n  color: CLAMP(1px, 2px, 3px);\n}\n",
+    "a {\n  color: clamp(1px, 2px, 3px);\n}\n"
+);
 test!(
     clamp_many_args,
     "a {\n  color: clamp(1, 2, 3);\n}\n",
     "a {\n  color: clamp(1, 2, 3);\n}\n"
 );
+error!(
+    calc_add_incompatible_units,
+    "a {\n  color: calc(1px + 2s);\n}\n", "Error: 1px and 2s are incompatible."
+);
+error!(
+    calc_add_incompatible_units_after_multiplication,
+    "a {\n  color: calc(1px + 2s * 3);\n}\n", "Error: 1px and 6s are incompatible."
+);
+error!(
+    calc_add_unitless_and_unit,
+    "a {\n  color: calc(1 + 1px);\n}\n", "Error: 1 and 1px are incompatible."
+);
+error!(
+    calc_add_compound_unit,
+    "a {\n  color: calc(1px * 1s + 1px);\n}\n",
+    "Error: Number 1px*s isn't compatible with CSS calculations."
+);
+test!(
+    calc_add_possibly_compatible_units,
+    "a {\n  color: calc(1px + 1em) calc(1deg + 1foo);\n}\n",
+    "a {\n  color: calc(1px + 1em) calc(1deg + 1foo);\n}\n"
+);
+test!(
+    min_adds_unitless_and_unit,
+    "a {\n  color: min(1 + 1px, 5px);\n}\n",
+    "a {\n  color: min(2px, 5px);\n}\n"
+);
@@ -0,0 +1,145 @@
+use std::fs;
+
+use grass::{FileStats, Options, Stats};
+use tempfile::tempdir;
+
+fn stats(input: &str, options: &Options) -> (String, Stats) {
+    grass::from_string_with_stats(input.to_owned(), options).unwrap()
+}
+
+fn stdin(stats: &Stats) -> &FileStats {
+    stats.file("stdin").unwrap()
+}
+
+#[test]
+fn counts_rules_selectors_and_declarations() {
+    let (css, stats) = stats(
+        "a, b c { color: red; d { width: 1px; height: 2px; } }",
+        &Options::default(),
+    );
+    let file = stdin(&stats);
+    assert_eq!(file.rules(), 2);
+    assert_eq!(file.selectors(), 4);
+    assert_eq!(file.declarations(), 3);
+    assert_eq!(stats.bytes(), css.len());
+}
+
+#[test]
+fn bytes_exclude_whitespace_between_rules() {
+    let (css, stats) = stats("a { color: red; } b { color: red; }", &Options::default());
+    assert_eq!(css, "a {\n  color: red;\n}\n\nb {\n  color: red;\n}\n");
+    assert_eq!(stdin(&stats).bytes(), css.len() - "\n\n\n".len());
+}
+
+#[test]
+fn compressed_bytes() {
+    let (css, stats) = stats(
+        "a { color: red; } b { color: red; }",
+        &Options::default().style(grass::OutputStyle::Compressed),
+    );
+    assert_eq!(css, "a{color:red}b{color:red}");
+    assert_eq!(stdin(&stats).bytes(), css.len());
+    assert_eq!(stats.bytes(), css.len());
+}
+
+#[test]
+fn empty_rules_are_not_counted() {
+    let (css, stats) = stats("a {} %b { color: red; }", &Options::default());
+    assert_eq!(css, "");
+    assert_eq!(stats.bytes(), 0);
+    assert!(stats.file("stdin").is_none());
+}
+
+#[test]
+fn placeholder_selectors_are_not_counted() {
+    let (_, stats) = stats("%a { color: red; } b { @extend %a; }", &Options::default());
+    assert_eq!(stdin(&stats).rules(), 1);
+    assert_eq!(stdin(&stats).selectors(), 1);
+}
+
+#[test]
+fn keyframes_rules_are_counted() {
+    let (_, stats) = stats(
+        "@keyframes a { from, 50% { width: 1px; } to { width: 2px; } }",
+        &Options::default(),
+    );
+    let file = stdin(&stats);
+    assert_eq!(file.rules(), 2);
+    assert_eq!(file.selectors(), 3);
+    assert_eq!(file.declarations(), 2);
+}
+
+#[test]
+fn declarations_outside_style_rules_are_counted() {
+    let (_, stats) = stats(
+        "@font-face { font-family: a; src: url(a.woff); }",
+        &Options::default(),
+    );
+    let file = stdin(&stats);
+    assert_eq!(file.rules(), 0);
+    assert_eq!(file.declarations(), 2);
+    assert_eq!(
+        file.bytes(),
+        "  font-family: a;".len() + "  src: url(a.woff);".len()
+    );
+}
+
+#[test]
+fn charset_counts_toward_total_bytes() {
+    let (css, stats) = stats("a { content: \"ë\"; }", &Options::default());
+    assert!(css.starts_with("@charset"));
+    assert_eq!(stats.bytes(), css.len());
+    assert!(stdin(&stats).bytes() < css.len());
+}
+
+#[test]
+fn declarations_from_mixins_are_attributed_to_the_mixin() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("_mixins.scss"),
+        "@mixin m { color: red; width: 1px; }",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(
+        &main,
+        "@import \"mixins\";\na { @include m; height: 2px; }\nb { color: blue; }",
+    )
+    .unwrap();
+
+    let (css, stats) =
+        grass::from_path_with_stats(main.to_str().unwrap(), &Options::default()).unwrap();
+    assert_eq!(stats.bytes(), css.len());
+
+    let files: Vec<&FileStats> = stats.files().collect();
+    assert_eq!(files.len(), 2);
+
+    let main = files
+        .iter()
+        .find(|file| file.name().ends_with("main.scss"))
+        .unwrap();
+    assert_eq!(main.rules(), 2);
+    assert_eq!(main.declarations(), 2);
+
+    let mixins = files
+        .iter()
+        .find(|file| file.name().ends_with("_mixins.scss"))
+        .unwrap();
+    assert_eq!(mixins.rules(), 0);
+    assert_eq!(mixins.declarations(), 2);
+}
+
+#[test]
+fn rules_from_imports_are_attributed_to_the_import() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("_a.scss"), "a { color: red; }").unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "@import \"a\";\nb { color: blue; }").unwrap();
+
+    let (_, stats) =
+        grass::from_path_with_stats(main.to_str().unwrap(), &Options::default()).unwrap();
+    let names: Vec<&str> = stats.files().map(FileStats::name).collect();
+    assert_eq!(names.len(), 2);
+    assert!(names[0].ends_with("_a.scss"));
+    assert!(names[1].ends_with("main.scss"));
+}
@@ -239,3 +239,275 @@ test!(
     "a {\n  color: \"#foo\";\n}\n",
     "a {\n  color: \"#foo\";\n}\n"
 );
+test!(
+    str_index_after_emoji,
+    "a {\n  color: str-index(\"👭👭abc\", \"b\");\n}\n",
+    "a {\n  color: 4;\n}\n"
+);
+test!(
+    str_index_cjk,
+    "a {\n  color: str-index(\"日本語のテキスト\", \"テキスト\");\n}\n",
+    "a {\n  color: 5;\n}\n"
+);
+test!(
+    str_slice_cjk,
+    "a {\n  color: str-slice(\"日本語のテキスト\", 5, -2);\n}\n",
+    "@charset \"UTF-8\";\na {\n  color: \"テキス\";\n}\n"
+);
+test!(
+    str_slice_emoji_negative_start,
+    "a {\n  color: str-slice(\"a👭b👭\", -3);\n}\n",
+    "@charset \"UTF-8\";\na {\n  color: \"👭b👭\";\n}\n"
+);
+test!(
+    str_length_emoji_and_cjk,
+    "a {\n  color: str-length(\"👭日本\");\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    str_insert_after_cjk,
+    "a {\n  color: str-insert(\"日本語\", \"x\", 3);\n}\n",
+    "@charset \"UTF-8\";\na {\n  color: \"日本x語\";\n}\n"
+);
+test!(
+    str_index_then_slice_round_trips_multibyte,
+    "a {\n  $s: \"ça👭va\";\n  color: str-slice($s, str-index($s, \"👭\"));\n}\n",
+    "@charset \"UTF-8\";\na {\n  color: \"👭va\";\n}\n"
+);
+
+#[cfg(feature = "random")]
+fn compile_unique_id(options: &grass::Options) -> String {
+    let css = grass::from_string("a {\n  color: unique-id();\n}\n".to_string(), options)
+        .expect("failed to compile unique-id()");
+    css.trim_start_matches("a {\n  color: ")
+        .trim_end_matches(";\n}\n")
+        .to_owned()
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_starts_with_prefix() {
+    for _ in 0..100 {
+        let id = compile_unique_id(&grass::Options::default());
+        assert!(id.starts_with('u'), "{}", id);
+        assert_eq!(id.len(), 8, "{}", id);
+        assert!(id.chars().all(|c| c.is_ascii_alphanumeric()), "{}", id);
+    }
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_custom_prefix_and_length() {
+    let id = compile_unique_id(
+        &grass::Options::default()
+            .unique_id_prefix("anim-")
+            .unique_id_length(16),
+    );
+    assert!(id.starts_with("anim-"), "{}", id);
+    assert_eq!(id.len(), 21, "{}", id);
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_without_prefix_starts_with_letter() {
+    for _ in 0..100 {
+        let id = compile_unique_id(&grass::Options::default().unique_id_prefix(""));
+        assert!(id.starts_with(|c: char| c.is_ascii_lowercase()), "{}", id);
+        assert_eq!(id.len(), 7, "{}", id);
+    }
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_dash_prefix_is_followed_by_letter() {
+    for _ in 0..100 {
+        let id = compile_unique_id(&grass::Options::default().unique_id_prefix("-"));
+        assert!(
+            id[1..].starts_with(|c: char| c.is_ascii_lowercase()),
+            "{}",
+            id
+        );
+    }
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_prefix_without_random_characters() {
+    assert_eq!(
+        compile_unique_id(
+            &grass::Options::default()
+                .unique_id_prefix("anim")
+                .unique_id_length(0)
+        ),
+        "anim"
+    );
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_invalid_prefix() {
+    for prefix in ["1a", "-1", "a.b", "a b"] {
+        let err = grass::from_string(
+            "a {\n  color: unique-id();\n}\n".to_string(),
+            &grass::Options::default().unique_id_prefix(prefix),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(&format!(
+                "unique-id() can't generate a valid identifier starting with \"{}\".",
+                prefix
+            )),
+            "{}",
+            err
+        );
+    }
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_empty_prefix_without_random_characters() {
+    for prefix in ["", "-"] {
+        let err = grass::from_string(
+            "a {\n  color: unique-id();\n}\n".to_string(),
+            &grass::Options::default()
+                .unique_id_prefix(prefix)
+                .unique_id_length(0),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("without any random characters."),
+            "{}",
+            err
+        );
+    }
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_seed_is_reproducible() {
+    let input = "a {\n  color: unique-id();\n  color: unique-id();\n}\n";
+    let options = grass::Options::default().unique_id_seed(Some(42));
+
+    let first = grass::from_string(input.to_string(), &options).unwrap();
+    let second = grass::from_string(input.to_string(), &options).unwrap();
+    assert_eq!(first, second);
+
+    let ids: Vec<&str> = first
+        .lines()
+        .filter_map(|line| line.strip_prefix("  color: "))
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_seed_output_is_stable() {
+    assert_eq!(
+        grass::from_string(
+            "a {\n  color: unique-id();\n  color: unique-id();\n}\n".to_string(),
+            &grass::Options::default().unique_id_seed(Some(42))
+        )
+        .unwrap(),
+        "a {\n  color: uOrJ8xbW;\n  color: uWFWpzwi;\n}\n"
+    );
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn unique_id_different_seeds() {
+    assert_ne!(
+        compile_unique_id(&grass::Options::default().unique_id_seed(Some(1))),
+        compile_unique_id(&grass::Options::default().unique_id_seed(Some(2)))
+    );
+}
+test!(
+    string_split_space,
+    "@use \"sass:string\";\na {\n  color: string.split(\"Segoe UI Emoji\", \" \");\n}\n",
+    "a {\n  color: [\"Segoe\", \"UI\", \"Emoji\"];\n}\n"
+);
+test!(
+    string_split_unquoted,
+    "@use \"sass:string\";\na {\n  color: string.split(a-b-c, \"-\");\n}\n",
+    "a {\n  color: [a, b, c];\n}\n"
+);
+test!(
+    string_split_limit,
+    "@use \"sass:string\";\na {\n  color: string.split(\"Segoe UI Emoji\", \" \", $limit: 1);\n}\n",
+    "a {\n  color: [\"Segoe\", \"UI Emoji\"];\n}\n"
+);
+test!(
+    string_split_empty_separator,
+    "@use \"sass:string\";\na {\n  color: string.split(\"a👭b\", \"\");\n}\n",
+    "@charset \"UTF-8\";\na {\n  color: [\"a\", \"👭\", \"b\"];\n}\n"
+);
+test!(
+    string_split_empty_string,
+    "@use \"sass:string\";\na {\n  color: inspect(string.split(\"\", \",\"));\n}\n",
+    "a {\n  color: [];\n}\n"
+);
+test!(
+    string_split_no_match,
+    "@use \"sass:string\";\na {\n  color: string.split(\"abc\", \",\");\n}\n",
+    "a {\n  color: [\"abc\"];\n}\n"
+);
+test!(
+    string_split_leading_and_trailing_separator,
+    "@use \"sass:string\";\na {\n  color: string.split(\",a,\", \",\");\n}\n",
+    "a {\n  color: [\"\", \"a\", \"\"];\n}\n"
+);
+error!(
+    string_split_limit_zero,
+    "@use \"sass:string\";\na {\n  color: string.split(\"a b\", \" \", 0);\n}\n",
+    "Error: $limit: Must be 1 or greater, was 0."
+);
+error!(
+    string_split_separator_not_string,
+    "@use \"sass:string\";\na {\n  color: string.split(\"a b\", 1);\n}\n",
+    "Error: $separator: 1 is not a string."
+);
+error!(
+    string_module_excludes_global_only_names,
+    "@use 'sass:string';\na {\n  color: string.str-length(abc);\n}\n", "Error: Undefined function."
+);
+test!(
+    string_module_slice,
+    "@use \"sass:string\";\na {\n  color: string.slice(\"abcd\", 2, 3);\n}\n",
+    "a {\n  color: \"bc\";\n}\n"
+);
+test!(
+    string_module_index,
+    "@use \"sass:string\";\na {\n  color: string.index(\"abcd\", \"c\");\n}\n",
+    "a {\n  color: 3;\n}\n"
+);
+test!(
+    string_module_insert,
+    "@use \"sass:string\";\na {\n  color: string.insert(\"abcd\", \"X\", 2);\n}\n",
+    "a {\n  color: \"aXbcd\";\n}\n"
+);
+test!(
+    string_module_length,
+    "@use \"sass:string\";\na {\n  color: string.length(\"abcd\");\n}\n",
+    "a {\n  color: 4;\n}\n"
+);
+test!(
+    string_module_quote_and_unquote,
+    "@use \"sass:string\";\na {\n  color: string.quote(abc) string.unquote(\"abc\");\n}\n",
+    "a {\n  color: \"abc\" abc;\n}\n"
+);
+test!(
+    string_module_case,
+    "@use \"sass:string\";\na {\n  color: string.to-upper-case(abc) string.to-lower-case(ABC);\n}\n",
+    "a {\n  color: ABC abc;\n}\n"
+);
+#[cfg(feature = "random")]
+#[test]
+fn string_module_unique_id() {
+    let css = grass::from_string(
+        "@use \"sass:string\";\na {\n  color: string.unique-id();\n}\n".to_string(),
+        &grass::Options::default().unique_id_seed(Some(1)),
+    )
+    .unwrap();
+    let id = compile_unique_id(&grass::Options::default().unique_id_seed(Some(1)));
+    assert_eq!(css, format!("a {{\n  color: {};\n}}\n", id));
+}
@@ -63,3 +63,63 @@ test!(
     }",
     "@supports (position: sticky) {\n  a {\n    color: red;\n  }\n}\n\na {\n  color: red;\n}\n"
 );
+test!(
+    not_condition,
+    "@supports not (display: grid) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports not (display: grid) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    and_conditions,
+    "@supports (display: grid) and (gap: 1px) and (not (display: inline-grid)) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (display: grid) and (gap: 1px) and (not (display: inline-grid)) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    or_conditions,
+    "@supports (display: flex) OR (display: -webkit-box) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (display: flex) or (display: -webkit-box) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    grouped_conditions,
+    "@supports ((display: grid) or (display: flex)) and (gap: 1px) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports ((display: grid) or (display: flex)) and (gap: 1px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+error!(
+    mixed_and_or_without_parens,
+    "@supports (a: b) and (c: d) or (e: f) {}", "Error: expected \"{\"."
+);
+test!(
+    declaration_is_evaluated,
+    "$prop: display;\n$value: grid;\n@supports ($prop: $value) and (width: 1px + 2px) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (display: grid) and (width: 3px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    declaration_whitespace_is_normalized,
+    "@supports (  display  :  grid  ) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (display: grid) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    custom_property_value_is_not_evaluated,
+    "@supports (--a: 1 + 2) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (--a: 1 + 2) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    interpolated_declaration,
+    "$prop: position;\n@supports (#{$prop}: sticky) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (position: sticky) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    interpolated_condition,
+    "$condition: \"(display: grid)\";\n@supports #{$condition} and (gap: 1px) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports (display: grid) and (gap: 1px) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    selector_function,
+    "@supports selector(a > b) {\n  a {\n    color: red;\n  }\n}\n",
+    "@supports selector(a > b) {\n  a {\n    color: red;\n  }\n}\n"
+);
+test!(
+    nested_style_rules_bubble,
+    "a {\n  @supports (display: grid) {\n    color: red;\n\n    b {\n      color: blue;\n    }\n  }\n}\n",
+    "@supports (display: grid) {\n  a {\n    color: red;\n  }\n\n  a b {\n    color: blue;\n  }\n}\n"
+);
+error!(missing_condition, "@supports {}", "Error: expected \"(\".");
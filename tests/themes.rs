@@ -0,0 +1,238 @@
+use std::fs;
+
+use grass::{compile_themes, Options};
+use tempfile::tempdir;
+
+use logger::Collector;
+
+mod logger;
+
+#[test]
+fn compiles_each_theme() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(
+        &main,
+        "$primary: black !default;\n$radius: 0 !default;\na { color: $primary; border-radius: $radius; }",
+    )
+    .unwrap();
+
+    let themes = compile_themes(
+        main.to_str().unwrap(),
+        &[
+            &[("primary", "red")],
+            &[("primary", "#00f"), ("radius", "2px * 2")],
+            &[],
+        ],
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        themes,
+        [
+            "a {\n  color: red;\n  border-radius: 0;\n}\n",
+            "a {\n  color: #00f;\n  border-radius: 4px;\n}\n",
+            "a {\n  color: black;\n  border-radius: 0;\n}\n",
+        ]
+    );
+}
+
+#[test]
+fn configures_imported_files() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("_variables.scss"),
+        "$primary: black !default;",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "@import \"variables\";\na { color: $primary; }").unwrap();
+
+    let themes = compile_themes(
+        main.to_str().unwrap(),
+        &[&[("primary", "red")], &[("$primary", "blue")]],
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        themes,
+        ["a {\n  color: red;\n}\n", "a {\n  color: blue;\n}\n"]
+    );
+}
+
+#[test]
+fn accepts_maps_and_lists() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(
+        &main,
+        "$sizes: (small: 1px) !default;\n$fonts: a !default;\na { width: map-get($sizes, small); font-family: $fonts; }",
+    )
+    .unwrap();
+
+    let themes = compile_themes(
+        main.to_str().unwrap(),
+        &[&[("sizes", "(small: 2px, large: 4px)"), ("fonts", "b, c")]],
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(themes, ["a {\n  width: 2px;\n  font-family: b, c;\n}\n"]);
+}
+
+#[test]
+fn error_for_variable_without_default() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "$primary: black;\na { color: $primary; }").unwrap();
+
+    let err = compile_themes(
+        main.to_str().unwrap(),
+        &[&[("primary", "red")]],
+        &Options::default(),
+    )
+    .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("This variable was not declared with !default in the entrypoint."));
+}
+
+#[test]
+fn error_for_invalid_value() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "$primary: black !default;").unwrap();
+
+    assert!(compile_themes(
+        main.to_str().unwrap(),
+        &[&[("primary", "1px +")]],
+        &Options::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn error_for_configuring_variable_twice() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "$primary: black !default;").unwrap();
+
+    let err = compile_themes(
+        main.to_str().unwrap(),
+        &[&[("primary", "red"), ("primary", "blue")]],
+        &Options::default(),
+    )
+    .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("The same variable may only be configured once."));
+}
+
+#[test]
+fn missing_entrypoint() {
+    let dir = tempdir().unwrap();
+
+    assert!(compile_themes(
+        dir.path().join("main.scss").to_str().unwrap(),
+        &[&[]],
+        &Options::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn error_for_value_that_is_not_a_single_expression() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "$primary: black !default;\n$radius: 0 !default;").unwrap();
+
+    for value in ["red), $radius: (1px", "red; } b { c: d", "red)"] {
+        assert!(compile_themes(
+            main.to_str().unwrap(),
+            &[&[("primary", value)]],
+            &Options::default(),
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn shares_modules_that_do_not_depend_on_the_theme() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("_lib.scss"),
+        "@debug lib;\n@function double($n) { @return $n * 2; }",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("_styles.scss"),
+        "@debug styles;\nb { c: d; }",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(
+        &main,
+        "@use \"lib\";\n@use \"styles\";\n$primary: black !default;\na { color: $primary; width: lib.double(2px); }",
+    )
+    .unwrap();
+
+    let collector = Collector::default();
+    let themes = compile_themes(
+        main.to_str().unwrap(),
+        &[&[("primary", "red")], &[("primary", "blue")]],
+        &Options::default().logger(&collector),
+    )
+    .unwrap();
+
+    assert_eq!(
+        themes,
+        [
+            "b {\n  c: d;\n}\n\na {\n  color: red;\n  width: 4px;\n}\n",
+            "b {\n  c: d;\n}\n\na {\n  color: blue;\n  width: 4px;\n}\n",
+        ]
+    );
+
+    let messages = collector.debug_messages();
+    assert_eq!(messages.iter().filter(|m| m.ends_with(" lib")).count(), 1);
+    assert_eq!(
+        messages.iter().filter(|m| m.ends_with(" styles")).count(),
+        2
+    );
+}
+
+#[test]
+fn evaluates_modules_that_use_a_configured_module_for_each_theme() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("_config.scss"), "$primary: black !default;").unwrap();
+    fs::write(
+        dir.path().join("_colors.scss"),
+        "@use \"config\";\n$link: config.$primary;",
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(
+        &main,
+        "@forward \"config\";\n@use \"colors\";\na { color: colors.$link; }",
+    )
+    .unwrap();
+
+    let themes = compile_themes(
+        main.to_str().unwrap(),
+        &[&[], &[("primary", "red")], &[("primary", "blue")]],
+        &Options::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        themes,
+        [
+            "a {\n  color: black;\n}\n",
+            "a {\n  color: red;\n}\n",
+            "a {\n  color: blue;\n}\n",
+        ]
+    );
+}
@@ -0,0 +1,57 @@
+use std::thread;
+
+use grass::{CancellationToken, Error, FileStats, Options, Stats};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn public_types_are_send_and_sync() {
+    assert_send_sync::<Options>();
+    assert_send_sync::<Error>();
+    assert_send_sync::<Box<Error>>();
+    assert_send_sync::<Stats>();
+    assert_send_sync::<FileStats>();
+    assert_send_sync::<CancellationToken>();
+}
+
+#[test]
+fn options_are_shared_between_threads() {
+    let options = Options::default().style(grass::OutputStyle::Compressed);
+
+    let outputs: Vec<String> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let options = &options;
+                scope.spawn(move || {
+                    grass::from_string(
+                        format!(
+                            "@use \"sass:math\";\n$i: {};\n.a-#{{$i}} {{ width: math.div(100%, $i + 1); }}",
+                            i
+                        ),
+                        options,
+                    )
+                    .unwrap()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    assert_eq!(outputs[0], ".a-0{width:100%}");
+    assert_eq!(outputs[3], ".a-3{width:25%}");
+}
+
+#[test]
+fn errors_are_sent_between_threads() {
+    let err = thread::spawn(|| {
+        grass::from_string("a { color: $a; }".to_owned(), &Options::default()).unwrap_err()
+    })
+    .join()
+    .unwrap();
+
+    assert!(err.to_string().contains("Undefined variable."));
+}
@@ -191,7 +191,6 @@ error!(
     "a {\n  color: (1 / 1em);\n}\n", "Error: 1em^-1 isn't a valid CSS value."
 );
 error!(
-    #[ignore = "non-comparable inverse units"]
     display_single_div_with_non_comparable_numerator,
     "a {\n  color: (1px / 1em);\n}\n", "Error: 1px/em isn't a valid CSS value."
 );
@@ -208,6 +207,101 @@ error!(
     display_single_div_with_none_numerator_percent,
     "a {\n  color: (35 / 7%);\n}\n", "Error: 5%^-1 isn't a valid CSS value."
 );
+error!(
+    display_div_with_mul_denominator,
+    "@use 'sass:math';\na {\n  color: math.div(1px, 1s * 1em);\n}\n",
+    "Error: 1px/s*em isn't a valid CSS value."
+);
+test!(
+    inspect_div_with_none_numerator_mul_denominator,
+    "@use 'sass:math';\na {\n  color: inspect(math.div(1, 1px * 1s));\n}\n",
+    "a {\n  color: 1(px*s)^-1;\n}\n"
+);
+test!(
+    unit_fn_non_comparable_div,
+    "@use 'sass:math';\na {\n  color: unit(math.div(1px, 1s));\n}\n",
+    "a {\n  color: \"px/s\";\n}\n"
+);
+test!(
+    mul_then_div_cancels_unit,
+    "a {\n  color: (10px * 3s) / 5s;\n}\n",
+    "a {\n  color: 6px;\n}\n"
+);
+test!(
+    div_then_mul_cancels_unit,
+    "@use 'sass:math';\na {\n  color: math.div(1px, 2s) * 4s;\n}\n",
+    "a {\n  color: 2px;\n}\n"
+);
+test!(
+    mul_by_inverse_cancels_unit,
+    "@use 'sass:math';\na {\n  color: 2px * math.div(1, 1px);\n}\n",
+    "a {\n  color: 2;\n}\n"
+);
+test!(
+    cancelled_units_are_converted,
+    "@use 'sass:math';\na {\n  color: math.div(1in * 1s, 1px);\n}\n",
+    "a {\n  color: 96s;\n}\n"
+);
+test!(
+    cancel_compound_numerator_and_denominator,
+    "@use 'sass:math';\na {\n  color: unit(math.div(1px * 1s, 1em * 1ms));\n}\n",
+    "a {\n  color: \"px/em\";\n}\n"
+);
+test!(
+    compound_units_cancel_entirely,
+    "@use 'sass:math';\na {\n  color: math.div(1px, 1s) * math.div(1s, 1px);\n}\n",
+    "a {\n  color: 1;\n}\n"
+);
+test!(
+    add_same_compound_units,
+    "@use 'sass:math';\na {\n  color: inspect(math.div(1px, 1s) + math.div(2px, 1s));\n}\n",
+    "a {\n  color: 3px/s;\n}\n"
+);
+test!(
+    nan_times_unit,
+    "a {\n  color: (0 / 0) * 1px;\n}\n",
+    "a {\n  color: NaNpx;\n}\n"
+);
+test!(
+    inches_equal_to_centimeters,
+    "a {\n  color: 1in == 2.54cm;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    inches_equal_to_millimeters,
+    "a {\n  color: 1in == 25.4mm;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    inches_equal_to_quarter_millimeters,
+    "a {\n  color: 1in == 101.6q;\n}\n",
+    "a {\n  color: true;\n}\n"
+);
+test!(
+    x_divided_by_dpi,
+    "@use 'sass:math';\na {\n  color: math.div(1x, 1dpi);\n}\n",
+    "a {\n  color: 96;\n}\n"
+);
+test!(
+    math_min_converts_units,
+    "@use 'sass:math';\na {\n  color: math.min(1in, 95px);\n}\n",
+    "a {\n  color: 95px;\n}\n"
+);
+test!(
+    add_comparable_compound_units,
+    "@use 'sass:math';\na {\n  color: inspect(math.div(1px, 1s) + math.div(1px, 1ms));\n}\n",
+    "a {\n  color: 1001px/s;\n}\n"
+);
+test!(
+    add_compound_units_different_order,
+    "a {\n  color: inspect(1px * 1s + 1s * 1in);\n}\n",
+    "a {\n  color: 97px*s;\n}\n"
+);
+test!(
+    compare_comparable_compound_units,
+    "@use 'sass:math';\na {\n  color: math.div(1px, 1s) == math.div(1px, 1000ms);\n}\n",
+    "a {\n  color: true;\n}\n"
+);
 
 macro_rules! test_unit_addition {
     ($u1:ident, $u2:ident, $out:literal) => {
@@ -312,11 +406,19 @@ test_unit_addition!(kHz, kHz, "2");
 test_unit_addition!(dpi, dpi, "2");
 test_unit_addition!(dpi, dpcm, "3.54");
 test_unit_addition!(dpi, dppx, "97");
+test_unit_addition!(dpi, x, "97");
 
 test_unit_addition!(dpcm, dpi, "1.3937007874");
 test_unit_addition!(dpcm, dpcm, "2");
 test_unit_addition!(dpcm, dppx, "38.7952755906");
+test_unit_addition!(dpcm, x, "38.7952755906");
 
 test_unit_addition!(dppx, dpi, "1.0104166667");
 test_unit_addition!(dppx, dpcm, "1.0264583333");
 test_unit_addition!(dppx, dppx, "2");
+test_unit_addition!(dppx, x, "2");
+
+test_unit_addition!(x, dpi, "1.0104166667");
+test_unit_addition!(x, dpcm, "1.0264583333");
+test_unit_addition!(x, dppx, "2");
+test_unit_addition!(x, x, "2");
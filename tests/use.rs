@@ -1,4 +1,6 @@
-use std::io::Write;
+use std::{fs, io::Write, path::Path};
+
+use tempfile::tempdir;
 
 #[macro_use]
 mod macros;
@@ -249,6 +251,45 @@ fn use_with_variable_never_used() {
     );
 }
 
+#[test]
+fn use_with_variable_not_default_points_to_config() {
+    let input = "@use \"use_with_variable_not_default_points_to_config\" with ($a: red, $b: foo);";
+    tempfile!(
+        "use_with_variable_not_default_points_to_config.scss",
+        "$a: green !default;\n$b: 5;"
+    );
+
+    let err = grass::from_string(input.to_string(), &grass::Options::default())
+        .expect_err("did not fail")
+        .to_string();
+
+    assert!(err.contains(
+        "\n  │                                                                      ^^^^^^^\n"
+    ));
+    assert!(err.ends_with("./stdin:1:70\n"));
+}
+
+#[test]
+fn use_with_trailing_comma() {
+    let input = "@use \"use_with_trailing_comma\" as lib with ($a: red, $b: blue , );\na {\n color: lib.$a lib.$b;\n}";
+    tempfile!(
+        "use_with_trailing_comma.scss",
+        "$a: green !default;\n$b: green !default;"
+    );
+    assert_eq!(
+        "a {\n  color: red blue;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_only_comma() {
+    let input = "@use \"use_with_only_comma\" with (,);";
+    tempfile!("use_with_only_comma.scss", "");
+
+    assert_err!("Error: expected \"$\".", input);
+}
+
 #[test]
 fn use_with_same_variable_multiple_times() {
     let input = "@use \"use_with_same_variable_multiple_times\" as foo with ($a: b, $a: c);";
@@ -442,3 +483,203 @@ fn use_variable_declaration_between_use() {
         &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
     );
 }
+
+#[test]
+fn use_members_and_configuration_normalize_underscores() {
+    let input = "@use \"use_members_and_configuration_normalize_underscores\" as lib with ($base_color: blue);\na {\n  color: lib.$base-color lib.do-it();\n  @include lib.mix-it;\n}\n";
+    tempfile!(
+        "use_members_and_configuration_normalize_underscores.scss",
+        "$base-color: red !default;\n@function do_it() {\n  @return 1;\n}\n@mixin mix_it {\n  x: y;\n}\n"
+    );
+    assert_eq!(
+        "a {\n  color: blue 1;\n  x: y;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_namespace_is_file_name_without_partial_prefix_or_extension() {
+    let input = "@use \"use_namespace_dir/_use_namespace_file.scss\";\na {\n  color: use_namespace_file.$a;\n}\n";
+    tempfile!(
+        "_use_namespace_file.scss",
+        "$a: red;",
+        dir = "use_namespace_dir"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn module_used_twice_is_emitted_once() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("_shared.scss"),
+        "$a: red;\nb {\n  c: d;\n}\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("_first.scss"), "@use \"shared\";").unwrap();
+    fs::write(dir.path().join("_second.scss"), "@use \"shared\";").unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(
+        &main,
+        "@use \"first\";\n@use \"second\";\n@use \"shared\";\na {\n  color: shared.$a;\n}\n",
+    )
+    .unwrap();
+    assert_eq!(
+        "b {\n  c: d;\n}\n\na {\n  color: red;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+}
+
+#[test]
+fn module_already_loaded_cannot_be_configured() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("_shared.scss"), "$a: red !default;").unwrap();
+    fs::write(dir.path().join("_first.scss"), "@use \"shared\";").unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "@use \"first\";\n@use \"shared\" with ($a: blue);\n").unwrap();
+    match grass::from_path(main.to_str().unwrap(), &grass::Options::default()) {
+        Err(e) => assert!(
+            e.to_string().starts_with(
+                "Error: This module was already loaded, so it can't be configured using \"with\".\n"
+            ),
+            "{}",
+            e
+        ),
+        Ok(..) => panic!("did not fail"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn module_used_through_symlink_is_emitted_once() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("_shared.scss"), "b {\n  c: d;\n}\n").unwrap();
+    std::os::unix::fs::symlink(
+        dir.path().join("_shared.scss"),
+        dir.path().join("_link.scss"),
+    )
+    .unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "@use \"shared\";\n@use \"link\";\n").unwrap();
+    assert_eq!(
+        "b {\n  c: d;\n}\n",
+        &grass::from_path(main.to_str().unwrap(), &grass::Options::default()).unwrap()
+    );
+    assert_eq!(
+        "b {\n  c: d;\n}\n\nb {\n  c: d;\n}\n",
+        &grass::from_path(
+            main.to_str().unwrap(),
+            &grass::Options::default().canonicalize_imports(false)
+        )
+        .unwrap()
+    );
+}
+
+/// A file system on which file names are case-insensitive
+#[derive(Debug)]
+struct CaseInsensitiveFs;
+
+impl grass::Fs for CaseInsensitiveFs {
+    fn is_dir(&self, _path: &Path) -> bool {
+        false
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.to_string_lossy().to_lowercase() == "_shared.scss"
+    }
+
+    fn read(&self, _path: &Path) -> std::io::Result<Vec<u8>> {
+        Ok(b"b {\n  c: d;\n}\n".to_vec())
+    }
+
+    fn canonicalize(&self, path: &Path) -> std::io::Result<std::path::PathBuf> {
+        Ok(path.to_string_lossy().to_lowercase().into())
+    }
+}
+
+#[test]
+fn module_used_with_different_case_is_emitted_once() {
+    let input = "@use \"shared\";\n@use \"Shared\" as other;\n";
+    assert_eq!(
+        "b {\n  c: d;\n}\n",
+        &grass::from_string(
+            input.to_string(),
+            &grass::Options::default().fs(&CaseInsensitiveFs).quiet(true)
+        )
+        .expect(input)
+    );
+}
+
+#[test]
+fn module_used_with_different_case_warns() {
+    let input = "@use \"shared\";\n@use \"Shared\" as other;\n";
+    match grass::from_string(
+        input.to_string(),
+        &grass::Options::default()
+            .fs(&CaseInsensitiveFs)
+            .fatal_warnings(true),
+    ) {
+        Err(e) => assert!(
+            e.to_string().starts_with(
+                "Error: \"_Shared.scss\" was previously loaded as \"_shared.scss\", which differs only in case.\n"
+            ),
+            "{}",
+            e
+        ),
+        Ok(..) => panic!("did not fail"),
+    }
+}
+
+#[test]
+fn use_with_overrides_variable_already_assigned() {
+    let input = "@use \"use_with_overrides_variable_already_assigned\" as lib with ($a: red);\na {\n color: lib.$a;\n}";
+    tempfile!(
+        "use_with_overrides_variable_already_assigned.scss",
+        "$a: green;\n$a: blue !default;"
+    );
+    assert_eq!(
+        "a {\n  color: red;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_null_uses_default() {
+    let input =
+        "@use \"use_with_null_uses_default\" as lib with ($a: null);\na {\n color: lib.$a;\n}";
+    tempfile!("use_with_null_uses_default.scss", "$a: green !default;");
+    assert_eq!(
+        "a {\n  color: green;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
+
+#[test]
+fn use_with_default_inside_control_flow_is_not_configurable() {
+    let input = "@use \"use_with_default_inside_control_flow_is_not_configurable\" with ($a: red);";
+    tempfile!(
+        "use_with_default_inside_control_flow_is_not_configurable.scss",
+        "@if true {\n  $a: green !default;\n}"
+    );
+
+    assert_err!(
+        "Error: This variable was not declared with !default in the @used module.",
+        input
+    );
+}
+
+#[test]
+fn use_as_star_default_inside_rule_sees_module_variable() {
+    let input = "@use \"use_as_star_default_inside_rule_sees_module_variable\" as *;\na {\n  $a: red !default;\n  color: $a;\n}";
+    tempfile!(
+        "use_as_star_default_inside_rule_sees_module_variable.scss",
+        "$a: green;"
+    );
+    assert_eq!(
+        "a {\n  color: green;\n}\n",
+        &grass::from_string(input.to_string(), &grass::Options::default()).expect(input)
+    );
+}
@@ -428,3 +428,61 @@ test!(
     }",
     "a {\n  color: red;\n}\n"
 );
+test!(
+    underscore_declaration_hyphen_reference,
+    "$a_b: red;\na {\n  color: $a-b;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+test!(
+    global_flag_normalizes_underscores,
+    "$a-b: red;\na {\n  $a_b: blue !global;\n}\nb {\n  color: $a-b;\n}\n",
+    "b {\n  color: blue;\n}\n"
+);
+test!(
+    each_and_for_variables_normalize_underscores,
+    "a {\n  @each $k_a, $v_a in (a: 1) {\n    color: $k-a $v-a;\n  }\n  @for $i_x from 1 through 1 {\n    color: $i-x;\n  }\n}\n",
+    "a {\n  color: a 1;\n  color: 1;\n}\n"
+);
+test!(
+    default_var_inside_rule_global_already_set,
+    "$a: global;\na {\n  $a: local !default;\n  color: $a;\n}\nb {\n  color: $a;\n}\n",
+    "a {\n  color: global;\n}\n\nb {\n  color: global;\n}\n"
+);
+test!(
+    default_var_inside_rule_global_null,
+    "$a: null;\na {\n  $a: local !default;\n  color: $a;\n}\nb {\n  color: inspect($a);\n}\n",
+    "a {\n  color: local;\n}\n\nb {\n  color: null;\n}\n"
+);
+test!(
+    default_var_inside_function_global_already_set,
+    "@function foo() {\n  $a: local !default;\n  @return $a;\n}\n$a: global;\na {\n  color: foo();\n}\n",
+    "a {\n  color: global;\n}\n"
+);
+test!(
+    default_var_inside_mixin_does_not_see_include_scope,
+    "@mixin foo {\n  $a: mixin !default;\n  color: $a;\n}\na {\n  $a: rule;\n  @include foo;\n}\n",
+    "a {\n  color: mixin;\n}\n"
+);
+test!(
+    duplicate_default_flag,
+    "$a: red !default !default;\na {\n  color: $a;\n}\n",
+    "a {\n  color: red;\n}\n"
+);
+error!(
+    duplicate_default_flag_deprecated,
+    "$a: red !default !default;",
+    "Error: !default should only be written once for each variable.",
+    grass::Options::default()
+        .compat(grass::Compat::DartSass(grass::Version::new(1, 62, 0)))
+        .fatal_warnings(true)
+);
+error!(
+    duplicate_global_flag_removed_in_dart_sass_2,
+    "$a: red !global !global;",
+    "Error: !global should only be written once for each variable.",
+    grass::Options::default().compat(grass::Compat::DartSass(grass::Version::new(2, 0, 0)))
+);
+error!(
+    default_flag_is_case_sensitive,
+    "$a: red !DEFAULT;", "Error: Invalid flag name."
+);
@@ -0,0 +1,163 @@
+#![cfg(feature = "watch")]
+
+use std::{fs, path::Path, thread, time::Duration};
+
+use grass::{
+    watch::{Backend, Watcher},
+    Options,
+};
+use tempfile::tempdir;
+
+/// Rewrites a file, making sure that its modification time changes even on
+/// file systems with a coarse timestamp resolution
+fn touch(path: &Path, contents: &str) {
+    let before = fs::metadata(path).and_then(|m| m.modified()).ok();
+    loop {
+        fs::write(path, contents).unwrap();
+        if fs::metadata(path).and_then(|m| m.modified()).ok() != before {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Changes a file from another thread, once the watcher has had time to
+/// start watching it
+fn touch_later(path: &Path, contents: &'static str) {
+    let path = path.to_path_buf();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        touch(&path, contents);
+    });
+}
+
+fn recompiles_when_dependency_changes(backend: Backend) {
+    let dir = tempdir().unwrap();
+    let partial = dir.path().join("_colors.scss");
+    fs::write(&partial, "$c: red;").unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "@import \"colors\";\na { color: $c; }").unwrap();
+
+    let mut outputs = Vec::new();
+
+    Watcher::new(&main)
+        .backend(backend)
+        .run(&Options::default(), |result| {
+            outputs.push(result.unwrap());
+            if outputs.len() == 1 {
+                touch_later(&partial, "$c: blue;");
+            }
+            outputs.len() < 2
+        })
+        .unwrap();
+
+    assert_eq!(
+        outputs,
+        ["a {\n  color: red;\n}\n", "a {\n  color: blue;\n}\n"]
+    );
+}
+
+#[test]
+fn native_recompiles_when_dependency_changes() {
+    recompiles_when_dependency_changes(Backend::Native);
+}
+
+#[test]
+fn poll_recompiles_when_dependency_changes() {
+    recompiles_when_dependency_changes(Backend::Poll(Duration::from_millis(20)));
+}
+
+#[test]
+fn recompiles_when_shadowing_file_is_created() {
+    let dir = tempdir().unwrap();
+    fs::create_dir(dir.path().join("lib")).unwrap();
+    fs::write(dir.path().join("lib/_colors.scss"), "$c: red;").unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "@import \"colors\";\na { color: $c; }").unwrap();
+
+    let load_path = dir.path().join("lib");
+    let shadowing = dir.path().join("_colors.scss");
+    let mut outputs = Vec::new();
+
+    Watcher::new(&main)
+        .backend(Backend::Poll(Duration::from_millis(20)))
+        .run(&Options::default().load_path(&load_path), |result| {
+            outputs.push(result.unwrap());
+            if outputs.len() == 1 {
+                touch_later(&shadowing, "$c: blue;");
+            }
+            outputs.len() < 2
+        })
+        .unwrap();
+
+    assert_eq!(
+        outputs,
+        ["a {\n  color: red;\n}\n", "a {\n  color: blue;\n}\n"]
+    );
+}
+
+#[test]
+fn recompiles_after_error_is_fixed() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "a { color: $c; }").unwrap();
+
+    let mut results = Vec::new();
+
+    Watcher::new(&main)
+        .run(&Options::default(), |result| {
+            results.push(result.map_err(|e| e.to_string()));
+            if results.len() == 1 {
+                touch_later(&main, "a { color: red; }");
+            }
+            results.len() < 2
+        })
+        .unwrap();
+
+    assert!(results[0]
+        .as_ref()
+        .unwrap_err()
+        .contains("Undefined variable."));
+    assert_eq!(results[1].as_deref(), Ok("a {\n  color: red;\n}\n"));
+}
+
+#[test]
+fn change_during_compilation_is_not_missed() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "a { color: red; }").unwrap();
+
+    let mut outputs = Vec::new();
+
+    Watcher::new(&main)
+        .run(&Options::default(), |result| {
+            outputs.push(result.unwrap());
+            if outputs.len() == 1 {
+                // before the watcher has started watching
+                touch(&main, "a { color: blue; }");
+            }
+            outputs.len() < 2
+        })
+        .unwrap();
+
+    assert_eq!(
+        outputs,
+        ["a {\n  color: red;\n}\n", "a {\n  color: blue;\n}\n"]
+    );
+}
+
+#[test]
+fn stops_when_callback_returns_false() {
+    let dir = tempdir().unwrap();
+    let main = dir.path().join("main.scss");
+    fs::write(&main, "a { color: red; }").unwrap();
+
+    let mut compiles = 0;
+    Watcher::new(&main)
+        .run(&Options::default(), |_| {
+            compiles += 1;
+            false
+        })
+        .unwrap();
+    assert_eq!(compiles, 1);
+}